@@ -1,3 +1,4 @@
+pub mod policy;
 pub mod usecase;
 
 #[cfg(test)]
@@ -0,0 +1,71 @@
+use domain::model::{
+    role::{CategoryScope, Permission, PermissionAction, PermissionDenied, PermissionResource},
+    user::User,
+};
+
+fn denied(resource: PermissionResource, action: PermissionAction) -> PermissionDenied {
+    PermissionDenied { resource, action }
+}
+
+pub fn authorize(
+    actor: &User,
+    resource: PermissionResource,
+    action: PermissionAction,
+) -> Result<(), PermissionDenied> {
+    if !actor.can(resource, action, None) {
+        return Err(denied(resource, action));
+    }
+
+    Ok(())
+}
+
+pub fn authorize_category(
+    actor: &User,
+    resource: PermissionResource,
+    action: PermissionAction,
+    category_id: &str,
+) -> Result<(), PermissionDenied> {
+    if !actor.can(resource, action, Some(category_id)) {
+        return Err(denied(resource, action));
+    }
+
+    Ok(())
+}
+
+pub fn authorize_grant(
+    actor: &User,
+    is_super_administrator: bool,
+    permissions: &[Permission],
+) -> Result<(), PermissionDenied> {
+    if actor.role.is_super_administrator {
+        return Ok(());
+    }
+
+    if is_super_administrator {
+        return Err(denied(PermissionResource::Users, PermissionAction::Update));
+    }
+
+    match permissions
+        .iter()
+        .find(|permission| !actor.role.covers(permission))
+    {
+        Some(permission) => Err(denied(permission.resource, permission.action)),
+        None => Ok(()),
+    }
+}
+
+pub fn category_scope(
+    actor: &User,
+    resource: PermissionResource,
+    action: PermissionAction,
+) -> Result<Option<Vec<String>>, PermissionDenied> {
+    match actor.role.category_scope(resource, action) {
+        CategoryScope::All => Ok(None),
+        CategoryScope::Only(category_ids) => Ok(Some(category_ids)),
+        CategoryScope::Denied => Err(denied(resource, action)),
+    }
+}
+
+pub fn permissions(actor: &User) -> Vec<Permission> {
+    actor.role.effective_permissions()
+}
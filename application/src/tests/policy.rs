@@ -0,0 +1,188 @@
+use domain::model::{
+    role::{Permission, PermissionAction, PermissionDenied, PermissionResource, Role},
+    user::User,
+};
+
+use crate::policy::{authorize, authorize_category, authorize_grant, category_scope, permissions};
+
+pub fn build_actor(is_super_administrator: bool, permissions: Vec<Permission>) -> User {
+    let role = Role::try_new(
+        "role".into(),
+        "role".into(),
+        "description".into(),
+        is_super_administrator,
+        permissions,
+    )
+    .unwrap();
+
+    User::try_new(
+        "user_id".into(),
+        "user1".into(),
+        "https://example.com/icon.png".into(),
+        role,
+    )
+    .unwrap()
+}
+
+pub fn super_administrator() -> User {
+    build_actor(true, vec![])
+}
+
+pub fn build_scoped_actor(
+    resource: PermissionResource,
+    action: PermissionAction,
+    category_ids: Vec<&str>,
+) -> User {
+    build_actor(
+        false,
+        vec![Permission::new(
+            resource,
+            action,
+            category_ids.into_iter().map(String::from).collect(),
+        )],
+    )
+}
+
+#[rstest::rstest]
+fn authorize_success() {
+    let actor = build_scoped_actor(PermissionResource::Tags, PermissionAction::Read, vec![]);
+
+    assert_eq!(
+        authorize(&actor, PermissionResource::Tags, PermissionAction::Read),
+        Ok(())
+    );
+    assert_eq!(
+        authorize(&actor, PermissionResource::Tags, PermissionAction::Delete),
+        Err(PermissionDenied {
+            resource: PermissionResource::Tags,
+            action: PermissionAction::Delete
+        })
+    );
+    assert_eq!(
+        authorize(
+            &super_administrator(),
+            PermissionResource::Tags,
+            PermissionAction::Delete
+        ),
+        Ok(())
+    );
+}
+
+#[rstest::rstest]
+#[case::allowed("news", true)]
+#[case::other("blog", false)]
+fn authorize_category_scoped(#[case] category_id: &str, #[case] expected: bool) {
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Update,
+        vec!["news"],
+    );
+
+    assert_eq!(
+        authorize_category(
+            &actor,
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            category_id
+        )
+        .is_ok(),
+        expected
+    );
+    assert!(authorize(
+        &actor,
+        PermissionResource::Contents,
+        PermissionAction::Update
+    )
+    .is_err());
+}
+
+#[rstest::rstest]
+fn authorize_grant_success() {
+    let actor = build_actor(
+        false,
+        vec![
+            Permission::new(PermissionResource::Users, PermissionAction::Update, vec![]),
+            Permission::new(PermissionResource::Tags, PermissionAction::Read, vec![]),
+        ],
+    );
+    let permissions = vec![Permission::new(
+        PermissionResource::Tags,
+        PermissionAction::Read,
+        vec![],
+    )];
+
+    assert_eq!(authorize_grant(&actor, false, &permissions), Ok(()));
+    assert_eq!(authorize_grant(&actor, false, &[]), Ok(()));
+    assert_eq!(
+        authorize_grant(&super_administrator(), true, &permissions),
+        Ok(())
+    );
+}
+
+#[rstest::rstest]
+#[case::super_administrator(true, vec![], PermissionResource::Users, PermissionAction::Update)]
+#[case::beyond_own(false, vec![
+    Permission::new(PermissionResource::Webhooks, PermissionAction::Delete, vec![]),
+], PermissionResource::Webhooks, PermissionAction::Delete)]
+#[case::wider_scope(false, vec![
+    Permission::new(PermissionResource::Contents, PermissionAction::Update, vec![]),
+], PermissionResource::Contents, PermissionAction::Update)]
+fn authorize_grant_failure(
+    #[case] is_super_administrator: bool,
+    #[case] permissions: Vec<Permission>,
+    #[case] resource: PermissionResource,
+    #[case] action: PermissionAction,
+) {
+    let actor = build_actor(
+        false,
+        vec![
+            Permission::new(PermissionResource::Users, PermissionAction::Update, vec![]),
+            Permission::new(
+                PermissionResource::Contents,
+                PermissionAction::Update,
+                vec!["news".into()],
+            ),
+        ],
+    );
+
+    assert_eq!(
+        authorize_grant(&actor, is_super_administrator, &permissions),
+        Err(PermissionDenied { resource, action })
+    );
+}
+
+#[rstest::rstest]
+#[case::unscoped(vec![], Ok(None))]
+#[case::scoped(vec!["news"], Ok(Some(vec!["news".to_string()])))]
+fn category_scope_allowed(
+    #[case] category_ids: Vec<&str>,
+    #[case] expected: Result<Option<Vec<String>>, PermissionDenied>,
+) {
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Read,
+        category_ids,
+    );
+
+    assert_eq!(
+        category_scope(&actor, PermissionResource::Contents, PermissionAction::Read),
+        expected
+    );
+    assert!(category_scope(
+        &actor,
+        PermissionResource::Categories,
+        PermissionAction::Read
+    )
+    .is_err());
+}
+
+#[rstest::rstest]
+fn permissions_success() {
+    let actor = build_scoped_actor(PermissionResource::Tags, PermissionAction::Read, vec![]);
+
+    assert_eq!(permissions(&actor).len(), 1);
+    assert_eq!(
+        permissions(&super_administrator()).len(),
+        PermissionResource::ALL.len() * PermissionAction::ALL.len()
+    );
+}
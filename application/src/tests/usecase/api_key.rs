@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use domain::{
+    model::api_key::{ApiKey, ApiKeyError, ApiKeyScope, ApiKeySecret},
+    repository::api_key::MockApiKeyRepository,
+};
+
+use crate::tests::policy::super_administrator;
+use crate::usecase::api_key::{ApiKeyUsecase, IssueApiKeyInput};
+
+fn build_api_key(
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> ApiKey {
+    ApiKey::try_new(
+        uuid::Uuid::now_v7(),
+        "frontend".into(),
+        "hcms_0123abcd".into(),
+        vec![ApiKeyScope::ReadPublished],
+        vec![],
+        expires_at,
+        revoked_at,
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn issue_success() {
+    let mut mock = MockApiKeyRepository::new();
+    mock.expect_create()
+        .withf(|x| x.secret_hash.len() == 64 && x.scopes == vec![ApiKeyScope::ReadPublished])
+        .returning(|_| Ok(build_api_key(None, None)));
+    let usecase = ApiKeyUsecase::new(Arc::new(mock));
+    let input = IssueApiKeyInput::new(
+        "frontend".into(),
+        vec![ApiKeyScope::ReadPublished],
+        vec![],
+        None,
+    );
+    let result = usecase.issue(&super_administrator(), input).await.unwrap();
+
+    assert!(result.token.starts_with("hcms_"));
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::no_scope(vec![], None)]
+#[case::expired(vec![ApiKeyScope::ReadDrafts], Some(chrono::Utc::now() - chrono::Duration::days(1)))]
+async fn issue_failure(
+    #[case] scopes: Vec<ApiKeyScope>,
+    #[case] expires_at: Option<chrono::DateTime<chrono::Utc>>,
+) {
+    let mut mock = MockApiKeyRepository::new();
+    mock.expect_create().never();
+    let usecase = ApiKeyUsecase::new(Arc::new(mock));
+    let input = IssueApiKeyInput::new("frontend".into(), scopes, vec![], expires_at);
+    let result = usecase.issue(&super_administrator(), input).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn authenticate_success() {
+    let token = "hcms_token".to_string();
+    let secret_hash = ApiKeySecret::hash(&token);
+
+    let mut mock = MockApiKeyRepository::new();
+    mock.expect_find_by_hash()
+        .withf(move |x| *x == secret_hash)
+        .returning(|_| Ok(Some(build_api_key(None, None))));
+    let usecase = ApiKeyUsecase::new(Arc::new(mock));
+    let result = usecase.authenticate(token).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::unknown(None, ApiKeyError::Invalid)]
+#[case::expired(
+    Some(build_api_key(Some(chrono::Utc::now() - chrono::Duration::days(1)), None)),
+    ApiKeyError::Expired
+)]
+#[case::revoked(
+    Some(build_api_key(None, Some(chrono::Utc::now()))),
+    ApiKeyError::Revoked
+)]
+async fn authenticate_failure(#[case] api_key: Option<ApiKey>, #[case] expected: ApiKeyError) {
+    let mut mock = MockApiKeyRepository::new();
+    mock.expect_find_by_hash()
+        .returning(move |_| Ok(api_key.clone()));
+    let usecase = ApiKeyUsecase::new(Arc::new(mock));
+    let result = usecase.authenticate("hcms_token".into()).await;
+
+    let error = result.unwrap_err();
+    assert_eq!(error.downcast_ref::<ApiKeyError>(), Some(&expected));
+}
@@ -0,0 +1,314 @@
+use std::sync::{Arc, Mutex};
+
+use domain::{
+    model::{
+        auth::{hash_password, AuthError, AuthToken, Credential, Session},
+        role::Role,
+        user::User,
+    },
+    repository::{auth::MockAuthRepository, mailer::MockMailer},
+};
+
+use crate::usecase::auth::{AuthUsecase, SignInInput, SignUpInput};
+
+fn build_session(
+    access_expires_at: chrono::DateTime<chrono::Utc>,
+    revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> Session {
+    let role = Role::try_new(
+        uuid::Uuid::now_v7().to_string(),
+        "member".into(),
+        "description".into(),
+        false,
+        vec![],
+    )
+    .unwrap();
+    let user = User::try_new(
+        uuid::Uuid::now_v7().to_string(),
+        "user1".into(),
+        "https://example.com/icon.png".into(),
+        role,
+    )
+    .unwrap();
+
+    Session::new(
+        uuid::Uuid::now_v7(),
+        user,
+        access_expires_at,
+        chrono::Utc::now() + chrono::Duration::days(30),
+        revoked_at,
+    )
+}
+
+fn build_signup_input(email: &str, password: &str) -> SignUpInput {
+    SignUpInput::new(
+        "user1".into(),
+        email.into(),
+        password.into(),
+        "https://example.com/icon.png".into(),
+    )
+}
+
+#[tokio::test]
+async fn signup_success() {
+    let sent_token = Arc::new(Mutex::new(String::new()));
+    let stored_hash = Arc::new(Mutex::new(String::new()));
+
+    let mut repository = MockAuthRepository::new();
+    repository.expect_find_credential().returning(|_| Ok(None));
+    let captured = stored_hash.clone();
+    repository
+        .expect_signup()
+        .withf(|x| x.password_hash.starts_with("$argon2") && x.email == "user@example.com")
+        .returning(move |x| {
+            *captured.lock().unwrap() = x.verification_token_hash;
+            Ok(uuid::Uuid::now_v7().to_string())
+        });
+    let mut mailer = MockMailer::new();
+    let captured = sent_token.clone();
+    mailer
+        .expect_send_verification()
+        .withf(|email, _| email == "user@example.com")
+        .returning(move |_, token| {
+            *captured.lock().unwrap() = token;
+            Ok(())
+        });
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(mailer));
+    let result = usecase
+        .signup(build_signup_input("user@example.com", "password"))
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        AuthToken::hash(&sent_token.lock().unwrap()),
+        *stored_hash.lock().unwrap()
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::invalid_email("user", "password")]
+#[case::short_password("user@example.com", "short")]
+async fn signup_validation_failure(#[case] email: &str, #[case] password: &str) {
+    let mut repository = MockAuthRepository::new();
+    repository.expect_signup().never();
+    let mut mailer = MockMailer::new();
+    mailer.expect_send_verification().never();
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(mailer));
+    let result = usecase.signup(build_signup_input(email, password)).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn signup_already_registered() {
+    let mut repository = MockAuthRepository::new();
+    repository
+        .expect_find_credential()
+        .returning(|_| Ok(Some(Credential::new("id".into(), None, None))));
+    repository.expect_signup().never();
+    let mut mailer = MockMailer::new();
+    mailer.expect_send_verification().never();
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(mailer));
+    let result = usecase
+        .signup(build_signup_input("user@example.com", "password"))
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::EmailAlreadyRegistered
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::verified(true)]
+#[case::unknown(false)]
+async fn verify(#[case] verified: bool) {
+    let token = "hcms_vt_token".to_string();
+    let token_hash = AuthToken::hash(&token);
+
+    let mut repository = MockAuthRepository::new();
+    repository
+        .expect_verify_email()
+        .withf(move |x, _| *x == token_hash)
+        .returning(move |_, _| Ok(verified));
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase.verify(token).await;
+
+    assert_eq!(result.is_ok(), verified);
+}
+
+#[tokio::test]
+async fn signin_success() {
+    let password_hash = hash_password("password").unwrap();
+
+    let mut repository = MockAuthRepository::new();
+    repository.expect_find_credential().returning(move |_| {
+        Ok(Some(Credential::new(
+            "id".into(),
+            Some(password_hash.clone()),
+            Some(chrono::Utc::now()),
+        )))
+    });
+    repository
+        .expect_create_session()
+        .withf(|x| x.user_id == "id" && x.access_token_hash != x.refresh_token_hash)
+        .returning(|x| Ok(build_session(x.access_expires_at, None)));
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase
+        .signin(SignInInput::new(
+            "user@example.com".into(),
+            "password".into(),
+        ))
+        .await
+        .unwrap();
+
+    assert!(result.access_token.starts_with("hcms_at_"));
+    assert!(result.refresh_token.starts_with("hcms_rt_"));
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::unknown_email(
+    false,
+    Some(chrono::Utc::now()),
+    "password",
+    AuthError::InvalidCredentials
+)]
+#[case::wrong_password(
+    true,
+    Some(chrono::Utc::now()),
+    "wrong password",
+    AuthError::InvalidCredentials
+)]
+#[case::unverified(true, None, "password", AuthError::EmailNotVerified)]
+async fn signin_failure(
+    #[case] registered: bool,
+    #[case] email_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[case] password: &str,
+    #[case] expected: AuthError,
+) {
+    let password_hash = hash_password("password").unwrap();
+
+    let mut repository = MockAuthRepository::new();
+    repository.expect_find_credential().returning(move |_| {
+        Ok(registered
+            .then(|| Credential::new("id".into(), Some(password_hash.clone()), email_verified_at)))
+    });
+    repository.expect_create_session().never();
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase
+        .signin(SignInInput::new("user@example.com".into(), password.into()))
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn refresh_success() {
+    let refresh_token = "hcms_rt_token".to_string();
+    let refresh_token_hash = AuthToken::hash(&refresh_token);
+    let session = build_session(chrono::Utc::now(), None);
+    let session_id = session.id;
+
+    let mut repository = MockAuthRepository::new();
+    let expected_hash = refresh_token_hash.clone();
+    let found = session.clone();
+    repository
+        .expect_find_session_by_refresh_token()
+        .withf(move |x| *x == expected_hash)
+        .returning(move |_| Ok(Some(found.clone())));
+    repository
+        .expect_rotate_session()
+        .withf(move |x| {
+            x.id == session_id
+                && x.previous_refresh_token_hash == refresh_token_hash
+                && x.refresh_token_hash != refresh_token_hash
+        })
+        .returning(move |_| Ok(Some(session.clone())));
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase.refresh(refresh_token.clone()).await.unwrap();
+
+    assert_ne!(result.refresh_token, refresh_token);
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::revoked(Some(chrono::Utc::now()), true)]
+#[case::already_rotated(None, false)]
+async fn refresh_failure(
+    #[case] revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[case] rotated: bool,
+) {
+    let session = build_session(chrono::Utc::now(), revoked_at);
+
+    let mut repository = MockAuthRepository::new();
+    let found = session.clone();
+    repository
+        .expect_find_session_by_refresh_token()
+        .returning(move |_| Ok(Some(found.clone())));
+    repository
+        .expect_rotate_session()
+        .returning(move |_| Ok(rotated.then(|| session.clone())));
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase.refresh("hcms_rt_token".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::InvalidToken
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::active(chrono::Utc::now() + chrono::Duration::minutes(1), None, true)]
+#[case::expired(chrono::Utc::now() - chrono::Duration::minutes(1), None, false)]
+#[case::revoked(chrono::Utc::now() + chrono::Duration::minutes(1), Some(chrono::Utc::now()), false)]
+async fn authenticate(
+    #[case] access_expires_at: chrono::DateTime<chrono::Utc>,
+    #[case] revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[case] expected: bool,
+) {
+    let token = "hcms_at_token".to_string();
+    let token_hash = AuthToken::hash(&token);
+
+    let mut repository = MockAuthRepository::new();
+    repository
+        .expect_find_session_by_access_token()
+        .withf(move |x| *x == token_hash)
+        .returning(move |_| Ok(Some(build_session(access_expires_at, revoked_at))));
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase.authenticate(token).await;
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+#[tokio::test]
+async fn signout_success() {
+    let session_id = uuid::Uuid::now_v7();
+
+    let mut repository = MockAuthRepository::new();
+    repository
+        .expect_revoke_session()
+        .withf(move |x| *x == session_id)
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let usecase = AuthUsecase::new(Arc::new(repository), Arc::new(MockMailer::new()));
+    let result = usecase.signout(session_id).await;
+
+    assert!(result.is_ok());
+}
@@ -1,15 +1,57 @@
 use std::sync::Arc;
 
 use domain::{
-    model::category::Category,
-    repository::category::{CreateCategory, MockCategoryRepository, UpdateCategory},
+    model::{
+        category::Category,
+        page::{Cursor, Page},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+    },
+    repository::{
+        category::{CreateCategory, MockCategoryRepository, UpdateCategory},
+        unit_of_work::{MockUnitOfWork, MockUnitOfWorkFactory},
+    },
 };
 
+use crate::tests::policy::{build_scoped_actor, super_administrator};
 use crate::usecase::category::{CategoryUsecase, GetCategoryInput};
 
+fn unit_of_work(categories: MockCategoryRepository, commits: usize) -> MockUnitOfWorkFactory {
+    let categories = Arc::new(categories);
+    let mut unit_of_work = MockUnitOfWork::new();
+    unit_of_work
+        .expect_categories()
+        .returning(move || categories.clone());
+    unit_of_work
+        .expect_commit()
+        .times(commits)
+        .returning(|| Ok(()));
+
+    let mut factory = MockUnitOfWorkFactory::new();
+    factory
+        .expect_begin()
+        .times(1)
+        .return_once(move || Ok(Box::new(unit_of_work)));
+
+    factory
+}
+
+fn no_unit_of_work() -> MockUnitOfWorkFactory {
+    let mut factory = MockUnitOfWorkFactory::new();
+    factory.expect_begin().never();
+
+    factory
+}
+
 #[rstest::fixture]
 fn category() -> Category {
-    Category::try_new("id".into(), "name".into(), "api_identifier".into(), None).unwrap()
+    Category::try_new(
+        "id".into(),
+        "name".into(),
+        "api_identifier".into(),
+        None,
+        vec![],
+    )
+    .unwrap()
 }
 
 #[tokio::test]
@@ -17,32 +59,43 @@ fn category() -> Category {
 async fn get_success(category: Category) {
     let mut mock = MockCategoryRepository::new();
     mock.expect_get()
-        .returning(move |_| Ok(vec![category.clone()]));
-    let usecase = CategoryUsecase::new(Arc::new(mock));
+        .returning(move |_| Ok(Page::new(vec![category.clone()], None, None, None)));
+    let usecase = CategoryUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
     let input = GetCategoryInput::default();
-    let result = usecase.get(input).await;
+    let result = usecase.get(&super_administrator(), input).await;
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
 #[rstest::rstest]
-#[case::simple(CreateCategory::new("name".into(), "api_identifier".into(), None)
+#[case::simple(CreateCategory::new("name".into(), "api_identifier".into(), None, vec![])
 )]
 async fn create_success(#[case] create_category: CreateCategory) {
     let mut mock = MockCategoryRepository::new();
     mock.expect_create().returning(move |x| {
-        Category::try_new("id".into(), x.name, x.api_identifier, x.description)
+        Category::try_new(
+            "id".into(),
+            x.name,
+            x.api_identifier,
+            x.description,
+            x.fields,
+        )
     });
-    let usecase = CategoryUsecase::new(Arc::new(mock));
-    let result = usecase.create(create_category).await;
+    let usecase = CategoryUsecase::new(
+        Arc::new(MockCategoryRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let result = usecase
+        .create(&super_administrator(), create_category)
+        .await;
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
 #[rstest::rstest]
-#[case::simple(UpdateCategory::new("id".into(), Some("new_name".into()), Some("new_api_identifier".into()), None))]
+#[case::simple(UpdateCategory::new("id".into(), Some("new_name".into()), Some("new_api_identifier".into()), None, None, None))]
 async fn update_success(category: Category, #[case] update_category: UpdateCategory) {
     let mut mock = MockCategoryRepository::new();
     mock.expect_update().returning(move |x| {
@@ -59,13 +112,29 @@ async fn update_success(category: Category, #[case] update_category: UpdateCateg
             None => category.description.clone(),
         };
 
-        Category::try_new(category.id.clone(), name, api_identifier, description)
+        let fields = match x.fields {
+            Some(fields) => fields,
+            None => category.fields.clone(),
+        };
+
+        Category::try_new(
+            category.id.clone(),
+            name,
+            api_identifier,
+            description,
+            fields,
+        )
     });
 
-    let usecase = CategoryUsecase::new(Arc::new(mock));
-    let result = usecase.update(update_category).await;
+    let usecase = CategoryUsecase::new(
+        Arc::new(MockCategoryRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let result = usecase
+        .update(&super_administrator(), update_category)
+        .await;
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
@@ -73,8 +142,99 @@ async fn update_success(category: Category, #[case] update_category: UpdateCateg
 async fn delete_success() {
     let mut mock = MockCategoryRepository::new();
     mock.expect_delete().returning(|_| Ok(()));
-    let usecase = CategoryUsecase::new(Arc::new(mock));
-    let result = usecase.delete("id".into()).await;
+    let usecase = CategoryUsecase::new(
+        Arc::new(MockCategoryRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let result = usecase.delete(&super_administrator(), "id".into()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+#[rstest::rstest]
+async fn get_restricts_to_readable_categories(category: Category) {
+    let mut mock = MockCategoryRepository::new();
+    mock.expect_get()
+        .withf(|x| x.category_ids == Some(vec!["id".to_string()]))
+        .returning(move |_| Ok(Page::new(vec![category.clone()], None, None, None)));
+    let usecase = CategoryUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let actor = build_scoped_actor(
+        PermissionResource::Categories,
+        PermissionAction::Read,
+        vec!["id"],
+    );
+    let result = usecase.get(&actor, GetCategoryInput::default()).await;
+
+    let ids: Vec<String> = result.unwrap().items.into_iter().map(|x| x.id).collect();
+    assert_eq!(ids, vec!["id".to_string()]);
+}
+
+#[tokio::test]
+async fn content_models_follow_pages_and_content_scope() {
+    let build =
+        |id: &str| Category::try_new(id.into(), id.into(), id.into(), None, vec![]).unwrap();
+    let next = Cursor::After(uuid::Uuid::nil()).encode();
+    let mut mock = MockCategoryRepository::new();
+    mock.expect_get()
+        .withf(|x| x.page.cursor.is_none() && x.category_ids.is_none())
+        .returning(move |_| {
+            Ok(Page::new(
+                vec![build("blog")],
+                Some(next.clone()),
+                None,
+                None,
+            ))
+        });
+    mock.expect_get()
+        .withf(|x| x.page.cursor.is_some())
+        .returning(move |_| Ok(Page::new(vec![build("news")], None, None, None)));
+    let usecase = CategoryUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Read,
+        vec!["news"],
+    );
+    let result = usecase.content_models(&actor).await;
+
+    let ids: Vec<String> = result.unwrap().into_iter().map(|x| x.id).collect();
+    assert_eq!(ids, vec!["news".to_string()]);
+}
+
+#[tokio::test]
+#[rstest::rstest]
+async fn delete_failure_without_permission() {
+    let usecase = CategoryUsecase::new(
+        Arc::new(MockCategoryRepository::new()),
+        Arc::new(no_unit_of_work()),
+    );
+    let actor = build_scoped_actor(
+        PermissionResource::Categories,
+        PermissionAction::Update,
+        vec![],
+    );
+    let result = usecase.delete(&actor, "id".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Categories,
+            action: PermissionAction::Delete,
+        })
+    );
+}
+
+#[tokio::test]
+async fn restore_failure_without_commit_when_repository_fails() {
+    let mut mock = MockCategoryRepository::new();
+    mock.expect_restore()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("database is unavailable")));
+    let usecase = CategoryUsecase::new(
+        Arc::new(MockCategoryRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let result = usecase.restore(&super_administrator(), "id".into()).await;
 
-    assert_eq!(result.is_ok(), true);
+    assert_eq!(result.unwrap_err().to_string(), "database is unavailable");
 }
@@ -0,0 +1,536 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        content::{
+            Content, ContentCategory, ContentStatus, ContentStatusError, CreatedBy, UpdatedBy,
+        },
+        locale::{ContentLocalization, LocaleChain},
+        page::Page,
+        relation::{ContentReferrer, Expand, RelatedContent},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+    },
+    repository::{
+        content::{CreateContent, GetContentQuery, MockContentRepository, UpdateContent},
+        unit_of_work::{MockUnitOfWork, MockUnitOfWorkFactory},
+    },
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::content::ContentUsecase;
+
+pub fn build_content(status: ContentStatus) -> Content {
+    Content::try_new(
+        uuid::Uuid::nil(),
+        "title".into(),
+        ContentCategory::new("id".into(), "sample".into()),
+        status,
+        serde_json::json!({}),
+        vec![],
+        CreatedBy::new("id".into(), "name".into()),
+        UpdatedBy::new("id".into(), "name".into()),
+        None,
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn unit_of_work(contents: MockContentRepository, commits: usize) -> MockUnitOfWorkFactory {
+    let contents = Arc::new(contents);
+    let mut unit_of_work = MockUnitOfWork::new();
+    unit_of_work
+        .expect_contents()
+        .returning(move || contents.clone());
+    unit_of_work
+        .expect_commit()
+        .times(commits)
+        .returning(|| Ok(()));
+
+    let mut factory = MockUnitOfWorkFactory::new();
+    factory
+        .expect_begin()
+        .times(1)
+        .return_once(move || Ok(Box::new(unit_of_work)));
+
+    factory
+}
+
+fn no_unit_of_work() -> MockUnitOfWorkFactory {
+    let mut factory = MockUnitOfWorkFactory::new();
+    factory.expect_begin().never();
+
+    factory
+}
+
+fn build_create_content(status: ContentStatus) -> CreateContent {
+    CreateContent::new(
+        "title".into(),
+        "category_id".into(),
+        serde_json::json!({}),
+        vec![],
+        status,
+        None,
+        None,
+        "user_id".into(),
+        "user_id".into(),
+    )
+}
+
+#[tokio::test]
+async fn publish_success() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_update_status()
+        .withf(|x| x.change.status == ContentStatus::Published && x.change.published_at.is_some())
+        .returning(|_| Ok(build_content(ContentStatus::Published)));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let result = usecase.publish(&super_administrator(), "id".into()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn unpublish_failure_with_invalid_transition() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_update_status().never();
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let result = usecase.unpublish(&super_administrator(), "id".into()).await;
+
+    let error = result.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ContentStatusError>(),
+        Some(&ContentStatusError::InvalidTransition {
+            from: ContentStatus::Draft,
+            to: ContentStatus::Unpublished,
+        })
+    );
+}
+
+#[tokio::test]
+async fn schedule_success() {
+    let published_at = chrono::Utc::now() + chrono::Duration::hours(1);
+
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Unpublished)));
+    mock.expect_update_status()
+        .withf(move |x| {
+            x.change.status == ContentStatus::Reserved
+                && x.change.published_at == Some(published_at)
+        })
+        .returning(|_| Ok(build_content(ContentStatus::Reserved)));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let result = usecase
+        .schedule(&super_administrator(), "id".into(), published_at, None)
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn revert_to_draft_success() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Published)));
+    mock.expect_update_status()
+        .withf(|x| x.change.status == ContentStatus::Draft && x.change.published_at.is_none())
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let result = usecase
+        .revert_to_draft(&super_administrator(), "id".into())
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn create_sets_published_at_when_published() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_create()
+        .withf(|x| x.status == ContentStatus::Published && x.published_at.is_some())
+        .returning(|_| Ok(build_content(ContentStatus::Published)));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let input = build_create_content(ContentStatus::Published);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn create_failure_with_reserved_without_schedule() {
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(no_unit_of_work()),
+    );
+    let input = build_create_content(ContentStatus::Reserved);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    let error = result.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ContentStatusError>(),
+        Some(&ContentStatusError::MissingSchedule)
+    );
+}
+
+#[tokio::test]
+async fn get_restricts_to_readable_categories() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_get()
+        .withf(|x| x.category_ids == Some(vec!["id".to_string()]))
+        .returning(|_| {
+            Ok(Page::new(
+                vec![build_content(ContentStatus::Draft)],
+                None,
+                None,
+                None,
+            ))
+        });
+    let usecase = ContentUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Read,
+        vec!["id"],
+    );
+    let result = usecase
+        .get(&actor, GetContentQuery::default(), &Expand::default(), None)
+        .await;
+
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn update_failure_with_category_out_of_scope() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_update().never();
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Update,
+        vec!["other"],
+    );
+    let input = UpdateContent::new(
+        "id".into(),
+        Some("title".into()),
+        None,
+        None,
+        None,
+        None,
+        "user_id".into(),
+        None,
+    );
+    let result = usecase.update(&actor, input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Contents,
+            action: PermissionAction::Update,
+        })
+    );
+}
+
+#[tokio::test]
+async fn publish_failure_without_publish_permission() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_update_status().never();
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Update,
+        vec![],
+    );
+    let result = usecase.publish(&actor, "id".into()).await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn find_success_with_nested_expand() {
+    let first = uuid::Uuid::now_v7();
+    let second = uuid::Uuid::now_v7();
+    let third = uuid::Uuid::now_v7();
+    let mut mock = MockContentRepository::new();
+    mock.expect_find().returning(move |_| {
+        Ok(Content {
+            id: first,
+            fields: serde_json::json!({"author": second.to_string(), "body": "text"}),
+            ..build_content(ContentStatus::Draft)
+        })
+    });
+    mock.expect_find_related()
+        .withf(move |x| {
+            x.content_ids == vec![first]
+                && x.field_keys == vec!["author"]
+                && x.category_ids == Some(vec!["id".to_string()])
+        })
+        .times(1)
+        .returning(move |_| {
+            Ok(vec![RelatedContent {
+                content_id: first,
+                field_key: "author".into(),
+                position: 0,
+                content: Content {
+                    id: second,
+                    fields: serde_json::json!({"author": third.to_string()}),
+                    ..build_content(ContentStatus::Published)
+                },
+            }])
+        });
+    mock.expect_find_related()
+        .withf(move |x| x.content_ids == vec![second])
+        .times(1)
+        .returning(move |_| {
+            Ok(vec![RelatedContent {
+                content_id: second,
+                field_key: "author".into(),
+                position: 0,
+                content: Content {
+                    id: third,
+                    ..build_content(ContentStatus::Published)
+                },
+            }])
+        });
+    let usecase = ContentUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Read,
+        vec!["id"],
+    );
+    let expand = Expand::parse("author.author").unwrap();
+    let result = usecase.find(&actor, first.to_string(), &expand, None).await;
+
+    let content = result.unwrap();
+    assert_eq!(content.fields["body"], "text");
+    assert_eq!(content.fields["author"]["id"], second.to_string());
+    assert_eq!(
+        content.fields["author"]["fields"]["author"]["id"],
+        third.to_string()
+    );
+}
+
+#[tokio::test]
+async fn find_success_without_expand() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_find_related().never();
+    let usecase = ContentUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let result = usecase
+        .find(
+            &super_administrator(),
+            "id".into(),
+            &Expand::default(),
+            None,
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn find_success_with_locale() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find().returning(|_| {
+        Ok(Content {
+            fields: serde_json::json!({"title": "タイトル", "price": 100}),
+            ..build_content(ContentStatus::Draft)
+        })
+    });
+    mock.expect_find_localizations()
+        .withf(|x| x == &vec![uuid::Uuid::nil()])
+        .times(1)
+        .returning(|_| {
+            Ok(vec![ContentLocalization::new(
+                uuid::Uuid::nil(),
+                "en".into(),
+                ContentStatus::Draft,
+                serde_json::json!({"title": "Title"}),
+                UpdatedBy::new("id".into(), "name".into()),
+                None,
+                chrono::Utc::now(),
+                chrono::Utc::now(),
+            )])
+        });
+    let usecase = ContentUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let chain = LocaleChain::new("en".into(), vec!["en".into()]);
+    let result = usecase
+        .find(
+            &super_administrator(),
+            "id".into(),
+            &Expand::default(),
+            Some(&chain),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.fields,
+        serde_json::json!({"title": "Title", "price": 100})
+    );
+    assert_eq!(result.locale, Some("en".into()));
+}
+
+#[tokio::test]
+async fn referrers_restricts_to_readable_categories() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_referrers()
+        .withf(|id, category_ids| id == "id" && category_ids == &Some(vec!["id".to_string()]))
+        .returning(|_, _| {
+            Ok(vec![ContentReferrer::new(
+                uuid::Uuid::now_v7(),
+                "title".into(),
+                "related".into(),
+            )])
+        });
+    let usecase = ContentUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Read,
+        vec!["id"],
+    );
+    let result = usecase.referrers(&actor, "id".into()).await;
+
+    assert_eq!(result.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn restore_restricts_to_deletable_categories() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_restore()
+        .withf(|id, category_ids| id == "id" && *category_ids == Some(vec!["id".to_string()]))
+        .returning(|_, _| Ok(build_content(ContentStatus::Draft)));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 1)),
+    );
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Delete,
+        vec!["id"],
+    );
+    let result = usecase.restore(&actor, "id".into()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn create_failure_without_commit_when_repository_fails() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_create()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("database is unavailable")));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let input = build_create_content(ContentStatus::Draft);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert_eq!(result.unwrap_err().to_string(), "database is unavailable");
+}
+
+#[tokio::test]
+async fn update_failure_without_commit_when_repository_fails() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .times(1)
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_update()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("database is unavailable")));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let input = UpdateContent::new(
+        "id".into(),
+        Some("title".into()),
+        None,
+        None,
+        None,
+        None,
+        "user_id".into(),
+        None,
+    );
+    let result = usecase.update(&super_administrator(), input).await;
+
+    assert_eq!(result.unwrap_err().to_string(), "database is unavailable");
+}
+
+#[tokio::test]
+async fn publish_failure_without_commit_when_status_changed() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .times(1)
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_update_status().times(1).returning(|x| {
+        Err(ContentStatusError::InvalidTransition {
+            from: x.from,
+            to: x.change.status,
+        }
+        .into())
+    });
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let result = usecase.publish(&super_administrator(), "id".into()).await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<ContentStatusError>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn delete_failure_without_commit_when_repository_fails() {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .times(1)
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+    mock.expect_delete()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("database is unavailable")));
+    let usecase = ContentUsecase::new(
+        Arc::new(MockContentRepository::new()),
+        Arc::new(unit_of_work(mock, 0)),
+    );
+    let result = usecase.delete(&super_administrator(), "id".into()).await;
+
+    assert!(result.is_err());
+}
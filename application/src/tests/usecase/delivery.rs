@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        api_key::{ApiKey, ApiKeyCategory, ApiKeyScope},
+        content::{Content, ContentStatus},
+        media::{ContentMedia, Media, MediaReference, MediaUploader},
+        relation::{Expand, RelatedContent},
+        upload::PresignedUrl,
+    },
+    repository::{delivery::MockDeliveryRepository, upload::MockMediaUploadRepository},
+};
+
+use crate::tests::usecase::content::build_content;
+use crate::usecase::delivery::{DeliveryUsecase, GetDeliveryInput};
+
+fn build_api_key(categories: Vec<&str>) -> ApiKey {
+    ApiKey::try_new(
+        uuid::Uuid::now_v7(),
+        "name".into(),
+        "prefix".into(),
+        vec![ApiKeyScope::ReadPublished],
+        categories
+            .into_iter()
+            .map(|x| ApiKeyCategory::new("id".into(), x.into()))
+            .collect(),
+        None,
+        None,
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn build_media(id: uuid::Uuid) -> Media {
+    Media::try_new(
+        id,
+        "media/key.png".into(),
+        "photo.png".into(),
+        "image/png".into(),
+        5,
+        "checksum".into(),
+        Some(400),
+        Some(200),
+        Some("A photo".into()),
+        MediaUploader::new("id".into(), "name".into()),
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn build_upload_mock() -> MockMediaUploadRepository {
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_presign_download().returning(|object_key, _| {
+        Ok(PresignedUrl::new(
+            format!("https://storage/{}", object_key),
+            chrono::Utc::now(),
+        ))
+    });
+
+    mock
+}
+
+#[tokio::test]
+async fn get_success() {
+    let mut mock = MockDeliveryRepository::new();
+    mock.expect_get()
+        .withf(|api_identifier, _| api_identifier == "blog")
+        .returning(|_, _| Ok(vec![build_content(ContentStatus::Published)]));
+    mock.expect_find_media().returning(|_| Ok(vec![]));
+    let usecase = DeliveryUsecase::new(Arc::new(mock), Arc::new(build_upload_mock()));
+    let result = usecase
+        .get(
+            &build_api_key(vec![]),
+            "blog".into(),
+            GetDeliveryInput::default(),
+            &Expand::default(),
+            None,
+        )
+        .await;
+
+    assert_eq!(result.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn find_failure() {
+    let mut mock = MockDeliveryRepository::new();
+    mock.expect_find()
+        .returning(|_, _, _| Err(anyhow::anyhow!("no rows returned")));
+    let usecase = DeliveryUsecase::new(Arc::new(mock), Arc::new(build_upload_mock()));
+    let result = usecase
+        .find(
+            &build_api_key(vec![]),
+            "blog".into(),
+            "id".into(),
+            false,
+            &Expand::default(),
+            None,
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn find_success_with_expanded_media() {
+    let media_id = uuid::Uuid::now_v7();
+    let mut mock = MockDeliveryRepository::new();
+    mock.expect_find().returning(move |_, _, _| {
+        Ok(Content {
+            fields: serde_json::json!({
+                "body": "text",
+                "thumbnail": media_id.to_string(),
+            }),
+            ..build_content(ContentStatus::Published)
+        })
+    });
+    mock.expect_find_media()
+        .withf(|content_ids| content_ids == &vec![uuid::Uuid::nil()])
+        .returning(move |_| {
+            Ok(vec![ContentMedia {
+                content_id: uuid::Uuid::nil(),
+                field_key: "thumbnail".into(),
+                media: build_media(media_id),
+            }])
+        });
+    let usecase = DeliveryUsecase::new(Arc::new(mock), Arc::new(build_upload_mock()));
+    let result = usecase
+        .find(
+            &build_api_key(vec![]),
+            "blog".into(),
+            "id".into(),
+            false,
+            &Expand::default(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let reference: MediaReference =
+        serde_json::from_value(result.fields["thumbnail"].clone()).unwrap();
+    assert_eq!(reference.id, media_id);
+    assert_eq!(reference.url, "https://storage/media/key.png");
+    assert_eq!(reference.width, Some(400));
+    assert_eq!(reference.height, Some(200));
+    assert_eq!(reference.alt_text.as_deref(), Some("A photo"));
+    assert_eq!(result.fields["body"], "text");
+}
+
+#[tokio::test]
+async fn find_success_with_expanded_relations() {
+    let related_id = uuid::Uuid::now_v7();
+    let media_id = uuid::Uuid::now_v7();
+    let mut mock = MockDeliveryRepository::new();
+    mock.expect_find().returning(move |_, _, _| {
+        Ok(Content {
+            fields: serde_json::json!({
+                "related": [related_id.to_string()],
+            }),
+            ..build_content(ContentStatus::Published)
+        })
+    });
+    mock.expect_find_related()
+        .withf(|x| {
+            x.content_ids == vec![uuid::Uuid::nil()]
+                && x.field_keys == vec!["related"]
+                && x.include_drafts
+                && x.api_identifiers == Some(vec!["blog".to_string(), "authors".to_string()])
+        })
+        .times(1)
+        .returning(move |_| {
+            Ok(vec![RelatedContent {
+                content_id: uuid::Uuid::nil(),
+                field_key: "related".into(),
+                position: 0,
+                content: Content {
+                    id: related_id,
+                    fields: serde_json::json!({"thumbnail": media_id.to_string()}),
+                    ..build_content(ContentStatus::Published)
+                },
+            }])
+        });
+    mock.expect_find_media().returning(move |content_ids| {
+        Ok(content_ids
+            .into_iter()
+            .filter(|id| *id == related_id)
+            .map(|content_id| ContentMedia {
+                content_id,
+                field_key: "thumbnail".into(),
+                media: build_media(media_id),
+            })
+            .collect())
+    });
+    let usecase = DeliveryUsecase::new(Arc::new(mock), Arc::new(build_upload_mock()));
+    let result = usecase
+        .find(
+            &build_api_key(vec!["blog", "authors"]),
+            "blog".into(),
+            "id".into(),
+            true,
+            &Expand::parse("related").unwrap(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let related = &result.fields["related"][0];
+    assert_eq!(related["id"], related_id.to_string());
+    assert_eq!(related["category"], "sample");
+    assert!(related.get("created_by").is_none());
+    assert_eq!(
+        related["fields"]["thumbnail"]["url"],
+        "https://storage/media/key.png"
+    );
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        content::{Content, ContentCategory, ContentStatus, CreatedBy, UpdatedBy},
+        locale::{ContentLocalization, LocaleError, Locales},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+    },
+    repository::{content::MockContentRepository, localization::MockContentLocalizationRepository},
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::localization::{ContentLocalizationUsecase, UpsertContentLocalizationInput};
+
+fn build_localization(status: ContentStatus) -> ContentLocalization {
+    ContentLocalization::new(
+        uuid::Uuid::nil(),
+        "en".into(),
+        status,
+        serde_json::json!({"title": "Title"}),
+        UpdatedBy::new("id".into(), "name".into()),
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+}
+
+fn content_repository() -> MockContentRepository {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find().returning(|_| {
+        Content::try_new(
+            uuid::Uuid::nil(),
+            "title".into(),
+            ContentCategory::new("category_id".into(), "sample".into()),
+            ContentStatus::Published,
+            serde_json::json!({"title": "タイトル"}),
+            vec![],
+            CreatedBy::new("id".into(), "name".into()),
+            UpdatedBy::new("id".into(), "name".into()),
+            None,
+            None,
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        )
+    });
+
+    mock
+}
+
+fn locales() -> Locales {
+    Locales::try_new("ja,en", "ja", "").unwrap()
+}
+
+#[tokio::test]
+async fn upsert_success() {
+    let mut mock = MockContentLocalizationRepository::new();
+    mock.expect_upsert()
+        .withf(|x| x.locale == "en")
+        .returning(|_| Ok(build_localization(ContentStatus::Draft)));
+    let usecase =
+        ContentLocalizationUsecase::new(Arc::new(mock), Arc::new(content_repository()), locales());
+    let input = UpsertContentLocalizationInput::new(
+        "id".into(),
+        "en".into(),
+        serde_json::json!({"title": "Title"}),
+        "user_id".into(),
+    );
+    let result = usecase.upsert(&super_administrator(), input).await;
+
+    assert_eq!(result.unwrap().status, ContentStatus::Draft);
+}
+
+#[rstest::rstest]
+#[case::default_locale("ja", LocaleError::DefaultLocale("ja".into()))]
+#[case::unknown("fr", LocaleError::Unknown("fr".into()))]
+#[tokio::test]
+async fn upsert_failure_with_locale(#[case] locale: &str, #[case] expected: LocaleError) {
+    let mut mock = MockContentLocalizationRepository::new();
+    mock.expect_upsert().never();
+    let usecase =
+        ContentLocalizationUsecase::new(Arc::new(mock), Arc::new(content_repository()), locales());
+    let input = UpsertContentLocalizationInput::new(
+        "id".into(),
+        locale.into(),
+        serde_json::json!({}),
+        "user_id".into(),
+    );
+    let result = usecase.upsert(&super_administrator(), input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<LocaleError>(),
+        Some(&expected)
+    );
+}
+
+#[tokio::test]
+async fn publish_success() {
+    let mut mock = MockContentLocalizationRepository::new();
+    mock.expect_find()
+        .returning(|_, _| Ok(build_localization(ContentStatus::Draft)));
+    mock.expect_update_status()
+        .withf(|x| {
+            x.locale == "en"
+                && x.change.status == ContentStatus::Published
+                && x.change.published_at.is_some()
+        })
+        .returning(|_| Ok(build_localization(ContentStatus::Published)));
+    let usecase =
+        ContentLocalizationUsecase::new(Arc::new(mock), Arc::new(content_repository()), locales());
+    let result = usecase
+        .publish(&super_administrator(), "id".into(), "en".into())
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn publish_failure_without_publish_permission() {
+    let mut mock = MockContentLocalizationRepository::new();
+    mock.expect_update_status().never();
+    let usecase =
+        ContentLocalizationUsecase::new(Arc::new(mock), Arc::new(content_repository()), locales());
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Update,
+        vec!["category_id"],
+    );
+    let result = usecase.publish(&actor, "id".into(), "en".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Contents,
+            action: PermissionAction::Publish,
+        })
+    );
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        media::{checksum, Media, MediaInUseError, MediaUploader, MediaUsage},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+    },
+    repository::media::{CreateMedia, MockMediaRepository},
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::media::{MediaUsecase, UploadMediaInput};
+
+fn build_media(data: CreateMedia) -> anyhow::Result<Media> {
+    Media::try_new(
+        uuid::Uuid::now_v7(),
+        "media/key".into(),
+        data.filename,
+        data.mime_type,
+        data.metadata.size,
+        data.metadata.checksum,
+        data.metadata.width,
+        data.metadata.height,
+        data.alt_text,
+        MediaUploader::new(data.uploaded_by_id, "name".into()),
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+}
+
+#[tokio::test]
+async fn upload_success() {
+    let mut mock = MockMediaRepository::new();
+    mock.expect_create()
+        .withf(|x| {
+            x.uploaded_by_id == "user_id"
+                && x.metadata.size == 5
+                && x.metadata.checksum == checksum(b"hello")
+        })
+        .returning(build_media);
+    let usecase = MediaUsecase::new(Arc::new(mock));
+    let input = UploadMediaInput::new(
+        "notes.txt".into(),
+        "text/plain".into(),
+        b"hello".to_vec(),
+        None,
+    );
+    let result = usecase.upload(&super_administrator(), input).await;
+
+    assert_eq!(result.unwrap().uploaded_by.id, "user_id");
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::empty_body("notes.txt", "text/plain", vec![])]
+#[case::invalid_filename("", "text/plain", b"hello".to_vec())]
+#[case::invalid_mime_type("notes.txt", "text", b"hello".to_vec())]
+async fn upload_failure_with_invalid_input(
+    #[case] filename: &str,
+    #[case] mime_type: &str,
+    #[case] body: Vec<u8>,
+) {
+    let mut mock = MockMediaRepository::new();
+    mock.expect_create().never();
+    let usecase = MediaUsecase::new(Arc::new(mock));
+    let input = UploadMediaInput::new(filename.into(), mime_type.into(), body, None);
+    let result = usecase.upload(&super_administrator(), input).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn upload_failure_without_permission() {
+    let mut mock = MockMediaRepository::new();
+    mock.expect_create().never();
+    let usecase = MediaUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(PermissionResource::Media, PermissionAction::Read, vec![]);
+    let input = UploadMediaInput::new(
+        "notes.txt".into(),
+        "text/plain".into(),
+        b"hello".to_vec(),
+        None,
+    );
+    let result = usecase.upload(&actor, input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Media,
+            action: PermissionAction::Create,
+        })
+    );
+}
+
+#[tokio::test]
+async fn delete_failure_without_permission() {
+    let mut mock = MockMediaRepository::new();
+    mock.expect_delete().never();
+    let usecase = MediaUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(PermissionResource::Media, PermissionAction::Update, vec![]);
+    let result = usecase.delete(&actor, "id".into(), false).await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn delete_failure_with_usages() {
+    let usage = MediaUsage::new(uuid::Uuid::now_v7(), "title".into(), "thumbnail".into());
+    let expected = usage.clone();
+    let mut mock = MockMediaRepository::new();
+    mock.expect_delete()
+        .withf(|id, force| id == "id" && !force)
+        .returning(move |_, _| {
+            Err(MediaInUseError {
+                usages: vec![usage.clone()],
+            }
+            .into())
+        });
+    let usecase = MediaUsecase::new(Arc::new(mock));
+    let result = usecase
+        .delete(&super_administrator(), "id".into(), false)
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<MediaInUseError>(),
+        Some(&MediaInUseError {
+            usages: vec![expected],
+        })
+    );
+}
+
+#[tokio::test]
+async fn usages_success() {
+    let mut mock = MockMediaRepository::new();
+    mock.expect_usages().withf(|id| id == "id").returning(|_| {
+        Ok(vec![MediaUsage::new(
+            uuid::Uuid::now_v7(),
+            "title".into(),
+            "thumbnail".into(),
+        )])
+    });
+    let usecase = MediaUsecase::new(Arc::new(mock));
+    let result = usecase.usages(&super_administrator(), "id".into()).await;
+
+    assert_eq!(result.unwrap().len(), 1);
+}
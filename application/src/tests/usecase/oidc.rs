@@ -0,0 +1,182 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        auth::{AuthError, Session},
+        oidc::{OidcClaims, OidcLoginState, RoleMapping},
+        role::Role,
+        user::User,
+    },
+    repository::{
+        auth::MockAuthRepository,
+        oidc::{MockOidcClient, MockOidcRepository},
+    },
+};
+
+use crate::usecase::oidc::OidcUsecase;
+
+fn build_claims(email_verified: bool, groups: Vec<&str>) -> OidcClaims {
+    OidcClaims {
+        issuer: "https://idp.example.com".into(),
+        subject: "subject".into(),
+        email: Some("user@example.com".into()),
+        email_verified,
+        name: Some("User One".into()),
+        picture: None,
+        groups: groups.into_iter().map(String::from).collect(),
+    }
+}
+
+fn build_session() -> Session {
+    let role = Role::try_new(
+        uuid::Uuid::now_v7().to_string(),
+        "member".into(),
+        "description".into(),
+        false,
+        vec![],
+    )
+    .unwrap();
+    let user = User::try_new(
+        uuid::Uuid::now_v7().to_string(),
+        "User One".into(),
+        "https://example.com/icon.png".into(),
+        role,
+    )
+    .unwrap();
+
+    Session::new(
+        uuid::Uuid::now_v7(),
+        user,
+        chrono::Utc::now() + chrono::Duration::minutes(15),
+        chrono::Utc::now() + chrono::Duration::days(30),
+        None,
+    )
+}
+
+#[tokio::test]
+async fn login_success() {
+    let mut repository = MockOidcRepository::new();
+    repository
+        .expect_save_login_state()
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut client = MockOidcClient::new();
+    client
+        .expect_authorization_url()
+        .returning(|x| Ok(format!("https://idp.example.com/auth?state={}", x.state)));
+
+    let usecase = OidcUsecase::new(
+        Arc::new(client),
+        Arc::new(repository),
+        Arc::new(MockAuthRepository::new()),
+        RoleMapping::default(),
+    );
+    let result = usecase.login().await.unwrap();
+
+    assert!(result.starts_with("https://idp.example.com/auth?state="));
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::mapped_verified(true, vec!["cms-admins"], Some("user@example.com"), Some("admin"))]
+#[case::unmapped_unverified(false, vec!["other"], None, Some("member"))]
+async fn callback_success(
+    #[case] email_verified: bool,
+    #[case] groups: Vec<&'static str>,
+    #[case] expected_email: Option<&'static str>,
+    #[case] expected_role: Option<&'static str>,
+) {
+    let login_state = OidcLoginState::generate(chrono::Utc::now());
+    let user_id = uuid::Uuid::now_v7().to_string();
+
+    let mut repository = MockOidcRepository::new();
+    let taken = login_state.clone();
+    repository
+        .expect_take_login_state()
+        .withf(|x, _| x == "state")
+        .returning(move |_, _| Ok(Some(taken.clone())));
+    let linked_user_id = user_id.clone();
+    repository
+        .expect_link_identity()
+        .withf(move |x| {
+            x.subject == "subject"
+                && x.name == "User One"
+                && x.email.as_deref() == expected_email
+                && x.role.as_deref() == expected_role
+                && x.default_role == "member"
+        })
+        .returning(move |_| Ok(linked_user_id.clone()));
+    let mut client = MockOidcClient::new();
+    client
+        .expect_exchange()
+        .withf(move |code, x| code == "code" && *x == login_state)
+        .returning(move |_, _| Ok(build_claims(email_verified, groups.clone())));
+    let mut auth_repository = MockAuthRepository::new();
+    auth_repository
+        .expect_create_session()
+        .withf(move |x| x.user_id == user_id)
+        .returning(|_| Ok(build_session()));
+
+    let usecase = OidcUsecase::new(
+        Arc::new(client),
+        Arc::new(repository),
+        Arc::new(auth_repository),
+        RoleMapping::from_str("cms-admins=admin").unwrap(),
+    );
+    let result = usecase.callback("code".into(), "state".into()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn callback_failure_with_unknown_state() {
+    let mut repository = MockOidcRepository::new();
+    repository
+        .expect_take_login_state()
+        .returning(|_, _| Ok(None));
+    repository.expect_link_identity().never();
+    let mut client = MockOidcClient::new();
+    client.expect_exchange().never();
+
+    let usecase = OidcUsecase::new(
+        Arc::new(client),
+        Arc::new(repository),
+        Arc::new(MockAuthRepository::new()),
+        RoleMapping::default(),
+    );
+    let result = usecase.callback("code".into(), "state".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::InvalidLoginState
+    );
+}
+
+#[tokio::test]
+async fn callback_failure_with_invalid_id_token() {
+    let mut repository = MockOidcRepository::new();
+    repository
+        .expect_take_login_state()
+        .returning(|_, _| Ok(Some(OidcLoginState::generate(chrono::Utc::now()))));
+    repository.expect_link_identity().never();
+    let mut client = MockOidcClient::new();
+    client
+        .expect_exchange()
+        .returning(|_, _| Err(AuthError::InvalidIdToken.into()));
+    let mut auth_repository = MockAuthRepository::new();
+    auth_repository.expect_create_session().never();
+
+    let usecase = OidcUsecase::new(
+        Arc::new(client),
+        Arc::new(repository),
+        Arc::new(auth_repository),
+        RoleMapping::default(),
+    );
+    let result = usecase.callback("code".into(), "state".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::InvalidIdToken
+    );
+}
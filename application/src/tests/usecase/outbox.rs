@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        outbox::OutboxEvent,
+        webhook::{WebhookEvent, WebhookPayload},
+    },
+    repository::{
+        outbox::{MockOutboxRepository, MockOutboxSink, OutboxSink},
+        webhook::MockWebhookRepository,
+    },
+};
+
+use crate::usecase::outbox::{OutboxRelayUsecase, WebhookSink};
+
+fn build_event(attempts: i32) -> OutboxEvent {
+    let payload = WebhookPayload::new(
+        WebhookEvent::ContentPublished,
+        serde_json::json!({"id": "id"}),
+        chrono::Utc::now(),
+    );
+
+    OutboxEvent::new(
+        payload.id,
+        Some("category_id".into()),
+        payload,
+        attempts,
+        None,
+        chrono::Utc::now(),
+    )
+}
+
+fn build_sink(name: &'static str, result: fn() -> anyhow::Result<()>) -> MockOutboxSink {
+    let mut sink = MockOutboxSink::new();
+    sink.expect_name().return_const(name);
+    sink.expect_handle().times(1).returning(move |_| result());
+
+    sink
+}
+
+#[tokio::test]
+async fn run_success() {
+    let event = build_event(0);
+    let id = event.id;
+
+    let mut mock = MockOutboxRepository::new();
+    mock.expect_claim_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![event.clone()]));
+    mock.expect_complete()
+        .withf(move |x| *x == id)
+        .times(1)
+        .returning(|_| Ok(()));
+    mock.expect_record_failure().never();
+    let sinks: Vec<Arc<dyn OutboxSink>> = vec![
+        Arc::new(build_sink("first", || Ok(()))),
+        Arc::new(build_sink("second", || Ok(()))),
+    ];
+    let usecase = OutboxRelayUsecase::new(Arc::new(mock), sinks);
+    let result = usecase.run(chrono::Utc::now()).await.unwrap();
+
+    assert_eq!(result.relayed, vec![id]);
+}
+
+#[tokio::test]
+async fn run_retrying_when_a_sink_fails() {
+    let event = build_event(2);
+    let id = event.id;
+    let now = chrono::Utc::now();
+
+    let mut mock = MockOutboxRepository::new();
+    mock.expect_claim_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![event.clone()]));
+    mock.expect_complete().never();
+    mock.expect_record_failure()
+        .withf(move |x| {
+            x.id == id
+                && x.attempts == 3
+                && x.next_attempt_at == now + chrono::Duration::seconds(40)
+                && x.error == "second: connection refused"
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+    let sinks: Vec<Arc<dyn OutboxSink>> = vec![
+        Arc::new(build_sink("first", || Ok(()))),
+        Arc::new(build_sink("second", || {
+            Err(anyhow::anyhow!("connection refused"))
+        })),
+    ];
+    let usecase = OutboxRelayUsecase::new(Arc::new(mock), sinks);
+    let result = usecase.run(now).await.unwrap();
+
+    assert_eq!(result.retrying, vec![id]);
+}
+
+#[tokio::test]
+async fn webhook_sink_enqueues_payload() {
+    let event = build_event(0);
+    let payload = event.payload.clone();
+
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_enqueue()
+        .withf(move |x| x.payload == payload && x.category_id.as_deref() == Some("category_id"))
+        .times(1)
+        .returning(|_| Ok(vec![]));
+    let sink = WebhookSink::new(Arc::new(mock));
+    let result = sink.handle(&event).await;
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        media::{Media, MediaUploader},
+        render::{RenderError, RenderOptions, RenderSigner, RenderedImage},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+    },
+    repository::{media::MockMediaRepository, render::MockMediaRenderRepository},
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::render::MediaRenderUsecase;
+
+fn build_media(id: uuid::Uuid, mime_type: &str) -> Media {
+    Media::try_new(
+        id,
+        "media/key".into(),
+        "photo.png".into(),
+        mime_type.into(),
+        5,
+        "checksum".into(),
+        Some(400),
+        Some(200),
+        None,
+        MediaUploader::new("user_id".into(), "name".into()),
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn build_media_mock(id: uuid::Uuid, mime_type: &'static str) -> MockMediaRepository {
+    let mut mock = MockMediaRepository::new();
+    mock.expect_find()
+        .returning(move |_| Ok(build_media(id, mime_type)));
+
+    mock
+}
+
+fn build_options() -> RenderOptions {
+    RenderOptions::try_new(Some(100), Some(100), None, None, None).unwrap()
+}
+
+fn build_rendered(body: &[u8]) -> RenderedImage {
+    RenderedImage {
+        content_type: "image/webp".into(),
+        body: body.to_vec(),
+    }
+}
+
+fn parse_query(url: &str) -> std::collections::HashMap<String, String> {
+    url.split_once('?')
+        .unwrap()
+        .1
+        .split('&')
+        .filter_map(|x| x.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+fn build_signer() -> RenderSigner {
+    RenderSigner::new(b"secret".to_vec())
+}
+
+#[tokio::test]
+async fn render_success_with_cached_variant() {
+    let id = uuid::Uuid::now_v7();
+    let key = build_options().variant_key(&id);
+    let mut mock = MockMediaRenderRepository::new();
+    mock.expect_find_variant()
+        .withf(move |x| x == &key)
+        .returning(|_| Ok(Some(build_rendered(b"cached"))));
+    mock.expect_render().never();
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(build_media_mock(id, "image/png")),
+        Arc::new(mock),
+        None,
+    );
+    let result = usecase
+        .render(&super_administrator(), id.to_string(), build_options())
+        .await;
+
+    assert_eq!(result.unwrap().body, b"cached");
+}
+
+#[tokio::test]
+async fn render_success_with_new_variant() {
+    let id = uuid::Uuid::now_v7();
+    let key = build_options().variant_key(&id);
+    let mut mock = MockMediaRenderRepository::new();
+    mock.expect_find_variant().returning(|_| Ok(None));
+    mock.expect_render()
+        .withf(move |media, _, x| media.id == id && x == &key)
+        .times(1)
+        .returning(|_, _, _| Ok(build_rendered(b"rendered")));
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(build_media_mock(id, "image/png")),
+        Arc::new(mock),
+        None,
+    );
+    let result = usecase
+        .render(&super_administrator(), id.to_string(), build_options())
+        .await;
+
+    assert_eq!(result.unwrap().body, b"rendered");
+}
+
+#[tokio::test]
+async fn render_failure_with_non_image() {
+    let id = uuid::Uuid::now_v7();
+    let mut mock = MockMediaRenderRepository::new();
+    mock.expect_find_variant().never();
+    mock.expect_render().never();
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(build_media_mock(id, "application/pdf")),
+        Arc::new(mock),
+        None,
+    );
+    let result = usecase
+        .render(&super_administrator(), id.to_string(), build_options())
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<RenderError>(),
+        Some(&RenderError::NotAnImage)
+    );
+}
+
+#[tokio::test]
+async fn render_failure_without_permission() {
+    let mut media_mock = MockMediaRepository::new();
+    media_mock.expect_find().never();
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(media_mock),
+        Arc::new(MockMediaRenderRepository::new()),
+        None,
+    );
+    let actor = build_scoped_actor(PermissionResource::Tags, PermissionAction::Read, vec![]);
+    let result = usecase.render(&actor, "id".into(), build_options()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Media,
+            action: PermissionAction::Read,
+        })
+    );
+}
+
+#[tokio::test]
+async fn sign_and_render_signed_success() {
+    let id = uuid::Uuid::now_v7();
+    let mut mock = MockMediaRenderRepository::new();
+    mock.expect_find_variant()
+        .returning(|_| Ok(Some(build_rendered(b"cached"))));
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(build_media_mock(id, "image/png")),
+        Arc::new(mock),
+        Some(build_signer()),
+    );
+
+    let signed = usecase
+        .sign(&super_administrator(), id.to_string(), build_options())
+        .await
+        .unwrap();
+    assert!(signed
+        .url
+        .starts_with(&format!("/medias/{}/render?w=100&h=100", id)));
+
+    let query = parse_query(&signed.url);
+    let expires: i64 = query["expires"].parse().unwrap();
+    let result = usecase
+        .render_signed(
+            id.to_string(),
+            build_options(),
+            expires,
+            query["sig"].clone(),
+        )
+        .await;
+
+    assert_eq!(result.unwrap().body, b"cached");
+    assert_eq!(expires, signed.expires_at.timestamp());
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::tampered(RenderOptions::try_new(Some(4000), None, None, None, None).unwrap(), RenderError::InvalidSignature)]
+#[case::expired(build_options(), RenderError::SignatureExpired)]
+async fn render_signed_failure(#[case] options: RenderOptions, #[case] error: RenderError) {
+    let mut media_mock = MockMediaRepository::new();
+    media_mock.expect_find().never();
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(media_mock),
+        Arc::new(MockMediaRenderRepository::new()),
+        Some(build_signer()),
+    );
+    let expires = chrono::Utc::now().timestamp() - 1;
+    let signature = build_signer().sign("id", &build_options(), expires);
+    let result = usecase
+        .render_signed("id".into(), options, expires, signature)
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<RenderError>(),
+        Some(&error)
+    );
+}
+
+#[tokio::test]
+async fn sign_failure_without_signer() {
+    let mut media_mock = MockMediaRepository::new();
+    media_mock.expect_find().never();
+    let usecase = MediaRenderUsecase::new(
+        Arc::new(media_mock),
+        Arc::new(MockMediaRenderRepository::new()),
+        None,
+    );
+    let result = usecase
+        .sign(&super_administrator(), "id".into(), build_options())
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<RenderError>(),
+        Some(&RenderError::SigningDisabled)
+    );
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        content::{Content, ContentCategory, ContentStatus, ContentTag, CreatedBy},
+        revision::ContentRevision,
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+    },
+    repository::{content::MockContentRepository, revision::MockContentRevisionRepository},
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::tests::usecase::content::build_content;
+use crate::usecase::revision::{ContentRevisionUsecase, GetRevisionInput};
+
+fn build_revision(revision: i32, title: &str) -> ContentRevision {
+    ContentRevision::new(
+        uuid::Uuid::now_v7(),
+        uuid::Uuid::nil(),
+        revision,
+        title.into(),
+        ContentCategory::new("category_id".into(), "sample".into()),
+        ContentStatus::Draft,
+        serde_json::json!({"body": title}),
+        vec![ContentTag::new("tag_id".into(), "sample".into())],
+        CreatedBy::new("id".into(), "name".into()),
+        chrono::Utc::now(),
+    )
+}
+
+fn content_repository() -> MockContentRepository {
+    let mut mock = MockContentRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(build_content(ContentStatus::Draft)));
+
+    mock
+}
+
+#[tokio::test]
+async fn get_success() {
+    let mut mock = MockContentRevisionRepository::new();
+    mock.expect_get()
+        .returning(|_, _| Ok(vec![build_revision(2, "b"), build_revision(1, "a")]));
+    let usecase = ContentRevisionUsecase::new(Arc::new(mock), Arc::new(content_repository()));
+    let result = usecase
+        .get(
+            &super_administrator(),
+            "id".into(),
+            GetRevisionInput::default(),
+        )
+        .await;
+
+    assert_eq!(result.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn diff_success() {
+    let mut mock = MockContentRevisionRepository::new();
+    mock.expect_find()
+        .returning(|_, revision| Ok(build_revision(revision, &format!("title{}", revision))));
+    let usecase = ContentRevisionUsecase::new(Arc::new(mock), Arc::new(content_repository()));
+    let result = usecase
+        .diff(&super_administrator(), "id".into(), 1, 2)
+        .await
+        .unwrap();
+
+    let paths: Vec<&str> = result.changes.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(paths, vec!["title", "fields.body"]);
+}
+
+#[tokio::test]
+async fn restore_success() {
+    let mut mock = MockContentRevisionRepository::new();
+    mock.expect_find()
+        .returning(|_, revision| Ok(build_revision(revision, "restored")));
+
+    let mut content_mock = content_repository();
+    content_mock
+        .expect_update()
+        .withf(|x| {
+            x.title.as_deref() == Some("restored")
+                && x.category_id.as_deref() == Some("category_id")
+                && x.tag_ids == Some(vec!["tag_id".to_string()])
+        })
+        .returning(|x| {
+            Ok(Content {
+                title: x.title.unwrap(),
+                fields: x.fields.unwrap(),
+                ..build_content(ContentStatus::Draft)
+            })
+        });
+
+    let usecase = ContentRevisionUsecase::new(Arc::new(mock), Arc::new(content_mock));
+    let result = usecase
+        .restore(&super_administrator(), "id".into(), 1)
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn restore_failure_without_update_permission() {
+    let mut content_mock = content_repository();
+    content_mock.expect_update().never();
+
+    let usecase = ContentRevisionUsecase::new(
+        Arc::new(MockContentRevisionRepository::new()),
+        Arc::new(content_mock),
+    );
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Read,
+        vec!["category_id"],
+    );
+    let result = usecase.restore(&actor, "id".into(), 1).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Contents,
+            action: PermissionAction::Update,
+        })
+    );
+}
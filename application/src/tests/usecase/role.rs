@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use domain::{
+    model::role::{
+        Permission, PermissionAction, PermissionDenied, PermissionResource, Role, RoleError,
+    },
+    repository::role::{MockRoleRepository, UpdateRole},
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::role::{CreateRoleInput, GetRoleInput, RoleUsecase};
+
+fn build_role(is_super_administrator: bool) -> Role {
+    Role::try_new(
+        "id".into(),
+        "editor".into(),
+        "description".into(),
+        is_super_administrator,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            vec!["category".into()],
+        )],
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn get_success() {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_get().returning(|_| Ok(vec![build_role(false)]));
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let result = usecase
+        .get(&super_administrator(), GetRoleInput::default())
+        .await;
+
+    assert_eq!(result.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn create_success() {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_create()
+        .withf(|x| x.name == "editor" && x.permissions.len() == 1)
+        .returning(|_| Ok(build_role(false)));
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let input = CreateRoleInput::new(
+        "editor".into(),
+        "description".into(),
+        build_role(false).permissions,
+    );
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::empty_name("".into(), vec![])]
+#[case::scoped_unscopable_resource("editor".into(), vec![
+    Permission::new(PermissionResource::ApiKeys, PermissionAction::Create, vec!["category".into()]),
+])]
+async fn create_failure(#[case] name: String, #[case] permissions: Vec<Permission>) {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_create().never();
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let input = CreateRoleInput::new(name, "description".into(), permissions);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn create_failure_with_permissions_beyond_actor() {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_create().never();
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Update, vec![]);
+    let input = CreateRoleInput::new(
+        "editor".into(),
+        "description".into(),
+        build_role(false).permissions,
+    );
+    let result = usecase.create(&actor, input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<PermissionDenied>().unwrap(),
+        PermissionDenied {
+            resource: PermissionResource::Contents,
+            action: PermissionAction::Update
+        }
+    );
+}
+
+#[tokio::test]
+async fn update_failure_with_permissions_beyond_actor() {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(Some(build_role(false))));
+    mock.expect_update().never();
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Update, vec![]);
+    let input = UpdateRole::new(
+        "id".into(),
+        None,
+        None,
+        Some(vec![Permission::new(
+            PermissionResource::Users,
+            PermissionAction::Delete,
+            vec![],
+        )]),
+    );
+    let result = usecase.update(&actor, input).await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn update_success() {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_find()
+        .returning(|_| Ok(Some(build_role(false))));
+    mock.expect_update()
+        .times(1)
+        .returning(|_| Ok(build_role(false)));
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let input = UpdateRole::new("id".into(), Some("writer".into()), None, Some(vec![]));
+    let result = usecase.update(&super_administrator(), input).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn update_failure_with_super_administrator() {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_find().returning(|_| Ok(Some(build_role(true))));
+    mock.expect_update().never();
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let input = UpdateRole::new("id".into(), Some("writer".into()), None, None);
+    let result = usecase.update(&super_administrator(), input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<RoleError>().unwrap(),
+        RoleError::Protected
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::modifiable(false, true)]
+#[case::super_administrator(true, false)]
+async fn delete(#[case] is_super_administrator: bool, #[case] expected: bool) {
+    let mut mock = MockRoleRepository::new();
+    mock.expect_find()
+        .returning(move |_| Ok(Some(build_role(is_super_administrator))));
+    mock.expect_delete()
+        .times(usize::from(expected))
+        .returning(|_| Ok(()));
+    let usecase = RoleUsecase::new(Arc::new(mock));
+    let result = usecase.delete(&super_administrator(), "id".into()).await;
+
+    assert_eq!(result.is_ok(), expected);
+}
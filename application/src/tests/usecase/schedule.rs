@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use domain::repository::schedule::MockContentScheduleRepository;
+
+use crate::usecase::schedule::ContentScheduleUsecase;
+
+#[tokio::test]
+async fn run_success() {
+    let published = uuid::Uuid::now_v7();
+    let unpublished = uuid::Uuid::now_v7();
+
+    let mut mock = MockContentScheduleRepository::new();
+    mock.expect_publish_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![published]));
+    mock.expect_unpublish_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![unpublished]));
+    let usecase = ContentScheduleUsecase::new(Arc::new(mock));
+    let result = usecase.run(chrono::Utc::now()).await.unwrap();
+
+    assert_eq!(result.published, vec![published]);
+    assert_eq!(result.unpublished, vec![unpublished]);
+}
+
+#[tokio::test]
+async fn run_until_batch_is_not_full() {
+    let mut mock = MockContentScheduleRepository::new();
+    let mut sequence = mockall::Sequence::new();
+    mock.expect_publish_due()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, limit| Ok((0..limit).map(|_| uuid::Uuid::now_v7()).collect()));
+    mock.expect_publish_due()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, _| Ok(vec![]));
+    mock.expect_unpublish_due().returning(|_, _| Ok(vec![]));
+    let usecase = ContentScheduleUsecase::new(Arc::new(mock));
+    let result = usecase.run(chrono::Utc::now()).await.unwrap();
+
+    assert_eq!(result.published.len(), 100);
+    assert!(result.unpublished.is_empty());
+}
+
+#[tokio::test]
+async fn run_failure() {
+    let mut mock = MockContentScheduleRepository::new();
+    mock.expect_publish_due()
+        .returning(|_, _| Err(anyhow::anyhow!("connection closed")));
+    let usecase = ContentScheduleUsecase::new(Arc::new(mock));
+    let result = usecase.run(chrono::Utc::now()).await;
+
+    assert!(result.is_err());
+}
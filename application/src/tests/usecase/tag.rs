@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::Page,
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+        tag::Tag,
+        webhook::WebhookEvent,
+    },
+    repository::{
+        outbox::MockOutboxRepository,
+        tag::{MockTagRepository, UpdateTag},
+        unit_of_work::{MockUnitOfWork, MockUnitOfWorkFactory},
+    },
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::tag::{CreateTagInput, GetTagInput, TagUsecase};
+
+fn unit_of_work(
+    tags: MockTagRepository,
+    outbox: MockOutboxRepository,
+    commits: usize,
+) -> MockUnitOfWorkFactory {
+    let tags = Arc::new(tags);
+    let outbox = Arc::new(outbox);
+    let mut unit_of_work = MockUnitOfWork::new();
+    unit_of_work.expect_tags().returning(move || tags.clone());
+    unit_of_work
+        .expect_outbox()
+        .returning(move || outbox.clone());
+    unit_of_work
+        .expect_commit()
+        .times(commits)
+        .returning(|| Ok(()));
+
+    let mut factory = MockUnitOfWorkFactory::new();
+    factory
+        .expect_begin()
+        .times(1)
+        .return_once(move || Ok(Box::new(unit_of_work)));
+
+    factory
+}
+
+fn no_unit_of_work() -> MockUnitOfWorkFactory {
+    let mut factory = MockUnitOfWorkFactory::new();
+    factory.expect_begin().never();
+
+    factory
+}
+
+#[tokio::test]
+async fn get_success() {
+    let mut mock = MockTagRepository::new();
+    mock.expect_get().returning(|_| {
+        let tag = Tag::try_new("id".into(), "Rust".into(), "".into())?;
+
+        Ok(Page::new(vec![tag], None, None, None))
+    });
+    let usecase = TagUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let result = usecase
+        .get(&super_administrator(), GetTagInput::default())
+        .await;
+
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn get_failure_without_permission() {
+    let mut mock = MockTagRepository::new();
+    mock.expect_get().never();
+    let usecase = TagUsecase::new(Arc::new(mock), Arc::new(no_unit_of_work()));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Read, vec![]);
+    let result = usecase.get(&actor, GetTagInput::default()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Tags,
+            action: PermissionAction::Read,
+        })
+    );
+}
+
+#[tokio::test]
+async fn create_success() {
+    let mut tags = MockTagRepository::new();
+    tags.expect_create()
+        .times(1)
+        .returning(|x| Tag::try_new("id".into(), x.name, "".into()));
+    let mut outbox = MockOutboxRepository::new();
+    outbox
+        .expect_append()
+        .withf(|x| x.payload.event == WebhookEvent::TagCreated && x.category_id.is_none())
+        .times(1)
+        .returning(|x| Ok(x.payload.id));
+    let usecase = TagUsecase::new(
+        Arc::new(MockTagRepository::new()),
+        Arc::new(unit_of_work(tags, outbox, 1)),
+    );
+    let input = CreateTagInput::new("Rust".into(), None);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert_eq!(result.unwrap().name, "Rust");
+}
+
+#[tokio::test]
+async fn create_failure_without_commit_when_outbox_fails() {
+    let mut tags = MockTagRepository::new();
+    tags.expect_create()
+        .times(1)
+        .returning(|x| Tag::try_new("id".into(), x.name, "".into()));
+    let mut outbox = MockOutboxRepository::new();
+    outbox
+        .expect_append()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("outbox is unavailable")));
+    let usecase = TagUsecase::new(
+        Arc::new(MockTagRepository::new()),
+        Arc::new(unit_of_work(tags, outbox, 0)),
+    );
+    let input = CreateTagInput::new("Rust".into(), None);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert_eq!(result.unwrap_err().to_string(), "outbox is unavailable");
+}
+
+#[tokio::test]
+async fn update_failure_without_event_when_tag_fails() {
+    let mut tags = MockTagRepository::new();
+    tags.expect_update()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("tag is not found")));
+    let mut outbox = MockOutboxRepository::new();
+    outbox.expect_append().never();
+    let usecase = TagUsecase::new(
+        Arc::new(MockTagRepository::new()),
+        Arc::new(unit_of_work(tags, outbox, 0)),
+    );
+    let input = UpdateTag::new("id".into(), Some("Rust".into()), None, None);
+    let result = usecase.update(&super_administrator(), input).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn delete_success() {
+    let mut tags = MockTagRepository::new();
+    tags.expect_delete()
+        .withf(|x| x == "id")
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut outbox = MockOutboxRepository::new();
+    outbox
+        .expect_append()
+        .withf(|x| x.payload.event == WebhookEvent::TagDeleted)
+        .times(1)
+        .returning(|x| Ok(x.payload.id));
+    let usecase = TagUsecase::new(
+        Arc::new(MockTagRepository::new()),
+        Arc::new(unit_of_work(tags, outbox, 1)),
+    );
+    let result = usecase.delete(&super_administrator(), "id".into()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn delete_failure_without_permission() {
+    let usecase = TagUsecase::new(
+        Arc::new(MockTagRepository::new()),
+        Arc::new(no_unit_of_work()),
+    );
+    let actor = build_scoped_actor(PermissionResource::Tags, PermissionAction::Update, vec![]);
+    let result = usecase.delete(&actor, "id".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Tags,
+            action: PermissionAction::Delete,
+        })
+    );
+}
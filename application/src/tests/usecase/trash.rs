@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::{Page, PageQuery},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+        trash::TrashKind,
+    },
+    repository::{
+        category::MockCategoryRepository, content::MockContentRepository,
+        media::MockMediaRepository, tag::MockTagRepository, trash::MockTrashRepository,
+    },
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::trash::{TrashPurgeUsecase, TrashUsecase};
+
+#[tokio::test]
+async fn get_success_with_all_kinds() {
+    let mut mock = MockTrashRepository::new();
+    mock.expect_get()
+        .withf(|x| {
+            x.scopes.iter().map(|scope| scope.kind).collect::<Vec<_>>() == TrashKind::ALL
+                && x.scopes.iter().all(|scope| scope.category_ids.is_none())
+        })
+        .returning(|_| Ok(Page::new(vec![], None, None, None)));
+    let usecase = TrashUsecase::new(Arc::new(mock));
+    let result = usecase
+        .get(&super_administrator(), PageQuery::default(), None)
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn get_skips_kinds_not_permitted() {
+    let mut mock = MockTrashRepository::new();
+    mock.expect_get()
+        .withf(|x| {
+            x.scopes.len() == 1
+                && x.scopes[0].kind == TrashKind::Content
+                && x.scopes[0].category_ids == Some(vec!["id".to_string()])
+        })
+        .returning(|_| Ok(Page::new(vec![], None, None, None)));
+    let usecase = TrashUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Delete,
+        vec!["id"],
+    );
+    let result = usecase.get(&actor, PageQuery::default(), None).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn get_failure_with_denied_kind() {
+    let mut mock = MockTrashRepository::new();
+    mock.expect_get().never();
+    let usecase = TrashUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(
+        PermissionResource::Contents,
+        PermissionAction::Delete,
+        vec![],
+    );
+    let kinds = Some(vec![TrashKind::Content, TrashKind::Tag]);
+    let result = usecase.get(&actor, PageQuery::default(), kinds).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Tags,
+            action: PermissionAction::Delete,
+        })
+    );
+}
+
+#[tokio::test]
+async fn purge_success() {
+    let now = chrono::Utc::now();
+    let before = now - chrono::Duration::days(7);
+    let category_id = uuid::Uuid::now_v7();
+
+    let mut sequence = mockall::Sequence::new();
+    let mut contents = MockContentRepository::new();
+    contents
+        .expect_purge()
+        .withf(move |x, _| *x == before)
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, limit| Ok((0..limit).map(|_| uuid::Uuid::now_v7()).collect()));
+    contents
+        .expect_purge()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, _| Ok(vec![]));
+    let mut media = MockMediaRepository::new();
+    media
+        .expect_purge()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, _| Ok(vec![]));
+    let mut tags = MockTagRepository::new();
+    tags.expect_purge()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(|_, _| Ok(vec![]));
+    let mut categories = MockCategoryRepository::new();
+    categories
+        .expect_purge()
+        .times(1)
+        .in_sequence(&mut sequence)
+        .returning(move |_, _| Ok(vec![category_id]));
+    let usecase = TrashPurgeUsecase::new(
+        Arc::new(contents),
+        Arc::new(media),
+        Arc::new(tags),
+        Arc::new(categories),
+        7,
+    );
+    let result = usecase.run(now).await.unwrap();
+
+    assert_eq!(result.contents.len(), 100);
+    assert!(result.media.is_empty());
+    assert!(result.tags.is_empty());
+    assert_eq!(result.categories, vec![category_id]);
+}
+
+#[tokio::test]
+async fn purge_failure() {
+    let mut contents = MockContentRepository::new();
+    contents
+        .expect_purge()
+        .returning(|_, _| Err(anyhow::anyhow!("connection closed")));
+    let mut media = MockMediaRepository::new();
+    media.expect_purge().never();
+    let usecase = TrashPurgeUsecase::new(
+        Arc::new(contents),
+        Arc::new(media),
+        Arc::new(MockTagRepository::new()),
+        Arc::new(MockCategoryRepository::new()),
+        30,
+    );
+    let result = usecase.run(chrono::Utc::now()).await;
+
+    assert!(result.is_err());
+}
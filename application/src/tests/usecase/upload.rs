@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        media::{checksum, Media, MediaMetadata, MediaUploader},
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+        upload::{PresignedPart, PresignedUrl, UploadError, UploadSession},
+    },
+    repository::{
+        media::MockMediaRepository,
+        upload::{CreateUploadSession, MockMediaUploadRepository},
+    },
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::upload::{MediaUploadUsecase, StartUploadInput};
+
+fn build_session(data: CreateUploadSession) -> UploadSession {
+    UploadSession {
+        id: uuid::Uuid::now_v7(),
+        object_key: "media/key.mp4".into(),
+        filename: data.filename,
+        mime_type: data.mime_type,
+        size: data.size,
+        checksum: data.checksum,
+        alt_text: data.alt_text,
+        multipart_upload_id: data.plan.as_ref().map(|_| "upload_id".into()),
+        plan: data.plan,
+        uploaded_by_id: data.uploaded_by_id,
+        expires_at: data.expires_at,
+        completed_at: None,
+    }
+}
+
+fn build_pending_session(body: &[u8]) -> UploadSession {
+    build_session(CreateUploadSession::new(
+        "video.mp4".into(),
+        "video/mp4".into(),
+        body.len() as i64,
+        checksum(body),
+        None,
+        None,
+        "user_id".into(),
+        chrono::Utc::now() + chrono::Duration::hours(1),
+    ))
+}
+
+fn build_media(session: UploadSession, metadata: MediaMetadata) -> anyhow::Result<Media> {
+    Media::try_new(
+        session.id,
+        session.object_key,
+        session.filename,
+        session.mime_type,
+        metadata.size,
+        metadata.checksum,
+        metadata.width,
+        metadata.height,
+        session.alt_text,
+        MediaUploader::new(session.uploaded_by_id, "name".into()),
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+}
+
+fn build_usecase(mock: MockMediaUploadRepository) -> MediaUploadUsecase {
+    MediaUploadUsecase::new(Arc::new(mock), Arc::new(MockMediaRepository::new()))
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::single(1024, 1)]
+#[case::multipart(200 * 1024 * 1024, 4)]
+async fn start_success(#[case] size: i64, #[case] part_count: usize) {
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_create_session()
+        .withf(move |x| {
+            x.uploaded_by_id == "user_id" && x.size == size && x.checksum == "a".repeat(64)
+        })
+        .returning(|data| Ok(build_session(data)));
+    mock.expect_presign_upload().returning(|session, _| {
+        let parts = match &session.plan {
+            Some(plan) => (1..=plan.part_count)
+                .map(|x| PresignedPart::new(x, "url".into(), plan.part_length(session.size, x)))
+                .collect(),
+            None => vec![PresignedPart::new(1, "url".into(), session.size)],
+        };
+        Ok(parts)
+    });
+    let usecase = build_usecase(mock);
+    let input = StartUploadInput::new(
+        "video.mp4".into(),
+        "video/mp4".into(),
+        size,
+        "A".repeat(64),
+        None,
+    );
+    let result = usecase.start(&super_administrator(), input).await.unwrap();
+
+    assert_eq!(result.parts.len(), part_count);
+    assert_eq!(result.session.is_multipart(), part_count > 1);
+    assert_eq!(
+        result.parts.iter().map(|x| x.size).sum::<i64>(),
+        result.session.size
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::empty_size("video.mp4", 0, "a")]
+#[case::invalid_checksum("video.mp4", 1024, "z")]
+#[case::invalid_filename("", 1024, "a")]
+async fn start_failure_with_invalid_input(
+    #[case] filename: &str,
+    #[case] size: i64,
+    #[case] checksum: &str,
+) {
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_create_session().never();
+    let usecase = build_usecase(mock);
+    let input = StartUploadInput::new(
+        filename.into(),
+        "video/mp4".into(),
+        size,
+        checksum.repeat(64),
+        None,
+    );
+    let result = usecase.start(&super_administrator(), input).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn start_failure_without_permission() {
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_create_session().never();
+    let usecase = build_usecase(mock);
+    let actor = build_scoped_actor(PermissionResource::Media, PermissionAction::Read, vec![]);
+    let input = StartUploadInput::new(
+        "video.mp4".into(),
+        "video/mp4".into(),
+        1024,
+        "a".repeat(64),
+        None,
+    );
+    let result = usecase.start(&actor, input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Media,
+            action: PermissionAction::Create,
+        })
+    );
+}
+
+#[tokio::test]
+async fn complete_success() {
+    let session = build_pending_session(b"hello");
+    let mut mock = MockMediaUploadRepository::new();
+    let found = session.clone();
+    mock.expect_find_session()
+        .returning(move |_| Ok(Some(found.clone())));
+    mock.expect_finish_upload().times(1).returning(|_| Ok(()));
+    mock.expect_inspect_object()
+        .returning(|_| Ok(MediaMetadata::inspect(b"hello")));
+    mock.expect_discard().never();
+    mock.expect_register().times(1).returning(build_media);
+    let usecase = build_usecase(mock);
+    let result = usecase
+        .complete(&super_administrator(), session.id.to_string())
+        .await;
+
+    assert_eq!(result.unwrap().id, session.id);
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::size(b"hello world".as_slice(), UploadError::SizeMismatch { expected: 5, actual: 11 })]
+#[case::checksum(b"HELLO".as_slice(), UploadError::ChecksumMismatch)]
+async fn complete_failure_with_mismatch(
+    #[case] uploaded: &'static [u8],
+    #[case] error: UploadError,
+) {
+    let session = build_pending_session(b"hello");
+    let mut mock = MockMediaUploadRepository::new();
+    let found = session.clone();
+    mock.expect_find_session()
+        .returning(move |_| Ok(Some(found.clone())));
+    mock.expect_finish_upload().returning(|_| Ok(()));
+    mock.expect_inspect_object()
+        .returning(move |_| Ok(MediaMetadata::inspect(uploaded)));
+    mock.expect_discard().times(1).returning(|_| Ok(()));
+    mock.expect_register().never();
+    let usecase = build_usecase(mock);
+    let result = usecase
+        .complete(&super_administrator(), session.id.to_string())
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<UploadError>(),
+        Some(&error)
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::expired(-1, None, "user_id", UploadError::Expired)]
+#[case::completed(1, Some(chrono::Utc::now()), "user_id", UploadError::AlreadyCompleted)]
+#[case::other_user(1, None, "other_user_id", UploadError::NotFound)]
+async fn complete_failure_with_session_state(
+    #[case] expires_in_hours: i64,
+    #[case] completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[case] uploaded_by_id: &str,
+    #[case] error: UploadError,
+) {
+    let mut session = build_pending_session(b"hello");
+    session.expires_at = chrono::Utc::now() + chrono::Duration::hours(expires_in_hours);
+    session.completed_at = completed_at;
+    session.uploaded_by_id = uploaded_by_id.into();
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_find_session()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock.expect_finish_upload().never();
+    mock.expect_register().never();
+    let usecase = build_usecase(mock);
+    let result = usecase.complete(&super_administrator(), "id".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<UploadError>(),
+        Some(&error)
+    );
+}
+
+#[tokio::test]
+async fn abort_success_with_expired_session() {
+    let mut session = build_pending_session(b"hello");
+    session.expires_at = chrono::Utc::now() - chrono::Duration::hours(1);
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_find_session()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock.expect_discard().times(1).returning(|_| Ok(()));
+    let usecase = build_usecase(mock);
+    let result = usecase.abort(&super_administrator(), "id".into()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn presign_download_success() {
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_presign_download()
+        .withf(|key, expires_in| key == "media/key.mp4" && expires_in.as_secs() == 15 * 60)
+        .returning(|key, _| Ok(PresignedUrl::new(key, chrono::Utc::now())));
+    let mut media_mock = MockMediaRepository::new();
+    media_mock.expect_find().returning(|_| {
+        build_media(
+            build_pending_session(b"hello"),
+            MediaMetadata::inspect(b"hello"),
+        )
+    });
+    let usecase = MediaUploadUsecase::new(Arc::new(mock), Arc::new(media_mock));
+    let result = usecase
+        .presign_download(&super_administrator(), "id".into())
+        .await;
+
+    assert_eq!(result.unwrap().url, "media/key.mp4");
+}
+
+#[tokio::test]
+async fn presign_download_failure_without_permission() {
+    let mut mock = MockMediaUploadRepository::new();
+    mock.expect_presign_download().never();
+    let usecase = build_usecase(mock);
+    let actor = build_scoped_actor(PermissionResource::Media, PermissionAction::Create, vec![]);
+    let result = usecase.presign_download(&actor, "id".into()).await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
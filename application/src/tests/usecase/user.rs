@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::Page,
+        role::{Permission, PermissionAction, PermissionDenied, PermissionResource, Role},
+        user::User,
+    },
+    repository::{role::MockRoleRepository, user::MockUserRepository},
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::user::{GetUserInput, UserUsecase};
+
+fn build_role() -> Role {
+    Role::try_new(
+        "role".into(),
+        "editor".into(),
+        "description".into(),
+        false,
+        vec![],
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn assign_role_success() {
+    let mut role_repository = MockRoleRepository::new();
+    role_repository
+        .expect_find()
+        .withf(|x| x == "role")
+        .returning(|_| Ok(Some(build_role())));
+    let mut repository = MockUserRepository::new();
+    repository
+        .expect_update()
+        .withf(|x| x.id == "user" && x.role_id.as_deref() == Some("role"))
+        .returning(|x| {
+            User::try_new(
+                x.id,
+                "user1".into(),
+                "https://example.com/icon.png".into(),
+                build_role(),
+            )
+        });
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(role_repository));
+    let result = usecase
+        .assign_role(&super_administrator(), "user".into(), "role".into())
+        .await;
+
+    assert_eq!(result.unwrap().role.name, "editor");
+}
+
+#[tokio::test]
+async fn assign_role_failure_with_unknown_role() {
+    let mut role_repository = MockRoleRepository::new();
+    role_repository.expect_find().returning(|_| Ok(None));
+    let mut repository = MockUserRepository::new();
+    repository.expect_update().never();
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(role_repository));
+    let result = usecase
+        .assign_role(&super_administrator(), "user".into(), "role".into())
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn assign_role_failure_without_permission() {
+    let mut repository = MockUserRepository::new();
+    repository.expect_update().never();
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(MockRoleRepository::new()));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Read, vec![]);
+    let result = usecase
+        .assign_role(&actor, "user".into(), "role".into())
+        .await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn assign_role_failure_with_super_administrator_role() {
+    let mut role_repository = MockRoleRepository::new();
+    role_repository.expect_find().returning(|_| {
+        Role::try_new(
+            "role".into(),
+            "administrator".into(),
+            "description".into(),
+            true,
+            vec![],
+        )
+        .map(Some)
+    });
+    let mut repository = MockUserRepository::new();
+    repository.expect_update().never();
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(role_repository));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Update, vec![]);
+    let result = usecase
+        .assign_role(&actor, "user_id".into(), "role".into())
+        .await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
+
+#[tokio::test]
+async fn assign_role_failure_with_permissions_beyond_actor() {
+    let mut role_repository = MockRoleRepository::new();
+    role_repository.expect_find().returning(|_| {
+        Role::try_new(
+            "role".into(),
+            "publisher".into(),
+            "description".into(),
+            false,
+            vec![Permission::new(
+                PermissionResource::Contents,
+                PermissionAction::Publish,
+                vec![],
+            )],
+        )
+        .map(Some)
+    });
+    let mut repository = MockUserRepository::new();
+    repository.expect_update().never();
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(role_repository));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Update, vec![]);
+    let result = usecase
+        .assign_role(&actor, "user_id".into(), "role".into())
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<PermissionDenied>().unwrap(),
+        PermissionDenied {
+            resource: PermissionResource::Contents,
+            action: PermissionAction::Publish
+        }
+    );
+}
+
+#[tokio::test]
+async fn get_success() {
+    let mut repository = MockUserRepository::new();
+    repository.expect_get().returning(|_| {
+        let user = User::try_new(
+            "user".into(),
+            "user1".into(),
+            "https://example.com/icon.png".into(),
+            build_role(),
+        )?;
+
+        Ok(Page::new(vec![user], None, None, None))
+    });
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(MockRoleRepository::new()));
+    let actor = build_scoped_actor(PermissionResource::Users, PermissionAction::Read, vec![]);
+    let result = usecase.get(&actor, GetUserInput::default()).await;
+
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn get_failure_without_permission() {
+    let mut repository = MockUserRepository::new();
+    repository.expect_get().never();
+
+    let usecase = UserUsecase::new(Arc::new(repository), Arc::new(MockRoleRepository::new()));
+    let actor = build_scoped_actor(PermissionResource::Tags, PermissionAction::Read, vec![]);
+    let result = usecase.get(&actor, GetUserInput::default()).await;
+
+    assert!(result
+        .unwrap_err()
+        .downcast_ref::<PermissionDenied>()
+        .is_some());
+}
@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        role::{PermissionAction, PermissionDenied, PermissionResource},
+        webhook::{
+            sign, Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookError, WebhookEvent,
+            WebhookPayload, WebhookResponse, WEBHOOK_SIGNATURE_HEADER,
+        },
+    },
+    repository::webhook::{
+        DueWebhookDelivery, MockWebhookRepository, MockWebhookSender, UpdateWebhook,
+    },
+};
+
+use crate::tests::policy::{build_scoped_actor, super_administrator};
+use crate::usecase::webhook::{CreateWebhookInput, WebhookDispatchUsecase, WebhookUsecase};
+
+fn build_webhook() -> Webhook {
+    Webhook::try_new(
+        uuid::Uuid::now_v7(),
+        "https://example.com/hook".into(),
+        vec![WebhookEvent::ContentPublished],
+        vec![],
+        true,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn build_due(attempts: i32) -> DueWebhookDelivery {
+    let payload = WebhookPayload::new(
+        WebhookEvent::ContentPublished,
+        serde_json::json!({"id": "id"}),
+        chrono::Utc::now(),
+    );
+    let delivery = WebhookDelivery::new(
+        uuid::Uuid::now_v7(),
+        uuid::Uuid::now_v7(),
+        payload.event,
+        payload,
+        WebhookDeliveryStatus::Pending,
+        attempts,
+        Some(chrono::Utc::now()),
+        None,
+        None,
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    );
+
+    DueWebhookDelivery::new(
+        delivery,
+        "https://example.com/hook".into(),
+        "0123456789abcdef".into(),
+    )
+}
+
+#[tokio::test]
+async fn create_success() {
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_create().returning(|_| Ok(build_webhook()));
+    let usecase = WebhookUsecase::new(Arc::new(mock));
+    let input = CreateWebhookInput::new(
+        "https://example.com/hook".into(),
+        vec![WebhookEvent::ContentPublished],
+        vec![],
+        "0123456789abcdef".into(),
+        true,
+    );
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::invalid_url("ftp://example.com", vec![WebhookEvent::TagCreated], "0123456789abcdef", WebhookError::InvalidUrl)]
+#[case::relative_url("/hook", vec![WebhookEvent::TagCreated], "0123456789abcdef", WebhookError::InvalidUrl)]
+#[case::no_events("https://example.com", vec![], "0123456789abcdef", WebhookError::NoEvents)]
+#[case::short_secret("https://example.com", vec![WebhookEvent::TagCreated], "secret", WebhookError::InvalidSecret)]
+async fn create_failure_with_invalid_input(
+    #[case] url: &str,
+    #[case] events: Vec<WebhookEvent>,
+    #[case] secret: &str,
+    #[case] expected: WebhookError,
+) {
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_create().never();
+    let usecase = WebhookUsecase::new(Arc::new(mock));
+    let input = CreateWebhookInput::new(url.into(), events, vec![], secret.into(), true);
+    let result = usecase.create(&super_administrator(), input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<WebhookError>(),
+        Some(&expected)
+    );
+}
+
+#[tokio::test]
+async fn update_failure_with_invalid_url() {
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_update().never();
+    let usecase = WebhookUsecase::new(Arc::new(mock));
+    let input = UpdateWebhook::new("id".into(), Some("hook".into()), None, None, None, None);
+    let result = usecase.update(&super_administrator(), input).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<WebhookError>(),
+        Some(&WebhookError::InvalidUrl)
+    );
+}
+
+#[tokio::test]
+async fn redeliver_failure_without_permission() {
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_redeliver().never();
+    let usecase = WebhookUsecase::new(Arc::new(mock));
+    let actor = build_scoped_actor(PermissionResource::Webhooks, PermissionAction::Read, vec![]);
+    let result = usecase.redeliver(&actor, "id".into(), "id".into()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<PermissionDenied>(),
+        Some(&PermissionDenied {
+            resource: PermissionResource::Webhooks,
+            action: PermissionAction::Update,
+        })
+    );
+}
+
+#[tokio::test]
+async fn dispatch_success() {
+    let due = build_due(0);
+    let id = due.delivery.id;
+    let now = chrono::Utc::now();
+    let expected_signature = sign(
+        "0123456789abcdef",
+        now.timestamp(),
+        &serde_json::to_string(&due.delivery.payload).unwrap(),
+    );
+
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_claim_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![due.clone()]));
+    mock.expect_record_attempt()
+        .withf(move |x| {
+            x.id == id
+                && x.status == WebhookDeliveryStatus::Succeeded
+                && x.attempts == 1
+                && x.response_status == Some(204)
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut sender = MockWebhookSender::new();
+    sender
+        .expect_send()
+        .withf(move |x| {
+            x.headers
+                .contains(&(WEBHOOK_SIGNATURE_HEADER.into(), expected_signature.clone()))
+        })
+        .returning(|_| Ok(WebhookResponse::new(204, "".into())));
+    let usecase = WebhookDispatchUsecase::new(Arc::new(mock), Arc::new(sender));
+    let result = usecase.run(now).await.unwrap();
+
+    assert_eq!(result.succeeded, vec![id]);
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::server_error(Ok(WebhookResponse::new(500, "error".into())))]
+#[case::connection_error(Err(anyhow::anyhow!("connection refused")))]
+async fn dispatch_retrying_with_failure(#[case] response: anyhow::Result<WebhookResponse>) {
+    let due = build_due(1);
+    let id = due.delivery.id;
+    let now = chrono::Utc::now();
+
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_claim_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![due.clone()]));
+    mock.expect_record_attempt()
+        .withf(move |x| {
+            x.status == WebhookDeliveryStatus::Pending
+                && x.attempts == 2
+                && x.next_attempt_at == Some(now + chrono::Duration::seconds(60))
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut sender = MockWebhookSender::new();
+    sender.expect_send().return_once(move |_| response);
+    let usecase = WebhookDispatchUsecase::new(Arc::new(mock), Arc::new(sender));
+    let result = usecase.run(now).await.unwrap();
+
+    assert_eq!(result.retrying, vec![id]);
+}
+
+#[tokio::test]
+async fn dispatch_failed_after_last_attempt() {
+    let due = build_due(5);
+    let id = due.delivery.id;
+
+    let mut mock = MockWebhookRepository::new();
+    mock.expect_claim_due()
+        .times(1)
+        .returning(move |_, _| Ok(vec![due.clone()]));
+    mock.expect_record_attempt()
+        .withf(|x| {
+            x.status == WebhookDeliveryStatus::Failed
+                && x.attempts == 6
+                && x.next_attempt_at.is_none()
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut sender = MockWebhookSender::new();
+    sender
+        .expect_send()
+        .returning(|_| Ok(WebhookResponse::new(404, "not found".into())));
+    let usecase = WebhookDispatchUsecase::new(Arc::new(mock), Arc::new(sender));
+    let result = usecase.run(chrono::Utc::now()).await.unwrap();
+
+    assert_eq!(result.failed, vec![id]);
+}
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        api_key::{validate_name, ApiKey, ApiKeyError, ApiKeyScope, ApiKeySecret},
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::api_key::{ApiKeyRepository, CreateApiKey, GetApiKeyQuery},
+};
+
+use crate::policy::authorize;
+
+pub type GetApiKeyInput = GetApiKeyQuery;
+
+#[derive(Debug, new)]
+pub struct IssueApiKeyInput {
+    pub name: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub category_ids: Vec<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug)]
+pub struct IssuedApiKey {
+    pub api_key: ApiKey,
+    pub token: String,
+}
+
+#[derive(new)]
+pub struct ApiKeyUsecase {
+    repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl ApiKeyUsecase {
+    pub async fn get(&self, actor: &User, input: GetApiKeyInput) -> Result<Vec<ApiKey>> {
+        authorize(actor, PermissionResource::ApiKeys, PermissionAction::Read)?;
+
+        self.repository.clone().get(input).await
+    }
+
+    pub async fn issue(&self, actor: &User, input: IssueApiKeyInput) -> Result<IssuedApiKey> {
+        authorize(actor, PermissionResource::ApiKeys, PermissionAction::Create)?;
+
+        let IssueApiKeyInput {
+            name,
+            scopes,
+            category_ids,
+            expires_at,
+        } = input;
+
+        validate_name(&name)?;
+
+        if scopes.is_empty() {
+            anyhow::bail!("Api key must have at least 1 scope")
+        }
+
+        if expires_at.is_some_and(|expires_at| expires_at <= chrono::Utc::now()) {
+            anyhow::bail!("Expiry must be in the future")
+        }
+
+        let secret = ApiKeySecret::generate();
+        let data = CreateApiKey::new(
+            name,
+            secret.prefix,
+            secret.hash,
+            scopes,
+            category_ids,
+            expires_at,
+        );
+        let api_key = self.repository.clone().create(data).await?;
+
+        Ok(IssuedApiKey {
+            api_key,
+            token: secret.token,
+        })
+    }
+
+    pub async fn revoke(&self, actor: &User, id: String) -> Result<()> {
+        authorize(actor, PermissionResource::ApiKeys, PermissionAction::Delete)?;
+
+        self.repository.clone().revoke(id).await
+    }
+
+    pub async fn authenticate(&self, token: String) -> Result<ApiKey> {
+        let secret_hash = ApiKeySecret::hash(&token);
+        let api_key = self
+            .repository
+            .clone()
+            .find_by_hash(secret_hash)
+            .await?
+            .ok_or(ApiKeyError::Invalid)?;
+
+        api_key.verify(chrono::Utc::now())?;
+
+        Ok(api_key)
+    }
+}
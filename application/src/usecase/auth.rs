@@ -0,0 +1,189 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        auth::{
+            hash_password, validate_email, validate_password, AuthError, AuthToken, Session,
+            SessionSecret, VERIFICATION_TOKEN_TTL_HOURS,
+        },
+        user::validate_profile,
+    },
+    repository::{
+        auth::{AuthRepository, CreateSession, RotateSession, SignUpUser},
+        mailer::Mailer,
+    },
+};
+
+#[derive(Debug, new)]
+pub struct SignUpInput {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    pub icon_url: String,
+}
+
+#[derive(Debug, new)]
+pub struct SignInInput {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug)]
+pub struct IssuedSession {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub access_expires_at: chrono::DateTime<chrono::Utc>,
+    pub refresh_expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<SessionSecret> for IssuedSession {
+    fn from(secret: SessionSecret) -> Self {
+        Self {
+            access_token: secret.access.token,
+            refresh_token: secret.refresh.token,
+            access_expires_at: secret.access_expires_at,
+            refresh_expires_at: secret.refresh_expires_at,
+        }
+    }
+}
+
+pub(crate) async fn issue_session(
+    repository: Arc<dyn AuthRepository>,
+    user_id: String,
+) -> Result<IssuedSession> {
+    let secret = SessionSecret::generate(chrono::Utc::now());
+    let data = CreateSession::new(
+        user_id,
+        secret.access.hash.clone(),
+        secret.access_expires_at,
+        secret.refresh.hash.clone(),
+        secret.refresh_expires_at,
+    );
+    repository.create_session(data).await?;
+
+    Ok(IssuedSession::from(secret))
+}
+
+#[derive(new)]
+pub struct AuthUsecase {
+    repository: Arc<dyn AuthRepository>,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl AuthUsecase {
+    pub async fn signup(&self, input: SignUpInput) -> Result<()> {
+        let SignUpInput {
+            name,
+            email,
+            password,
+            icon_url,
+        } = input;
+
+        validate_profile(&name, &icon_url)?;
+        validate_email(&email)?;
+        validate_password(&password)?;
+
+        let registered = self
+            .repository
+            .clone()
+            .find_credential(email.clone())
+            .await?;
+        if registered.is_some() {
+            return Err(AuthError::EmailAlreadyRegistered.into());
+        }
+
+        let password_hash = hash_password(&password)?;
+        let verification = AuthToken::verification();
+        let data = SignUpUser::new(
+            name,
+            email.clone(),
+            icon_url,
+            password_hash,
+            verification.hash,
+            chrono::Utc::now() + chrono::Duration::hours(VERIFICATION_TOKEN_TTL_HOURS),
+        );
+        self.repository.clone().signup(data).await?;
+
+        self.mailer
+            .clone()
+            .send_verification(email, verification.token)
+            .await
+    }
+
+    pub async fn verify(&self, token: String) -> Result<()> {
+        let verified = self
+            .repository
+            .clone()
+            .verify_email(AuthToken::hash(&token), chrono::Utc::now())
+            .await?;
+
+        if !verified {
+            return Err(AuthError::InvalidToken.into());
+        }
+
+        Ok(())
+    }
+
+    pub async fn signin(&self, input: SignInInput) -> Result<IssuedSession> {
+        let SignInInput { email, password } = input;
+
+        let credential = self
+            .repository
+            .clone()
+            .find_credential(email)
+            .await?
+            .ok_or(AuthError::InvalidCredentials)?;
+        credential.verify(&password)?;
+
+        issue_session(self.repository.clone(), credential.user_id).await
+    }
+
+    pub async fn refresh(&self, refresh_token: String) -> Result<IssuedSession> {
+        let now = chrono::Utc::now();
+        let previous_refresh_token_hash = AuthToken::hash(&refresh_token);
+
+        let session = self
+            .repository
+            .clone()
+            .find_session_by_refresh_token(previous_refresh_token_hash.clone())
+            .await?
+            .ok_or(AuthError::InvalidToken)?;
+        session.verify_refresh(now)?;
+
+        let secret = SessionSecret::generate(now);
+        let data = RotateSession::new(
+            session.id,
+            previous_refresh_token_hash,
+            secret.access.hash.clone(),
+            secret.access_expires_at,
+            secret.refresh.hash.clone(),
+            secret.refresh_expires_at,
+        );
+        self.repository
+            .clone()
+            .rotate_session(data)
+            .await?
+            .ok_or(AuthError::InvalidToken)?;
+
+        Ok(IssuedSession::from(secret))
+    }
+
+    pub async fn signout(&self, session_id: uuid::Uuid) -> Result<()> {
+        self.repository.clone().revoke_session(session_id).await
+    }
+
+    pub async fn authenticate(&self, access_token: String) -> Result<Session> {
+        let session = self
+            .repository
+            .clone()
+            .find_session_by_access_token(AuthToken::hash(&access_token))
+            .await?
+            .ok_or(AuthError::InvalidToken)?;
+
+        session.verify_access(chrono::Utc::now())?;
+
+        Ok(session)
+    }
+}
@@ -3,10 +3,20 @@ use derive_new::new;
 use std::sync::Arc;
 
 use domain::{
-    model::category::Category,
-    repository::category::{CategoryRepository, CreateCategory, GetCategoryQuery, UpdateCategory},
+    model::{
+        category::Category,
+        page::{Page, PageQuery, MAX_PAGE_LIMIT},
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::{
+        category::{CategoryRepository, CreateCategory, GetCategoryQuery, UpdateCategory},
+        unit_of_work::UnitOfWorkFactory,
+    },
 };
 
+use crate::policy::{authorize, authorize_category, category_scope};
+
 pub type GetCategoryInput = GetCategoryQuery;
 pub type CreateCategoryInput = CreateCategory;
 pub type UpdateCategoryInput = UpdateCategory;
@@ -14,22 +24,101 @@ pub type UpdateCategoryInput = UpdateCategory;
 #[derive(new)]
 pub struct CategoryUsecase {
     repository: Arc<dyn CategoryRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl CategoryUsecase {
-    pub async fn get(&self, input: GetCategoryInput) -> Result<Vec<Category>> {
+    pub async fn get(&self, actor: &User, mut input: GetCategoryInput) -> Result<Page<Category>> {
+        input.category_ids = category_scope(
+            actor,
+            PermissionResource::Categories,
+            PermissionAction::Read,
+        )?;
+
         self.repository.clone().get(input).await
     }
 
-    pub async fn create(&self, input: CreateCategoryInput) -> Result<Category> {
-        self.repository.clone().create(input).await
+    pub async fn content_models(&self, actor: &User) -> Result<Vec<Category>> {
+        let mut categories = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let page = PageQuery::try_new(Some(MAX_PAGE_LIMIT), cursor.as_deref(), false)?;
+            let result = self
+                .repository
+                .clone()
+                .get(GetCategoryInput::new(page))
+                .await?;
+
+            categories.extend(result.items.into_iter().filter(|category| {
+                actor.can(
+                    PermissionResource::Contents,
+                    PermissionAction::Read,
+                    Some(&category.id),
+                )
+            }));
+
+            cursor = result.next_cursor;
+            if cursor.is_none() {
+                return Ok(categories);
+            }
+        }
+    }
+
+    pub async fn create(&self, actor: &User, input: CreateCategoryInput) -> Result<Category> {
+        authorize(
+            actor,
+            PermissionResource::Categories,
+            PermissionAction::Create,
+        )?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let category = unit_of_work.categories().create(input).await?;
+        unit_of_work.commit().await?;
+
+        Ok(category)
+    }
+
+    pub async fn update(&self, actor: &User, input: UpdateCategoryInput) -> Result<Category> {
+        authorize_category(
+            actor,
+            PermissionResource::Categories,
+            PermissionAction::Update,
+            &input.id,
+        )?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let category = unit_of_work.categories().update(input).await?;
+        unit_of_work.commit().await?;
+
+        Ok(category)
     }
 
-    pub async fn update(&self, input: UpdateCategoryInput) -> Result<Category> {
-        self.repository.clone().update(input).await
+    pub async fn delete(&self, actor: &User, id: String) -> Result<()> {
+        authorize_category(
+            actor,
+            PermissionResource::Categories,
+            PermissionAction::Delete,
+            &id,
+        )?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        unit_of_work.categories().delete(id).await?;
+        unit_of_work.commit().await
     }
 
-    pub async fn delete(&self, id: String) -> Result<()> {
-        self.repository.clone().delete(id).await
+    pub async fn restore(&self, actor: &User, id: String) -> Result<Category> {
+        authorize_category(
+            actor,
+            PermissionResource::Categories,
+            PermissionAction::Delete,
+            &id,
+        )?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let category = unit_of_work.categories().restore(id).await?;
+        unit_of_work.commit().await?;
+
+        Ok(category)
     }
 }
@@ -1,15 +1,33 @@
 use anyhow::Result;
 use derive_new::new;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use domain::{
-    model::content::Content,
-    repository::content::{ContentRepository, CreateContent, GetContentQuery, UpdateContent},
+    model::{
+        content::{Content, ContentStatusChange, ContentStatusError},
+        locale::{localize, LocaleChain},
+        page::Page,
+        relation::{embed_related, ContentReferrer, Expand},
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::{
+        content::{
+            ContentRepository, CreateContent, FindRelatedQuery, GetContentQuery, UpdateContent,
+            UpdateContentStatus,
+        },
+        unit_of_work::UnitOfWorkFactory,
+    },
 };
 
+use crate::policy::{authorize_category, category_scope};
+
 #[derive(new)]
 pub struct ContentUsecase {
     repository: Arc<dyn ContentRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
 }
 
 pub type GetContentInput = GetContentQuery;
@@ -17,19 +35,242 @@ pub type CreateContentInput = CreateContent;
 pub type UpdateContentInput = UpdateContent;
 
 impl ContentUsecase {
-    pub async fn get(&self, input: GetContentInput) -> Result<Vec<Content>> {
-        self.repository.clone().get(input).await
+    pub async fn get(
+        &self,
+        actor: &User,
+        mut input: GetContentInput,
+        expand: &Expand,
+        locale: Option<&LocaleChain>,
+    ) -> Result<Page<Content>> {
+        let category_ids =
+            category_scope(actor, PermissionResource::Contents, PermissionAction::Read)?;
+        input.category_ids = category_ids.clone();
+
+        let mut page = self.repository.clone().get(input).await?;
+        self.resolve(&mut page.items, expand, locale, &category_ids)
+            .await?;
+
+        Ok(page)
+    }
+
+    pub async fn find(
+        &self,
+        actor: &User,
+        id: String,
+        expand: &Expand,
+        locale: Option<&LocaleChain>,
+    ) -> Result<Content> {
+        let content = find_authorized(&self.repository, actor, id, PermissionAction::Read).await?;
+        let category_ids =
+            category_scope(actor, PermissionResource::Contents, PermissionAction::Read)?;
+
+        let mut contents = vec![content];
+        self.resolve(&mut contents, expand, locale, &category_ids)
+            .await?;
+
+        Ok(contents.remove(0))
+    }
+
+    pub async fn referrers(&self, actor: &User, id: String) -> Result<Vec<ContentReferrer>> {
+        find_authorized(&self.repository, actor, id.clone(), PermissionAction::Read).await?;
+        let category_ids =
+            category_scope(actor, PermissionResource::Contents, PermissionAction::Read)?;
+
+        self.repository.clone().referrers(id, category_ids).await
+    }
+
+    pub async fn create(&self, actor: &User, mut input: CreateContentInput) -> Result<Content> {
+        authorize_category(
+            actor,
+            PermissionResource::Contents,
+            PermissionAction::Create,
+            &input.category_id,
+        )?;
+
+        let change = ContentStatusChange::initial(
+            input.status,
+            input.published_at,
+            input.unpublish_at,
+            chrono::Utc::now(),
+        )?;
+        input.status = change.status;
+        input.published_at = change.published_at;
+        input.unpublish_at = change.unpublish_at;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let content = unit_of_work.contents().create(input).await?;
+        unit_of_work.commit().await?;
+
+        Ok(content)
+    }
+
+    pub async fn update(&self, actor: &User, input: UpdateContentInput) -> Result<Content> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let contents = unit_of_work.contents();
+        find_authorized(&contents, actor, input.id.clone(), PermissionAction::Update).await?;
+
+        if let Some(category_id) = &input.category_id {
+            authorize_category(
+                actor,
+                PermissionResource::Contents,
+                PermissionAction::Update,
+                category_id,
+            )?;
+        }
+
+        let content = contents.update(input).await?;
+        unit_of_work.commit().await?;
+
+        Ok(content)
+    }
+
+    pub async fn delete(&self, actor: &User, id: String) -> Result<()> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let contents = unit_of_work.contents();
+        find_authorized(&contents, actor, id.clone(), PermissionAction::Delete).await?;
+
+        contents.delete(id).await?;
+        unit_of_work.commit().await
+    }
+
+    pub async fn restore(&self, actor: &User, id: String) -> Result<Content> {
+        let category_ids = category_scope(
+            actor,
+            PermissionResource::Contents,
+            PermissionAction::Delete,
+        )?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let content = unit_of_work.contents().restore(id, category_ids).await?;
+        unit_of_work.commit().await?;
+
+        Ok(content)
+    }
+
+    pub async fn publish(&self, actor: &User, id: String) -> Result<Content> {
+        self.update_status(actor, id, |content| content.publish(chrono::Utc::now()))
+            .await
+    }
+
+    pub async fn unpublish(&self, actor: &User, id: String) -> Result<Content> {
+        self.update_status(actor, id, Content::unpublish).await
+    }
+
+    pub async fn schedule(
+        &self,
+        actor: &User,
+        id: String,
+        published_at: chrono::DateTime<chrono::Utc>,
+        unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Content> {
+        self.update_status(actor, id, |content| {
+            content.schedule(published_at, unpublish_at, chrono::Utc::now())
+        })
+        .await
     }
 
-    pub async fn create(&self, input: CreateContentInput) -> Result<Content> {
-        self.repository.clone().create(input).await
+    pub async fn revert_to_draft(&self, actor: &User, id: String) -> Result<Content> {
+        self.update_status(actor, id, Content::revert_to_draft)
+            .await
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        contents: &'a mut [Content],
+        expand: &'a Expand,
+        locale: Option<&'a LocaleChain>,
+        category_ids: &'a Option<Vec<String>>,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            if let Some(locale) = locale {
+                self.localize(contents, locale).await?;
+            }
+
+            if expand.is_empty() || contents.is_empty() {
+                return Ok(());
+            }
+
+            let content_ids = contents.iter().map(|content| content.id).collect();
+            let query = FindRelatedQuery::new(content_ids, expand.keys(), category_ids.clone());
+            let mut related = self.repository.clone().find_related(query).await?;
+
+            for (key, child) in &expand.fields {
+                let (matched, rest) = related
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|x| &x.field_key == key);
+                related = rest;
+
+                let (source_ids, mut targets): (Vec<_>, Vec<_>) = matched
+                    .into_iter()
+                    .map(|x| (x.content_id, x.content))
+                    .unzip();
+                self.resolve(&mut targets, child, locale, category_ids)
+                    .await?;
+
+                let values = source_ids
+                    .into_iter()
+                    .zip(targets)
+                    .map(|(id, target)| Ok((id, serde_json::to_value(target)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                embed_related(contents, key, values);
+            }
+
+            Ok(())
+        })
     }
 
-    pub async fn update(&self, input: UpdateContentInput) -> Result<Content> {
-        self.repository.clone().update(input).await
+    async fn localize(&self, contents: &mut [Content], locale: &LocaleChain) -> Result<()> {
+        let localizations = match locale.locales.is_empty() || contents.is_empty() {
+            true => vec![],
+            false => {
+                let content_ids = contents.iter().map(|content| content.id).collect();
+                self.repository
+                    .clone()
+                    .find_localizations(content_ids)
+                    .await?
+            }
+        };
+
+        for content in contents {
+            localize(content, locale, &localizations);
+        }
+
+        Ok(())
     }
 
-    pub async fn delete(&self, id: String) -> Result<()> {
-        self.repository.clone().delete(id).await
+    async fn update_status(
+        &self,
+        actor: &User,
+        id: String,
+        change: impl FnOnce(&Content) -> Result<ContentStatusChange, ContentStatusError>,
+    ) -> Result<Content> {
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let contents = unit_of_work.contents();
+        let content =
+            find_authorized(&contents, actor, id.clone(), PermissionAction::Publish).await?;
+        let change = change(&content)?;
+
+        let input = UpdateContentStatus::new(id, content.status, change, actor.id.clone());
+        let content = contents.update_status(input).await?;
+        unit_of_work.commit().await?;
+
+        Ok(content)
     }
 }
+
+async fn find_authorized(
+    repository: &Arc<dyn ContentRepository>,
+    actor: &User,
+    id: String,
+    action: PermissionAction,
+) -> Result<Content> {
+    let content = repository.clone().find(id).await?;
+    authorize_category(
+        actor,
+        PermissionResource::Contents,
+        action,
+        &content.category.id,
+    )?;
+
+    Ok(content)
+}
@@ -0,0 +1,197 @@
+use anyhow::Result;
+use derive_new::new;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        api_key::ApiKey,
+        content::Content,
+        delivery::DeliveryContent,
+        locale::{localize, LocaleChain},
+        media::{MediaReference, DELIVERY_URL_EXPIRES_IN},
+        relation::{embed_related, Expand},
+    },
+    repository::{
+        delivery::{DeliveryRepository, FindDeliveryRelatedQuery, GetDeliveryQuery},
+        upload::MediaUploadRepository,
+    },
+};
+
+pub type GetDeliveryInput = GetDeliveryQuery;
+
+struct DeliveryScope {
+    include_drafts: bool,
+    api_identifiers: Option<Vec<String>>,
+    locale: Option<LocaleChain>,
+}
+
+impl DeliveryScope {
+    fn new(api_key: &ApiKey, include_drafts: bool, locale: Option<&LocaleChain>) -> Self {
+        let api_identifiers = Some(&api_key.categories)
+            .filter(|categories| !categories.is_empty())
+            .map(|categories| {
+                categories
+                    .iter()
+                    .map(|category| category.api_identifier.clone())
+                    .collect()
+            });
+
+        Self {
+            include_drafts,
+            api_identifiers,
+            locale: locale.cloned(),
+        }
+    }
+}
+
+#[derive(new)]
+pub struct DeliveryUsecase {
+    repository: Arc<dyn DeliveryRepository>,
+    upload_repository: Arc<dyn MediaUploadRepository>,
+}
+
+impl DeliveryUsecase {
+    pub async fn get(
+        &self,
+        api_key: &ApiKey,
+        api_identifier: String,
+        input: GetDeliveryInput,
+        expand: &Expand,
+        locale: Option<&LocaleChain>,
+    ) -> Result<Vec<Content>> {
+        let include_drafts = input.include_drafts;
+        let mut contents = self.repository.clone().get(api_identifier, input).await?;
+        let scope = DeliveryScope::new(api_key, include_drafts, locale);
+        self.resolve(&mut contents, expand, &scope).await?;
+
+        Ok(contents)
+    }
+
+    pub async fn find(
+        &self,
+        api_key: &ApiKey,
+        api_identifier: String,
+        id: String,
+        include_drafts: bool,
+        expand: &Expand,
+        locale: Option<&LocaleChain>,
+    ) -> Result<Content> {
+        let content = self
+            .repository
+            .clone()
+            .find(api_identifier, id, include_drafts)
+            .await?;
+        let mut contents = vec![content];
+        let scope = DeliveryScope::new(api_key, include_drafts, locale);
+        self.resolve(&mut contents, expand, &scope).await?;
+
+        Ok(contents.remove(0))
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        contents: &'a mut [Content],
+        expand: &'a Expand,
+        scope: &'a DeliveryScope,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            if let Some(locale) = &scope.locale {
+                self.localize(contents, locale, scope.include_drafts)
+                    .await?;
+            }
+            self.expand_media(contents).await?;
+
+            if expand.is_empty() || contents.is_empty() {
+                return Ok(());
+            }
+
+            let content_ids = contents.iter().map(|content| content.id).collect();
+            let query = FindDeliveryRelatedQuery::new(
+                content_ids,
+                expand.keys(),
+                scope.include_drafts,
+                scope.api_identifiers.clone(),
+            );
+            let mut related = self.repository.clone().find_related(query).await?;
+
+            for (key, child) in &expand.fields {
+                let (matched, rest) = related
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|x| &x.field_key == key);
+                related = rest;
+
+                let (source_ids, mut targets): (Vec<_>, Vec<_>) = matched
+                    .into_iter()
+                    .map(|x| (x.content_id, x.content))
+                    .unzip();
+                self.resolve(&mut targets, child, scope).await?;
+
+                let values = source_ids
+                    .into_iter()
+                    .zip(targets)
+                    .map(|(id, target)| {
+                        Ok((id, serde_json::to_value(DeliveryContent::from(target))?))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                embed_related(contents, key, values);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn localize(
+        &self,
+        contents: &mut [Content],
+        locale: &LocaleChain,
+        include_drafts: bool,
+    ) -> Result<()> {
+        let localizations = match locale.locales.is_empty() || contents.is_empty() {
+            true => vec![],
+            false => {
+                let content_ids = contents.iter().map(|content| content.id).collect();
+                self.repository
+                    .clone()
+                    .find_localizations(content_ids, include_drafts)
+                    .await?
+            }
+        };
+
+        for content in contents {
+            localize(content, locale, &localizations);
+        }
+
+        Ok(())
+    }
+
+    async fn expand_media(&self, contents: &mut [Content]) -> Result<()> {
+        if contents.is_empty() {
+            return Ok(());
+        }
+
+        let content_ids = contents.iter().map(|content| content.id).collect();
+        let references = self.repository.clone().find_media(content_ids).await?;
+
+        for reference in references {
+            let Some(fields) = contents
+                .iter_mut()
+                .find(|content| content.id == reference.content_id)
+                .and_then(|content| content.fields.as_object_mut())
+            else {
+                continue;
+            };
+
+            let url = self
+                .upload_repository
+                .clone()
+                .presign_download(reference.media.object_key.clone(), DELIVERY_URL_EXPIRES_IN)
+                .await?;
+            let value = serde_json::to_value(MediaReference::new(reference.media, url.url))?;
+            fields.insert(reference.field_key, value);
+        }
+
+        Ok(())
+    }
+}
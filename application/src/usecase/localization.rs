@@ -0,0 +1,120 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        locale::{ContentLocalization, Locales},
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::{
+        content::ContentRepository,
+        localization::{
+            ContentLocalizationRepository, UpdateContentLocalizationStatus,
+            UpsertContentLocalization,
+        },
+    },
+};
+
+use crate::policy::authorize_category;
+
+pub type UpsertContentLocalizationInput = UpsertContentLocalization;
+
+#[derive(new)]
+pub struct ContentLocalizationUsecase {
+    repository: Arc<dyn ContentLocalizationRepository>,
+    content_repository: Arc<dyn ContentRepository>,
+    locales: Locales,
+}
+
+impl ContentLocalizationUsecase {
+    pub async fn get(&self, actor: &User, content_id: String) -> Result<Vec<ContentLocalization>> {
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Read)
+            .await?;
+
+        self.repository.clone().get(content_id).await
+    }
+
+    pub async fn upsert(
+        &self,
+        actor: &User,
+        input: UpsertContentLocalizationInput,
+    ) -> Result<ContentLocalization> {
+        self.locales.localizable(&input.locale)?;
+        self.authorize_content(actor, input.content_id.clone(), PermissionAction::Update)
+            .await?;
+
+        self.repository.clone().upsert(input).await
+    }
+
+    pub async fn delete(&self, actor: &User, content_id: String, locale: String) -> Result<()> {
+        self.locales.localizable(&locale)?;
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Update)
+            .await?;
+
+        self.repository.clone().delete(content_id, locale).await
+    }
+
+    pub async fn publish(
+        &self,
+        actor: &User,
+        content_id: String,
+        locale: String,
+    ) -> Result<ContentLocalization> {
+        let localization = self
+            .find_authorized(actor, content_id.clone(), locale.clone())
+            .await?;
+        let change = localization.publish(chrono::Utc::now())?;
+        let input =
+            UpdateContentLocalizationStatus::new(content_id, locale, change, actor.id.clone());
+
+        self.repository.clone().update_status(input).await
+    }
+
+    pub async fn unpublish(
+        &self,
+        actor: &User,
+        content_id: String,
+        locale: String,
+    ) -> Result<ContentLocalization> {
+        let localization = self
+            .find_authorized(actor, content_id.clone(), locale.clone())
+            .await?;
+        let change = localization.unpublish()?;
+        let input =
+            UpdateContentLocalizationStatus::new(content_id, locale, change, actor.id.clone());
+
+        self.repository.clone().update_status(input).await
+    }
+
+    async fn find_authorized(
+        &self,
+        actor: &User,
+        content_id: String,
+        locale: String,
+    ) -> Result<ContentLocalization> {
+        self.locales.localizable(&locale)?;
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Publish)
+            .await?;
+
+        self.repository.clone().find(content_id, locale).await
+    }
+
+    async fn authorize_content(
+        &self,
+        actor: &User,
+        content_id: String,
+        action: PermissionAction,
+    ) -> Result<()> {
+        let content = self.content_repository.clone().find(content_id).await?;
+        authorize_category(
+            actor,
+            PermissionResource::Contents,
+            action,
+            &content.category.id,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        media::{
+            validate_alt_text, validate_filename, validate_mime_type, Media, MediaMetadata,
+            MediaUsage,
+        },
+        page::Page,
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::media::{CreateMedia, GetMediaQuery, MediaObject, MediaRepository, UpdateMedia},
+};
+
+use crate::policy::authorize;
+
+pub type GetMediaInput = GetMediaQuery;
+pub type UpdateMediaInput = UpdateMedia;
+
+#[derive(Debug, new)]
+pub struct UploadMediaInput {
+    pub filename: String,
+    pub mime_type: String,
+    pub body: Vec<u8>,
+    pub alt_text: Option<String>,
+}
+
+#[derive(new)]
+pub struct MediaUsecase {
+    repository: Arc<dyn MediaRepository>,
+}
+
+impl MediaUsecase {
+    pub async fn get(&self, actor: &User, input: GetMediaInput) -> Result<Page<Media>> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        self.repository.clone().get(input).await
+    }
+
+    pub async fn find(&self, actor: &User, id: String) -> Result<Media> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        self.repository.clone().find(id).await
+    }
+
+    pub async fn download(&self, actor: &User, id: String) -> Result<MediaObject> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        self.repository.clone().download(id).await
+    }
+
+    pub async fn upload(&self, actor: &User, input: UploadMediaInput) -> Result<Media> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Create)?;
+
+        let UploadMediaInput {
+            filename,
+            mime_type,
+            body,
+            alt_text,
+        } = input;
+
+        validate_filename(&filename)?;
+        validate_mime_type(&mime_type)?;
+
+        if let Some(alt_text) = &alt_text {
+            validate_alt_text(alt_text)?;
+        }
+
+        if body.is_empty() {
+            anyhow::bail!("Media must not be empty")
+        }
+
+        let metadata = MediaMetadata::inspect(&body);
+        let data = CreateMedia::new(
+            filename,
+            mime_type,
+            body,
+            metadata,
+            alt_text,
+            actor.id.clone(),
+        );
+
+        self.repository.clone().create(data).await
+    }
+
+    pub async fn update(&self, actor: &User, input: UpdateMediaInput) -> Result<Media> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Update)?;
+
+        if let Some(filename) = &input.filename {
+            validate_filename(filename)?;
+        }
+
+        if let Some(alt_text) = &input.alt_text {
+            validate_alt_text(alt_text)?;
+        }
+
+        self.repository.clone().update(input).await
+    }
+
+    pub async fn usages(&self, actor: &User, id: String) -> Result<Vec<MediaUsage>> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        self.repository.clone().usages(id).await
+    }
+
+    pub async fn delete(&self, actor: &User, id: String, force: bool) -> Result<()> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Delete)?;
+
+        self.repository.clone().delete(id, force).await
+    }
+
+    pub async fn restore(&self, actor: &User, id: String) -> Result<Media> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Delete)?;
+
+        self.repository.clone().restore(id).await
+    }
+}
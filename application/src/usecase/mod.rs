@@ -1,2 +1,18 @@
+pub mod api_key;
+pub mod auth;
 pub mod category;
 pub mod content;
+pub mod delivery;
+pub mod localization;
+pub mod media;
+pub mod oidc;
+pub mod outbox;
+pub mod render;
+pub mod revision;
+pub mod role;
+pub mod schedule;
+pub mod tag;
+pub mod trash;
+pub mod upload;
+pub mod user;
+pub mod webhook;
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        auth::AuthError,
+        oidc::{OidcLoginState, RoleMapping},
+    },
+    repository::{
+        auth::AuthRepository,
+        oidc::{LinkOidcIdentity, OidcClient, OidcRepository},
+    },
+};
+
+use crate::usecase::auth::{issue_session, IssuedSession};
+
+#[derive(new)]
+pub struct OidcUsecase {
+    client: Arc<dyn OidcClient>,
+    repository: Arc<dyn OidcRepository>,
+    auth_repository: Arc<dyn AuthRepository>,
+    role_mapping: RoleMapping,
+}
+
+impl OidcUsecase {
+    pub async fn login(&self) -> Result<String> {
+        let login_state = OidcLoginState::generate(chrono::Utc::now());
+        self.repository
+            .clone()
+            .save_login_state(login_state.clone())
+            .await?;
+
+        self.client.clone().authorization_url(login_state).await
+    }
+
+    pub async fn callback(&self, code: String, state: String) -> Result<IssuedSession> {
+        let login_state = self
+            .repository
+            .clone()
+            .take_login_state(state, chrono::Utc::now())
+            .await?
+            .ok_or(AuthError::InvalidLoginState)?;
+
+        let claims = self.client.clone().exchange(code, login_state).await?;
+
+        let data = LinkOidcIdentity::new(
+            claims.issuer.clone(),
+            claims.subject.clone(),
+            claims.verified_email(),
+            claims.display_name(),
+            claims.icon_url(),
+            self.role_mapping.resolve(&claims.groups),
+            self.role_mapping.default_role().into(),
+        );
+        let user_id = self.repository.clone().link_identity(data).await?;
+
+        issue_session(self.auth_repository.clone(), user_id).await
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        outbox::OutboxEvent,
+        webhook::{WebhookEvent, WebhookPayload},
+    },
+    repository::{
+        outbox::{AppendOutboxEvent, OutboxRepository, OutboxSink, RecordOutboxFailure},
+        webhook::{EnqueueWebhookEvent, WebhookRepository},
+    },
+};
+
+const BATCH_SIZE: i64 = 100;
+
+pub(crate) async fn append(
+    outbox: &Arc<dyn OutboxRepository>,
+    event: WebhookEvent,
+    category_id: Option<&str>,
+    data: &impl serde::Serialize,
+) -> Result<()> {
+    let payload = WebhookPayload::new(event, serde_json::to_value(data)?, chrono::Utc::now());
+    let input = AppendOutboxEvent::new(category_id.map(String::from), payload);
+    outbox.clone().append(input).await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct RelayOutput {
+    pub relayed: Vec<uuid::Uuid>,
+    pub retrying: Vec<uuid::Uuid>,
+}
+
+#[derive(new)]
+pub struct OutboxRelayUsecase {
+    repository: Arc<dyn OutboxRepository>,
+    sinks: Vec<Arc<dyn OutboxSink>>,
+}
+
+impl OutboxRelayUsecase {
+    pub async fn run(&self, now: chrono::DateTime<chrono::Utc>) -> Result<RelayOutput> {
+        let mut output = RelayOutput::default();
+
+        loop {
+            let events = self.repository.clone().claim_due(now, BATCH_SIZE).await?;
+            let done = (events.len() as i64) < BATCH_SIZE;
+
+            for event in events {
+                let id = event.id;
+                match self.relay(event, now).await? {
+                    true => output.relayed.push(id),
+                    false => output.retrying.push(id),
+                }
+            }
+
+            if done {
+                return Ok(output);
+            }
+        }
+    }
+
+    async fn relay(&self, event: OutboxEvent, now: chrono::DateTime<chrono::Utc>) -> Result<bool> {
+        let mut errors = vec![];
+
+        for sink in self.sinks.iter() {
+            if let Err(error) = sink.handle(&event).await {
+                errors.push(format!("{}: {}", sink.name(), error));
+            }
+        }
+
+        if errors.is_empty() {
+            self.repository.clone().complete(event.id).await?;
+            return Ok(true);
+        }
+
+        let input = RecordOutboxFailure::new(
+            event.id,
+            event.attempts + 1,
+            event.retry_at(now),
+            errors.join("\n"),
+        );
+        self.repository.clone().record_failure(input).await?;
+
+        Ok(false)
+    }
+}
+
+#[derive(new)]
+pub struct WebhookSink {
+    repository: Arc<dyn WebhookRepository>,
+}
+
+#[async_trait::async_trait]
+impl OutboxSink for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn handle(&self, event: &OutboxEvent) -> Result<()> {
+        let input = EnqueueWebhookEvent::new(event.category_id.clone(), event.payload.clone());
+        self.repository.clone().enqueue(input).await?;
+
+        Ok(())
+    }
+}
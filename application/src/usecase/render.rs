@@ -0,0 +1,93 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        render::{RenderError, RenderOptions, RenderSigner, RenderedImage, RENDER_URL_EXPIRES_IN},
+        role::{PermissionAction, PermissionResource},
+        upload::PresignedUrl,
+        user::User,
+    },
+    repository::{media::MediaRepository, render::MediaRenderRepository},
+};
+
+use crate::policy::authorize;
+
+#[derive(new)]
+pub struct MediaRenderUsecase {
+    media_repository: Arc<dyn MediaRepository>,
+    render_repository: Arc<dyn MediaRenderRepository>,
+    signer: Option<RenderSigner>,
+}
+
+impl MediaRenderUsecase {
+    pub async fn render(
+        &self,
+        actor: &User,
+        id: String,
+        options: RenderOptions,
+    ) -> Result<RenderedImage> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        self.render_media(id, options).await
+    }
+
+    pub async fn render_signed(
+        &self,
+        id: String,
+        options: RenderOptions,
+        expires: i64,
+        signature: String,
+    ) -> Result<RenderedImage> {
+        let signer = self.signer.as_ref().ok_or(RenderError::SigningDisabled)?;
+        signer.verify(&id, &options, expires, &signature, chrono::Utc::now())?;
+
+        self.render_media(id, options).await
+    }
+
+    pub async fn sign(
+        &self,
+        actor: &User,
+        id: String,
+        options: RenderOptions,
+    ) -> Result<PresignedUrl> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        let signer = self.signer.as_ref().ok_or(RenderError::SigningDisabled)?;
+        let media = self.media_repository.clone().find(id).await?;
+
+        if !media.is_image() {
+            return Err(RenderError::NotAnImage.into());
+        }
+
+        let expires_at = chrono::Utc::now() + RENDER_URL_EXPIRES_IN;
+        let path = signer.signed_path(&media.id.to_string(), &options, expires_at.timestamp());
+
+        Ok(PresignedUrl::new(path, expires_at))
+    }
+
+    async fn render_media(&self, id: String, options: RenderOptions) -> Result<RenderedImage> {
+        let media = self.media_repository.clone().find(id).await?;
+
+        if !media.is_image() {
+            return Err(RenderError::NotAnImage.into());
+        }
+
+        let key = options.variant_key(&media.id);
+
+        if let Some(variant) = self
+            .render_repository
+            .clone()
+            .find_variant(key.clone())
+            .await?
+        {
+            return Ok(variant);
+        }
+
+        self.render_repository
+            .clone()
+            .render(media, options, key)
+            .await
+    }
+}
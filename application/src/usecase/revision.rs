@@ -0,0 +1,132 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        content::Content,
+        revision::{ContentRevision, RevisionDiff},
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::{
+        content::{ContentRepository, UpdateContent},
+        revision::{ContentRevisionRepository, GetRevisionQuery},
+    },
+};
+
+use crate::policy::authorize_category;
+
+pub type GetRevisionInput = GetRevisionQuery;
+
+#[derive(new)]
+pub struct ContentRevisionUsecase {
+    repository: Arc<dyn ContentRevisionRepository>,
+    content_repository: Arc<dyn ContentRepository>,
+}
+
+impl ContentRevisionUsecase {
+    pub async fn get(
+        &self,
+        actor: &User,
+        content_id: String,
+        input: GetRevisionInput,
+    ) -> Result<Vec<ContentRevision>> {
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Read)
+            .await?;
+
+        self.repository.clone().get(content_id, input).await
+    }
+
+    pub async fn find(
+        &self,
+        actor: &User,
+        content_id: String,
+        revision: i32,
+    ) -> Result<ContentRevision> {
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Read)
+            .await?;
+
+        self.repository.clone().find(content_id, revision).await
+    }
+
+    pub async fn diff(
+        &self,
+        actor: &User,
+        content_id: String,
+        from: i32,
+        to: i32,
+    ) -> Result<RevisionDiff> {
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Read)
+            .await?;
+
+        let from = self
+            .repository
+            .clone()
+            .find(content_id.clone(), from)
+            .await?;
+        let to = self.repository.clone().find(content_id, to).await?;
+
+        Ok(from.diff(&to))
+    }
+
+    pub async fn restore(
+        &self,
+        actor: &User,
+        content_id: String,
+        revision: i32,
+    ) -> Result<Content> {
+        self.authorize_content(actor, content_id.clone(), PermissionAction::Update)
+            .await?;
+
+        let ContentRevision {
+            title,
+            category,
+            fields,
+            tags,
+            ..
+        } = self
+            .repository
+            .clone()
+            .find(content_id.clone(), revision)
+            .await?;
+
+        authorize_category(
+            actor,
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            &category.id,
+        )?;
+
+        let tag_ids = tags.into_iter().map(|tag| tag.id).collect();
+        let input = UpdateContent::new(
+            content_id,
+            Some(title),
+            Some(category.id),
+            Some(fields),
+            Some(tag_ids),
+            None,
+            actor.id.clone(),
+            None,
+        );
+
+        self.content_repository.clone().update(input).await
+    }
+
+    async fn authorize_content(
+        &self,
+        actor: &User,
+        content_id: String,
+        action: PermissionAction,
+    ) -> Result<()> {
+        let content = self.content_repository.clone().find(content_id).await?;
+        authorize_category(
+            actor,
+            PermissionResource::Contents,
+            action,
+            &content.category.id,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        role::{
+            validate_description, validate_name, validate_permissions, Permission,
+            PermissionAction, PermissionResource, Role, RoleError,
+        },
+        user::User,
+    },
+    repository::role::{CreateRole, GetRoleQuery, RoleRepository, UpdateRole},
+};
+
+use crate::policy::{authorize, authorize_grant};
+
+pub type GetRoleInput = GetRoleQuery;
+pub type UpdateRoleInput = UpdateRole;
+
+#[derive(Debug, new)]
+pub struct CreateRoleInput {
+    pub name: String,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(new)]
+pub struct RoleUsecase {
+    repository: Arc<dyn RoleRepository>,
+}
+
+impl RoleUsecase {
+    pub async fn get(&self, actor: &User, input: GetRoleInput) -> Result<Vec<Role>> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Read)?;
+
+        self.repository.clone().get(input).await
+    }
+
+    pub async fn find(&self, actor: &User, id: String) -> Result<Option<Role>> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Read)?;
+
+        self.repository.clone().find(id).await
+    }
+
+    pub async fn create(&self, actor: &User, input: CreateRoleInput) -> Result<Role> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Update)?;
+
+        let CreateRoleInput {
+            name,
+            description,
+            permissions,
+        } = input;
+
+        validate_name(&name)?;
+        validate_description(&description)?;
+        validate_permissions(&permissions)?;
+        authorize_grant(actor, false, &permissions)?;
+
+        let data = CreateRole::new(name, description, permissions);
+        self.repository.clone().create(data).await
+    }
+
+    pub async fn update(&self, actor: &User, input: UpdateRoleInput) -> Result<Role> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Update)?;
+
+        if let Some(name) = &input.name {
+            validate_name(name)?;
+        }
+
+        if let Some(description) = &input.description {
+            validate_description(description)?;
+        }
+
+        if let Some(permissions) = &input.permissions {
+            validate_permissions(permissions)?;
+            authorize_grant(actor, false, permissions)?;
+        }
+
+        self.ensure_modifiable(input.id.clone()).await?;
+        self.repository.clone().update(input).await
+    }
+
+    pub async fn delete(&self, actor: &User, id: String) -> Result<()> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Update)?;
+
+        self.ensure_modifiable(id.clone()).await?;
+        self.repository.clone().delete(id).await
+    }
+
+    async fn ensure_modifiable(&self, id: String) -> Result<()> {
+        let role = self
+            .repository
+            .clone()
+            .find(id.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Role {} is not found", id))?;
+
+        if role.is_super_administrator {
+            return Err(RoleError::Protected.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::repository::schedule::ContentScheduleRepository;
+
+const BATCH_SIZE: i64 = 100;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ScheduleOutput {
+    pub published: Vec<uuid::Uuid>,
+    pub unpublished: Vec<uuid::Uuid>,
+}
+
+#[derive(new)]
+pub struct ContentScheduleUsecase {
+    repository: Arc<dyn ContentScheduleRepository>,
+}
+
+impl ContentScheduleUsecase {
+    pub async fn run(&self, now: chrono::DateTime<chrono::Utc>) -> Result<ScheduleOutput> {
+        let mut output = ScheduleOutput::default();
+
+        loop {
+            let published = self.repository.clone().publish_due(now, BATCH_SIZE).await?;
+            let done = (published.len() as i64) < BATCH_SIZE;
+            output.published.extend(published);
+            if done {
+                break;
+            }
+        }
+
+        loop {
+            let unpublished = self
+                .repository
+                .clone()
+                .unpublish_due(now, BATCH_SIZE)
+                .await?;
+            let done = (unpublished.len() as i64) < BATCH_SIZE;
+            output.unpublished.extend(unpublished);
+            if done {
+                break;
+            }
+        }
+
+        Ok(output)
+    }
+}
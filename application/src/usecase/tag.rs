@@ -0,0 +1,95 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::Page,
+        role::{PermissionAction, PermissionResource},
+        tag::Tag,
+        user::User,
+        webhook::WebhookEvent,
+    },
+    repository::{
+        tag::{CreateTag, GetTagQuery, TagRepository, UpdateTag},
+        unit_of_work::UnitOfWorkFactory,
+    },
+};
+
+use crate::policy::authorize;
+use crate::usecase::outbox::append;
+
+pub type GetTagInput = GetTagQuery;
+pub type CreateTagInput = CreateTag;
+pub type UpdateTagInput = UpdateTag;
+
+#[derive(new)]
+pub struct TagUsecase {
+    repository: Arc<dyn TagRepository>,
+    unit_of_work: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl TagUsecase {
+    pub async fn get(&self, actor: &User, input: GetTagInput) -> Result<Page<Tag>> {
+        authorize(actor, PermissionResource::Tags, PermissionAction::Read)?;
+
+        self.repository.clone().get(input).await
+    }
+
+    pub async fn create(&self, actor: &User, input: CreateTagInput) -> Result<Tag> {
+        authorize(actor, PermissionResource::Tags, PermissionAction::Create)?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let tag = unit_of_work.tags().create(input).await?;
+        append(&unit_of_work.outbox(), WebhookEvent::TagCreated, None, &tag).await?;
+        unit_of_work.commit().await?;
+
+        Ok(tag)
+    }
+
+    pub async fn update(&self, actor: &User, input: UpdateTagInput) -> Result<Tag> {
+        authorize(actor, PermissionResource::Tags, PermissionAction::Update)?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let tag = unit_of_work.tags().update(input).await?;
+        append(&unit_of_work.outbox(), WebhookEvent::TagUpdated, None, &tag).await?;
+        unit_of_work.commit().await?;
+
+        Ok(tag)
+    }
+
+    pub async fn delete(&self, actor: &User, id: String) -> Result<()> {
+        authorize(actor, PermissionResource::Tags, PermissionAction::Delete)?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        unit_of_work.tags().delete(id.clone()).await?;
+        let data = serde_json::json!({ "id": id });
+        append(
+            &unit_of_work.outbox(),
+            WebhookEvent::TagDeleted,
+            None,
+            &data,
+        )
+        .await?;
+        unit_of_work.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn restore(&self, actor: &User, id: String) -> Result<Tag> {
+        authorize(actor, PermissionResource::Tags, PermissionAction::Delete)?;
+
+        let unit_of_work = self.unit_of_work.begin().await?;
+        let tag = unit_of_work.tags().restore(id).await?;
+        append(
+            &unit_of_work.outbox(),
+            WebhookEvent::TagRestored,
+            None,
+            &tag,
+        )
+        .await?;
+        unit_of_work.commit().await?;
+
+        Ok(tag)
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::Result;
+use derive_new::new;
+use std::future::Future;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::{Page, PageQuery},
+        role::PermissionAction,
+        trash::{purge_before, TrashKind, TrashedItem},
+        user::User,
+    },
+    repository::{
+        category::CategoryRepository,
+        content::ContentRepository,
+        media::MediaRepository,
+        tag::TagRepository,
+        trash::{GetTrashQuery, TrashRepository, TrashScope},
+    },
+};
+
+use crate::policy::category_scope;
+
+const BATCH_SIZE: i64 = 100;
+
+#[derive(new)]
+pub struct TrashUsecase {
+    repository: Arc<dyn TrashRepository>,
+}
+
+impl TrashUsecase {
+    pub async fn get(
+        &self,
+        actor: &User,
+        page: PageQuery,
+        kinds: Option<Vec<TrashKind>>,
+    ) -> Result<Page<TrashedItem>> {
+        let mut scopes: Vec<TrashScope> = vec![];
+
+        for kind in kinds.clone().unwrap_or(TrashKind::ALL.to_vec()) {
+            if scopes.iter().any(|scope| scope.kind == kind) {
+                continue;
+            }
+
+            match category_scope(actor, kind.resource(), PermissionAction::Delete) {
+                Ok(category_ids) => scopes.push(TrashScope::new(kind, category_ids)),
+                Err(error) if kinds.is_some() => return Err(error.into()),
+                Err(_) => {}
+            }
+        }
+
+        self.repository
+            .clone()
+            .get(GetTrashQuery::new(page, scopes))
+            .await
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct PurgeOutput {
+    pub contents: Vec<uuid::Uuid>,
+    pub media: Vec<uuid::Uuid>,
+    pub tags: Vec<uuid::Uuid>,
+    pub categories: Vec<uuid::Uuid>,
+}
+
+#[derive(new)]
+pub struct TrashPurgeUsecase {
+    contents: Arc<dyn ContentRepository>,
+    media: Arc<dyn MediaRepository>,
+    tags: Arc<dyn TagRepository>,
+    categories: Arc<dyn CategoryRepository>,
+    retention_days: i64,
+}
+
+impl TrashPurgeUsecase {
+    pub async fn run(&self, now: chrono::DateTime<chrono::Utc>) -> Result<PurgeOutput> {
+        let before = purge_before(now, self.retention_days);
+
+        Ok(PurgeOutput {
+            contents: drain(|| self.contents.purge(before, BATCH_SIZE)).await?,
+            media: drain(|| self.media.purge(before, BATCH_SIZE)).await?,
+            tags: drain(|| self.tags.purge(before, BATCH_SIZE)).await?,
+            categories: drain(|| self.categories.purge(before, BATCH_SIZE)).await?,
+        })
+    }
+}
+
+async fn drain<F, Fut>(mut purge: F) -> Result<Vec<uuid::Uuid>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Vec<uuid::Uuid>>>,
+{
+    let mut ids = vec![];
+
+    loop {
+        let purged = purge().await?;
+        let done = (purged.len() as i64) < BATCH_SIZE;
+        ids.extend(purged);
+        if done {
+            return Ok(ids);
+        }
+    }
+}
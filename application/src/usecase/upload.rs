@@ -0,0 +1,151 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        media::{validate_alt_text, validate_filename, validate_mime_type, Media},
+        role::{PermissionAction, PermissionResource},
+        upload::{
+            validate_checksum, PresignedPart, PresignedUrl, UploadError, UploadPlan, UploadSession,
+            DOWNLOAD_EXPIRES_IN, UPLOAD_EXPIRES_IN,
+        },
+        user::User,
+    },
+    repository::{
+        media::MediaRepository,
+        upload::{CreateUploadSession, MediaUploadRepository},
+    },
+};
+
+use crate::policy::authorize;
+
+#[derive(Debug, new)]
+pub struct StartUploadInput {
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StartedUpload {
+    pub session: UploadSession,
+    pub parts: Vec<PresignedPart>,
+}
+
+#[derive(new)]
+pub struct MediaUploadUsecase {
+    repository: Arc<dyn MediaUploadRepository>,
+    media_repository: Arc<dyn MediaRepository>,
+}
+
+impl MediaUploadUsecase {
+    pub async fn start(&self, actor: &User, input: StartUploadInput) -> Result<StartedUpload> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Create)?;
+
+        let StartUploadInput {
+            filename,
+            mime_type,
+            size,
+            checksum,
+            alt_text,
+        } = input;
+
+        validate_filename(&filename)?;
+        validate_mime_type(&mime_type)?;
+        validate_checksum(&checksum)?;
+
+        if let Some(alt_text) = &alt_text {
+            validate_alt_text(alt_text)?;
+        }
+
+        let plan = UploadPlan::for_size(size)?;
+        let data = CreateUploadSession::new(
+            filename,
+            mime_type,
+            size,
+            checksum.to_ascii_lowercase(),
+            alt_text,
+            plan,
+            actor.id.clone(),
+            chrono::Utc::now() + UPLOAD_EXPIRES_IN,
+        );
+
+        let session = self.repository.clone().create_session(data).await?;
+        let parts = self
+            .repository
+            .clone()
+            .presign_upload(session.clone(), UPLOAD_EXPIRES_IN)
+            .await?;
+
+        Ok(StartedUpload { session, parts })
+    }
+
+    pub async fn complete(&self, actor: &User, id: String) -> Result<Media> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Create)?;
+
+        let session = self.find_session(actor, id).await?;
+        session.verify(&actor.id, chrono::Utc::now())?;
+
+        self.repository
+            .clone()
+            .finish_upload(session.clone())
+            .await?;
+        let metadata = self
+            .repository
+            .clone()
+            .inspect_object(session.object_key.clone())
+            .await?;
+
+        let mismatch = if metadata.size != session.size {
+            Some(UploadError::SizeMismatch {
+                expected: session.size,
+                actual: metadata.size,
+            })
+        } else if metadata.checksum != session.checksum {
+            Some(UploadError::ChecksumMismatch)
+        } else {
+            None
+        };
+
+        if let Some(error) = mismatch {
+            self.repository.clone().discard(session).await?;
+            return Err(error.into());
+        }
+
+        self.repository.clone().register(session, metadata).await
+    }
+
+    pub async fn abort(&self, actor: &User, id: String) -> Result<()> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Create)?;
+
+        let session = self.find_session(actor, id).await?;
+
+        match session.verify(&actor.id, chrono::Utc::now()) {
+            Ok(()) | Err(UploadError::Expired) => self.repository.clone().discard(session).await,
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    pub async fn presign_download(&self, actor: &User, media_id: String) -> Result<PresignedUrl> {
+        authorize(actor, PermissionResource::Media, PermissionAction::Read)?;
+
+        let media = self.media_repository.clone().find(media_id).await?;
+
+        self.repository
+            .clone()
+            .presign_download(media.object_key, DOWNLOAD_EXPIRES_IN)
+            .await
+    }
+
+    async fn find_session(&self, actor: &User, id: String) -> Result<UploadSession> {
+        let session = self.repository.clone().find_session(id).await?;
+
+        match session {
+            Some(session) if session.uploaded_by_id == actor.id => Ok(session),
+            _ => Err(UploadError::NotFound.into()),
+        }
+    }
+}
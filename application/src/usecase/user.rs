@@ -0,0 +1,53 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::Page,
+        role::{PermissionAction, PermissionResource},
+        user::User,
+    },
+    repository::{
+        role::RoleRepository,
+        user::{GetUserQuery, UpdateUser, UserRepository},
+    },
+};
+
+use crate::policy::{authorize, authorize_grant};
+
+pub type GetUserInput = GetUserQuery;
+
+#[derive(new)]
+pub struct UserUsecase {
+    repository: Arc<dyn UserRepository>,
+    role_repository: Arc<dyn RoleRepository>,
+}
+
+impl UserUsecase {
+    pub async fn get(&self, actor: &User, input: GetUserInput) -> Result<Page<User>> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Read)?;
+
+        self.repository.clone().get(input).await
+    }
+
+    pub async fn assign_role(
+        &self,
+        actor: &User,
+        user_id: String,
+        role_id: String,
+    ) -> Result<User> {
+        authorize(actor, PermissionResource::Users, PermissionAction::Update)?;
+
+        let role = self
+            .role_repository
+            .clone()
+            .find(role_id.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Role {} is not found", role_id))?;
+        authorize_grant(actor, role.is_super_administrator, &role.permissions)?;
+
+        let data = UpdateUser::new(user_id, None, None, Some(role_id));
+        self.repository.clone().update(data).await
+    }
+}
@@ -0,0 +1,195 @@
+use anyhow::Result;
+use derive_new::new;
+use std::sync::Arc;
+
+use domain::{
+    model::{
+        page::Page,
+        role::{PermissionAction, PermissionResource},
+        user::User,
+        webhook::{
+            validate_events, validate_secret, validate_url, Webhook, WebhookDelivery,
+            WebhookDeliveryStatus, WebhookRequest,
+        },
+    },
+    repository::webhook::{
+        CreateWebhook, DueWebhookDelivery, GetWebhookDeliveryQuery, RecordWebhookAttempt,
+        UpdateWebhook, WebhookRepository, WebhookSender,
+    },
+};
+
+use crate::policy::authorize;
+
+const BATCH_SIZE: i64 = 100;
+
+pub type CreateWebhookInput = CreateWebhook;
+pub type UpdateWebhookInput = UpdateWebhook;
+pub type GetWebhookDeliveryInput = GetWebhookDeliveryQuery;
+
+#[derive(new)]
+pub struct WebhookUsecase {
+    repository: Arc<dyn WebhookRepository>,
+}
+
+impl WebhookUsecase {
+    pub async fn get(&self, actor: &User) -> Result<Vec<Webhook>> {
+        authorize(actor, PermissionResource::Webhooks, PermissionAction::Read)?;
+
+        self.repository.clone().get().await
+    }
+
+    pub async fn find(&self, actor: &User, id: String) -> Result<Webhook> {
+        authorize(actor, PermissionResource::Webhooks, PermissionAction::Read)?;
+
+        self.repository.clone().find(id).await
+    }
+
+    pub async fn create(&self, actor: &User, input: CreateWebhookInput) -> Result<Webhook> {
+        authorize(
+            actor,
+            PermissionResource::Webhooks,
+            PermissionAction::Create,
+        )?;
+
+        validate_url(&input.url)?;
+        validate_events(&input.events)?;
+        validate_secret(&input.secret)?;
+
+        self.repository.clone().create(input).await
+    }
+
+    pub async fn update(&self, actor: &User, input: UpdateWebhookInput) -> Result<Webhook> {
+        authorize(
+            actor,
+            PermissionResource::Webhooks,
+            PermissionAction::Update,
+        )?;
+
+        if let Some(url) = &input.url {
+            validate_url(url)?;
+        }
+
+        if let Some(events) = &input.events {
+            validate_events(events)?;
+        }
+
+        if let Some(secret) = &input.secret {
+            validate_secret(secret)?;
+        }
+
+        self.repository.clone().update(input).await
+    }
+
+    pub async fn delete(&self, actor: &User, id: String) -> Result<()> {
+        authorize(
+            actor,
+            PermissionResource::Webhooks,
+            PermissionAction::Delete,
+        )?;
+
+        self.repository.clone().delete(id).await
+    }
+
+    pub async fn get_deliveries(
+        &self,
+        actor: &User,
+        input: GetWebhookDeliveryInput,
+    ) -> Result<Page<WebhookDelivery>> {
+        authorize(actor, PermissionResource::Webhooks, PermissionAction::Read)?;
+
+        self.repository.clone().get_deliveries(input).await
+    }
+
+    pub async fn redeliver(
+        &self,
+        actor: &User,
+        webhook_id: String,
+        delivery_id: String,
+    ) -> Result<WebhookDelivery> {
+        authorize(
+            actor,
+            PermissionResource::Webhooks,
+            PermissionAction::Update,
+        )?;
+
+        self.repository
+            .clone()
+            .redeliver(webhook_id, delivery_id)
+            .await
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct DispatchOutput {
+    pub succeeded: Vec<uuid::Uuid>,
+    pub retrying: Vec<uuid::Uuid>,
+    pub failed: Vec<uuid::Uuid>,
+}
+
+#[derive(new)]
+pub struct WebhookDispatchUsecase {
+    repository: Arc<dyn WebhookRepository>,
+    sender: Arc<dyn WebhookSender>,
+}
+
+impl WebhookDispatchUsecase {
+    pub async fn run(&self, now: chrono::DateTime<chrono::Utc>) -> Result<DispatchOutput> {
+        let mut output = DispatchOutput::default();
+
+        loop {
+            let deliveries = self.repository.clone().claim_due(now, BATCH_SIZE).await?;
+            let done = (deliveries.len() as i64) < BATCH_SIZE;
+
+            for due in deliveries {
+                let id = due.delivery.id;
+                match self.dispatch(due, now).await? {
+                    WebhookDeliveryStatus::Succeeded => output.succeeded.push(id),
+                    WebhookDeliveryStatus::Pending => output.retrying.push(id),
+                    WebhookDeliveryStatus::Failed => output.failed.push(id),
+                }
+            }
+
+            if done {
+                return Ok(output);
+            }
+        }
+    }
+
+    async fn dispatch(
+        &self,
+        due: DueWebhookDelivery,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<WebhookDeliveryStatus> {
+        let DueWebhookDelivery {
+            delivery,
+            url,
+            secret,
+        } = due;
+
+        let request = WebhookRequest::signed(url, &secret, &delivery, now)?;
+        let (succeeded, response_status, response_body, error) =
+            match self.sender.clone().send(request).await {
+                Ok(response) => (
+                    response.is_success(),
+                    Some(i32::from(response.status)),
+                    Some(response.logged_body()),
+                    None,
+                ),
+                Err(error) => (false, None, None, Some(error.to_string())),
+            };
+
+        let attempt = delivery.attempt(succeeded, now);
+        let input = RecordWebhookAttempt::new(
+            delivery.id,
+            attempt.status,
+            attempt.attempts,
+            attempt.next_attempt_at,
+            response_status,
+            response_body,
+            error,
+        );
+        self.repository.clone().record_attempt(input).await?;
+
+        Ok(attempt.status)
+    }
+}
@@ -0,0 +1,157 @@
+use sha2::Digest;
+
+const TOKEN_PREFIX: &str = "hcms_";
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, Eq, Hash,
+)]
+pub enum ApiKeyScope {
+    ReadPublished,
+    ReadDrafts,
+}
+
+impl ApiKeyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReadPublished => "ReadPublished",
+            Self::ReadDrafts => "ReadDrafts",
+        }
+    }
+}
+
+impl std::str::FromStr for ApiKeyScope {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "ReadPublished" => Ok(Self::ReadPublished),
+            "ReadDrafts" => Ok(Self::ReadDrafts),
+            _ => anyhow::bail!("Unknown api key scope {}", value),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
+pub struct ApiKeyCategory {
+    pub id: String,
+    pub api_identifier: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone)]
+pub struct ApiKey {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub categories: Vec<ApiKeyCategory>,
+    #[schema(value_type = Option<String>)]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[schema(value_type = Option<String>)]
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[schema(value_type = String)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ApiKey {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: uuid::Uuid,
+        name: String,
+        prefix: String,
+        scopes: Vec<ApiKeyScope>,
+        categories: Vec<ApiKeyCategory>,
+        expires_at: Option<chrono::DateTime<chrono::Utc>>,
+        revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+        created_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Self> {
+        validate_name(&name)?;
+
+        if scopes.is_empty() {
+            anyhow::bail!("Api key must have at least 1 scope")
+        }
+
+        Ok(Self {
+            id,
+            name,
+            prefix,
+            scopes,
+            categories,
+            expires_at,
+            revoked_at,
+            created_at,
+        })
+    }
+
+    pub fn verify(&self, now: chrono::DateTime<chrono::Utc>) -> Result<(), ApiKeyError> {
+        if self.revoked_at.is_some() {
+            return Err(ApiKeyError::Revoked);
+        }
+
+        if self.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(ApiKeyError::Expired);
+        }
+
+        Ok(())
+    }
+
+    pub fn has_scope(&self, scope: &ApiKeyScope) -> bool {
+        self.scopes.contains(scope)
+    }
+
+    pub fn allows_category(&self, api_identifier: &str) -> bool {
+        self.categories.is_empty()
+            || self
+                .categories
+                .iter()
+                .any(|category| category.api_identifier == api_identifier)
+    }
+}
+
+pub fn validate_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty() {
+        anyhow::bail!("Name must be at least 1 character long")
+    }
+
+    if name.len() > 100 {
+        anyhow::bail!("Name exceeds maximum length(100 characters)")
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ApiKeyError {
+    #[error("Api key is invalid")]
+    Invalid,
+    #[error("Api key is expired")]
+    Expired,
+    #[error("Api key is revoked")]
+    Revoked,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiKeySecret {
+    pub token: String,
+    pub prefix: String,
+    pub hash: String,
+}
+
+impl ApiKeySecret {
+    pub fn generate() -> Self {
+        let bytes: [u8; 32] = rand::random();
+        let token = format!("{}{}", TOKEN_PREFIX, hex::encode(bytes));
+        let prefix = token[..TOKEN_PREFIX.len() + 8].to_string();
+        let hash = Self::hash(&token);
+
+        Self {
+            token,
+            prefix,
+            hash,
+        }
+    }
+
+    pub fn hash(token: &str) -> String {
+        hex::encode(sha2::Sha256::digest(token.as_bytes()))
+    }
+}
@@ -0,0 +1,172 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use sha2::Digest;
+
+use crate::model::user::User;
+
+const ACCESS_TOKEN_PREFIX: &str = "hcms_at_";
+const REFRESH_TOKEN_PREFIX: &str = "hcms_rt_";
+const VERIFICATION_TOKEN_PREFIX: &str = "hcms_vt_";
+
+pub const ACCESS_TOKEN_TTL_MINUTES: i64 = 15;
+pub const REFRESH_TOKEN_TTL_DAYS: i64 = 30;
+pub const VERIFICATION_TOKEN_TTL_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum AuthError {
+    #[error("Email or password is incorrect")]
+    InvalidCredentials,
+    #[error("Email address is not verified")]
+    EmailNotVerified,
+    #[error("Email address is already registered")]
+    EmailAlreadyRegistered,
+    #[error("Email address belongs to an account that cannot be linked")]
+    EmailNotLinkable,
+    #[error("Token is invalid or expired")]
+    InvalidToken,
+    #[error("Login state is invalid or expired")]
+    InvalidLoginState,
+    #[error("ID token is invalid")]
+    InvalidIdToken,
+}
+
+pub fn validate_email(email: &str) -> anyhow::Result<()> {
+    if email.len() > 255 {
+        anyhow::bail!("Email exceeds maximum length(255 characters)")
+    }
+
+    match email.split_once('@') {
+        Some((local, domain)) if !local.is_empty() && domain.contains('.') => Ok(()),
+        _ => anyhow::bail!("Email is invalid"),
+    }
+}
+
+pub fn validate_password(password: &str) -> anyhow::Result<()> {
+    let length = password.chars().count();
+
+    if length < 8 {
+        anyhow::bail!("Password must be at least 8 characters long")
+    }
+
+    if length > 128 {
+        anyhow::bail!("Password exceeds maximum length(128 characters)")
+    }
+
+    Ok(())
+}
+
+pub fn hash_password(password: &str) -> anyhow::Result<String> {
+    let bytes: [u8; 16] = rand::random();
+    let salt = SaltString::encode_b64(&bytes).map_err(|error| anyhow::anyhow!(error))?;
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|error| anyhow::anyhow!(error))?;
+
+    Ok(hash.to_string())
+}
+
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|hash| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthToken {
+    pub token: String,
+    pub hash: String,
+}
+
+impl AuthToken {
+    fn generate(prefix: &str) -> Self {
+        let bytes: [u8; 32] = rand::random();
+        let token = format!("{}{}", prefix, hex::encode(bytes));
+        let hash = Self::hash(&token);
+
+        Self { token, hash }
+    }
+
+    pub fn verification() -> Self {
+        Self::generate(VERIFICATION_TOKEN_PREFIX)
+    }
+
+    pub fn hash(token: &str) -> String {
+        hex::encode(sha2::Sha256::digest(token.as_bytes()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionSecret {
+    pub access: AuthToken,
+    pub refresh: AuthToken,
+    pub access_expires_at: chrono::DateTime<chrono::Utc>,
+    pub refresh_expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl SessionSecret {
+    pub fn generate(now: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            access: AuthToken::generate(ACCESS_TOKEN_PREFIX),
+            refresh: AuthToken::generate(REFRESH_TOKEN_PREFIX),
+            access_expires_at: now + chrono::Duration::minutes(ACCESS_TOKEN_TTL_MINUTES),
+            refresh_expires_at: now + chrono::Duration::days(REFRESH_TOKEN_TTL_DAYS),
+        }
+    }
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+pub struct Credential {
+    pub user_id: String,
+    pub password_hash: Option<String>,
+    pub email_verified_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl Credential {
+    pub fn verify(&self, password: &str) -> Result<(), AuthError> {
+        let matched = self
+            .password_hash
+            .as_deref()
+            .is_some_and(|password_hash| verify_password(password, password_hash));
+
+        if !matched {
+            return Err(AuthError::InvalidCredentials);
+        }
+
+        if self.email_verified_at.is_none() {
+            return Err(AuthError::EmailNotVerified);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+pub struct Session {
+    pub id: uuid::Uuid,
+    pub user: User,
+    pub access_expires_at: chrono::DateTime<chrono::Utc>,
+    pub refresh_expires_at: chrono::DateTime<chrono::Utc>,
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl Session {
+    pub fn verify_access(&self, now: chrono::DateTime<chrono::Utc>) -> Result<(), AuthError> {
+        if self.revoked_at.is_some() || self.access_expires_at <= now {
+            return Err(AuthError::InvalidToken);
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_refresh(&self, now: chrono::DateTime<chrono::Utc>) -> Result<(), AuthError> {
+        if self.revoked_at.is_some() || self.refresh_expires_at <= now {
+            return Err(AuthError::InvalidToken);
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
+use crate::model::field::{validate_schema, Field};
+use crate::model::version::INITIAL_VERSION;
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone)]
 pub struct Category {
     pub id: String,
     pub name: String,
     pub api_identifier: String,
     pub description: Option<String>,
+    pub fields: Vec<Field>,
+    pub version: i32,
 }
 
 impl Category {
@@ -12,8 +17,9 @@ impl Category {
         name: String,
         api_identifier: String,
         description: Option<String>,
+        fields: Vec<Field>,
     ) -> anyhow::Result<Self> {
-        if name.len() < 1 {
+        if name.is_empty() {
             anyhow::bail!("Name must be at least 1 character long");
         }
 
@@ -21,7 +27,7 @@ impl Category {
             anyhow::bail!("Name exceeds maximum length(50 characters)");
         }
 
-        if api_identifier.len() < 1 {
+        if api_identifier.is_empty() {
             anyhow::bail!("Api identifier must be at least 1 character long");
         }
 
@@ -35,11 +41,15 @@ impl Category {
             }
         }
 
+        validate_schema(&fields)?;
+
         Ok(Self {
             id,
             name,
             api_identifier,
             description,
+            fields,
+            version: INITIAL_VERSION,
         })
     }
 }
@@ -1,16 +1,25 @@
-#[derive(Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema)]
+use crate::model::search::SearchMatch;
+use crate::model::version::INITIAL_VERSION;
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema, Clone, PartialEq,
+)]
 pub struct ContentCategory {
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema)]
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema, Clone, PartialEq,
+)]
 pub struct ContentTag {
     pub id: String,
     pub name: String,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema)]
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema, Clone, PartialEq,
+)]
 pub enum ContentStatus {
     Draft,
     Published,
@@ -18,6 +27,127 @@ pub enum ContentStatus {
     Unpublished,
 }
 
+impl ContentStatus {
+    pub fn can_transition_to(&self, to: &ContentStatus) -> bool {
+        use ContentStatus::*;
+
+        matches!(
+            (self, to),
+            (Draft, Published)
+                | (Draft, Reserved)
+                | (Reserved, Reserved)
+                | (Reserved, Published)
+                | (Reserved, Draft)
+                | (Published, Unpublished)
+                | (Published, Draft)
+                | (Unpublished, Published)
+                | (Unpublished, Reserved)
+                | (Unpublished, Draft)
+        )
+    }
+
+    pub(crate) fn transition_to(&self, to: &ContentStatus) -> Result<(), ContentStatusError> {
+        if !self.can_transition_to(to) {
+            return Err(ContentStatusError::InvalidTransition {
+                from: self.clone(),
+                to: to.clone(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+pub enum ContentStatusError {
+    #[error("Content status cannot change from {from:?} to {to:?}")]
+    InvalidTransition {
+        from: ContentStatus,
+        to: ContentStatus,
+    },
+    #[error("Reserved content requires a publish time")]
+    MissingSchedule,
+    #[error("Scheduled publish time must be in the future")]
+    ScheduleInPast,
+    #[error("Unpublish time must be after publish time")]
+    InvalidUnpublishTime,
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct ContentStatusChange {
+    pub status: ContentStatus,
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ContentStatusChange {
+    pub fn initial(
+        status: ContentStatus,
+        published_at: Option<chrono::DateTime<chrono::Utc>>,
+        unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Self, ContentStatusError> {
+        match status {
+            ContentStatus::Draft => Ok(Self::new(ContentStatus::Draft, None, None)),
+            ContentStatus::Published => publish(&ContentStatus::Draft, None, unpublish_at, now),
+            ContentStatus::Reserved => schedule(
+                &ContentStatus::Draft,
+                published_at.ok_or(ContentStatusError::MissingSchedule)?,
+                unpublish_at,
+                now,
+            ),
+            ContentStatus::Unpublished => Err(ContentStatusError::InvalidTransition {
+                from: ContentStatus::Draft,
+                to: ContentStatus::Unpublished,
+            }),
+        }
+    }
+}
+
+pub(crate) fn publish(
+    from: &ContentStatus,
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
+    unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<ContentStatusChange, ContentStatusError> {
+    from.transition_to(&ContentStatus::Published)?;
+
+    let published_at = match from {
+        ContentStatus::Unpublished => published_at.unwrap_or(now),
+        _ => now,
+    };
+    let unpublish_at = unpublish_at.filter(|unpublish_at| *unpublish_at > now);
+
+    Ok(ContentStatusChange::new(
+        ContentStatus::Published,
+        Some(published_at),
+        unpublish_at,
+    ))
+}
+
+fn schedule(
+    from: &ContentStatus,
+    published_at: chrono::DateTime<chrono::Utc>,
+    unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<ContentStatusChange, ContentStatusError> {
+    from.transition_to(&ContentStatus::Reserved)?;
+
+    if published_at <= now {
+        return Err(ContentStatusError::ScheduleInPast);
+    }
+
+    if unpublish_at.is_some_and(|unpublish_at| unpublish_at <= published_at) {
+        return Err(ContentStatusError::InvalidUnpublishTime);
+    }
+
+    Ok(ContentStatusChange::new(
+        ContentStatus::Reserved,
+        Some(published_at),
+        unpublish_at,
+    ))
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
 pub struct CreatedBy {
     pub id: String,
@@ -30,6 +160,7 @@ pub struct UpdatedBy {
     pub name: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 #[derive(Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema)]
 pub struct Content {
     #[schema(value_type = String)]
@@ -43,13 +174,24 @@ pub struct Content {
     pub updated_by: UpdatedBy,
     #[schema(value_type = Option<String>)]
     pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[schema(value_type = Option<String>)]
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
     #[schema(value_type = String)]
     pub created_at: chrono::DateTime<chrono::Utc>,
     #[schema(value_type = String)]
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    #[new(value = "INITIAL_VERSION")]
+    pub version: i32,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<SearchMatch>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
 }
 
 impl Content {
+    #[allow(clippy::too_many_arguments)]
     pub fn try_new(
         id: uuid::Uuid,
         title: String,
@@ -60,10 +202,11 @@ impl Content {
         created_by: CreatedBy,
         updated_by: UpdatedBy,
         published_at: Option<chrono::DateTime<chrono::Utc>>,
+        unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
         created_at: chrono::DateTime<chrono::Utc>,
         updated_at: chrono::DateTime<chrono::Utc>,
     ) -> anyhow::Result<Self> {
-        if title.len() < 1 {
+        if title.is_empty() {
             anyhow::bail!("Title must be at least 1 character long")
         }
 
@@ -71,6 +214,8 @@ impl Content {
             anyhow::bail!("Title exceeds maximum length(50 characters)")
         }
 
+        validate_schedule(published_at, unpublish_at)?;
+
         Ok(Self {
             id,
             title,
@@ -81,8 +226,59 @@ impl Content {
             created_by,
             updated_by,
             published_at,
+            unpublish_at,
             created_at,
             updated_at,
+            version: INITIAL_VERSION,
+            search: None,
+            locale: None,
         })
     }
 }
+
+impl Content {
+    pub fn publish(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ContentStatusChange, ContentStatusError> {
+        publish(&self.status, self.published_at, self.unpublish_at, now)
+    }
+
+    pub fn unpublish(&self) -> Result<ContentStatusChange, ContentStatusError> {
+        self.status.transition_to(&ContentStatus::Unpublished)?;
+
+        Ok(ContentStatusChange::new(
+            ContentStatus::Unpublished,
+            self.published_at,
+            None,
+        ))
+    }
+
+    pub fn schedule(
+        &self,
+        published_at: chrono::DateTime<chrono::Utc>,
+        unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ContentStatusChange, ContentStatusError> {
+        schedule(&self.status, published_at, unpublish_at, now)
+    }
+
+    pub fn revert_to_draft(&self) -> Result<ContentStatusChange, ContentStatusError> {
+        self.status.transition_to(&ContentStatus::Draft)?;
+
+        Ok(ContentStatusChange::new(ContentStatus::Draft, None, None))
+    }
+}
+
+pub fn validate_schedule(
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
+    unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> anyhow::Result<()> {
+    if let (Some(published_at), Some(unpublish_at)) = (published_at, unpublish_at) {
+        if unpublish_at <= published_at {
+            anyhow::bail!("Unpublish time must be after publish time")
+        }
+    }
+
+    Ok(())
+}
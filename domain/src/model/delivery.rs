@@ -0,0 +1,44 @@
+use crate::model::content::Content;
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+pub struct DeliveryContent {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub title: String,
+    pub category: String,
+    pub fields: serde_json::Value,
+    pub tags: Vec<String>,
+    #[schema(value_type = Option<String>)]
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[schema(value_type = String)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+impl From<Content> for DeliveryContent {
+    fn from(content: Content) -> Self {
+        let Content {
+            id,
+            title,
+            category,
+            fields,
+            tags,
+            published_at,
+            updated_at,
+            locale,
+            ..
+        } = content;
+
+        Self {
+            id,
+            title,
+            category: category.name,
+            fields,
+            tags: tags.into_iter().map(|tag| tag.name).collect(),
+            published_at,
+            updated_at,
+            locale,
+        }
+    }
+}
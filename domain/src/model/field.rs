@@ -0,0 +1,295 @@
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq)]
+pub enum FieldType {
+    Text,
+    RichText,
+    Number,
+    Boolean,
+    Date,
+    Media,
+    Content,
+    Enum,
+    Json,
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new,
+)]
+pub struct Field {
+    pub field_type: FieldType,
+    pub key: String,
+    #[new(default)]
+    #[serde(default)]
+    pub required: bool,
+    #[new(default)]
+    #[serde(default)]
+    pub unique: bool,
+    #[new(default)]
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[new(default)]
+    #[serde(default)]
+    pub max: Option<f64>,
+    #[new(default)]
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[new(default)]
+    #[serde(default)]
+    pub multiple: bool,
+    #[new(default)]
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[new(default)]
+    #[serde(default)]
+    pub localizable: bool,
+}
+
+impl Field {
+    pub fn validate_definition(&self) -> anyhow::Result<()> {
+        if self.key.is_empty() {
+            anyhow::bail!("Field key must be at least 1 character long");
+        }
+
+        if self.key.len() > 64 {
+            anyhow::bail!("Field key exceeds maximum length(64 characters)");
+        }
+
+        if let (Some(min), Some(max)) = (self.min, self.max) {
+            if min > max {
+                anyhow::bail!("Field {} has min greater than max", self.key);
+            }
+        }
+
+        if self.field_type == FieldType::Enum && self.options.is_empty() {
+            anyhow::bail!("Enum field {} must have at least 1 option", self.key);
+        }
+
+        if self.field_type != FieldType::Content && (self.multiple || !self.categories.is_empty()) {
+            anyhow::bail!(
+                "Field {} can only be multiple or restricted to categories as a content field",
+                self.key
+            );
+        }
+
+        if self.localizable && matches!(self.field_type, FieldType::Media | FieldType::Content) {
+            anyhow::bail!(
+                "Field {} cannot be localizable as a media or content field",
+                self.key
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_value(&self, value: Option<&serde_json::Value>) -> Result<(), FieldError> {
+        let value = match value {
+            None | Some(serde_json::Value::Null) => {
+                if self.required {
+                    return Err(self.error("is required"));
+                }
+                return Ok(());
+            }
+            Some(value) => value,
+        };
+
+        match self.field_type {
+            FieldType::Text | FieldType::RichText => {
+                let text = value
+                    .as_str()
+                    .ok_or_else(|| self.error("must be a string"))?;
+                self.validate_range(text.chars().count() as f64, "length")
+            }
+            FieldType::Number => {
+                let number = value
+                    .as_f64()
+                    .ok_or_else(|| self.error("must be a number"))?;
+                self.validate_range(number, "value")
+            }
+            FieldType::Boolean => {
+                value
+                    .as_bool()
+                    .ok_or_else(|| self.error("must be a boolean"))?;
+                Ok(())
+            }
+            FieldType::Date => {
+                let date = value
+                    .as_str()
+                    .ok_or_else(|| self.error("must be a string"))?;
+                let is_datetime = chrono::DateTime::parse_from_rfc3339(date).is_ok();
+                let is_date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok();
+                if !is_datetime && !is_date {
+                    return Err(self.error("must be a RFC 3339 date"));
+                }
+                Ok(())
+            }
+            FieldType::Content if self.multiple => {
+                let ids = value
+                    .as_array()
+                    .ok_or_else(|| self.error("must be an array"))?;
+                for id in ids {
+                    self.validate_id(id)?;
+                }
+                self.validate_range(ids.len() as f64, "count")
+            }
+            FieldType::Media | FieldType::Content => self.validate_id(value),
+            FieldType::Enum => {
+                let option = value
+                    .as_str()
+                    .ok_or_else(|| self.error("must be a string"))?;
+                if !self.options.iter().any(|o| o == option) {
+                    return Err(self.error("is not one of the allowed options"));
+                }
+                Ok(())
+            }
+            FieldType::Json => Ok(()),
+        }
+    }
+
+    fn validate_id(&self, value: &serde_json::Value) -> Result<(), FieldError> {
+        let id = value
+            .as_str()
+            .ok_or_else(|| self.error("must be a string"))?;
+        uuid::Uuid::parse_str(id).map_err(|_| self.error("must be a valid id"))?;
+        Ok(())
+    }
+
+    fn validate_range(&self, actual: f64, label: &str) -> Result<(), FieldError> {
+        if let Some(min) = self.min {
+            if actual < min {
+                return Err(self.error(&format!("{} must be at least {}", label, min)));
+            }
+        }
+
+        if let Some(max) = self.max {
+            if actual > max {
+                return Err(self.error(&format!("{} must be at most {}", label, max)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn error(&self, message: &str) -> FieldError {
+        FieldError::new(self.key.clone(), message.into())
+    }
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new)]
+pub struct FieldError {
+    pub key: String,
+    pub message: String,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, thiserror::Error)]
+#[error("Fields do not match the category schema")]
+pub struct FieldValidationError {
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct FieldReference {
+    pub key: String,
+    pub id: uuid::Uuid,
+}
+
+pub fn field_references(
+    schema: &[Field],
+    fields: &serde_json::Value,
+    field_type: FieldType,
+) -> Vec<FieldReference> {
+    schema
+        .iter()
+        .filter(|field| field.field_type == field_type)
+        .flat_map(|field| {
+            let values = match fields.get(&field.key) {
+                Some(serde_json::Value::Array(values)) => values.iter().collect(),
+                Some(value) => vec![value],
+                None => vec![],
+            };
+
+            values
+                .into_iter()
+                .filter_map(|value| uuid::Uuid::parse_str(value.as_str()?).ok())
+                .map(|id| FieldReference::new(field.key.clone(), id))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn validate_schema(schema: &[Field]) -> anyhow::Result<()> {
+    for (i, field) in schema.iter().enumerate() {
+        field.validate_definition()?;
+
+        if schema[..i].iter().any(|f| f.key == field.key) {
+            anyhow::bail!("Field key {} is duplicated", field.key);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_fields(
+    schema: &[Field],
+    fields: &serde_json::Value,
+) -> Result<(), FieldValidationError> {
+    let object = match fields.as_object() {
+        Some(object) => object,
+        None => {
+            let error = FieldError::new("".into(), "fields must be an object".into());
+            return Err(FieldValidationError {
+                errors: vec![error],
+            });
+        }
+    };
+
+    let mut errors: Vec<FieldError> = schema
+        .iter()
+        .filter_map(|field| field.validate_value(object.get(&field.key)).err())
+        .collect();
+
+    for key in object.keys() {
+        if !schema.iter().any(|field| &field.key == key) {
+            errors.push(FieldError::new(key.clone(), "is not defined".into()));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(FieldValidationError { errors });
+    }
+
+    Ok(())
+}
+
+pub fn validate_localized_fields(
+    schema: &[Field],
+    fields: &serde_json::Value,
+) -> Result<(), FieldValidationError> {
+    let object = match fields.as_object() {
+        Some(object) => object,
+        None => {
+            let error = FieldError::new("".into(), "fields must be an object".into());
+            return Err(FieldValidationError {
+                errors: vec![error],
+            });
+        }
+    };
+
+    let errors: Vec<FieldError> = object
+        .iter()
+        .filter_map(
+            |(key, value)| match schema.iter().find(|field| &field.key == key) {
+                None => Some(FieldError::new(key.clone(), "is not defined".into())),
+                Some(field) if !field.localizable => {
+                    Some(FieldError::new(key.clone(), "is not localizable".into()))
+                }
+                Some(_) if value.is_null() => None,
+                Some(field) => field.validate_value(Some(value)).err(),
+            },
+        )
+        .collect();
+
+    if !errors.is_empty() {
+        return Err(FieldValidationError { errors });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use crate::model::content::{
+    publish, Content, ContentStatus, ContentStatusChange, ContentStatusError, UpdatedBy,
+};
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+pub enum LocaleError {
+    #[error("Locale {0} is not configured")]
+    Unknown(String),
+    #[error("Locale {0} is the default locale and is edited through the content itself")]
+    DefaultLocale(String),
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq)]
+pub struct Locales {
+    pub codes: Vec<String>,
+    pub default: String,
+    pub fallbacks: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for Locales {
+    fn default() -> Self {
+        Self {
+            codes: vec!["en".into()],
+            default: "en".into(),
+            fallbacks: BTreeMap::new(),
+        }
+    }
+}
+
+fn is_locale_code(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 35
+        && value.chars().all(|x| x.is_ascii_alphanumeric() || x == '-')
+}
+
+fn split_codes(value: &str, separator: char) -> Vec<String> {
+    value
+        .split(separator)
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+impl Locales {
+    pub fn try_new(codes: &str, default: &str, fallbacks: &str) -> anyhow::Result<Self> {
+        let codes = split_codes(codes, ',');
+
+        if codes.is_empty() {
+            anyhow::bail!("At least 1 locale must be configured");
+        }
+
+        for (i, code) in codes.iter().enumerate() {
+            if !is_locale_code(code) {
+                anyhow::bail!("Locale {} is not a valid locale code", code);
+            }
+
+            if codes[..i].contains(code) {
+                anyhow::bail!("Locale {} is duplicated", code);
+            }
+        }
+
+        let default = match default.trim() {
+            "" => codes[0].clone(),
+            default => default.to_string(),
+        };
+
+        if !codes.contains(&default) {
+            anyhow::bail!("Default locale {} is not configured", default);
+        }
+
+        let mut rules = BTreeMap::new();
+        for rule in split_codes(fallbacks, ',') {
+            let (locale, chain) = match rule.split_once('=') {
+                Some((locale, chain)) if !locale.trim().is_empty() => {
+                    (locale.trim().to_string(), split_codes(chain, '>'))
+                }
+                _ => anyhow::bail!(
+                    "Locale fallback rule {} must be formatted as locale=fallback>fallback",
+                    rule
+                ),
+            };
+
+            if let Some(code) = std::iter::once(&locale)
+                .chain(&chain)
+                .find(|code| !codes.contains(code))
+            {
+                anyhow::bail!(
+                    "Locale {} in fallback rule {} is not configured",
+                    code,
+                    rule
+                );
+            }
+
+            rules.insert(locale, chain);
+        }
+
+        Ok(Self {
+            codes,
+            default,
+            fallbacks: rules,
+        })
+    }
+
+    pub fn chain(&self, locale: &str) -> Result<LocaleChain, LocaleError> {
+        if !self.codes.iter().any(|code| code == locale) {
+            return Err(LocaleError::Unknown(locale.into()));
+        }
+
+        let mut locales: Vec<String> = vec![];
+        let fallbacks = self.fallbacks.get(locale).into_iter().flatten();
+        for code in std::iter::once(&locale.to_string()).chain(fallbacks) {
+            if *code == self.default {
+                break;
+            }
+
+            if !locales.contains(code) {
+                locales.push(code.clone());
+            }
+        }
+
+        Ok(LocaleChain::new(locale.into(), locales))
+    }
+
+    pub fn localizable(&self, locale: &str) -> Result<(), LocaleError> {
+        if !self.codes.iter().any(|code| code == locale) {
+            return Err(LocaleError::Unknown(locale.into()));
+        }
+
+        if locale == self.default {
+            return Err(LocaleError::DefaultLocale(locale.into()));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct LocaleChain {
+    pub locale: String,
+    pub locales: Vec<String>,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
+pub struct ContentLocalization {
+    #[schema(value_type = String)]
+    pub content_id: uuid::Uuid,
+    pub locale: String,
+    pub status: ContentStatus,
+    pub fields: serde_json::Value,
+    pub updated_by: UpdatedBy,
+    #[schema(value_type = Option<String>)]
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[schema(value_type = String)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = String)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ContentLocalization {
+    pub fn publish(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ContentStatusChange, ContentStatusError> {
+        publish(&self.status, self.published_at, None, now)
+    }
+
+    pub fn unpublish(&self) -> Result<ContentStatusChange, ContentStatusError> {
+        self.status.transition_to(&ContentStatus::Unpublished)?;
+
+        Ok(ContentStatusChange::new(
+            ContentStatus::Unpublished,
+            self.published_at,
+            None,
+        ))
+    }
+}
+
+pub fn localize(content: &mut Content, chain: &LocaleChain, localizations: &[ContentLocalization]) {
+    let layers: Vec<&serde_json::Map<String, serde_json::Value>> = chain
+        .locales
+        .iter()
+        .filter_map(|locale| {
+            localizations
+                .iter()
+                .find(|x| x.content_id == content.id && &x.locale == locale)
+        })
+        .filter_map(|localization| localization.fields.as_object())
+        .collect();
+
+    if let Some(fields) = content.fields.as_object_mut() {
+        for layer in layers.into_iter().rev() {
+            for (key, value) in layer.iter().filter(|(_, value)| !value.is_null()) {
+                fields.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    content.locale = Some(chain.locale.clone());
+}
@@ -0,0 +1,229 @@
+use sha2::Digest;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
+pub struct MediaUploader {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone)]
+pub struct Media {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub object_key: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub alt_text: Option<String>,
+    pub uploaded_by: MediaUploader,
+    #[schema(value_type = String)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = String)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Media {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        id: uuid::Uuid,
+        object_key: String,
+        filename: String,
+        mime_type: String,
+        size: i64,
+        checksum: String,
+        width: Option<i32>,
+        height: Option<i32>,
+        alt_text: Option<String>,
+        uploaded_by: MediaUploader,
+        created_at: chrono::DateTime<chrono::Utc>,
+        updated_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Self> {
+        validate_filename(&filename)?;
+        validate_mime_type(&mime_type)?;
+
+        if let Some(alt_text) = &alt_text {
+            validate_alt_text(alt_text)?;
+        }
+
+        Ok(Self {
+            id,
+            object_key,
+            filename,
+            mime_type,
+            size,
+            checksum,
+            width,
+            height,
+            alt_text,
+            uploaded_by,
+            created_at,
+            updated_at,
+        })
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new,
+)]
+pub struct MediaUsage {
+    #[schema(value_type = String)]
+    pub content_id: uuid::Uuid,
+    pub content_title: String,
+    pub field_key: String,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+#[error("Media is referenced by contents")]
+pub struct MediaInUseError {
+    pub usages: Vec<MediaUsage>,
+}
+
+pub const DELIVERY_URL_EXPIRES_IN: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone)]
+pub struct ContentMedia {
+    pub content_id: uuid::Uuid,
+    pub field_key: String,
+    pub media: Media,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq)]
+pub struct MediaReference {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub url: String,
+    pub mime_type: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub alt_text: Option<String>,
+}
+
+impl MediaReference {
+    pub fn new(media: Media, url: String) -> Self {
+        let Media {
+            id,
+            mime_type,
+            width,
+            height,
+            alt_text,
+            ..
+        } = media;
+
+        Self {
+            id,
+            url,
+            mime_type,
+            width,
+            height,
+            alt_text,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaMetadata {
+    pub size: i64,
+    pub checksum: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl MediaMetadata {
+    pub fn inspect(body: &[u8]) -> Self {
+        let mut inspector = MediaInspector::default();
+        inspector.update(body);
+
+        inspector.finish()
+    }
+}
+
+const INSPECT_HEAD_SIZE: usize = 64 * 1024;
+
+#[derive(Default)]
+pub struct MediaInspector {
+    hasher: sha2::Sha256,
+    size: i64,
+    head: Vec<u8>,
+}
+
+impl MediaInspector {
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.hasher.update(chunk);
+        self.size += chunk.len() as i64;
+
+        let remaining = INSPECT_HEAD_SIZE.saturating_sub(self.head.len());
+        self.head
+            .extend_from_slice(&chunk[..remaining.min(chunk.len())]);
+    }
+
+    pub fn finish(self) -> MediaMetadata {
+        let dimensions = imagesize::blob_size(&self.head).ok();
+
+        MediaMetadata {
+            size: self.size,
+            checksum: hex::encode(self.hasher.finalize()),
+            width: dimensions.and_then(|size| i32::try_from(size.width).ok()),
+            height: dimensions.and_then(|size| i32::try_from(size.height).ok()),
+        }
+    }
+}
+
+pub fn checksum(body: &[u8]) -> String {
+    hex::encode(sha2::Sha256::digest(body))
+}
+
+pub fn object_key(id: &uuid::Uuid, filename: &str) -> String {
+    let extension = std::path::Path::new(filename)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .filter(|extension| extension.chars().all(|x| x.is_ascii_alphanumeric()));
+
+    match extension {
+        Some(extension) => format!("media/{}.{}", id, extension.to_ascii_lowercase()),
+        None => format!("media/{}", id),
+    }
+}
+
+pub fn validate_filename(filename: &str) -> anyhow::Result<()> {
+    if filename.is_empty() {
+        anyhow::bail!("Filename must be at least 1 character long");
+    }
+
+    if filename.len() > 255 {
+        anyhow::bail!("Filename exceeds maximum length(255 characters)");
+    }
+
+    if filename.contains(['/', '\\']) {
+        anyhow::bail!("Filename must not contain path separators");
+    }
+
+    Ok(())
+}
+
+pub fn validate_mime_type(mime_type: &str) -> anyhow::Result<()> {
+    let valid = mime_type.len() <= 255
+        && mime_type
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| !kind.is_empty() && !subtype.is_empty());
+
+    if !valid {
+        anyhow::bail!("Mime type {} is invalid", mime_type);
+    }
+
+    Ok(())
+}
+
+pub fn validate_alt_text(alt_text: &str) -> anyhow::Result<()> {
+    if alt_text.len() > 1000 {
+        anyhow::bail!("Alt text exceeds maximum length(1000 characters)");
+    }
+
+    Ok(())
+}
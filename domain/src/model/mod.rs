@@ -1,4 +1,23 @@
+pub mod api_key;
+pub mod auth;
 pub mod category;
 pub mod content;
+pub mod delivery;
+pub mod field;
+pub mod locale;
+pub mod media;
+pub mod oidc;
+pub mod outbox;
+pub mod page;
+pub mod query;
+pub mod relation;
+pub mod render;
+pub mod revision;
+pub mod role;
+pub mod search;
 pub mod tag;
+pub mod trash;
+pub mod upload;
 pub mod user;
+pub mod version;
+pub mod webhook;
@@ -0,0 +1,140 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use sha2::Digest;
+
+use crate::model::user::DEFAULT_ICON_URL;
+
+pub const LOGIN_STATE_TTL_MINUTES: i64 = 10;
+
+fn random_string() -> String {
+    let bytes: [u8; 32] = rand::random();
+
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn code_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(sha2::Sha256::digest(code_verifier.as_bytes()))
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct OidcLoginState {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OidcLoginState {
+    pub fn generate(now: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            state: random_string(),
+            nonce: random_string(),
+            code_verifier: random_string(),
+            expires_at: now + chrono::Duration::minutes(LOGIN_STATE_TTL_MINUTES),
+        }
+    }
+
+    pub fn code_challenge(&self) -> String {
+        code_challenge(&self.code_verifier)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OidcClaims {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub name: Option<String>,
+    pub picture: Option<String>,
+    pub groups: Vec<String>,
+}
+
+impl OidcClaims {
+    pub fn display_name(&self) -> String {
+        let name = [&self.name, &self.email]
+            .into_iter()
+            .flatten()
+            .find(|value| !value.is_empty())
+            .unwrap_or(&self.subject);
+
+        name.chars().take(50).collect()
+    }
+
+    pub fn icon_url(&self) -> String {
+        self.picture
+            .clone()
+            .filter(|picture| url::Url::parse(picture).is_ok())
+            .unwrap_or_else(|| DEFAULT_ICON_URL.into())
+    }
+
+    pub fn verified_email(&self) -> Option<String> {
+        self.email.clone().filter(|_| self.email_verified)
+    }
+}
+
+pub const DEFAULT_ROLE: &str = "member";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleMapping {
+    rules: Vec<(String, String)>,
+    default_role: String,
+}
+
+impl Default for RoleMapping {
+    fn default() -> Self {
+        Self {
+            rules: vec![],
+            default_role: DEFAULT_ROLE.into(),
+        }
+    }
+}
+
+impl RoleMapping {
+    pub fn with_default_role(self, default_role: String) -> Self {
+        Self {
+            default_role,
+            ..self
+        }
+    }
+
+    pub fn default_role(&self) -> &str {
+        &self.default_role
+    }
+
+    pub fn resolve(&self, groups: &[String]) -> Option<String> {
+        if self.rules.is_empty() {
+            return None;
+        }
+
+        let role = self
+            .rules
+            .iter()
+            .find(|(group, _)| groups.contains(group))
+            .map_or(&self.default_role, |(_, role)| role);
+
+        Some(role.clone())
+    }
+}
+
+impl std::str::FromStr for RoleMapping {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        let rules = value
+            .split(',')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+            .map(|rule| match rule.split_once('=') {
+                Some((group, role)) if !group.trim().is_empty() && !role.trim().is_empty() => {
+                    Ok((group.trim().to_string(), role.trim().to_string()))
+                }
+                _ => anyhow::bail!("Role mapping rule {} must be formatted as group=role", rule),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            rules,
+            ..Self::default()
+        })
+    }
+}
@@ -0,0 +1,23 @@
+use crate::model::webhook::WebhookPayload;
+
+pub const OUTBOX_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+pub const MAX_OUTBOX_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3600);
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct OutboxEvent {
+    pub id: uuid::Uuid,
+    pub category_id: Option<String>,
+    pub payload: WebhookPayload,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl OutboxEvent {
+    pub fn retry_at(&self, now: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
+        let backoff = OUTBOX_RETRY_INTERVAL * 2u32.pow(self.attempts.clamp(0, 12) as u32);
+        let backoff = backoff.min(MAX_OUTBOX_RETRY_INTERVAL);
+
+        now + chrono::Duration::from_std(backoff).unwrap_or_default()
+    }
+}
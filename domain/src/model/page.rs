@@ -0,0 +1,196 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+pub const DEFAULT_PAGE_LIMIT: i32 = 100;
+pub const MAX_PAGE_LIMIT: i32 = 1000;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PageError {
+    #[error("Limit must be between 1 and {}", MAX_PAGE_LIMIT)]
+    InvalidLimit,
+    #[error("Cursor is invalid")]
+    InvalidCursor,
+    #[error("Cursor does not match the listing order")]
+    CursorMismatch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cursor {
+    After(uuid::Uuid),
+    Before(uuid::Uuid),
+    Offset(i64),
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let value = match self {
+            Self::After(id) => format!("after:{}", id),
+            Self::Before(id) => format!("before:{}", id),
+            Self::Offset(offset) => format!("offset:{}", offset),
+        };
+
+        URL_SAFE_NO_PAD.encode(value)
+    }
+
+    pub fn decode(value: &str) -> Result<Self, PageError> {
+        let decoded = URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|_| PageError::InvalidCursor)?;
+        let decoded = String::from_utf8(decoded).map_err(|_| PageError::InvalidCursor)?;
+        let parse_id = |id: &str| uuid::Uuid::parse_str(id).map_err(|_| PageError::InvalidCursor);
+
+        match decoded.split_once(':') {
+            Some(("after", id)) => Ok(Self::After(parse_id(id)?)),
+            Some(("before", id)) => Ok(Self::Before(parse_id(id)?)),
+            Some(("offset", offset)) => match offset.parse() {
+                Ok(offset) if offset >= 0 => Ok(Self::Offset(offset)),
+                _ => Err(PageError::InvalidCursor),
+            },
+            _ => Err(PageError::InvalidCursor),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct PageQuery {
+    pub limit: i32,
+    pub cursor: Option<Cursor>,
+    pub with_total: bool,
+}
+
+impl Default for PageQuery {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_PAGE_LIMIT,
+            cursor: None,
+            with_total: false,
+        }
+    }
+}
+
+impl PageQuery {
+    pub fn try_new(
+        limit: Option<i32>,
+        cursor: Option<&str>,
+        with_total: bool,
+    ) -> Result<Self, PageError> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(PageError::InvalidLimit);
+        }
+
+        let cursor = cursor
+            .filter(|cursor| !cursor.is_empty())
+            .map(Cursor::decode)
+            .transpose()?;
+
+        Ok(Self::new(limit, cursor, with_total))
+    }
+
+    pub fn fetch_limit(&self) -> i64 {
+        i64::from(self.limit) + 1
+    }
+
+    pub fn keyset(&self) -> Result<Option<Cursor>, PageError> {
+        match self.cursor {
+            Some(Cursor::Offset(_)) => Err(PageError::CursorMismatch),
+            cursor => Ok(cursor),
+        }
+    }
+
+    pub fn offset(&self) -> Result<i64, PageError> {
+        match self.cursor {
+            None => Ok(0),
+            Some(Cursor::Offset(offset)) => Ok(offset),
+            Some(_) => Err(PageError::CursorMismatch),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, utoipa::ToSchema, derive_new::new)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub total: Option<i64>,
+}
+
+impl<T> Page<T> {
+    pub fn from_keyset_rows(
+        mut rows: Vec<T>,
+        query: &PageQuery,
+        id: impl Fn(&T) -> uuid::Uuid,
+        total: Option<i64>,
+    ) -> Self {
+        let has_more = rows.len() > query.limit as usize;
+        rows.truncate(query.limit as usize);
+
+        let first = rows.first().map(&id);
+        let last = rows.last().map(&id);
+
+        let (next_cursor, prev_cursor) = match query.cursor {
+            Some(Cursor::Before(_)) => {
+                rows.reverse();
+                (
+                    first.map(Cursor::After),
+                    last.filter(|_| has_more).map(Cursor::Before),
+                )
+            }
+            Some(_) => (
+                last.filter(|_| has_more).map(Cursor::After),
+                first.map(Cursor::Before),
+            ),
+            None => (last.filter(|_| has_more).map(Cursor::After), None),
+        };
+
+        Self::new(
+            rows,
+            next_cursor.map(|cursor| cursor.encode()),
+            prev_cursor.map(|cursor| cursor.encode()),
+            total,
+        )
+    }
+
+    pub fn from_offset_rows(
+        mut rows: Vec<T>,
+        query: &PageQuery,
+        offset: i64,
+        total: Option<i64>,
+    ) -> Self {
+        let limit = i64::from(query.limit);
+        let has_more = rows.len() as i64 > limit;
+        rows.truncate(query.limit as usize);
+
+        let next_cursor = has_more.then(|| Cursor::Offset(offset + limit));
+        let prev_cursor = (offset > 0).then(|| Cursor::Offset((offset - limit).max(0)));
+
+        Self::new(
+            rows,
+            next_cursor.map(|cursor| cursor.encode()),
+            prev_cursor.map(|cursor| cursor.encode()),
+            total,
+        )
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            prev_cursor: self.prev_cursor,
+            total: self.total,
+        }
+    }
+
+    pub fn try_map<U>(self, f: impl FnMut(T) -> anyhow::Result<U>) -> anyhow::Result<Page<U>> {
+        Ok(Page {
+            items: self
+                .items
+                .into_iter()
+                .map(f)
+                .collect::<anyhow::Result<_>>()?,
+            next_cursor: self.next_cursor,
+            prev_cursor: self.prev_cursor,
+            total: self.total,
+        })
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use crate::model::page::Page;
+
+pub const MAX_FIELD_FILTERS: usize = 10;
+pub const MAX_SORT_KEYS: usize = 3;
+pub const CONTENT_KEYS: [&str; 13] = [
+    "id",
+    "title",
+    "category",
+    "status",
+    "fields",
+    "tags",
+    "created_by",
+    "updated_by",
+    "published_at",
+    "unpublish_at",
+    "created_at",
+    "updated_at",
+    "search",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize)]
+pub enum FilterOperator {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    In,
+    Contains,
+    Exists,
+}
+
+impl FromStr for FilterOperator {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "eq" => Ok(Self::Eq),
+            "ne" => Ok(Self::Ne),
+            "gt" => Ok(Self::Gt),
+            "gte" => Ok(Self::Gte),
+            "lt" => Ok(Self::Lt),
+            "lte" => Ok(Self::Lte),
+            "in" => Ok(Self::In),
+            "contains" => Ok(Self::Contains),
+            "exists" => Ok(Self::Exists),
+            _ => anyhow::bail!("Filter operator {} is not supported", value),
+        }
+    }
+}
+
+fn parse_scalar(value: &str) -> serde_json::Value {
+    match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(parsed) if parsed.is_number() || parsed.is_boolean() || parsed.is_null() => parsed,
+        _ => serde_json::Value::String(value.into()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, derive_new::new)]
+pub struct FieldFilter {
+    pub key: String,
+    pub operator: FilterOperator,
+    pub value: serde_json::Value,
+}
+
+impl FieldFilter {
+    pub fn parse(key: &str, operator: FilterOperator, value: &str) -> anyhow::Result<Self> {
+        let value = match operator {
+            FilterOperator::In => value.split(',').map(parse_scalar).collect(),
+            FilterOperator::Contains => serde_json::Value::String(value.into()),
+            FilterOperator::Exists => match value {
+                "true" => serde_json::Value::Bool(true),
+                "false" => serde_json::Value::Bool(false),
+                _ => anyhow::bail!("Filter fields.{}[exists] must be true or false", key),
+            },
+            _ => parse_scalar(value),
+        };
+
+        Ok(Self::new(key.into(), operator, value))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+pub struct DateRange {
+    pub gt: Option<chrono::DateTime<chrono::Utc>>,
+    pub gte: Option<chrono::DateTime<chrono::Utc>>,
+    pub lt: Option<chrono::DateTime<chrono::Utc>>,
+    pub lte: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl DateRange {
+    fn set(&mut self, name: &str, operator: FilterOperator, value: &str) -> anyhow::Result<()> {
+        let value = chrono::DateTime::parse_from_rfc3339(value)
+            .map_err(|_| anyhow::anyhow!("Filter {} must be an RFC 3339 date time", name))?
+            .with_timezone(&chrono::Utc);
+
+        match operator {
+            FilterOperator::Gt => self.gt = Some(value),
+            FilterOperator::Gte => self.gte = Some(value),
+            FilterOperator::Lt => self.lt = Some(value),
+            FilterOperator::Lte => self.lte = Some(value),
+            _ => anyhow::bail!("Filter {} only supports gt, gte, lt and lte", name),
+        }
+
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+pub struct ContentFilter {
+    pub published_at: DateRange,
+    pub updated_at: DateRange,
+    pub fields: Vec<FieldFilter>,
+}
+
+fn split_operator(key: &str) -> anyhow::Result<(&str, FilterOperator)> {
+    match key.strip_suffix(']').and_then(|key| key.split_once('[')) {
+        Some((name, operator)) => Ok((name, FilterOperator::from_str(operator)?)),
+        None if key.contains('[') || key.contains(']') => {
+            anyhow::bail!("Filter {} is invalid", key)
+        }
+        None => Ok((key, FilterOperator::Eq)),
+    }
+}
+
+impl ContentFilter {
+    pub fn parse(pairs: &[(String, String)]) -> anyhow::Result<Self> {
+        let mut filter = Self::default();
+
+        for (key, value) in pairs {
+            if !key.contains('[') && !key.starts_with("fields.") {
+                continue;
+            }
+
+            let (name, operator) = split_operator(key)?;
+
+            match name {
+                "published_at" => filter.published_at.set(name, operator, value)?,
+                "updated_at" => filter.updated_at.set(name, operator, value)?,
+                name => match name.strip_prefix("fields.") {
+                    Some(field_key) if !field_key.is_empty() => filter
+                        .fields
+                        .push(FieldFilter::parse(field_key, operator, value)?),
+                    _ => anyhow::bail!("Filter {} is not supported", key),
+                },
+            }
+        }
+
+        if filter.fields.len() > MAX_FIELD_FILTERS {
+            anyhow::bail!("Field filters exceed maximum count({})", MAX_FIELD_FILTERS);
+        }
+
+        Ok(filter)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Deserialize)]
+pub enum TagMatch {
+    #[default]
+    All,
+    Any,
+}
+
+impl FromStr for TagMatch {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "all" => Ok(Self::All),
+            "any" => Ok(Self::Any),
+            _ => anyhow::bail!("Tag match {} must be all or any", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+pub enum SortKey {
+    Title,
+    Status,
+    PublishedAt,
+    UpdatedAt,
+    CreatedAt,
+    Field(String),
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, derive_new::new)]
+pub struct SortOrder {
+    pub key: SortKey,
+    pub descending: bool,
+}
+
+impl SortOrder {
+    pub fn parse_list(value: &str) -> anyhow::Result<Vec<Self>> {
+        let orders = value
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(|item| {
+                let (name, descending) = match item.strip_prefix('-') {
+                    Some(name) => (name, true),
+                    None => (item, false),
+                };
+
+                let key = match name {
+                    "title" => SortKey::Title,
+                    "status" => SortKey::Status,
+                    "published_at" => SortKey::PublishedAt,
+                    "updated_at" => SortKey::UpdatedAt,
+                    "created_at" => SortKey::CreatedAt,
+                    name => match name.strip_prefix("fields.") {
+                        Some(field_key) if !field_key.is_empty() => {
+                            SortKey::Field(field_key.into())
+                        }
+                        _ => anyhow::bail!("Sort key {} is not supported", name),
+                    },
+                };
+
+                Ok(Self::new(key, descending))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if orders.len() > MAX_SORT_KEYS {
+            anyhow::bail!("Sort keys exceed maximum count({})", MAX_SORT_KEYS);
+        }
+
+        Ok(orders)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Projection {
+    pub keys: BTreeSet<String>,
+    pub field_keys: BTreeSet<String>,
+}
+
+impl Projection {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        let mut projection = Self::default();
+
+        for key in value.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            match key.strip_prefix("fields.") {
+                Some(field_key) if !field_key.is_empty() => {
+                    projection.field_keys.insert(field_key.into());
+                }
+                _ if CONTENT_KEYS.contains(&key) => {
+                    projection.keys.insert(key.into());
+                }
+                _ => anyhow::bail!("Select key {} is not supported", key),
+            }
+        }
+
+        Ok(projection)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty() && self.field_keys.is_empty()
+    }
+
+    pub fn apply(&self, value: serde_json::Value) -> serde_json::Value {
+        let serde_json::Value::Object(mut object) = value else {
+            return value;
+        };
+
+        if self.is_empty() {
+            return serde_json::Value::Object(object);
+        }
+
+        object.retain(|key, value| {
+            if key == "id" || self.keys.contains(key) {
+                return true;
+            }
+
+            if key != "fields" || self.field_keys.is_empty() {
+                return false;
+            }
+
+            if let serde_json::Value::Object(fields) = value {
+                fields.retain(|field_key, _| self.field_keys.contains(field_key));
+            }
+
+            true
+        });
+
+        serde_json::Value::Object(object)
+    }
+
+    pub fn project<T: serde::Serialize>(
+        &self,
+        page: Page<T>,
+    ) -> anyhow::Result<Page<serde_json::Value>> {
+        page.try_map(|value| Ok(self.apply(serde_json::to_value(value)?)))
+    }
+}
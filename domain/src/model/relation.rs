@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use crate::model::content::Content;
+
+pub const MAX_EXPAND_DEPTH: usize = 3;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Expand {
+    pub fields: BTreeMap<String, Expand>,
+}
+
+impl Expand {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        let mut expand = Self::default();
+
+        for path in value.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            let keys: Vec<&str> = path.split('.').collect();
+
+            if keys.iter().any(|key| key.is_empty()) {
+                anyhow::bail!("Expand path {} is invalid", path);
+            }
+
+            if keys.len() > MAX_EXPAND_DEPTH {
+                anyhow::bail!(
+                    "Expand path {} exceeds maximum depth({})",
+                    path,
+                    MAX_EXPAND_DEPTH
+                );
+            }
+
+            let mut node = &mut expand;
+            for key in keys {
+                node = node.fields.entry(key.to_string()).or_default();
+            }
+        }
+
+        Ok(expand)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.fields.keys().cloned().collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct RelatedContent {
+    pub content_id: uuid::Uuid,
+    pub field_key: String,
+    pub position: i32,
+    pub content: Content,
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new,
+)]
+pub struct ContentReferrer {
+    #[schema(value_type = String)]
+    pub content_id: uuid::Uuid,
+    pub content_title: String,
+    pub field_key: String,
+}
+
+pub fn embed_related(
+    contents: &mut [Content],
+    key: &str,
+    related: Vec<(uuid::Uuid, serde_json::Value)>,
+) {
+    for content in contents {
+        let Some(value) = content
+            .fields
+            .as_object_mut()
+            .and_then(|fields| fields.get_mut(key))
+        else {
+            continue;
+        };
+
+        let mut values: Vec<serde_json::Value> = related
+            .iter()
+            .filter(|(id, _)| *id == content.id)
+            .map(|(_, value)| value.clone())
+            .collect();
+
+        *value = match value {
+            serde_json::Value::Array(_) => serde_json::Value::Array(values),
+            _ => values.pop().unwrap_or(serde_json::Value::Null),
+        };
+    }
+}
@@ -0,0 +1,240 @@
+use hmac::Mac;
+use sha2::Digest;
+
+pub const MAX_RENDER_DIMENSION: u32 = 4096;
+pub const DEFAULT_RENDER_QUALITY: u8 = 80;
+pub const RENDER_URL_EXPIRES_IN: std::time::Duration =
+    std::time::Duration::from_secs(30 * 24 * 60 * 60);
+
+#[derive(
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderFit {
+    #[default]
+    Cover,
+    Contain,
+    Fill,
+}
+
+impl RenderFit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cover => "cover",
+            Self::Contain => "contain",
+            Self::Fill => "fill",
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    utoipa::ToSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderFormat {
+    #[default]
+    Webp,
+    Png,
+    Jpeg,
+}
+
+impl RenderFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Webp => "webp",
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Webp => "webp",
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+        }
+    }
+
+    pub fn is_lossy(&self) -> bool {
+        matches!(self, Self::Jpeg)
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Webp => "image/webp",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderOptions {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub fit: RenderFit,
+    pub format: RenderFormat,
+    pub quality: u8,
+}
+
+impl RenderOptions {
+    pub fn try_new(
+        width: Option<u32>,
+        height: Option<u32>,
+        fit: Option<RenderFit>,
+        format: Option<RenderFormat>,
+        quality: Option<u8>,
+    ) -> anyhow::Result<Self> {
+        for dimension in [width, height].into_iter().flatten() {
+            if dimension == 0 || dimension > MAX_RENDER_DIMENSION {
+                anyhow::bail!(
+                    "Dimension must be between 1 and {} pixels",
+                    MAX_RENDER_DIMENSION
+                );
+            }
+        }
+
+        let quality = quality.unwrap_or(DEFAULT_RENDER_QUALITY);
+        if quality == 0 || quality > 100 {
+            anyhow::bail!("Quality must be between 1 and 100");
+        }
+
+        let format = format.unwrap_or_default();
+        let quality = match format.is_lossy() {
+            true => quality,
+            false => DEFAULT_RENDER_QUALITY,
+        };
+
+        Ok(Self {
+            width,
+            height,
+            fit: fit.unwrap_or_default(),
+            format,
+            quality,
+        })
+    }
+
+    pub fn canonical(&self) -> String {
+        let mut params = vec![];
+
+        if let Some(width) = self.width {
+            params.push(format!("w={}", width));
+        }
+
+        if let Some(height) = self.height {
+            params.push(format!("h={}", height));
+        }
+
+        params.push(format!("fit={}", self.fit.as_str()));
+        params.push(format!("format={}", self.format.as_str()));
+        params.push(format!("q={}", self.quality));
+
+        params.join("&")
+    }
+
+    pub fn variant_key(&self, media_id: &uuid::Uuid) -> String {
+        let digest = hex::encode(sha2::Sha256::digest(self.canonical()));
+
+        format!(
+            "variants/{}/{}.{}",
+            media_id,
+            &digest[..32],
+            self.format.extension()
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderedImage {
+    pub content_type: String,
+    pub body: Vec<u8>,
+}
+
+#[derive(Clone, derive_new::new)]
+pub struct RenderSigner {
+    key: Vec<u8>,
+}
+
+impl std::fmt::Debug for RenderSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderSigner").finish_non_exhaustive()
+    }
+}
+
+impl RenderSigner {
+    fn mac(
+        &self,
+        media_id: &str,
+        options: &RenderOptions,
+        expires: i64,
+    ) -> hmac::Hmac<sha2::Sha256> {
+        let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(&self.key)
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}?{}&expires={}", media_id, options.canonical(), expires).as_bytes());
+
+        mac
+    }
+
+    pub fn sign(&self, media_id: &str, options: &RenderOptions, expires: i64) -> String {
+        hex::encode(self.mac(media_id, options, expires).finalize().into_bytes())
+    }
+
+    pub fn verify(
+        &self,
+        media_id: &str,
+        options: &RenderOptions,
+        expires: i64,
+        signature: &str,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RenderError> {
+        let signature = hex::decode(signature).map_err(|_| RenderError::InvalidSignature)?;
+
+        self.mac(media_id, options, expires)
+            .verify_slice(&signature)
+            .map_err(|_| RenderError::InvalidSignature)?;
+
+        if expires <= now.timestamp() {
+            return Err(RenderError::SignatureExpired);
+        }
+
+        Ok(())
+    }
+
+    pub fn signed_path(&self, media_id: &str, options: &RenderOptions, expires: i64) -> String {
+        format!(
+            "/medias/{}/render?{}&expires={}&sig={}",
+            media_id,
+            options.canonical(),
+            expires,
+            self.sign(media_id, options, expires)
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RenderError {
+    #[error("Media is not a renderable image")]
+    NotAnImage,
+    #[error("Render signature is invalid")]
+    InvalidSignature,
+    #[error("Render signature is expired")]
+    SignatureExpired,
+    #[error("Signed render URLs are not configured")]
+    SigningDisabled,
+}
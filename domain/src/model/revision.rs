@@ -0,0 +1,97 @@
+use crate::model::content::{ContentCategory, ContentStatus, ContentTag, CreatedBy};
+
+#[allow(clippy::too_many_arguments)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
+pub struct ContentRevision {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    #[schema(value_type = String)]
+    pub content_id: uuid::Uuid,
+    pub revision: i32,
+    pub title: String,
+    pub category: ContentCategory,
+    pub status: ContentStatus,
+    pub fields: serde_json::Value,
+    pub tags: Vec<ContentTag>,
+    pub created_by: CreatedBy,
+    #[schema(value_type = String)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new)]
+pub struct RevisionChange {
+    pub path: String,
+    pub from: Option<serde_json::Value>,
+    pub to: Option<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
+pub struct RevisionDiff {
+    pub from: i32,
+    pub to: i32,
+    pub changes: Vec<RevisionChange>,
+}
+
+impl ContentRevision {
+    pub fn diff(&self, to: &ContentRevision) -> RevisionDiff {
+        let mut changes = vec![];
+
+        if self.title != to.title {
+            changes.push(RevisionChange::new(
+                "title".into(),
+                Some(self.title.clone().into()),
+                Some(to.title.clone().into()),
+            ));
+        }
+
+        if self.category.id != to.category.id {
+            changes.push(RevisionChange::new(
+                "category".into(),
+                serde_json::to_value(&self.category).ok(),
+                serde_json::to_value(&to.category).ok(),
+            ));
+        }
+
+        if self.status != to.status {
+            changes.push(RevisionChange::new(
+                "status".into(),
+                serde_json::to_value(&self.status).ok(),
+                serde_json::to_value(&to.status).ok(),
+            ));
+        }
+
+        let mut from_tag_ids: Vec<&String> = self.tags.iter().map(|tag| &tag.id).collect();
+        let mut to_tag_ids: Vec<&String> = to.tags.iter().map(|tag| &tag.id).collect();
+        from_tag_ids.sort();
+        to_tag_ids.sort();
+        if from_tag_ids != to_tag_ids {
+            changes.push(RevisionChange::new(
+                "tags".into(),
+                serde_json::to_value(&self.tags).ok(),
+                serde_json::to_value(&to.tags).ok(),
+            ));
+        }
+
+        let empty = serde_json::Map::new();
+        let from_fields = self.fields.as_object().unwrap_or(&empty);
+        let to_fields = to.fields.as_object().unwrap_or(&empty);
+        let mut keys: Vec<&String> = from_fields.keys().chain(to_fields.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            let from_value = from_fields.get(key);
+            let to_value = to_fields.get(key);
+
+            if from_value != to_value {
+                changes.push(RevisionChange::new(
+                    format!("fields.{}", key),
+                    from_value.cloned(),
+                    to_value.cloned(),
+                ));
+            }
+        }
+
+        RevisionDiff::new(self.revision, to.revision, changes)
+    }
+}
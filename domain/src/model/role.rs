@@ -0,0 +1,294 @@
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, Copy, PartialEq, Eq, Hash,
+)]
+pub enum PermissionResource {
+    Contents,
+    Categories,
+    Tags,
+    Media,
+    Users,
+    ApiKeys,
+    Webhooks,
+}
+
+impl PermissionResource {
+    pub const ALL: [Self; 7] = [
+        Self::Contents,
+        Self::Categories,
+        Self::Tags,
+        Self::Media,
+        Self::Users,
+        Self::ApiKeys,
+        Self::Webhooks,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Contents => "Contents",
+            Self::Categories => "Categories",
+            Self::Tags => "Tags",
+            Self::Media => "Media",
+            Self::Users => "Users",
+            Self::ApiKeys => "ApiKeys",
+            Self::Webhooks => "Webhooks",
+        }
+    }
+
+    pub fn is_category_scoped(&self) -> bool {
+        matches!(self, Self::Contents | Self::Categories)
+    }
+}
+
+impl std::str::FromStr for PermissionResource {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "Contents" => Ok(Self::Contents),
+            "Categories" => Ok(Self::Categories),
+            "Tags" => Ok(Self::Tags),
+            "Media" => Ok(Self::Media),
+            "Users" => Ok(Self::Users),
+            "ApiKeys" => Ok(Self::ApiKeys),
+            "Webhooks" => Ok(Self::Webhooks),
+            _ => anyhow::bail!("Unknown permission resource {}", value),
+        }
+    }
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, Copy, PartialEq, Eq, Hash,
+)]
+pub enum PermissionAction {
+    Read,
+    Create,
+    Update,
+    Delete,
+    Publish,
+}
+
+impl PermissionAction {
+    pub const ALL: [Self; 5] = [
+        Self::Read,
+        Self::Create,
+        Self::Update,
+        Self::Delete,
+        Self::Publish,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "Read",
+            Self::Create => "Create",
+            Self::Update => "Update",
+            Self::Delete => "Delete",
+            Self::Publish => "Publish",
+        }
+    }
+}
+
+impl std::str::FromStr for PermissionAction {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "Read" => Ok(Self::Read),
+            "Create" => Ok(Self::Create),
+            "Update" => Ok(Self::Update),
+            "Delete" => Ok(Self::Delete),
+            "Publish" => Ok(Self::Publish),
+            _ => anyhow::bail!("Unknown permission action {}", value),
+        }
+    }
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new,
+)]
+pub struct Permission {
+    pub resource: PermissionResource,
+    pub action: PermissionAction,
+    #[serde(default)]
+    pub category_ids: Vec<String>,
+}
+
+impl Permission {
+    pub fn matches(
+        &self,
+        resource: PermissionResource,
+        action: PermissionAction,
+        category_id: Option<&str>,
+    ) -> bool {
+        if self.resource != resource || self.action != action {
+            return false;
+        }
+
+        if self.category_ids.is_empty() {
+            return true;
+        }
+
+        category_id.is_some_and(|category_id| self.category_ids.iter().any(|x| x == category_id))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CategoryScope {
+    All,
+    Only(Vec<String>),
+    Denied,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq)]
+pub struct Role {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub is_super_administrator: bool,
+    pub permissions: Vec<Permission>,
+}
+
+impl Role {
+    pub fn try_new(
+        id: String,
+        name: String,
+        description: String,
+        is_super_administrator: bool,
+        permissions: Vec<Permission>,
+    ) -> anyhow::Result<Self> {
+        validate_name(&name)?;
+        validate_description(&description)?;
+        validate_permissions(&permissions)?;
+
+        Ok(Self {
+            id,
+            name,
+            description,
+            is_super_administrator,
+            permissions,
+        })
+    }
+
+    pub fn allows(
+        &self,
+        resource: PermissionResource,
+        action: PermissionAction,
+        category_id: Option<&str>,
+    ) -> bool {
+        self.is_super_administrator
+            || self
+                .permissions
+                .iter()
+                .any(|permission| permission.matches(resource, action, category_id))
+    }
+
+    pub fn category_scope(
+        &self,
+        resource: PermissionResource,
+        action: PermissionAction,
+    ) -> CategoryScope {
+        if self.is_super_administrator {
+            return CategoryScope::All;
+        }
+
+        let permission = self
+            .permissions
+            .iter()
+            .find(|permission| permission.resource == resource && permission.action == action);
+
+        match permission {
+            Some(permission) if permission.category_ids.is_empty() => CategoryScope::All,
+            Some(permission) => CategoryScope::Only(permission.category_ids.clone()),
+            None => CategoryScope::Denied,
+        }
+    }
+
+    pub fn covers(&self, permission: &Permission) -> bool {
+        if self.is_super_administrator {
+            return true;
+        }
+
+        self.permissions.iter().any(|granted| {
+            granted.resource == permission.resource
+                && granted.action == permission.action
+                && (granted.category_ids.is_empty()
+                    || (!permission.category_ids.is_empty()
+                        && permission
+                            .category_ids
+                            .iter()
+                            .all(|x| granted.category_ids.contains(x))))
+        })
+    }
+
+    pub fn effective_permissions(&self) -> Vec<Permission> {
+        if !self.is_super_administrator {
+            return self.permissions.clone();
+        }
+
+        PermissionResource::ALL
+            .iter()
+            .flat_map(|resource| {
+                PermissionAction::ALL
+                    .iter()
+                    .map(|action| Permission::new(*resource, *action, vec![]))
+            })
+            .collect()
+    }
+}
+
+pub fn validate_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty() {
+        anyhow::bail!("Name must be at least 1 character long");
+    }
+
+    if name.len() > 50 {
+        anyhow::bail!("Name exceeds maximum length(50 characters)");
+    }
+
+    Ok(())
+}
+
+pub fn validate_description(description: &str) -> anyhow::Result<()> {
+    if description.len() > 500 {
+        anyhow::bail!("Description exceeds maximum length(500 characters)");
+    }
+
+    Ok(())
+}
+
+pub fn validate_permissions(permissions: &[Permission]) -> anyhow::Result<()> {
+    let mut seen = std::collections::HashSet::new();
+
+    for permission in permissions {
+        if !seen.insert((permission.resource, permission.action)) {
+            anyhow::bail!(
+                "Permission {} {} is duplicated",
+                permission.resource.as_str(),
+                permission.action.as_str()
+            );
+        }
+
+        if !permission.category_ids.is_empty() && !permission.resource.is_category_scoped() {
+            anyhow::bail!(
+                "Permission for {} cannot be scoped to categories",
+                permission.resource.as_str()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RoleError {
+    #[error("Super administrator role cannot be modified")]
+    Protected,
+    #[error("Role is assigned to users")]
+    InUse,
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Permission {} {} is denied", resource.as_str(), action.as_str())]
+pub struct PermissionDenied {
+    pub resource: PermissionResource,
+    pub action: PermissionAction,
+}
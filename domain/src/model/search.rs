@@ -0,0 +1,113 @@
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_STOP: &str = "</mark>";
+pub const SNIPPET_CONTEXT: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchLanguage {
+    TextSearch(String),
+    Trigram,
+}
+
+impl Default for SearchLanguage {
+    fn default() -> Self {
+        Self::TextSearch("simple".into())
+    }
+}
+
+impl SearchLanguage {
+    pub fn text_search_config(&self) -> &str {
+        match self {
+            Self::TextSearch(config) => config,
+            Self::Trigram => "simple",
+        }
+    }
+}
+
+impl std::str::FromStr for SearchLanguage {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        let value = value.trim().to_lowercase();
+
+        match value.as_str() {
+            "" => Ok(Self::default()),
+            "trigram" => Ok(Self::Trigram),
+            config if config.chars().all(|x| x.is_ascii_lowercase() || x == '_') => {
+                Ok(Self::TextSearch(config.into()))
+            }
+            config => anyhow::bail!("Search language {} is not a valid configuration", config),
+        }
+    }
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, derive_new::new, utoipa::ToSchema, Clone, PartialEq,
+)]
+pub struct SearchMatch {
+    pub rank: f32,
+    pub snippet: String,
+}
+
+pub fn search_terms(keyword: &str) -> Vec<String> {
+    keyword.split_whitespace().map(String::from).collect()
+}
+
+fn chars_eq(left: &[char], right: &[char]) -> bool {
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right)
+            .all(|(x, y)| x.to_lowercase().eq(y.to_lowercase()))
+}
+
+pub fn highlight(text: &str, keyword: &str, context: usize) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let terms: Vec<Vec<char>> = search_terms(keyword)
+        .iter()
+        .map(|term| term.chars().collect())
+        .collect();
+
+    let match_at = |index: usize| {
+        terms
+            .iter()
+            .map(Vec::len)
+            .filter(|len| index + len <= chars.len())
+            .filter(|len| {
+                terms
+                    .iter()
+                    .any(|term| chars_eq(term, &chars[index..index + len]))
+            })
+            .max()
+    };
+
+    let (first, len) = (0..chars.len()).find_map(|index| Some((index, match_at(index)?)))?;
+    let start = first.saturating_sub(context);
+    let end = (first + len + context).min(chars.len());
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+
+    let mut index = start;
+    while index < end {
+        match match_at(index) {
+            Some(len) => {
+                snippet.push_str(HIGHLIGHT_START);
+                snippet.extend(&chars[index..index + len]);
+                snippet.push_str(HIGHLIGHT_STOP);
+                index += len;
+            }
+            None => {
+                snippet.push(chars[index]);
+                index += 1;
+            }
+        }
+    }
+
+    if index < chars.len() {
+        snippet.push('…');
+    }
+
+    Some(snippet)
+}
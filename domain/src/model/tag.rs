@@ -1,13 +1,16 @@
+use crate::model::version::INITIAL_VERSION;
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
 pub struct Tag {
     pub id: String,
     pub name: String,
     pub description: String,
+    pub version: i32,
 }
 
 impl Tag {
     pub fn try_new(id: String, name: String, description: String) -> anyhow::Result<Self> {
-        if name.len() < 1 {
+        if name.is_empty() {
             anyhow::bail!("Name must be at least 1 character long");
         }
 
@@ -23,6 +26,7 @@ impl Tag {
             id,
             name,
             description,
+            version: INITIAL_VERSION,
         })
     }
 }
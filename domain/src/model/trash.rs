@@ -0,0 +1,74 @@
+use crate::model::role::PermissionResource;
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, Copy, PartialEq, Eq,
+)]
+pub enum TrashKind {
+    Content,
+    Category,
+    Tag,
+    Media,
+}
+
+impl TrashKind {
+    pub const ALL: [Self; 4] = [Self::Content, Self::Category, Self::Tag, Self::Media];
+
+    pub fn resource(&self) -> PermissionResource {
+        match self {
+            Self::Content => PermissionResource::Contents,
+            Self::Category => PermissionResource::Categories,
+            Self::Tag => PermissionResource::Tags,
+            Self::Media => PermissionResource::Media,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Content => "Content",
+            Self::Category => "Category",
+            Self::Tag => "Tag",
+            Self::Media => "Media",
+        }
+    }
+}
+
+impl std::str::FromStr for TrashKind {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == value)
+            .ok_or_else(|| anyhow::anyhow!("Trash kind {} is not supported", value))
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone)]
+pub struct TrashedItem {
+    pub id: String,
+    pub kind: TrashKind,
+    pub name: String,
+    #[schema(value_type = String)]
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+pub enum TrashError {
+    #[error("Category still has contents outside the trash")]
+    CategoryInUse,
+    #[error("Category of the content is in the trash")]
+    CategoryInTrash,
+    #[error("Api identifier of the category is used by another category")]
+    CategoryIdentifierInUse,
+    #[error("Tag is in the trash")]
+    TagInTrash,
+    #[error("Record is not in the trash")]
+    NotInTrash,
+}
+
+pub fn purge_before(
+    now: chrono::DateTime<chrono::Utc>,
+    retention_days: i64,
+) -> chrono::DateTime<chrono::Utc> {
+    now - chrono::Duration::days(retention_days.max(0))
+}
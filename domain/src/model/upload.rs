@@ -0,0 +1,128 @@
+pub const MULTIPART_THRESHOLD: i64 = 64 * 1024 * 1024;
+pub const MIN_PART_SIZE: i64 = 64 * 1024 * 1024;
+pub const MAX_PART_COUNT: i64 = 10_000;
+pub const MAX_UPLOAD_SIZE: i64 = 5 * 1024 * 1024 * 1024 * 1024;
+pub const UPLOAD_EXPIRES_IN: std::time::Duration = std::time::Duration::from_secs(12 * 60 * 60);
+pub const DOWNLOAD_EXPIRES_IN: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq)]
+pub struct UploadPlan {
+    pub part_size: i64,
+    pub part_count: i32,
+}
+
+impl UploadPlan {
+    pub fn for_size(size: i64) -> anyhow::Result<Option<Self>> {
+        if size <= 0 {
+            anyhow::bail!("Size must be greater than 0");
+        }
+
+        if size > MAX_UPLOAD_SIZE {
+            anyhow::bail!(
+                "Size exceeds maximum upload size({} bytes)",
+                MAX_UPLOAD_SIZE
+            );
+        }
+
+        if size <= MULTIPART_THRESHOLD {
+            return Ok(None);
+        }
+
+        let part_size = MIN_PART_SIZE.max((size + MAX_PART_COUNT - 1) / MAX_PART_COUNT);
+        let part_count = i32::try_from((size + part_size - 1) / part_size)?;
+
+        Ok(Some(Self {
+            part_size,
+            part_count,
+        }))
+    }
+
+    pub fn part_length(&self, size: i64, part_number: i32) -> i64 {
+        let offset = self.part_size * (i64::from(part_number) - 1);
+
+        self.part_size.min(size - offset)
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone)]
+pub struct UploadSession {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub object_key: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+    pub multipart_upload_id: Option<String>,
+    pub plan: Option<UploadPlan>,
+    pub uploaded_by_id: String,
+    #[schema(value_type = String)]
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = Option<String>)]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl UploadSession {
+    pub fn verify(
+        &self,
+        uploaded_by_id: &str,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), UploadError> {
+        if self.uploaded_by_id != uploaded_by_id {
+            return Err(UploadError::NotFound);
+        }
+
+        if self.completed_at.is_some() {
+            return Err(UploadError::AlreadyCompleted);
+        }
+
+        if self.expires_at <= now {
+            return Err(UploadError::Expired);
+        }
+
+        Ok(())
+    }
+
+    pub fn is_multipart(&self) -> bool {
+        self.multipart_upload_id.is_some()
+    }
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new)]
+pub struct PresignedPart {
+    pub part_number: i32,
+    pub url: String,
+    pub size: i64,
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, derive_new::new)]
+pub struct PresignedUrl {
+    pub url: String,
+    #[schema(value_type = String)]
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn validate_checksum(checksum: &str) -> anyhow::Result<()> {
+    if checksum.len() != 64 || !checksum.chars().all(|x| x.is_ascii_hexdigit()) {
+        anyhow::bail!("Checksum must be a hex encoded SHA-256 digest");
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema, thiserror::Error)]
+pub enum UploadError {
+    #[error("Upload session is not found")]
+    NotFound,
+    #[error("Upload session is expired")]
+    Expired,
+    #[error("Upload session is already completed")]
+    AlreadyCompleted,
+    #[error("Uploaded object size {actual} does not match {expected}")]
+    SizeMismatch { expected: i64, actual: i64 },
+    #[error("Uploaded object checksum does not match")]
+    ChecksumMismatch,
+    #[error("Uploaded object is not found")]
+    ObjectMissing,
+}
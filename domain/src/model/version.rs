@@ -0,0 +1,44 @@
+pub const INITIAL_VERSION: i32 = 1;
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+#[error("Version {expected_version} is outdated")]
+pub struct VersionConflict {
+    pub expected_version: i32,
+    #[schema(value_type = Object)]
+    pub current: serde_json::Value,
+}
+
+impl VersionConflict {
+    pub fn try_new(expected_version: i32, current: &impl serde::Serialize) -> anyhow::Result<Self> {
+        Ok(Self {
+            expected_version,
+            current: serde_json::to_value(current)?,
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+pub enum PreconditionError {
+    #[error("If-Match is required")]
+    Missing,
+    #[error("If-Match must be * or an ETag")]
+    Invalid,
+}
+
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+pub fn parse_if_match(value: &str) -> Result<Option<i32>, PreconditionError> {
+    let value = value.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .and_then(|value| value.parse().ok())
+        .map(Some)
+        .ok_or(PreconditionError::Invalid)
+}
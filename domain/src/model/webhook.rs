@@ -0,0 +1,401 @@
+use hmac::Mac;
+
+use crate::model::content::ContentStatus;
+
+pub const MAX_WEBHOOK_ATTEMPTS: i32 = 6;
+pub const WEBHOOK_RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+pub const MAX_WEBHOOK_RESPONSE_LENGTH: usize = 2000;
+
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Hcms-Event";
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Hcms-Delivery";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Hcms-Signature";
+pub const WEBHOOK_IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, Copy, PartialEq, Eq, Hash,
+)]
+pub enum WebhookEvent {
+    #[serde(rename = "content.created")]
+    ContentCreated,
+    #[serde(rename = "content.updated")]
+    ContentUpdated,
+    #[serde(rename = "content.deleted")]
+    ContentDeleted,
+    #[serde(rename = "content.restored")]
+    ContentRestored,
+    #[serde(rename = "content.published")]
+    ContentPublished,
+    #[serde(rename = "content.unpublished")]
+    ContentUnpublished,
+    #[serde(rename = "category.created")]
+    CategoryCreated,
+    #[serde(rename = "category.updated")]
+    CategoryUpdated,
+    #[serde(rename = "category.deleted")]
+    CategoryDeleted,
+    #[serde(rename = "category.restored")]
+    CategoryRestored,
+    #[serde(rename = "tag.created")]
+    TagCreated,
+    #[serde(rename = "tag.updated")]
+    TagUpdated,
+    #[serde(rename = "tag.deleted")]
+    TagDeleted,
+    #[serde(rename = "tag.restored")]
+    TagRestored,
+    #[serde(rename = "media.created")]
+    MediaCreated,
+    #[serde(rename = "media.updated")]
+    MediaUpdated,
+    #[serde(rename = "media.deleted")]
+    MediaDeleted,
+    #[serde(rename = "media.restored")]
+    MediaRestored,
+}
+
+impl WebhookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ContentCreated => "content.created",
+            Self::ContentUpdated => "content.updated",
+            Self::ContentDeleted => "content.deleted",
+            Self::ContentRestored => "content.restored",
+            Self::ContentPublished => "content.published",
+            Self::ContentUnpublished => "content.unpublished",
+            Self::CategoryCreated => "category.created",
+            Self::CategoryUpdated => "category.updated",
+            Self::CategoryDeleted => "category.deleted",
+            Self::CategoryRestored => "category.restored",
+            Self::TagCreated => "tag.created",
+            Self::TagUpdated => "tag.updated",
+            Self::TagDeleted => "tag.deleted",
+            Self::TagRestored => "tag.restored",
+            Self::MediaCreated => "media.created",
+            Self::MediaUpdated => "media.updated",
+            Self::MediaDeleted => "media.deleted",
+            Self::MediaRestored => "media.restored",
+        }
+    }
+}
+
+impl WebhookEvent {
+    pub fn status_change(from: &ContentStatus, to: &ContentStatus) -> Self {
+        match (from, to) {
+            (_, ContentStatus::Published) => Self::ContentPublished,
+            (ContentStatus::Published, _) => Self::ContentUnpublished,
+            _ => Self::ContentUpdated,
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookEvent {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "content.created" => Ok(Self::ContentCreated),
+            "content.updated" => Ok(Self::ContentUpdated),
+            "content.deleted" => Ok(Self::ContentDeleted),
+            "content.restored" => Ok(Self::ContentRestored),
+            "content.published" => Ok(Self::ContentPublished),
+            "content.unpublished" => Ok(Self::ContentUnpublished),
+            "category.created" => Ok(Self::CategoryCreated),
+            "category.updated" => Ok(Self::CategoryUpdated),
+            "category.deleted" => Ok(Self::CategoryDeleted),
+            "category.restored" => Ok(Self::CategoryRestored),
+            "tag.created" => Ok(Self::TagCreated),
+            "tag.updated" => Ok(Self::TagUpdated),
+            "tag.deleted" => Ok(Self::TagDeleted),
+            "tag.restored" => Ok(Self::TagRestored),
+            "media.created" => Ok(Self::MediaCreated),
+            "media.updated" => Ok(Self::MediaUpdated),
+            "media.deleted" => Ok(Self::MediaDeleted),
+            "media.restored" => Ok(Self::MediaRestored),
+            _ => anyhow::bail!("Unknown webhook event {}", value),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone, PartialEq, thiserror::Error)]
+pub enum WebhookError {
+    #[error("Webhook url must be an absolute http or https url up to 2048 characters")]
+    InvalidUrl,
+    #[error("Webhook url must not point to a loopback, link-local or private address")]
+    PrivateUrl,
+    #[error("Webhook must subscribe to at least 1 event")]
+    NoEvents,
+    #[error("Webhook secret must be between 16 and 255 characters long")]
+    InvalidSecret,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone)]
+pub struct Webhook {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub category_ids: Vec<String>,
+    pub active: bool,
+    #[schema(value_type = String)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = String)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Webhook {
+    pub fn try_new(
+        id: uuid::Uuid,
+        url: String,
+        events: Vec<WebhookEvent>,
+        category_ids: Vec<String>,
+        active: bool,
+        created_at: chrono::DateTime<chrono::Utc>,
+        updated_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Self> {
+        validate_url(&url)?;
+        validate_events(&events)?;
+
+        Ok(Self {
+            id,
+            url,
+            events,
+            category_ids,
+            active,
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+pub fn validate_url(url: &str) -> Result<(), WebhookError> {
+    if url.len() > 2048 {
+        return Err(WebhookError::InvalidUrl);
+    }
+
+    let url = match url::Url::parse(url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => url,
+        _ => return Err(WebhookError::InvalidUrl),
+    };
+
+    let private = match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.');
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        Some(url::Host::Ipv4(address)) => is_private_address(&address.into()),
+        Some(url::Host::Ipv6(address)) => is_private_address(&address.into()),
+        None => return Err(WebhookError::InvalidUrl),
+    };
+
+    if private {
+        return Err(WebhookError::PrivateUrl);
+    }
+
+    Ok(())
+}
+
+pub fn is_private_address(address: &std::net::IpAddr) -> bool {
+    match address {
+        std::net::IpAddr::V4(address) => {
+            address.is_loopback()
+                || address.is_private()
+                || address.is_link_local()
+                || address.is_unspecified()
+        }
+        std::net::IpAddr::V6(address) => match address.to_ipv4_mapped() {
+            Some(address) => is_private_address(&address.into()),
+            None => {
+                address.is_loopback()
+                    || address.is_unspecified()
+                    || (address.segments()[0] & 0xfe00) == 0xfc00
+                    || (address.segments()[0] & 0xffc0) == 0xfe80
+            }
+        },
+    }
+}
+
+pub fn validate_events(events: &[WebhookEvent]) -> Result<(), WebhookError> {
+    if events.is_empty() {
+        return Err(WebhookError::NoEvents);
+    }
+
+    Ok(())
+}
+
+pub fn validate_secret(secret: &str) -> Result<(), WebhookError> {
+    if !(16..=255).contains(&secret.len()) {
+        return Err(WebhookError::InvalidSecret);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, PartialEq)]
+pub struct WebhookPayload {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    pub event: WebhookEvent,
+    #[schema(value_type = String)]
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+    pub data: serde_json::Value,
+}
+
+impl WebhookPayload {
+    pub fn new(
+        event: WebhookEvent,
+        data: serde_json::Value,
+        occurred_at: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::now_v7(),
+            event,
+            occurred_at,
+            data,
+        }
+    }
+}
+
+#[derive(
+    Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, Copy, PartialEq, Eq,
+)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Succeeded => "Succeeded",
+            Self::Failed => "Failed",
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookDeliveryStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "Pending" => Ok(Self::Pending),
+            "Succeeded" => Ok(Self::Succeeded),
+            "Failed" => Ok(Self::Failed),
+            _ => anyhow::bail!("Unknown webhook delivery status {}", value),
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, utoipa::ToSchema, Clone, derive_new::new)]
+pub struct WebhookDelivery {
+    #[schema(value_type = String)]
+    pub id: uuid::Uuid,
+    #[schema(value_type = String)]
+    pub webhook_id: uuid::Uuid,
+    pub event: WebhookEvent,
+    pub payload: WebhookPayload,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    #[schema(value_type = Option<String>)]
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    #[schema(value_type = String)]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = String)]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct WebhookAttempt {
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl WebhookDelivery {
+    pub fn attempt(&self, succeeded: bool, now: chrono::DateTime<chrono::Utc>) -> WebhookAttempt {
+        let attempts = self.attempts + 1;
+
+        if succeeded {
+            return WebhookAttempt::new(WebhookDeliveryStatus::Succeeded, attempts, None);
+        }
+
+        if attempts >= MAX_WEBHOOK_ATTEMPTS {
+            return WebhookAttempt::new(WebhookDeliveryStatus::Failed, attempts, None);
+        }
+
+        let backoff = WEBHOOK_RETRY_INTERVAL * 2u32.pow(attempts as u32 - 1);
+        let next_attempt_at = now + chrono::Duration::from_std(backoff).unwrap_or_default();
+
+        WebhookAttempt::new(
+            WebhookDeliveryStatus::Pending,
+            attempts,
+            Some(next_attempt_at),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl WebhookRequest {
+    pub fn signed(
+        url: String,
+        secret: &str,
+        delivery: &WebhookDelivery,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Self> {
+        let body = serde_json::to_string(&delivery.payload)?;
+        let signature = sign(secret, now.timestamp(), &body);
+        let headers = vec![
+            (WEBHOOK_EVENT_HEADER.into(), delivery.event.as_str().into()),
+            (WEBHOOK_DELIVERY_HEADER.into(), delivery.id.to_string()),
+            (WEBHOOK_SIGNATURE_HEADER.into(), signature),
+            (
+                WEBHOOK_IDEMPOTENCY_KEY_HEADER.into(),
+                delivery.payload.id.to_string(),
+            ),
+        ];
+
+        Ok(Self::new(url, headers, body))
+    }
+}
+
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC can take key of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+
+    format!(
+        "t={},v1={}",
+        timestamp,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, derive_new::new)]
+pub struct WebhookResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl WebhookResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn logged_body(&self) -> String {
+        self.body
+            .chars()
+            .take(MAX_WEBHOOK_RESPONSE_LENGTH)
+            .collect()
+    }
+}
@@ -0,0 +1,35 @@
+use crate::model::api_key::{ApiKey, ApiKeyScope};
+
+#[derive(Debug, serde::Deserialize, derive_new::new)]
+pub struct GetApiKeyQuery {
+    pub limit: i32,
+    pub offset: i32,
+}
+
+impl Default for GetApiKeyQuery {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            offset: 0,
+        }
+    }
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct CreateApiKey {
+    pub name: String,
+    pub prefix: String,
+    pub secret_hash: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub category_ids: Vec<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn get(&self, query: GetApiKeyQuery) -> anyhow::Result<Vec<ApiKey>>;
+    async fn find_by_hash(&self, secret_hash: String) -> anyhow::Result<Option<ApiKey>>;
+    async fn create(&self, data: CreateApiKey) -> anyhow::Result<ApiKey>;
+    async fn revoke(&self, id: String) -> anyhow::Result<()>;
+}
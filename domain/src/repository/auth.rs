@@ -0,0 +1,53 @@
+use crate::model::auth::{Credential, Session};
+
+#[derive(Debug, derive_new::new)]
+pub struct SignUpUser {
+    pub name: String,
+    pub email: String,
+    pub icon_url: String,
+    pub password_hash: String,
+    pub verification_token_hash: String,
+    pub verification_expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct CreateSession {
+    pub user_id: String,
+    pub access_token_hash: String,
+    pub access_expires_at: chrono::DateTime<chrono::Utc>,
+    pub refresh_token_hash: String,
+    pub refresh_expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct RotateSession {
+    pub id: uuid::Uuid,
+    pub previous_refresh_token_hash: String,
+    pub access_token_hash: String,
+    pub access_expires_at: chrono::DateTime<chrono::Utc>,
+    pub refresh_token_hash: String,
+    pub refresh_expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait AuthRepository: Send + Sync {
+    async fn find_credential(&self, email: String) -> anyhow::Result<Option<Credential>>;
+    async fn signup(&self, data: SignUpUser) -> anyhow::Result<String>;
+    async fn verify_email(
+        &self,
+        token_hash: String,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<bool>;
+    async fn create_session(&self, data: CreateSession) -> anyhow::Result<Session>;
+    async fn find_session_by_access_token(
+        &self,
+        token_hash: String,
+    ) -> anyhow::Result<Option<Session>>;
+    async fn find_session_by_refresh_token(
+        &self,
+        token_hash: String,
+    ) -> anyhow::Result<Option<Session>>;
+    async fn rotate_session(&self, data: RotateSession) -> anyhow::Result<Option<Session>>;
+    async fn revoke_session(&self, id: uuid::Uuid) -> anyhow::Result<()>;
+}
@@ -1,18 +1,12 @@
 use crate::model::category::Category;
+use crate::model::field::Field;
+use crate::model::page::{Page, PageQuery};
 
-#[derive(Debug, serde::Deserialize, derive_new::new)]
+#[derive(Debug, Default, derive_new::new)]
 pub struct GetCategoryQuery {
-    pub limit: i32,
-    pub offset: i32,
-}
-
-impl Default for GetCategoryQuery {
-    fn default() -> Self {
-        Self {
-            limit: 100,
-            offset: 0,
-        }
-    }
+    pub page: PageQuery,
+    #[new(default)]
+    pub category_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, serde::Deserialize, derive_new::new)]
@@ -20,6 +14,7 @@ pub struct CreateCategory {
     pub name: String,
     pub api_identifier: String,
     pub description: Option<String>,
+    pub fields: Vec<Field>,
 }
 
 #[derive(Debug, serde::Deserialize, derive_new::new)]
@@ -28,13 +23,21 @@ pub struct UpdateCategory {
     pub name: Option<String>,
     pub api_identifier: Option<String>,
     pub description: Option<String>,
+    pub fields: Option<Vec<Field>>,
+    pub expected_version: Option<i32>,
 }
 
 #[mockall::automock]
 #[async_trait::async_trait]
 pub trait CategoryRepository: Send + Sync {
-    async fn get(&self, query: GetCategoryQuery) -> anyhow::Result<Vec<Category>>;
+    async fn get(&self, query: GetCategoryQuery) -> anyhow::Result<Page<Category>>;
     async fn create(&self, data: CreateCategory) -> anyhow::Result<Category>;
     async fn update(&self, data: UpdateCategory) -> anyhow::Result<Category>;
     async fn delete(&self, id: String) -> anyhow::Result<()>;
+    async fn restore(&self, id: String) -> anyhow::Result<Category>;
+    async fn purge(
+        &self,
+        before: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>>;
 }
@@ -1,20 +1,33 @@
-use crate::model::content::{Content, ContentStatus};
+use crate::model::content::{Content, ContentStatus, ContentStatusChange};
+use crate::model::locale::ContentLocalization;
+use crate::model::page::{Page, PageQuery};
+use crate::model::query::{ContentFilter, SortOrder, TagMatch};
+use crate::model::relation::{ContentReferrer, RelatedContent};
 
-#[derive(Debug, serde::Deserialize, derive_new::new)]
+#[derive(Debug, Default, derive_new::new)]
 pub struct GetContentQuery {
-    pub limit: i32,
-    pub offset: i32,
-}
-
-impl Default for GetContentQuery {
-    fn default() -> Self {
-        Self {
-            limit: 100,
-            offset: 0,
-        }
-    }
+    pub page: PageQuery,
+    #[new(default)]
+    pub keyword: Option<String>,
+    #[new(default)]
+    pub category_id: Option<String>,
+    #[new(default)]
+    pub tag_ids: Option<Vec<String>>,
+    #[new(default)]
+    pub tag_match: TagMatch,
+    #[new(default)]
+    pub statuses: Option<Vec<ContentStatus>>,
+    #[new(default)]
+    pub created_by_ids: Option<Vec<String>>,
+    #[new(default)]
+    pub filter: ContentFilter,
+    #[new(default)]
+    pub sort: Vec<SortOrder>,
+    #[new(default)]
+    pub category_ids: Option<Vec<String>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 #[derive(Debug, serde::Deserialize, derive_new::new)]
 pub struct CreateContent {
     pub title: String,
@@ -22,10 +35,13 @@ pub struct CreateContent {
     pub fields: serde_json::Value,
     pub tag_ids: Vec<String>,
     pub status: ContentStatus,
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_by_id: String,
     pub updated_by_id: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 #[derive(Debug, serde::Deserialize, derive_new::new)]
 pub struct UpdateContent {
     pub id: String,
@@ -33,14 +49,56 @@ pub struct UpdateContent {
     pub category_id: Option<String>,
     pub fields: Option<serde_json::Value>,
     pub tag_ids: Option<Vec<String>>,
-    pub status: Option<ContentStatus>,
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_by_id: String,
+    pub expected_version: Option<i32>,
+    #[new(default)]
+    #[serde(default)]
+    pub clear_unpublish_at: bool,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct UpdateContentStatus {
+    pub id: String,
+    pub from: ContentStatus,
+    pub change: ContentStatusChange,
+    pub updated_by_id: String,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct FindRelatedQuery {
+    pub content_ids: Vec<uuid::Uuid>,
+    pub field_keys: Vec<String>,
+    pub category_ids: Option<Vec<String>>,
 }
 
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait ContentRepository: Send + Sync {
-    async fn get(&self, query: GetContentQuery) -> anyhow::Result<Vec<Content>>;
+    async fn get(&self, query: GetContentQuery) -> anyhow::Result<Page<Content>>;
+    async fn find(&self, id: String) -> anyhow::Result<Content>;
     async fn create(&self, data: CreateContent) -> anyhow::Result<Content>;
     async fn update(&self, data: UpdateContent) -> anyhow::Result<Content>;
+    async fn update_status(&self, data: UpdateContentStatus) -> anyhow::Result<Content>;
     async fn delete(&self, id: String) -> anyhow::Result<()>;
+    async fn restore(
+        &self,
+        id: String,
+        category_ids: Option<Vec<String>>,
+    ) -> anyhow::Result<Content>;
+    async fn purge(
+        &self,
+        before: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>>;
+    async fn find_related(&self, query: FindRelatedQuery) -> anyhow::Result<Vec<RelatedContent>>;
+    async fn referrers(
+        &self,
+        id: String,
+        category_ids: Option<Vec<String>>,
+    ) -> anyhow::Result<Vec<ContentReferrer>>;
+    async fn find_localizations(
+        &self,
+        content_ids: Vec<uuid::Uuid>,
+    ) -> anyhow::Result<Vec<ContentLocalization>>;
 }
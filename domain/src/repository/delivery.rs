@@ -0,0 +1,55 @@
+use crate::model::content::Content;
+use crate::model::locale::ContentLocalization;
+use crate::model::media::ContentMedia;
+use crate::model::relation::RelatedContent;
+
+#[derive(Debug, serde::Deserialize, derive_new::new)]
+pub struct GetDeliveryQuery {
+    pub limit: i32,
+    pub offset: i32,
+    pub include_drafts: bool,
+}
+
+impl Default for GetDeliveryQuery {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            offset: 0,
+            include_drafts: false,
+        }
+    }
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct FindDeliveryRelatedQuery {
+    pub content_ids: Vec<uuid::Uuid>,
+    pub field_keys: Vec<String>,
+    pub include_drafts: bool,
+    pub api_identifiers: Option<Vec<String>>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait DeliveryRepository: Send + Sync {
+    async fn get(
+        &self,
+        api_identifier: String,
+        query: GetDeliveryQuery,
+    ) -> anyhow::Result<Vec<Content>>;
+    async fn find(
+        &self,
+        api_identifier: String,
+        id: String,
+        include_drafts: bool,
+    ) -> anyhow::Result<Content>;
+    async fn find_related(
+        &self,
+        query: FindDeliveryRelatedQuery,
+    ) -> anyhow::Result<Vec<RelatedContent>>;
+    async fn find_media(&self, content_ids: Vec<uuid::Uuid>) -> anyhow::Result<Vec<ContentMedia>>;
+    async fn find_localizations(
+        &self,
+        content_ids: Vec<uuid::Uuid>,
+        include_drafts: bool,
+    ) -> anyhow::Result<Vec<ContentLocalization>>;
+}
@@ -0,0 +1,32 @@
+use crate::model::content::ContentStatusChange;
+use crate::model::locale::ContentLocalization;
+
+#[derive(Debug, serde::Deserialize, derive_new::new)]
+pub struct UpsertContentLocalization {
+    pub content_id: String,
+    pub locale: String,
+    pub fields: serde_json::Value,
+    pub updated_by_id: String,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct UpdateContentLocalizationStatus {
+    pub content_id: String,
+    pub locale: String,
+    pub change: ContentStatusChange,
+    pub updated_by_id: String,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ContentLocalizationRepository: Send + Sync {
+    async fn get(&self, content_id: String) -> anyhow::Result<Vec<ContentLocalization>>;
+    async fn find(&self, content_id: String, locale: String)
+        -> anyhow::Result<ContentLocalization>;
+    async fn upsert(&self, data: UpsertContentLocalization) -> anyhow::Result<ContentLocalization>;
+    async fn update_status(
+        &self,
+        data: UpdateContentLocalizationStatus,
+    ) -> anyhow::Result<ContentLocalization>;
+    async fn delete(&self, content_id: String, locale: String) -> anyhow::Result<()>;
+}
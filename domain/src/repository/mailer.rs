@@ -0,0 +1,5 @@
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send_verification(&self, email: String, token: String) -> anyhow::Result<()>;
+}
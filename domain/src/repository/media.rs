@@ -0,0 +1,48 @@
+use crate::model::media::{Media, MediaMetadata, MediaUsage};
+use crate::model::page::{Page, PageQuery};
+
+#[derive(Debug, Default, derive_new::new)]
+pub struct GetMediaQuery {
+    pub page: PageQuery,
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct CreateMedia {
+    pub filename: String,
+    pub mime_type: String,
+    pub body: Vec<u8>,
+    pub metadata: MediaMetadata,
+    pub alt_text: Option<String>,
+    pub uploaded_by_id: String,
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct UpdateMedia {
+    pub id: String,
+    pub filename: Option<String>,
+    pub alt_text: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MediaObject {
+    pub media: Media,
+    pub body: Vec<u8>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait MediaRepository: Send + Sync {
+    async fn get(&self, query: GetMediaQuery) -> anyhow::Result<Page<Media>>;
+    async fn find(&self, id: String) -> anyhow::Result<Media>;
+    async fn create(&self, data: CreateMedia) -> anyhow::Result<Media>;
+    async fn update(&self, data: UpdateMedia) -> anyhow::Result<Media>;
+    async fn delete(&self, id: String, force: bool) -> anyhow::Result<()>;
+    async fn restore(&self, id: String) -> anyhow::Result<Media>;
+    async fn purge(
+        &self,
+        before: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>>;
+    async fn usages(&self, id: String) -> anyhow::Result<Vec<MediaUsage>>;
+    async fn download(&self, id: String) -> anyhow::Result<MediaObject>;
+}
@@ -1,4 +1,20 @@
+pub mod api_key;
+pub mod auth;
 pub mod category;
 pub mod content;
+pub mod delivery;
+pub mod localization;
+pub mod mailer;
+pub mod media;
+pub mod oidc;
+pub mod outbox;
+pub mod render;
+pub mod revision;
+pub mod role;
+pub mod schedule;
 pub mod tag;
+pub mod trash;
+pub mod unit_of_work;
+pub mod upload;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,35 @@
+use crate::model::oidc::{OidcClaims, OidcLoginState};
+
+#[derive(Debug, derive_new::new)]
+pub struct LinkOidcIdentity {
+    pub issuer: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub name: String,
+    pub icon_url: String,
+    pub role: Option<String>,
+    pub default_role: String,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OidcClient: Send + Sync {
+    async fn authorization_url(&self, login_state: OidcLoginState) -> anyhow::Result<String>;
+    async fn exchange(
+        &self,
+        code: String,
+        login_state: OidcLoginState,
+    ) -> anyhow::Result<OidcClaims>;
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OidcRepository: Send + Sync {
+    async fn save_login_state(&self, login_state: OidcLoginState) -> anyhow::Result<()>;
+    async fn take_login_state(
+        &self,
+        state: String,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Option<OidcLoginState>>;
+    async fn link_identity(&self, data: LinkOidcIdentity) -> anyhow::Result<String>;
+}
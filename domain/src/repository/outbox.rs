@@ -0,0 +1,36 @@
+use crate::model::outbox::OutboxEvent;
+use crate::model::webhook::WebhookPayload;
+
+#[derive(Debug, derive_new::new)]
+pub struct AppendOutboxEvent {
+    pub category_id: Option<String>,
+    pub payload: WebhookPayload,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct RecordOutboxFailure {
+    pub id: uuid::Uuid,
+    pub attempts: i32,
+    pub next_attempt_at: chrono::DateTime<chrono::Utc>,
+    pub error: String,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OutboxRepository: Send + Sync {
+    async fn append(&self, data: AppendOutboxEvent) -> anyhow::Result<uuid::Uuid>;
+    async fn claim_due(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<OutboxEvent>>;
+    async fn complete(&self, id: uuid::Uuid) -> anyhow::Result<()>;
+    async fn record_failure(&self, data: RecordOutboxFailure) -> anyhow::Result<()>;
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait OutboxSink: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn handle(&self, event: &OutboxEvent) -> anyhow::Result<()>;
+}
@@ -0,0 +1,14 @@
+use crate::model::media::Media;
+use crate::model::render::{RenderOptions, RenderedImage};
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait MediaRenderRepository: Send + Sync {
+    async fn find_variant(&self, key: String) -> anyhow::Result<Option<RenderedImage>>;
+    async fn render(
+        &self,
+        media: Media,
+        options: RenderOptions,
+        key: String,
+    ) -> anyhow::Result<RenderedImage>;
+}
@@ -0,0 +1,27 @@
+use crate::model::revision::ContentRevision;
+
+#[derive(Debug, serde::Deserialize, derive_new::new)]
+pub struct GetRevisionQuery {
+    pub limit: i32,
+    pub offset: i32,
+}
+
+impl Default for GetRevisionQuery {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            offset: 0,
+        }
+    }
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ContentRevisionRepository: Send + Sync {
+    async fn get(
+        &self,
+        content_id: String,
+        query: GetRevisionQuery,
+    ) -> anyhow::Result<Vec<ContentRevision>>;
+    async fn find(&self, content_id: String, revision: i32) -> anyhow::Result<ContentRevision>;
+}
@@ -0,0 +1,41 @@
+use crate::model::role::{Permission, Role};
+
+#[derive(Debug, serde::Deserialize, derive_new::new)]
+pub struct GetRoleQuery {
+    pub limit: i32,
+    pub offset: i32,
+}
+
+impl Default for GetRoleQuery {
+    fn default() -> Self {
+        Self {
+            limit: 100,
+            offset: 0,
+        }
+    }
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct CreateRole {
+    pub name: String,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct UpdateRole {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permissions: Option<Vec<Permission>>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait RoleRepository: Send + Sync {
+    async fn get(&self, query: GetRoleQuery) -> anyhow::Result<Vec<Role>>;
+    async fn find(&self, id: String) -> anyhow::Result<Option<Role>>;
+    async fn create(&self, data: CreateRole) -> anyhow::Result<Role>;
+    async fn update(&self, data: UpdateRole) -> anyhow::Result<Role>;
+    async fn delete(&self, id: String) -> anyhow::Result<()>;
+}
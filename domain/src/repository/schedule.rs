@@ -0,0 +1,14 @@
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait ContentScheduleRepository: Send + Sync {
+    async fn publish_due(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>>;
+    async fn unpublish_due(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>>;
+}
@@ -1,18 +1,9 @@
+use crate::model::page::{Page, PageQuery};
 use crate::model::tag::Tag;
 
-#[derive(Debug, serde::Deserialize, derive_new::new)]
+#[derive(Debug, Default, derive_new::new)]
 pub struct GetTagQuery {
-    pub limit: i32,
-    pub offset: i32,
-}
-
-impl Default for GetTagQuery {
-    fn default() -> Self {
-        Self {
-            limit: 100,
-            offset: 0,
-        }
-    }
+    pub page: PageQuery,
 }
 
 #[derive(derive_new::new, Debug)]
@@ -26,12 +17,20 @@ pub struct UpdateTag {
     pub id: String,
     pub name: Option<String>,
     pub description: Option<String>,
+    pub expected_version: Option<i32>,
 }
 
+#[mockall::automock]
 #[async_trait::async_trait]
 pub trait TagRepository: Send + Sync {
-    async fn get(&self, query: GetTagQuery) -> anyhow::Result<Vec<Tag>>;
+    async fn get(&self, query: GetTagQuery) -> anyhow::Result<Page<Tag>>;
     async fn create(&self, create_tag: CreateTag) -> anyhow::Result<Tag>;
     async fn update(&self, update_tag: UpdateTag) -> anyhow::Result<Tag>;
     async fn delete(&self, id: String) -> anyhow::Result<()>;
+    async fn restore(&self, id: String) -> anyhow::Result<Tag>;
+    async fn purge(
+        &self,
+        before: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>>;
 }
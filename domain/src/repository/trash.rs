@@ -0,0 +1,20 @@
+use crate::model::page::{Page, PageQuery};
+use crate::model::trash::{TrashKind, TrashedItem};
+
+#[derive(Debug, Clone, derive_new::new)]
+pub struct TrashScope {
+    pub kind: TrashKind,
+    pub category_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct GetTrashQuery {
+    pub page: PageQuery,
+    pub scopes: Vec<TrashScope>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait TrashRepository: Send + Sync {
+    async fn get(&self, query: GetTrashQuery) -> anyhow::Result<Page<TrashedItem>>;
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::repository::category::CategoryRepository;
+use crate::repository::content::ContentRepository;
+use crate::repository::outbox::OutboxRepository;
+use crate::repository::tag::TagRepository;
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn categories(&self) -> Arc<dyn CategoryRepository>;
+    fn contents(&self) -> Arc<dyn ContentRepository>;
+    fn tags(&self) -> Arc<dyn TagRepository>;
+    fn outbox(&self) -> Arc<dyn OutboxRepository>;
+    async fn commit(self: Box<Self>) -> anyhow::Result<()>;
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    async fn begin(&self) -> anyhow::Result<Box<dyn UnitOfWork>>;
+}
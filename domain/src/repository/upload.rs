@@ -0,0 +1,40 @@
+use crate::model::media::{Media, MediaMetadata};
+use crate::model::upload::{PresignedPart, PresignedUrl, UploadPlan, UploadSession};
+
+#[allow(clippy::too_many_arguments)]
+#[derive(derive_new::new, Debug)]
+pub struct CreateUploadSession {
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+    pub plan: Option<UploadPlan>,
+    pub uploaded_by_id: String,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait MediaUploadRepository: Send + Sync {
+    async fn create_session(&self, data: CreateUploadSession) -> anyhow::Result<UploadSession>;
+    async fn find_session(&self, id: String) -> anyhow::Result<Option<UploadSession>>;
+    async fn presign_upload(
+        &self,
+        session: UploadSession,
+        expires_in: std::time::Duration,
+    ) -> anyhow::Result<Vec<PresignedPart>>;
+    async fn finish_upload(&self, session: UploadSession) -> anyhow::Result<()>;
+    async fn inspect_object(&self, object_key: String) -> anyhow::Result<MediaMetadata>;
+    async fn register(
+        &self,
+        session: UploadSession,
+        metadata: MediaMetadata,
+    ) -> anyhow::Result<Media>;
+    async fn discard(&self, session: UploadSession) -> anyhow::Result<()>;
+    async fn presign_download(
+        &self,
+        object_key: String,
+        expires_in: std::time::Duration,
+    ) -> anyhow::Result<PresignedUrl>;
+}
@@ -1,18 +1,9 @@
-use crate::model::user::{Role, User};
+use crate::model::page::{Page, PageQuery};
+use crate::model::user::User;
 
-#[derive(derive_new::new, Debug)]
+#[derive(Debug, Default, derive_new::new)]
 pub struct GetUserQuery {
-    pub limit: i32,
-    pub offset: i32,
-}
-
-impl Default for GetUserQuery {
-    fn default() -> Self {
-        Self {
-            limit: 100,
-            offset: 0,
-        }
-    }
+    pub page: PageQuery,
 }
 
 #[derive(derive_new::new, Debug)]
@@ -30,11 +21,12 @@ pub struct UpdateUser {
     pub role_id: Option<String>,
 }
 
+#[mockall::automock]
 #[async_trait::async_trait]
-pub trait UserRepository<T: Role>: Send + Sync {
-    async fn get(&self, query: GetUserQuery) -> anyhow::Result<Vec<User<T>>>;
-    async fn find(&self, id: String) -> anyhow::Result<User<T>>;
-    async fn create(&self, create_user: CreateUser) -> anyhow::Result<User<T>>;
-    async fn update(&self, update_user: UpdateUser) -> anyhow::Result<User<T>>;
+pub trait UserRepository: Send + Sync {
+    async fn get(&self, query: GetUserQuery) -> anyhow::Result<Page<User>>;
+    async fn find(&self, id: String) -> anyhow::Result<User>;
+    async fn create(&self, create_user: CreateUser) -> anyhow::Result<User>;
+    async fn update(&self, update_user: UpdateUser) -> anyhow::Result<User>;
     async fn delete(&self, id: String) -> anyhow::Result<()>;
 }
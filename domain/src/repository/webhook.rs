@@ -0,0 +1,86 @@
+use crate::model::page::{Page, PageQuery};
+use crate::model::webhook::{
+    Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent, WebhookPayload, WebhookRequest,
+    WebhookResponse,
+};
+
+#[derive(Debug, derive_new::new)]
+pub struct CreateWebhook {
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub category_ids: Vec<String>,
+    pub secret: String,
+    pub active: bool,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct UpdateWebhook {
+    pub id: String,
+    pub url: Option<String>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub category_ids: Option<Vec<String>>,
+    pub secret: Option<String>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct GetWebhookDeliveryQuery {
+    pub webhook_id: String,
+    pub page: PageQuery,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct EnqueueWebhookEvent {
+    pub category_id: Option<String>,
+    pub payload: WebhookPayload,
+}
+
+#[derive(Debug, derive_new::new)]
+pub struct RecordWebhookAttempt {
+    pub id: uuid::Uuid,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub response_status: Option<i32>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, derive_new::new)]
+pub struct DueWebhookDelivery {
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn get(&self) -> anyhow::Result<Vec<Webhook>>;
+    async fn find(&self, id: String) -> anyhow::Result<Webhook>;
+    async fn create(&self, data: CreateWebhook) -> anyhow::Result<Webhook>;
+    async fn update(&self, data: UpdateWebhook) -> anyhow::Result<Webhook>;
+    async fn delete(&self, id: String) -> anyhow::Result<()>;
+    async fn get_deliveries(
+        &self,
+        query: GetWebhookDeliveryQuery,
+    ) -> anyhow::Result<Page<WebhookDelivery>>;
+    async fn enqueue(&self, data: EnqueueWebhookEvent) -> anyhow::Result<Vec<uuid::Uuid>>;
+    async fn claim_due(
+        &self,
+        now: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<DueWebhookDelivery>>;
+    async fn record_attempt(&self, data: RecordWebhookAttempt) -> anyhow::Result<()>;
+    async fn redeliver(
+        &self,
+        webhook_id: String,
+        delivery_id: String,
+    ) -> anyhow::Result<WebhookDelivery>;
+}
+
+#[mockall::automock]
+#[async_trait::async_trait]
+pub trait WebhookSender: Send + Sync {
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<WebhookResponse>;
+}
@@ -0,0 +1,71 @@
+use crate::model::api_key::{ApiKey, ApiKeyCategory, ApiKeyError, ApiKeyScope, ApiKeySecret};
+
+fn build_api_key(categories: Vec<ApiKeyCategory>) -> ApiKey {
+    ApiKey::try_new(
+        uuid::Uuid::now_v7(),
+        "frontend".into(),
+        "hcms_0123abcd".into(),
+        vec![ApiKeyScope::ReadPublished],
+        categories,
+        None,
+        None,
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+#[rstest::rstest]
+fn secret_generate_success() {
+    let secret = ApiKeySecret::generate();
+
+    assert!(secret.token.starts_with(&secret.prefix));
+    assert_eq!(secret.hash, ApiKeySecret::hash(&secret.token));
+    assert_ne!(secret.hash, secret.token);
+    assert_ne!(secret.token, ApiKeySecret::generate().token);
+}
+
+#[rstest::rstest]
+#[case::empty_name("".into(), vec![ApiKeyScope::ReadPublished])]
+#[case::too_long_name("a".repeat(101), vec![ApiKeyScope::ReadPublished])]
+#[case::no_scope("frontend".into(), vec![])]
+fn api_key_new_failure(#[case] name: String, #[case] scopes: Vec<ApiKeyScope>) {
+    let result = ApiKey::try_new(
+        uuid::Uuid::now_v7(),
+        name,
+        "hcms_0123abcd".into(),
+        scopes,
+        vec![],
+        None,
+        None,
+        chrono::Utc::now(),
+    );
+
+    assert!(result.is_err());
+}
+
+#[rstest::rstest]
+fn api_key_verify() {
+    let now = chrono::Utc::now();
+    let mut api_key = build_api_key(vec![]);
+    assert_eq!(api_key.verify(now), Ok(()));
+
+    api_key.expires_at = Some(now - chrono::Duration::seconds(1));
+    assert_eq!(api_key.verify(now), Err(ApiKeyError::Expired));
+
+    api_key.revoked_at = Some(now);
+    assert_eq!(api_key.verify(now), Err(ApiKeyError::Revoked));
+}
+
+#[rstest::rstest]
+#[case::all_categories(vec![], "blog", true)]
+#[case::allowed(vec![ApiKeyCategory::new("id".into(), "blog".into())], "blog", true)]
+#[case::not_allowed(vec![ApiKeyCategory::new("id".into(), "news".into())], "blog", false)]
+fn api_key_allows_category(
+    #[case] categories: Vec<ApiKeyCategory>,
+    #[case] api_identifier: &str,
+    #[case] expected: bool,
+) {
+    let api_key = build_api_key(categories);
+
+    assert_eq!(api_key.allows_category(api_identifier), expected);
+}
@@ -0,0 +1,126 @@
+use crate::model::auth::{
+    hash_password, validate_email, validate_password, verify_password, AuthError, AuthToken,
+    Credential, Session, SessionSecret,
+};
+use crate::model::role::Role;
+use crate::model::user::User;
+
+fn build_session(now: chrono::DateTime<chrono::Utc>) -> Session {
+    let role = Role::try_new(
+        uuid::Uuid::now_v7().to_string(),
+        "member".into(),
+        "description".into(),
+        false,
+        vec![],
+    )
+    .unwrap();
+    let user = User::try_new(
+        uuid::Uuid::now_v7().to_string(),
+        "user1".into(),
+        "https://example.com/icon.png".into(),
+        role,
+    )
+    .unwrap();
+
+    Session::new(
+        uuid::Uuid::now_v7(),
+        user,
+        now + chrono::Duration::minutes(15),
+        now + chrono::Duration::days(30),
+        None,
+    )
+}
+
+#[rstest::rstest]
+#[case::simple("user@example.com")]
+#[case::subdomain("user.name+tag@mail.example.co.jp")]
+fn validate_email_success(#[case] email: &str) {
+    assert!(validate_email(email).is_ok());
+}
+
+#[rstest::rstest]
+#[case::empty("")]
+#[case::no_at("user.example.com")]
+#[case::no_local("@example.com")]
+#[case::no_domain_dot("user@localhost")]
+#[case::too_long(&format!("{}@example.com", "a".repeat(250)))]
+fn validate_email_failure(#[case] email: &str) {
+    assert!(validate_email(email).is_err());
+}
+
+#[rstest::rstest]
+#[case::too_short("passwor", false)]
+#[case::minimum("password", true)]
+#[case::maximum(&"a".repeat(128), true)]
+#[case::too_long(&"a".repeat(129), false)]
+fn validate_password_length(#[case] password: &str, #[case] expected: bool) {
+    assert_eq!(validate_password(password).is_ok(), expected);
+}
+
+#[rstest::rstest]
+fn password_hash_and_verify() {
+    let hash = hash_password("correct horse").unwrap();
+
+    assert!(hash.starts_with("$argon2"));
+    assert_ne!(hash, hash_password("correct horse").unwrap());
+    assert!(verify_password("correct horse", &hash));
+    assert!(!verify_password("battery staple", &hash));
+    assert!(!verify_password("correct horse", "not a hash"));
+}
+
+#[rstest::rstest]
+fn session_secret_generate_success() {
+    let now = chrono::Utc::now();
+    let secret = SessionSecret::generate(now);
+
+    assert_eq!(secret.access.hash, AuthToken::hash(&secret.access.token));
+    assert_eq!(secret.refresh.hash, AuthToken::hash(&secret.refresh.token));
+    assert_ne!(secret.access.token, secret.refresh.token);
+    assert!(secret.access_expires_at > now);
+    assert!(secret.refresh_expires_at > secret.access_expires_at);
+}
+
+#[rstest::rstest]
+fn credential_verify() {
+    let password_hash = hash_password("password").unwrap();
+    let verified = Credential::new(
+        "id".into(),
+        Some(password_hash.clone()),
+        Some(chrono::Utc::now()),
+    );
+    let unverified = Credential::new("id".into(), Some(password_hash), None);
+    let without_password = Credential::new("id".into(), None, Some(chrono::Utc::now()));
+
+    assert_eq!(verified.verify("password"), Ok(()));
+    assert_eq!(
+        verified.verify("wrong password"),
+        Err(AuthError::InvalidCredentials)
+    );
+    assert_eq!(
+        unverified.verify("password"),
+        Err(AuthError::EmailNotVerified)
+    );
+    assert_eq!(
+        unverified.verify("wrong password"),
+        Err(AuthError::InvalidCredentials)
+    );
+    assert_eq!(
+        without_password.verify("password"),
+        Err(AuthError::InvalidCredentials)
+    );
+}
+
+#[rstest::rstest]
+fn session_verify() {
+    let now = chrono::Utc::now();
+    let mut session = build_session(now);
+    assert_eq!(session.verify_access(now), Ok(()));
+    assert_eq!(session.verify_refresh(now), Ok(()));
+
+    session.access_expires_at = now - chrono::Duration::seconds(1);
+    assert_eq!(session.verify_access(now), Err(AuthError::InvalidToken));
+    assert_eq!(session.verify_refresh(now), Ok(()));
+
+    session.revoked_at = Some(now);
+    assert_eq!(session.verify_refresh(now), Err(AuthError::InvalidToken));
+}
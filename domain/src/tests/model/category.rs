@@ -8,9 +8,9 @@ fn category_new_success(
     #[case] api_identifier: String,
     #[case] description: Option<String>,
 ) {
-    let result = Category::try_new("id".into(), name, api_identifier, description);
+    let result = Category::try_new("id".into(), name, api_identifier, description, vec![]);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[rstest::rstest]
@@ -22,7 +22,7 @@ fn category_new_failure(
     #[case] api_identifier: String,
     #[case] description: Option<String>,
 ) {
-    let result = Category::try_new("id".into(), name, api_identifier, description);
+    let result = Category::try_new("id".into(), name, api_identifier, description, vec![]);
 
-    assert_eq!(result.is_err(), true);
+    assert!(result.is_err());
 }
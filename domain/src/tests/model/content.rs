@@ -1,5 +1,6 @@
 use crate::model::content::{
-    Content, ContentCategory, ContentStatus, ContentTag, CreatedBy, UpdatedBy,
+    Content, ContentCategory, ContentStatus, ContentStatusChange, ContentStatusError, ContentTag,
+    CreatedBy, UpdatedBy,
 };
 
 #[rstest::rstest]
@@ -13,6 +14,7 @@ fn content_new_success(#[case] title: String) {
     let created_by = CreatedBy::new("id".into(), "name".into());
     let updated_by = UpdatedBy::new("id".into(), "name".into());
     let published_at = None;
+    let unpublish_at = None;
     let created_at = chrono::Utc::now();
     let updated_at = chrono::Utc::now();
 
@@ -26,11 +28,12 @@ fn content_new_success(#[case] title: String) {
         created_by,
         updated_by,
         published_at,
+        unpublish_at,
         created_at,
         updated_at,
     );
 
-    assert_eq!(content.is_ok(), true);
+    assert!(content.is_ok());
     assert_eq!("a".repeat(50), content.unwrap().title);
 }
 
@@ -45,6 +48,7 @@ fn content_new_failure(#[case] title: String) {
     let created_by = CreatedBy::new("id".into(), "name".into());
     let updated_by = UpdatedBy::new("id".into(), "name".into());
     let published_at = None;
+    let unpublish_at = None;
     let created_at = chrono::Utc::now();
     let updated_at = chrono::Utc::now();
 
@@ -58,9 +62,180 @@ fn content_new_failure(#[case] title: String) {
         created_by,
         updated_by,
         published_at,
+        unpublish_at,
         created_at,
         updated_at,
     );
 
-    assert_eq!(content.is_err(), true);
+    assert!(content.is_err());
+}
+
+#[rstest::rstest]
+#[case::same_time(0)]
+#[case::before_publish(-1)]
+fn content_new_failure_with_invalid_schedule(#[case] offset_hours: i64) {
+    let published_at = chrono::Utc::now();
+    let unpublish_at = published_at + chrono::Duration::hours(offset_hours);
+
+    let content = Content::try_new(
+        uuid::Uuid::now_v7(),
+        "title".into(),
+        ContentCategory::new("id".into(), "sample".into()),
+        ContentStatus::Reserved,
+        serde_json::json!({}),
+        vec![],
+        CreatedBy::new("id".into(), "name".into()),
+        UpdatedBy::new("id".into(), "name".into()),
+        Some(published_at),
+        Some(unpublish_at),
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    );
+
+    assert!(content.is_err());
+}
+
+pub fn build_content(status: ContentStatus) -> Content {
+    Content::try_new(
+        uuid::Uuid::now_v7(),
+        "title".into(),
+        ContentCategory::new("id".into(), "sample".into()),
+        status,
+        serde_json::json!({}),
+        vec![],
+        CreatedBy::new("id".into(), "name".into()),
+        UpdatedBy::new("id".into(), "name".into()),
+        None,
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+#[rstest::rstest]
+#[case::draft_to_published(ContentStatus::Draft, ContentStatus::Published, true)]
+#[case::draft_to_reserved(ContentStatus::Draft, ContentStatus::Reserved, true)]
+#[case::draft_to_unpublished(ContentStatus::Draft, ContentStatus::Unpublished, false)]
+#[case::draft_to_draft(ContentStatus::Draft, ContentStatus::Draft, false)]
+#[case::reserved_to_published(ContentStatus::Reserved, ContentStatus::Published, true)]
+#[case::reserved_to_unpublished(ContentStatus::Reserved, ContentStatus::Unpublished, false)]
+#[case::published_to_unpublished(ContentStatus::Published, ContentStatus::Unpublished, true)]
+#[case::published_to_reserved(ContentStatus::Published, ContentStatus::Reserved, false)]
+#[case::published_to_published(ContentStatus::Published, ContentStatus::Published, false)]
+#[case::unpublished_to_reserved(ContentStatus::Unpublished, ContentStatus::Reserved, true)]
+#[case::unpublished_to_draft(ContentStatus::Unpublished, ContentStatus::Draft, true)]
+fn content_status_transition(
+    #[case] from: ContentStatus,
+    #[case] to: ContentStatus,
+    #[case] expected: bool,
+) {
+    assert_eq!(from.can_transition_to(&to), expected);
+}
+
+#[rstest::rstest]
+fn content_publish_success() {
+    let now = chrono::Utc::now();
+    let content = build_content(ContentStatus::Draft);
+
+    let change = content.publish(now).unwrap();
+
+    assert_eq!(
+        change,
+        ContentStatusChange::new(ContentStatus::Published, Some(now), None)
+    );
+}
+
+#[rstest::rstest]
+fn content_publish_failure() {
+    let content = build_content(ContentStatus::Published);
+
+    let error = content.publish(chrono::Utc::now()).unwrap_err();
+
+    assert_eq!(
+        error,
+        ContentStatusError::InvalidTransition {
+            from: ContentStatus::Published,
+            to: ContentStatus::Published,
+        }
+    );
+}
+
+#[rstest::rstest]
+fn content_republish_keeps_published_at() {
+    let published_at = chrono::Utc::now() - chrono::Duration::days(1);
+    let mut content = build_content(ContentStatus::Unpublished);
+    content.published_at = Some(published_at);
+
+    let change = content.publish(chrono::Utc::now()).unwrap();
+
+    assert_eq!(
+        change,
+        ContentStatusChange::new(ContentStatus::Published, Some(published_at), None)
+    );
+}
+
+#[rstest::rstest]
+fn content_unpublish_keeps_published_at() {
+    let published_at = chrono::Utc::now() - chrono::Duration::days(1);
+    let mut content = build_content(ContentStatus::Published);
+    content.published_at = Some(published_at);
+
+    let change = content.unpublish().unwrap();
+
+    assert_eq!(
+        change,
+        ContentStatusChange::new(ContentStatus::Unpublished, Some(published_at), None)
+    );
+}
+
+#[rstest::rstest]
+#[case::in_past(-1, None, ContentStatusError::ScheduleInPast)]
+#[case::unpublish_before_publish(1, Some(0), ContentStatusError::InvalidUnpublishTime)]
+fn content_schedule_failure(
+    #[case] publish_offset_hours: i64,
+    #[case] unpublish_offset_hours: Option<i64>,
+    #[case] expected: ContentStatusError,
+) {
+    let now = chrono::Utc::now();
+    let content = build_content(ContentStatus::Draft);
+    let published_at = now + chrono::Duration::hours(publish_offset_hours);
+    let unpublish_at = unpublish_offset_hours.map(|hours| now + chrono::Duration::hours(hours));
+
+    let error = content
+        .schedule(published_at, unpublish_at, now)
+        .unwrap_err();
+
+    assert_eq!(error, expected);
+}
+
+#[rstest::rstest]
+fn content_revert_to_draft_clears_schedule() {
+    let mut content = build_content(ContentStatus::Reserved);
+    content.published_at = Some(chrono::Utc::now() + chrono::Duration::hours(1));
+
+    let change = content.revert_to_draft().unwrap();
+
+    assert_eq!(
+        change,
+        ContentStatusChange::new(ContentStatus::Draft, None, None)
+    );
+}
+
+#[rstest::rstest]
+#[case::reserved_without_schedule(ContentStatus::Reserved, ContentStatusError::MissingSchedule)]
+#[case::unpublished(
+    ContentStatus::Unpublished,
+    ContentStatusError::InvalidTransition {
+        from: ContentStatus::Draft,
+        to: ContentStatus::Unpublished,
+    }
+)]
+fn content_status_initial_failure(
+    #[case] status: ContentStatus,
+    #[case] expected: ContentStatusError,
+) {
+    let error = ContentStatusChange::initial(status, None, None, chrono::Utc::now()).unwrap_err();
+
+    assert_eq!(error, expected);
 }
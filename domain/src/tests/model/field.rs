@@ -0,0 +1,204 @@
+use crate::model::category::Category;
+use crate::model::field::{
+    field_references, validate_fields, validate_localized_fields, Field, FieldReference, FieldType,
+};
+
+#[rstest::fixture]
+fn schema() -> Vec<Field> {
+    let mut title = Field::new(FieldType::Text, "title".into());
+    title.required = true;
+    title.max = Some(10.0);
+
+    let mut price = Field::new(FieldType::Number, "price".into());
+    price.min = Some(0.0);
+
+    let mut size = Field::new(FieldType::Enum, "size".into());
+    size.options = vec!["S".into(), "M".into(), "L".into()];
+
+    let mut related = Field::new(FieldType::Content, "related".into());
+    related.multiple = true;
+    related.max = Some(2.0);
+
+    vec![
+        title,
+        price,
+        size,
+        Field::new(FieldType::Boolean, "visible".into()),
+        Field::new(FieldType::Date, "released_at".into()),
+        Field::new(FieldType::Media, "thumbnail".into()),
+        Field::new(FieldType::Json, "extra".into()),
+        Field::new(FieldType::Content, "author".into()),
+        related,
+    ]
+}
+
+#[rstest::rstest]
+#[case::required_only(serde_json::json!({"title": "Hello"}))]
+#[case::all_fill(serde_json::json!({
+    "title": "Hello",
+    "price": 100,
+    "size": "M",
+    "visible": true,
+    "released_at": "2024-12-07",
+    "thumbnail": "0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4a",
+    "extra": {"any": ["thing"]},
+    "author": "0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4b",
+    "related": ["0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4c"],
+}))]
+fn validate_fields_success(schema: Vec<Field>, #[case] fields: serde_json::Value) {
+    let result = validate_fields(&schema, &fields);
+
+    assert!(result.is_ok());
+}
+
+#[rstest::rstest]
+#[case::missing_required(serde_json::json!({}), vec!["title"])]
+#[case::too_long(serde_json::json!({"title": "a".repeat(11)}), vec!["title"])]
+#[case::below_min(serde_json::json!({"title": "Hello", "price": -1}), vec!["price"])]
+#[case::wrong_types(
+    serde_json::json!({"title": 1, "visible": "yes", "released_at": "yesterday"}),
+    vec!["title", "visible", "released_at"]
+)]
+#[case::unknown_option(serde_json::json!({"title": "Hello", "size": "XL"}), vec!["size"])]
+#[case::invalid_reference(serde_json::json!({"title": "Hello", "thumbnail": "1"}), vec!["thumbnail"])]
+#[case::single_related(serde_json::json!({"title": "Hello", "related": "0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4c"}), vec!["related"])]
+#[case::too_many_related(
+    serde_json::json!({"title": "Hello", "related": [
+        "0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4a",
+        "0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4b",
+        "0193a0a6-7e8c-7d2a-8f1c-3c3b6f1d2e4c",
+    ]}),
+    vec!["related"]
+)]
+#[case::invalid_related(serde_json::json!({"title": "Hello", "related": ["1"]}), vec!["related"])]
+#[case::undefined_key(serde_json::json!({"title": "Hello", "body": "text"}), vec!["body"])]
+#[case::not_object(serde_json::json!(["Hello"]), vec![""])]
+fn validate_fields_failure(
+    schema: Vec<Field>,
+    #[case] fields: serde_json::Value,
+    #[case] keys: Vec<&str>,
+) {
+    let result = validate_fields(&schema, &fields);
+
+    let error = result.unwrap_err();
+    let error_keys: Vec<&str> = error.errors.iter().map(|e| e.key.as_str()).collect();
+    assert_eq!(error_keys, keys);
+}
+
+#[rstest::rstest]
+#[case::duplicated_key(vec![
+    Field::new(FieldType::Text, "title".into()),
+    Field::new(FieldType::RichText, "title".into()),
+])]
+#[case::empty_key(vec![Field::new(FieldType::Text, "".into())])]
+#[case::enum_without_options(vec![Field::new(FieldType::Enum, "size".into())])]
+#[case::multiple_text(vec![Field { multiple: true, ..Field::new(FieldType::Text, "title".into()) }])]
+#[case::restricted_media(vec![Field {
+    categories: vec!["id".into()],
+    ..Field::new(FieldType::Media, "thumbnail".into())
+}])]
+#[case::localizable_media(vec![Field {
+    localizable: true,
+    ..Field::new(FieldType::Media, "thumbnail".into())
+}])]
+#[case::localizable_content(vec![Field {
+    localizable: true,
+    ..Field::new(FieldType::Content, "author".into())
+}])]
+fn category_schema_failure(#[case] fields: Vec<Field>) {
+    let result = Category::try_new(
+        "id".into(),
+        "name".into(),
+        "api_identifier".into(),
+        None,
+        fields,
+    );
+
+    assert!(result.is_err());
+}
+
+#[rstest::rstest]
+#[case::partial(serde_json::json!({"title": "Hello"}))]
+#[case::null_falls_back(serde_json::json!({"title": null, "size": "S"}))]
+fn validate_localized_fields_success(schema: Vec<Field>, #[case] fields: serde_json::Value) {
+    let schema = localizable(schema);
+
+    let result = validate_localized_fields(&schema, &fields);
+
+    assert!(result.is_ok());
+}
+
+#[rstest::rstest]
+#[case::shared(serde_json::json!({"title": "Hello", "price": 100}), vec!["price"])]
+#[case::too_long(serde_json::json!({"title": "a".repeat(11)}), vec!["title"])]
+#[case::unknown_option(serde_json::json!({"size": "XL"}), vec!["size"])]
+#[case::undefined_key(serde_json::json!({"body": "text"}), vec!["body"])]
+#[case::not_object(serde_json::json!(["Hello"]), vec![""])]
+fn validate_localized_fields_failure(
+    schema: Vec<Field>,
+    #[case] fields: serde_json::Value,
+    #[case] keys: Vec<&str>,
+) {
+    let schema = localizable(schema);
+
+    let result = validate_localized_fields(&schema, &fields);
+
+    let error = result.unwrap_err();
+    let error_keys: Vec<&str> = error.errors.iter().map(|e| e.key.as_str()).collect();
+    assert_eq!(error_keys, keys);
+}
+
+fn localizable(schema: Vec<Field>) -> Vec<Field> {
+    schema
+        .into_iter()
+        .map(|field| Field {
+            localizable: ["title", "size"].contains(&field.key.as_str()),
+            ..field
+        })
+        .collect()
+}
+
+#[rstest::rstest]
+fn field_references_success(schema: Vec<Field>) {
+    let id = uuid::Uuid::now_v7();
+    let fields = serde_json::json!({"title": "Hello", "thumbnail": id.to_string()});
+
+    let references = field_references(&schema, &fields, FieldType::Media);
+
+    assert_eq!(
+        references,
+        vec![FieldReference::new("thumbnail".into(), id)]
+    );
+}
+
+#[rstest::rstest]
+#[case::missing(serde_json::json!({"title": "Hello"}))]
+#[case::null(serde_json::json!({"title": "Hello", "thumbnail": null}))]
+#[case::invalid(serde_json::json!({"title": "Hello", "thumbnail": "1"}))]
+fn field_references_empty(schema: Vec<Field>, #[case] fields: serde_json::Value) {
+    let references = field_references(&schema, &fields, FieldType::Media);
+
+    assert!(references.is_empty());
+}
+
+#[rstest::rstest]
+fn field_references_success_with_multiple(schema: Vec<Field>) {
+    let first = uuid::Uuid::now_v7();
+    let second = uuid::Uuid::now_v7();
+    let fields = serde_json::json!({
+        "title": "Hello",
+        "author": first.to_string(),
+        "related": [second.to_string(), first.to_string()],
+    });
+
+    let references = field_references(&schema, &fields, FieldType::Content);
+
+    assert_eq!(
+        references,
+        vec![
+            FieldReference::new("author".into(), first),
+            FieldReference::new("related".into(), second),
+            FieldReference::new("related".into(), first),
+        ]
+    );
+}
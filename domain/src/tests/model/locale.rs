@@ -0,0 +1,176 @@
+use crate::model::content::{
+    Content, ContentCategory, ContentStatus, ContentStatusError, CreatedBy, UpdatedBy,
+};
+use crate::model::locale::{localize, ContentLocalization, LocaleChain, LocaleError, Locales};
+
+#[rstest::fixture]
+fn locales() -> Locales {
+    Locales::try_new("ja,en,en-GB,fr", "ja", "en-GB=en,fr=en>ja>fr").unwrap()
+}
+
+fn content(fields: serde_json::Value) -> Content {
+    Content::try_new(
+        uuid::Uuid::now_v7(),
+        "title".into(),
+        ContentCategory::new("id".into(), "sample".into()),
+        ContentStatus::Published,
+        fields,
+        vec![],
+        CreatedBy::new("id".into(), "name".into()),
+        UpdatedBy::new("id".into(), "name".into()),
+        None,
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn localization(
+    content_id: uuid::Uuid,
+    locale: &str,
+    status: ContentStatus,
+    fields: serde_json::Value,
+) -> ContentLocalization {
+    ContentLocalization::new(
+        content_id,
+        locale.into(),
+        status,
+        fields,
+        UpdatedBy::new("id".into(), "name".into()),
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+}
+
+#[rstest::rstest]
+fn locales_new_success(locales: Locales) {
+    assert_eq!(locales.codes, vec!["ja", "en", "en-GB", "fr"]);
+    assert_eq!(locales.default, "ja");
+    assert_eq!(locales.fallbacks["en-GB"], vec!["en"]);
+}
+
+#[rstest::rstest]
+fn locales_new_success_with_first_code_as_default() {
+    let locales = Locales::try_new(" en , ja ", "", "").unwrap();
+
+    assert_eq!(locales.codes, vec!["en", "ja"]);
+    assert_eq!(locales.default, "en");
+}
+
+#[rstest::rstest]
+#[case::empty("", "", "")]
+#[case::invalid_code("en_US", "", "")]
+#[case::duplicated("en,ja,en", "", "")]
+#[case::unknown_default("en,ja", "fr", "")]
+#[case::malformed_rule("en,ja", "", "ja>en")]
+#[case::unknown_fallback("en,ja", "", "ja=fr")]
+fn locales_new_failure(#[case] codes: &str, #[case] default: &str, #[case] fallbacks: &str) {
+    let result = Locales::try_new(codes, default, fallbacks);
+
+    assert!(result.is_err());
+}
+
+#[rstest::rstest]
+#[case::default_locale("ja", vec![])]
+#[case::without_rule("en", vec!["en"])]
+#[case::with_rule("en-GB", vec!["en-GB", "en"])]
+#[case::stops_at_default("fr", vec!["fr", "en"])]
+fn locales_chain_success(locales: Locales, #[case] locale: &str, #[case] expected: Vec<&str>) {
+    let chain = locales.chain(locale).unwrap();
+
+    assert_eq!(chain.locale, locale);
+    assert_eq!(chain.locales, expected);
+}
+
+#[rstest::rstest]
+fn locales_chain_failure(locales: Locales) {
+    let result = locales.chain("de");
+
+    assert_eq!(result.unwrap_err(), LocaleError::Unknown("de".into()));
+}
+
+#[rstest::rstest]
+#[case::unknown("de", LocaleError::Unknown("de".into()))]
+#[case::default_locale("ja", LocaleError::DefaultLocale("ja".into()))]
+fn locales_localizable_failure(
+    locales: Locales,
+    #[case] locale: &str,
+    #[case] expected: LocaleError,
+) {
+    assert!(locales.localizable("en").is_ok());
+    assert_eq!(locales.localizable(locale).unwrap_err(), expected);
+}
+
+#[rstest::rstest]
+fn localize_success_with_fallback() {
+    let mut content =
+        content(serde_json::json!({"title": "タイトル", "body": "本文", "price": 100}));
+    let localizations = vec![
+        localization(
+            content.id,
+            "en",
+            ContentStatus::Published,
+            serde_json::json!({"title": "Title", "body": "Body"}),
+        ),
+        localization(
+            content.id,
+            "en-GB",
+            ContentStatus::Published,
+            serde_json::json!({"title": "Title (GB)", "body": null}),
+        ),
+        localization(
+            uuid::Uuid::now_v7(),
+            "en-GB",
+            ContentStatus::Published,
+            serde_json::json!({"price": 1}),
+        ),
+    ];
+    let chain = LocaleChain::new("en-GB".into(), vec!["en-GB".into(), "en".into()]);
+
+    localize(&mut content, &chain, &localizations);
+
+    assert_eq!(
+        content.fields,
+        serde_json::json!({"title": "Title (GB)", "body": "Body", "price": 100})
+    );
+    assert_eq!(content.locale, Some("en-GB".into()));
+}
+
+#[rstest::rstest]
+#[case::draft(ContentStatus::Draft, true)]
+#[case::published(ContentStatus::Published, false)]
+#[case::unpublished(ContentStatus::Unpublished, true)]
+fn localization_publish(#[case] status: ContentStatus, #[case] success: bool) {
+    let localization = localization(uuid::Uuid::now_v7(), "en", status, serde_json::json!({}));
+
+    let result = localization.publish(chrono::Utc::now());
+
+    assert_eq!(result.is_ok(), success);
+    if success {
+        let change = result.unwrap();
+        assert_eq!(change.status, ContentStatus::Published);
+        assert!(change.published_at.is_some());
+    }
+}
+
+#[rstest::rstest]
+fn localization_unpublish_failure() {
+    let localization = localization(
+        uuid::Uuid::now_v7(),
+        "en",
+        ContentStatus::Draft,
+        serde_json::json!({}),
+    );
+
+    let result = localization.unpublish();
+
+    assert_eq!(
+        result.unwrap_err(),
+        ContentStatusError::InvalidTransition {
+            from: ContentStatus::Draft,
+            to: ContentStatus::Unpublished,
+        }
+    );
+}
@@ -0,0 +1,108 @@
+use crate::model::media::{
+    checksum, object_key, validate_mime_type, Media, MediaInspector, MediaMetadata, MediaUploader,
+};
+
+pub fn png_header(width: u32, height: u32) -> Vec<u8> {
+    let mut body = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+    body.extend_from_slice(&13u32.to_be_bytes());
+    body.extend_from_slice(b"IHDR");
+    body.extend_from_slice(&width.to_be_bytes());
+    body.extend_from_slice(&height.to_be_bytes());
+    body.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    body
+}
+
+fn build_media(filename: &str, mime_type: &str, alt_text: Option<String>) -> anyhow::Result<Media> {
+    Media::try_new(
+        uuid::Uuid::now_v7(),
+        "media/key".into(),
+        filename.into(),
+        mime_type.into(),
+        1,
+        checksum(b"a"),
+        None,
+        None,
+        alt_text,
+        MediaUploader::new("id".into(), "name".into()),
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+}
+
+#[rstest::rstest]
+fn media_new_success() {
+    let media = build_media("photo.png", "image/png", Some("A photo".into())).unwrap();
+
+    assert!(media.is_image());
+    assert!(!build_media("doc.pdf", "application/pdf", None)
+        .unwrap()
+        .is_image());
+}
+
+#[rstest::rstest]
+#[case::empty_filename("", "image/png", None)]
+#[case::too_long_filename(&"a".repeat(256), "image/png", None)]
+#[case::path_in_filename("../photo.png", "image/png", None)]
+#[case::invalid_mime_type("photo.png", "png", None)]
+#[case::too_long_alt_text("photo.png", "image/png", Some("a".repeat(1001)))]
+fn media_new_failure(
+    #[case] filename: &str,
+    #[case] mime_type: &str,
+    #[case] alt_text: Option<String>,
+) {
+    assert!(build_media(filename, mime_type, alt_text).is_err());
+}
+
+#[rstest::rstest]
+#[case::valid("image/svg+xml", true)]
+#[case::missing_subtype("image/", false)]
+#[case::missing_kind("/png", false)]
+fn validate_mime_type_cases(#[case] mime_type: &str, #[case] expected: bool) {
+    assert_eq!(validate_mime_type(mime_type).is_ok(), expected);
+}
+
+#[rstest::rstest]
+fn metadata_inspect_image() {
+    let body = png_header(640, 480);
+    let metadata = MediaMetadata::inspect(&body);
+
+    assert_eq!(metadata.size, body.len() as i64);
+    assert_eq!(metadata.checksum, checksum(&body));
+    assert_eq!(metadata.checksum.len(), 64);
+    assert_eq!((metadata.width, metadata.height), (Some(640), Some(480)));
+}
+
+#[rstest::rstest]
+fn metadata_inspect_non_image() {
+    let metadata = MediaMetadata::inspect(b"plain text");
+
+    assert_eq!(metadata.size, 10);
+    assert_eq!((metadata.width, metadata.height), (None, None));
+}
+
+#[rstest::rstest]
+#[case::extension("Photo.PNG", "media/{}.png")]
+#[case::no_extension("README", "media/{}")]
+#[case::unsafe_extension("photo.p?g", "media/{}")]
+fn object_key_cases(#[case] filename: &str, #[case] expected: &str) {
+    let id = uuid::Uuid::now_v7();
+
+    assert_eq!(
+        object_key(&id, filename),
+        expected.replace("{}", &id.to_string())
+    );
+}
+
+#[rstest::rstest]
+fn inspector_matches_inspect() {
+    let mut body = png_header(32, 16);
+    body.extend(std::iter::repeat(0u8).take(100 * 1024));
+
+    let mut inspector = MediaInspector::default();
+    for chunk in body.chunks(7 * 1024) {
+        inspector.update(chunk);
+    }
+
+    assert_eq!(inspector.finish(), MediaMetadata::inspect(&body));
+}
@@ -1,3 +1,21 @@
+pub mod api_key;
+pub mod auth;
 pub mod category;
 pub mod content;
+pub mod field;
+pub mod locale;
+pub mod media;
+pub mod oidc;
+pub mod outbox;
+pub mod page;
+pub mod query;
+pub mod relation;
+pub mod render;
+pub mod revision;
+pub mod role;
+pub mod search;
+pub mod trash;
+pub mod upload;
 pub mod user;
+pub mod version;
+pub mod webhook;
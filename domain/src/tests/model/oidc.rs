@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use crate::model::oidc::{code_challenge, OidcClaims, OidcLoginState, RoleMapping};
+use crate::model::user::DEFAULT_ICON_URL;
+
+#[rstest::rstest]
+fn code_challenge_success() {
+    let challenge = code_challenge("code-verifier");
+
+    assert_eq!(challenge, "qdgLLRr1saFHT6DWfWU28VNPIi7e9ynEBnBG3Oadw9g");
+}
+
+#[rstest::rstest]
+fn login_state_generate_success() {
+    let now = chrono::Utc::now();
+    let login_state = OidcLoginState::generate(now);
+    let other = OidcLoginState::generate(now);
+
+    assert_eq!(login_state.code_verifier.len(), 43);
+    assert_ne!(login_state.state, other.state);
+    assert_ne!(login_state.nonce, other.nonce);
+    assert_ne!(login_state.code_challenge(), login_state.code_verifier);
+    assert!(login_state.expires_at > now);
+}
+
+#[rstest::rstest]
+#[case::name(Some("User One"), Some("user@example.com"), "User One")]
+#[case::email(None, Some("user@example.com"), "user@example.com")]
+#[case::subject(None, None, "subject")]
+#[case::empty_name(Some(""), None, "subject")]
+fn claims_display_name(
+    #[case] name: Option<&str>,
+    #[case] email: Option<&str>,
+    #[case] expected: &str,
+) {
+    let claims = OidcClaims {
+        subject: "subject".into(),
+        name: name.map(String::from),
+        email: email.map(String::from),
+        ..Default::default()
+    };
+
+    assert_eq!(claims.display_name(), expected);
+}
+
+#[rstest::rstest]
+fn claims_display_name_truncated() {
+    let claims = OidcClaims {
+        name: Some("a".repeat(60)),
+        ..Default::default()
+    };
+
+    assert_eq!(claims.display_name().len(), 50);
+}
+
+#[rstest::rstest]
+#[case::valid(Some("https://example.com/icon.png"), "https://example.com/icon.png")]
+#[case::invalid(Some("not a url"), DEFAULT_ICON_URL)]
+#[case::missing(None, DEFAULT_ICON_URL)]
+fn claims_icon_url(#[case] picture: Option<&str>, #[case] expected: &str) {
+    let claims = OidcClaims {
+        picture: picture.map(String::from),
+        ..Default::default()
+    };
+
+    assert_eq!(claims.icon_url(), expected);
+}
+
+#[rstest::rstest]
+#[case::verified(true, Some("user@example.com"))]
+#[case::unverified(false, None)]
+fn claims_verified_email(#[case] email_verified: bool, #[case] expected: Option<&str>) {
+    let claims = OidcClaims {
+        email: Some("user@example.com".into()),
+        email_verified,
+        ..Default::default()
+    };
+
+    assert_eq!(claims.verified_email().as_deref(), expected);
+}
+
+#[rstest::rstest]
+#[case::first_rule(vec!["cms-admins", "cms-editors"], Some("admin"))]
+#[case::second_rule(vec!["cms-editors"], Some("member"))]
+#[case::no_match(vec!["other"], Some("viewer"))]
+fn role_mapping_resolve(#[case] groups: Vec<&str>, #[case] expected: Option<&str>) {
+    let mapping = RoleMapping::from_str("cms-admins=admin, cms-editors = member")
+        .unwrap()
+        .with_default_role("viewer".into());
+    let groups: Vec<String> = groups.into_iter().map(String::from).collect();
+
+    assert_eq!(mapping.resolve(&groups).as_deref(), expected);
+}
+
+#[rstest::rstest]
+fn role_mapping_resolve_without_rules() {
+    let mapping = RoleMapping::default();
+
+    assert_eq!(mapping.resolve(&["cms-admins".into()]), None);
+    assert_eq!(mapping.default_role(), "member");
+}
+
+#[rstest::rstest]
+#[case::empty("", true)]
+#[case::missing_role("cms-admins=", false)]
+#[case::missing_separator("cms-admins", false)]
+fn role_mapping_parse(#[case] value: &str, #[case] expected: bool) {
+    assert_eq!(RoleMapping::from_str(value).is_ok(), expected);
+}
@@ -0,0 +1,33 @@
+use crate::model::outbox::OutboxEvent;
+use crate::model::webhook::{WebhookEvent, WebhookPayload};
+
+fn event(attempts: i32) -> OutboxEvent {
+    let payload = WebhookPayload::new(
+        WebhookEvent::TagCreated,
+        serde_json::json!({"id": "id"}),
+        chrono::Utc::now(),
+    );
+
+    OutboxEvent::new(
+        payload.id,
+        None,
+        payload,
+        attempts,
+        None,
+        chrono::Utc::now(),
+    )
+}
+
+#[rstest::rstest]
+#[case::first(0, 10)]
+#[case::third(2, 40)]
+#[case::capped(9, 3600)]
+#[case::many(100, 3600)]
+fn retry_at_backoff(#[case] attempts: i32, #[case] expected_seconds: i64) {
+    let now = chrono::Utc::now();
+
+    assert_eq!(
+        event(attempts).retry_at(now),
+        now + chrono::Duration::seconds(expected_seconds)
+    );
+}
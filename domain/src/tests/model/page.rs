@@ -0,0 +1,113 @@
+use crate::model::page::{Cursor, Page, PageError, PageQuery, DEFAULT_PAGE_LIMIT};
+
+fn ids(count: u128) -> Vec<uuid::Uuid> {
+    (1..=count).map(uuid::Uuid::from_u128).collect()
+}
+
+fn decode(cursor: Option<String>) -> Option<Cursor> {
+    cursor.map(|cursor| Cursor::decode(&cursor).unwrap())
+}
+
+#[rstest::rstest]
+#[case::after(Cursor::After(uuid::Uuid::now_v7()))]
+#[case::before(Cursor::Before(uuid::Uuid::now_v7()))]
+#[case::offset(Cursor::Offset(200))]
+fn cursor_round_trip(#[case] cursor: Cursor) {
+    assert_eq!(Cursor::decode(&cursor.encode()), Ok(cursor));
+}
+
+#[rstest::rstest]
+#[case::not_base64("!!!")]
+#[case::unknown_kind("c2lkZTox")]
+#[case::invalid_id("YWZ0ZXI6MTIz")]
+#[case::negative_offset("b2Zmc2V0Oi0x")]
+fn cursor_decode_failure(#[case] value: &str) {
+    assert_eq!(Cursor::decode(value), Err(PageError::InvalidCursor));
+}
+
+#[test]
+fn page_query_try_new_success() {
+    let cursor = Cursor::After(uuid::Uuid::now_v7());
+    let query = PageQuery::try_new(Some(20), Some(&cursor.encode()), true).unwrap();
+    assert_eq!(query, PageQuery::new(20, Some(cursor), true));
+
+    let query = PageQuery::try_new(None, Some(""), false).unwrap();
+    assert_eq!(query, PageQuery::new(DEFAULT_PAGE_LIMIT, None, false));
+}
+
+#[rstest::rstest]
+#[case::zero(0)]
+#[case::too_large(1001)]
+fn page_query_try_new_failure_with_limit(#[case] limit: i32) {
+    assert_eq!(
+        PageQuery::try_new(Some(limit), None, false),
+        Err(PageError::InvalidLimit)
+    );
+}
+
+#[test]
+fn page_query_rejects_mismatched_cursor() {
+    let keyset = PageQuery::new(10, Some(Cursor::After(uuid::Uuid::nil())), false);
+    assert_eq!(keyset.offset(), Err(PageError::CursorMismatch));
+
+    let offset = PageQuery::new(10, Some(Cursor::Offset(10)), false);
+    assert_eq!(offset.keyset(), Err(PageError::CursorMismatch));
+}
+
+#[test]
+fn page_from_keyset_rows_first_page() {
+    let query = PageQuery::new(2, None, false);
+    let page = Page::from_keyset_rows(ids(3), &query, |id| *id, Some(3));
+
+    assert_eq!(page.items, ids(2));
+    assert_eq!(decode(page.next_cursor), Some(Cursor::After(ids(2)[1])));
+    assert_eq!(page.prev_cursor, None);
+    assert_eq!(page.total, Some(3));
+}
+
+#[test]
+fn page_from_keyset_rows_last_page_after_cursor() {
+    let query = PageQuery::new(2, Some(Cursor::After(uuid::Uuid::from_u128(2))), false);
+    let page = Page::from_keyset_rows(vec![uuid::Uuid::from_u128(3)], &query, |id| *id, None);
+
+    assert_eq!(page.items, vec![uuid::Uuid::from_u128(3)]);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(
+        decode(page.prev_cursor),
+        Some(Cursor::Before(uuid::Uuid::from_u128(3)))
+    );
+}
+
+#[test]
+fn page_from_keyset_rows_restores_order_before_cursor() {
+    let query = PageQuery::new(2, Some(Cursor::Before(uuid::Uuid::from_u128(5))), false);
+    let rows = vec![
+        uuid::Uuid::from_u128(4),
+        uuid::Uuid::from_u128(3),
+        uuid::Uuid::from_u128(2),
+    ];
+    let page = Page::from_keyset_rows(rows, &query, |id| *id, None);
+
+    assert_eq!(
+        page.items,
+        vec![uuid::Uuid::from_u128(3), uuid::Uuid::from_u128(4)]
+    );
+    assert_eq!(
+        decode(page.next_cursor),
+        Some(Cursor::After(uuid::Uuid::from_u128(4)))
+    );
+    assert_eq!(
+        decode(page.prev_cursor),
+        Some(Cursor::Before(uuid::Uuid::from_u128(3)))
+    );
+}
+
+#[test]
+fn page_from_offset_rows() {
+    let query = PageQuery::new(2, Some(Cursor::Offset(2)), false);
+    let page = Page::from_offset_rows(vec![3, 4, 5], &query, 2, None);
+
+    assert_eq!(page.items, vec![3, 4]);
+    assert_eq!(decode(page.next_cursor), Some(Cursor::Offset(4)));
+    assert_eq!(decode(page.prev_cursor), Some(Cursor::Offset(0)));
+}
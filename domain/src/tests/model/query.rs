@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use crate::model::query::{
+    ContentFilter, FieldFilter, FilterOperator, Projection, SortKey, SortOrder, TagMatch,
+};
+
+fn pairs(values: &[(&str, &str)]) -> Vec<(String, String)> {
+    values
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn content_filter_parse_success() {
+    let filter = ContentFilter::parse(&pairs(&[
+        ("offset", "0"),
+        ("status", "Published"),
+        ("published_at[gte]", "2024-01-01T00:00:00Z"),
+        ("updated_at[lt]", "2024-02-01T09:00:00+09:00"),
+        ("fields.price[gte]", "100"),
+        ("fields.slug", "hello"),
+        ("fields.color[in]", "red,1"),
+        ("fields.body[contains]", "50%"),
+        ("fields.cover[exists]", "false"),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        filter.published_at.gte.unwrap().to_rfc3339(),
+        "2024-01-01T00:00:00+00:00"
+    );
+    assert_eq!(
+        filter.updated_at.lt.unwrap().to_rfc3339(),
+        "2024-02-01T00:00:00+00:00"
+    );
+    assert_eq!(
+        filter.fields,
+        vec![
+            FieldFilter::new("price".into(), FilterOperator::Gte, serde_json::json!(100)),
+            FieldFilter::new(
+                "slug".into(),
+                FilterOperator::Eq,
+                serde_json::json!("hello")
+            ),
+            FieldFilter::new(
+                "color".into(),
+                FilterOperator::In,
+                serde_json::json!(["red", 1])
+            ),
+            FieldFilter::new(
+                "body".into(),
+                FilterOperator::Contains,
+                serde_json::json!("50%")
+            ),
+            FieldFilter::new(
+                "cover".into(),
+                FilterOperator::Exists,
+                serde_json::json!(false)
+            ),
+        ]
+    );
+}
+
+#[rstest::rstest]
+#[case::operator("fields.price[between]", "1")]
+#[case::unclosed("fields.price[gte", "1")]
+#[case::empty_key("fields.[eq]", "1")]
+#[case::unknown("status[eq]", "Draft")]
+#[case::date("published_at[gte]", "yesterday")]
+#[case::date_operator("published_at[in]", "2024-01-01T00:00:00Z")]
+#[case::exists("fields.cover[exists]", "maybe")]
+fn content_filter_parse_failure(#[case] key: &str, #[case] value: &str) {
+    assert!(ContentFilter::parse(&pairs(&[(key, value)])).is_err());
+}
+
+#[test]
+fn content_filter_parse_failure_with_too_many_filters() {
+    let values: Vec<(String, String)> = (0..11)
+        .map(|i| (format!("fields.key{}", i), "1".to_string()))
+        .collect();
+
+    assert!(ContentFilter::parse(&values).is_err());
+}
+
+#[test]
+fn tag_match_from_str() {
+    assert_eq!(TagMatch::from_str("any").unwrap(), TagMatch::Any);
+    assert_eq!(TagMatch::from_str("all").unwrap(), TagMatch::All);
+    assert!(TagMatch::from_str("some").is_err());
+}
+
+#[test]
+fn sort_order_parse_success() {
+    let orders = SortOrder::parse_list("-published_at, title,fields.price").unwrap();
+
+    assert_eq!(
+        orders,
+        vec![
+            SortOrder::new(SortKey::PublishedAt, true),
+            SortOrder::new(SortKey::Title, false),
+            SortOrder::new(SortKey::Field("price".into()), false),
+        ]
+    );
+    assert!(SortOrder::parse_list("").unwrap().is_empty());
+}
+
+#[rstest::rstest]
+#[case::unknown("category")]
+#[case::empty_field("fields.")]
+#[case::too_many("title,status,created_at,updated_at")]
+fn sort_order_parse_failure(#[case] value: &str) {
+    assert!(SortOrder::parse_list(value).is_err());
+}
+
+#[rstest::rstest]
+#[case::empty("", serde_json::json!({"id": "1", "title": "title", "fields": {"a": 1, "b": 2}}))]
+#[case::keys("title", serde_json::json!({"id": "1", "title": "title"}))]
+#[case::field_keys("fields.a", serde_json::json!({"id": "1", "fields": {"a": 1}}))]
+#[case::fields("fields,fields.a", serde_json::json!({"id": "1", "fields": {"a": 1, "b": 2}}))]
+fn projection_apply(#[case] select: &str, #[case] expected: serde_json::Value) {
+    let projection = Projection::parse(select).unwrap();
+    let value = serde_json::json!({"id": "1", "title": "title", "fields": {"a": 1, "b": 2}});
+
+    assert_eq!(projection.apply(value), expected);
+}
+
+#[test]
+fn projection_parse_failure() {
+    assert!(Projection::parse("title,password").is_err());
+}
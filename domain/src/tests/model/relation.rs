@@ -0,0 +1,66 @@
+use crate::model::content::{Content, ContentStatus};
+use crate::model::relation::{embed_related, Expand};
+use crate::tests::model::content::build_content;
+
+#[test]
+fn expand_parse_success() {
+    let expand = Expand::parse("author, related.author,related.category.parent").unwrap();
+
+    assert_eq!(expand.keys(), vec!["author", "related"]);
+    assert!(expand.fields["author"].is_empty());
+    assert_eq!(expand.fields["related"].keys(), vec!["author", "category"]);
+    assert_eq!(
+        expand.fields["related"].fields["category"].keys(),
+        vec!["parent"]
+    );
+}
+
+#[test]
+fn expand_parse_success_with_empty() {
+    assert!(Expand::parse("").unwrap().is_empty());
+}
+
+#[rstest::rstest]
+#[case::empty_segment("related..author")]
+#[case::too_deep("a.b.c.d")]
+fn expand_parse_failure(#[case] value: &str) {
+    assert!(Expand::parse(value).is_err());
+}
+
+#[test]
+fn embed_related_success() {
+    let first = uuid::Uuid::now_v7();
+    let second = uuid::Uuid::now_v7();
+    let mut contents = vec![
+        Content {
+            id: first,
+            fields: serde_json::json!({"author": "a", "related": ["b", "c"]}),
+            ..build_content(ContentStatus::Published)
+        },
+        Content {
+            id: second,
+            fields: serde_json::json!({"author": "d", "related": []}),
+            ..build_content(ContentStatus::Published)
+        },
+    ];
+    let authors = vec![(first, serde_json::json!({"title": "author"}))];
+    let related = vec![
+        (first, serde_json::json!({"title": "b"})),
+        (first, serde_json::json!({"title": "c"})),
+    ];
+
+    embed_related(&mut contents, "author", authors);
+    embed_related(&mut contents, "related", related);
+
+    assert_eq!(
+        contents[0].fields,
+        serde_json::json!({
+            "author": {"title": "author"},
+            "related": [{"title": "b"}, {"title": "c"}],
+        })
+    );
+    assert_eq!(
+        contents[1].fields,
+        serde_json::json!({"author": null, "related": []})
+    );
+}
@@ -0,0 +1,112 @@
+use crate::model::render::{
+    RenderError, RenderFit, RenderFormat, RenderOptions, RenderSigner, DEFAULT_RENDER_QUALITY,
+};
+
+fn build_options() -> RenderOptions {
+    RenderOptions::try_new(Some(320), Some(200), None, None, None).unwrap()
+}
+
+#[rstest::rstest]
+fn options_defaults() {
+    let options = RenderOptions::try_new(Some(320), None, None, None, None).unwrap();
+
+    assert_eq!(options.fit, RenderFit::Cover);
+    assert_eq!(options.format, RenderFormat::Webp);
+    assert_eq!(options.quality, DEFAULT_RENDER_QUALITY);
+    assert_eq!(options.canonical(), "w=320&fit=cover&format=webp&q=80");
+}
+
+#[rstest::rstest]
+#[case::zero_width(Some(0), None, None)]
+#[case::too_large_height(None, Some(4097), None)]
+#[case::zero_quality(None, None, Some(0))]
+#[case::too_large_quality(None, None, Some(101))]
+fn options_failure(
+    #[case] width: Option<u32>,
+    #[case] height: Option<u32>,
+    #[case] quality: Option<u8>,
+) {
+    assert!(RenderOptions::try_new(width, height, None, None, quality).is_err());
+}
+
+#[rstest::rstest]
+#[case::webp(RenderFormat::Webp, 80)]
+#[case::png(RenderFormat::Png, 80)]
+#[case::jpeg(RenderFormat::Jpeg, 50)]
+fn try_new_normalizes_quality(#[case] format: RenderFormat, #[case] expected: u8) {
+    let id = uuid::Uuid::now_v7();
+    let options = RenderOptions::try_new(Some(320), None, None, Some(format), Some(50)).unwrap();
+    let default = RenderOptions::try_new(Some(320), None, None, Some(format), None).unwrap();
+
+    assert_eq!(options.quality, expected);
+    assert_eq!(
+        options.variant_key(&id) == default.variant_key(&id),
+        !format.is_lossy()
+    );
+}
+
+#[rstest::rstest]
+fn variant_key_is_deterministic() {
+    let id = uuid::Uuid::now_v7();
+    let options = build_options();
+    let other =
+        RenderOptions::try_new(Some(320), Some(200), None, Some(RenderFormat::Png), None).unwrap();
+
+    assert_eq!(options.variant_key(&id), build_options().variant_key(&id));
+    assert_ne!(options.variant_key(&id), other.variant_key(&id));
+    assert!(options
+        .variant_key(&id)
+        .starts_with(&format!("variants/{}/", id)));
+    assert!(other.variant_key(&id).ends_with(".png"));
+}
+
+#[rstest::rstest]
+fn signer_verify_success() {
+    let signer = RenderSigner::new(b"secret".to_vec());
+    let options = build_options();
+    let now = chrono::Utc::now();
+    let expires = now.timestamp() + 60;
+    let signature = signer.sign("media_id", &options, expires);
+
+    assert_eq!(
+        signer.verify("media_id", &options, expires, &signature, now),
+        Ok(())
+    );
+    assert!(signer
+        .signed_path("media_id", &options, expires)
+        .ends_with(&format!("&expires={}&sig={}", expires, signature)));
+}
+
+#[rstest::rstest]
+fn signer_verify_failure() {
+    let signer = RenderSigner::new(b"secret".to_vec());
+    let options = build_options();
+    let now = chrono::Utc::now();
+    let expires = now.timestamp() + 60;
+    let signature = signer.sign("media_id", &options, expires);
+    let other = RenderOptions::try_new(Some(4000), Some(4000), None, None, None).unwrap();
+
+    assert_eq!(
+        signer.verify("media_id", &other, expires, &signature, now),
+        Err(RenderError::InvalidSignature)
+    );
+    assert_eq!(
+        signer.verify("other_id", &options, expires, &signature, now),
+        Err(RenderError::InvalidSignature)
+    );
+    assert_eq!(
+        RenderSigner::new(b"other".to_vec()).verify("media_id", &options, expires, &signature, now),
+        Err(RenderError::InvalidSignature)
+    );
+    assert_eq!(
+        signer.verify("media_id", &options, expires, "not hex", now),
+        Err(RenderError::InvalidSignature)
+    );
+
+    let expired = now.timestamp() - 1;
+    let signature = signer.sign("media_id", &options, expired);
+    assert_eq!(
+        signer.verify("media_id", &options, expired, &signature, now),
+        Err(RenderError::SignatureExpired)
+    );
+}
@@ -0,0 +1,69 @@
+use crate::model::content::{ContentCategory, ContentStatus, ContentTag, CreatedBy};
+use crate::model::revision::ContentRevision;
+
+fn build_revision(
+    revision: i32,
+    title: &str,
+    fields: serde_json::Value,
+    tags: Vec<ContentTag>,
+) -> ContentRevision {
+    ContentRevision::new(
+        uuid::Uuid::now_v7(),
+        uuid::Uuid::nil(),
+        revision,
+        title.into(),
+        ContentCategory::new("id".into(), "sample".into()),
+        ContentStatus::Draft,
+        fields,
+        tags,
+        CreatedBy::new("id".into(), "name".into()),
+        chrono::Utc::now(),
+    )
+}
+
+#[rstest::rstest]
+fn diff_without_changes() {
+    let fields = serde_json::json!({"body": "Hello"});
+    let from = build_revision(1, "title", fields.clone(), vec![]);
+    let to = build_revision(2, "title", fields, vec![]);
+
+    let diff = from.diff(&to);
+
+    assert_eq!(diff.from, 1);
+    assert_eq!(diff.to, 2);
+    assert!(diff.changes.is_empty());
+}
+
+#[rstest::rstest]
+fn diff_with_changes() {
+    let from = build_revision(
+        1,
+        "title",
+        serde_json::json!({"body": "Hello", "removed": 1}),
+        vec![ContentTag::new("a".into(), "a".into())],
+    );
+    let to = build_revision(
+        2,
+        "changed",
+        serde_json::json!({"body": "World", "added": true}),
+        vec![ContentTag::new("b".into(), "b".into())],
+    );
+
+    let diff = from.diff(&to);
+
+    let paths: Vec<&str> = diff.changes.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "title",
+            "tags",
+            "fields.added",
+            "fields.body",
+            "fields.removed"
+        ]
+    );
+
+    let added = &diff.changes[2];
+    assert_eq!(added.from, None);
+    assert_eq!(added.to, Some(serde_json::json!(true)));
+}
@@ -0,0 +1,199 @@
+use std::str::FromStr;
+
+use crate::model::role::{CategoryScope, Permission, PermissionAction, PermissionResource, Role};
+
+fn build_role(is_super_administrator: bool, permissions: Vec<Permission>) -> Role {
+    Role::try_new(
+        "role".into(),
+        "editor".into(),
+        "description".into(),
+        is_super_administrator,
+        permissions,
+    )
+    .unwrap()
+}
+
+#[rstest::rstest]
+#[case::contents(PermissionResource::Contents, "Contents")]
+#[case::categories(PermissionResource::Categories, "Categories")]
+#[case::tags(PermissionResource::Tags, "Tags")]
+#[case::media(PermissionResource::Media, "Media")]
+#[case::users(PermissionResource::Users, "Users")]
+#[case::api_keys(PermissionResource::ApiKeys, "ApiKeys")]
+#[case::webhooks(PermissionResource::Webhooks, "Webhooks")]
+fn permission_resource_round_trip(#[case] resource: PermissionResource, #[case] value: &str) {
+    assert_eq!(resource.as_str(), value);
+    assert_eq!(PermissionResource::from_str(value).unwrap(), resource);
+}
+
+#[rstest::rstest]
+#[case::read(PermissionAction::Read, "Read")]
+#[case::create(PermissionAction::Create, "Create")]
+#[case::update(PermissionAction::Update, "Update")]
+#[case::delete(PermissionAction::Delete, "Delete")]
+#[case::publish(PermissionAction::Publish, "Publish")]
+fn permission_action_round_trip(#[case] action: PermissionAction, #[case] value: &str) {
+    assert_eq!(action.as_str(), value);
+    assert_eq!(PermissionAction::from_str(value).unwrap(), action);
+}
+
+#[rstest::rstest]
+fn permission_from_str_failure() {
+    assert!(PermissionResource::from_str("contents").is_err());
+    assert!(PermissionAction::from_str("get").is_err());
+}
+
+#[rstest::rstest]
+#[case::unscoped(vec![], None, true)]
+#[case::unscoped_with_category(vec![], Some("news"), true)]
+#[case::scoped_with_allowed_category(vec!["news".into()], Some("news"), true)]
+#[case::scoped_with_other_category(vec!["news".into()], Some("blog"), false)]
+#[case::scoped_without_category(vec!["news".into()], None, false)]
+fn role_allows_category(
+    #[case] category_ids: Vec<String>,
+    #[case] category_id: Option<&str>,
+    #[case] expected: bool,
+) {
+    let role = build_role(
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            category_ids,
+        )],
+    );
+
+    assert_eq!(
+        role.allows(
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            category_id
+        ),
+        expected
+    );
+    assert!(!role.allows(
+        PermissionResource::Contents,
+        PermissionAction::Delete,
+        category_id
+    ));
+    assert!(!role.allows(PermissionResource::Tags, PermissionAction::Update, None));
+}
+
+#[rstest::rstest]
+fn role_allows_everything_for_super_administrator() {
+    let role = build_role(true, vec![]);
+
+    assert!(role.allows(PermissionResource::Users, PermissionAction::Delete, None));
+    assert!(role.allows(
+        PermissionResource::Contents,
+        PermissionAction::Publish,
+        Some("news")
+    ));
+}
+
+#[rstest::rstest]
+#[case::empty_name("".into(), "description".into(), vec![])]
+#[case::too_long_name("a".repeat(51), "description".into(), vec![])]
+#[case::too_long_description("editor".into(), "a".repeat(501), vec![])]
+#[case::duplicated_permission("editor".into(), "description".into(), vec![
+    Permission::new(PermissionResource::Tags, PermissionAction::Read, vec![]),
+    Permission::new(PermissionResource::Tags, PermissionAction::Read, vec![]),
+])]
+#[case::scoped_unscopable_resource("editor".into(), "description".into(), vec![
+    Permission::new(PermissionResource::Media, PermissionAction::Read, vec!["news".into()]),
+])]
+fn role_new_failure(
+    #[case] name: String,
+    #[case] description: String,
+    #[case] permissions: Vec<Permission>,
+) {
+    let result = Role::try_new("role".into(), name, description, false, permissions);
+
+    assert!(result.is_err());
+}
+
+#[rstest::rstest]
+#[case::unscoped(vec![], CategoryScope::All)]
+#[case::scoped(vec!["news".into()], CategoryScope::Only(vec!["news".into()]))]
+fn role_category_scope(#[case] category_ids: Vec<String>, #[case] expected: CategoryScope) {
+    let role = build_role(
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Read,
+            category_ids,
+        )],
+    );
+
+    assert_eq!(
+        role.category_scope(PermissionResource::Contents, PermissionAction::Read),
+        expected
+    );
+    assert_eq!(
+        role.category_scope(PermissionResource::Contents, PermissionAction::Delete),
+        CategoryScope::Denied
+    );
+    assert_eq!(
+        build_role(true, vec![])
+            .category_scope(PermissionResource::Contents, PermissionAction::Delete),
+        CategoryScope::All
+    );
+}
+
+#[rstest::rstest]
+fn role_effective_permissions() {
+    let permissions = vec![Permission::new(
+        PermissionResource::Tags,
+        PermissionAction::Read,
+        vec![],
+    )];
+
+    assert_eq!(
+        build_role(false, permissions.clone()).effective_permissions(),
+        permissions
+    );
+    assert_eq!(
+        build_role(true, permissions).effective_permissions().len(),
+        PermissionResource::ALL.len() * PermissionAction::ALL.len()
+    );
+}
+
+#[rstest::rstest]
+#[case::same_unscoped(vec![], vec![], true)]
+#[case::narrower_scope(vec![], vec!["news".into()], true)]
+#[case::same_scope(vec!["news".into()], vec!["news".into()], true)]
+#[case::wider_scope(vec!["news".into()], vec![], false)]
+#[case::other_scope(vec!["news".into()], vec!["news".into(), "blog".into()], false)]
+fn role_covers(
+    #[case] granted: Vec<String>,
+    #[case] requested: Vec<String>,
+    #[case] expected: bool,
+) {
+    let role = build_role(
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            granted,
+        )],
+    );
+
+    assert_eq!(
+        role.covers(&Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Update,
+            requested
+        )),
+        expected
+    );
+    assert!(!role.covers(&Permission::new(
+        PermissionResource::Contents,
+        PermissionAction::Delete,
+        vec![]
+    )));
+    assert!(build_role(true, vec![]).covers(&Permission::new(
+        PermissionResource::Contents,
+        PermissionAction::Delete,
+        vec![]
+    )));
+}
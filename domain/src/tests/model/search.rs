@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use crate::model::search::{highlight, SearchLanguage};
+
+#[rstest::rstest]
+#[case::empty("", SearchLanguage::TextSearch("simple".into()))]
+#[case::english("english", SearchLanguage::TextSearch("english".into()))]
+#[case::upper(" English ", SearchLanguage::TextSearch("english".into()))]
+#[case::trigram("trigram", SearchLanguage::Trigram)]
+fn search_language_from_str_success(#[case] value: &str, #[case] expected: SearchLanguage) {
+    assert_eq!(SearchLanguage::from_str(value).unwrap(), expected);
+}
+
+#[rstest::rstest]
+#[case::quote("english'")]
+#[case::schema("pg_catalog.english")]
+fn search_language_from_str_failure(#[case] value: &str) {
+    assert!(SearchLanguage::from_str(value).is_err());
+}
+
+#[rstest::rstest]
+fn search_language_text_search_config() {
+    assert_eq!(
+        SearchLanguage::TextSearch("english".into()).text_search_config(),
+        "english"
+    );
+    assert_eq!(SearchLanguage::Trigram.text_search_config(), "simple");
+}
+
+#[rstest::rstest]
+#[case::whole("Hello World", "world", 40, "Hello <mark>World</mark>")]
+#[case::terms(
+    "Hello World",
+    "hello world",
+    40,
+    "<mark>Hello</mark> <mark>World</mark>"
+)]
+#[case::context("abcdefghij", "e", 2, "…cd<mark>e</mark>fg…")]
+#[case::japanese("今日は良い天気です", "天気", 3, "…は良い<mark>天気</mark>です")]
+fn highlight_success(
+    #[case] text: &str,
+    #[case] keyword: &str,
+    #[case] context: usize,
+    #[case] expected: &str,
+) {
+    assert_eq!(highlight(text, keyword, context).unwrap(), expected);
+}
+
+#[rstest::rstest]
+#[case::missing("Hello World", "rust")]
+#[case::blank("Hello World", " ")]
+fn highlight_none(#[case] text: &str, #[case] keyword: &str) {
+    assert_eq!(highlight(text, keyword, 40), None);
+}
@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use crate::model::role::PermissionResource;
+use crate::model::trash::{purge_before, TrashKind};
+
+#[rstest::rstest]
+#[case::content("Content", TrashKind::Content, PermissionResource::Contents)]
+#[case::category("Category", TrashKind::Category, PermissionResource::Categories)]
+#[case::tag("Tag", TrashKind::Tag, PermissionResource::Tags)]
+#[case::media("Media", TrashKind::Media, PermissionResource::Media)]
+fn parse_trash_kind(
+    #[case] value: &str,
+    #[case] kind: TrashKind,
+    #[case] resource: PermissionResource,
+) {
+    assert_eq!(TrashKind::from_str(value).unwrap(), kind);
+    assert_eq!(kind.resource(), resource);
+    assert_eq!(serde_json::to_value(kind).unwrap(), value);
+}
+
+#[test]
+fn parse_trash_kind_failure() {
+    assert!(TrashKind::from_str("contents").is_err());
+}
+
+#[rstest::rstest]
+#[case::retention(30, "2026-09-18T00:00:00Z")]
+#[case::immediately(0, "2026-10-18T00:00:00Z")]
+#[case::negative(-1, "2026-10-18T00:00:00Z")]
+fn purge_before_retention(#[case] retention_days: i64, #[case] expected: &str) {
+    let now = "2026-10-18T00:00:00Z".parse().unwrap();
+
+    assert_eq!(
+        purge_before(now, retention_days),
+        expected.parse::<chrono::DateTime<chrono::Utc>>().unwrap()
+    );
+}
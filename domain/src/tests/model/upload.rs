@@ -0,0 +1,82 @@
+use crate::model::upload::{
+    validate_checksum, UploadError, UploadPlan, UploadSession, MAX_PART_COUNT, MAX_UPLOAD_SIZE,
+    MIN_PART_SIZE, MULTIPART_THRESHOLD,
+};
+
+fn build_session(
+    expires_at: chrono::DateTime<chrono::Utc>,
+    completed_at: Option<chrono::DateTime<chrono::Utc>>,
+) -> UploadSession {
+    UploadSession {
+        id: uuid::Uuid::now_v7(),
+        object_key: "media/key.mp4".into(),
+        filename: "movie.mp4".into(),
+        mime_type: "video/mp4".into(),
+        size: 10,
+        checksum: "a".repeat(64),
+        alt_text: None,
+        multipart_upload_id: None,
+        plan: None,
+        uploaded_by_id: "user_id".into(),
+        expires_at,
+        completed_at,
+    }
+}
+
+#[rstest::rstest]
+#[case::single(1, None)]
+#[case::threshold(MULTIPART_THRESHOLD, None)]
+#[case::multipart(MULTIPART_THRESHOLD + 1, Some(UploadPlan { part_size: MIN_PART_SIZE, part_count: 2 }))]
+fn plan_for_size(#[case] size: i64, #[case] expected: Option<UploadPlan>) {
+    assert_eq!(UploadPlan::for_size(size).unwrap(), expected);
+}
+
+#[rstest::rstest]
+fn plan_for_size_grows_part_size() {
+    let plan = UploadPlan::for_size(MAX_UPLOAD_SIZE).unwrap().unwrap();
+
+    assert!(plan.part_size > MIN_PART_SIZE);
+    assert!(i64::from(plan.part_count) <= MAX_PART_COUNT);
+    assert!(plan.part_size * i64::from(plan.part_count) >= MAX_UPLOAD_SIZE);
+}
+
+#[rstest::rstest]
+#[case::empty(0)]
+#[case::too_large(MAX_UPLOAD_SIZE + 1)]
+fn plan_for_size_failure(#[case] size: i64) {
+    assert!(UploadPlan::for_size(size).is_err());
+}
+
+#[rstest::rstest]
+fn plan_part_length() {
+    let size = MIN_PART_SIZE * 2 + 10;
+    let plan = UploadPlan::for_size(size).unwrap().unwrap();
+
+    assert_eq!(plan.part_count, 3);
+    assert_eq!(plan.part_length(size, 1), MIN_PART_SIZE);
+    assert_eq!(plan.part_length(size, 3), 10);
+}
+
+#[rstest::rstest]
+#[case::valid(chrono::Utc::now() + chrono::Duration::hours(1), None, "user_id", Ok(()))]
+#[case::other_user(chrono::Utc::now() + chrono::Duration::hours(1), None, "other", Err(UploadError::NotFound))]
+#[case::completed(chrono::Utc::now() + chrono::Duration::hours(1), Some(chrono::Utc::now()), "user_id", Err(UploadError::AlreadyCompleted))]
+#[case::expired(chrono::Utc::now() - chrono::Duration::hours(1), None, "user_id", Err(UploadError::Expired))]
+fn session_verify(
+    #[case] expires_at: chrono::DateTime<chrono::Utc>,
+    #[case] completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[case] uploaded_by_id: &str,
+    #[case] expected: Result<(), UploadError>,
+) {
+    let session = build_session(expires_at, completed_at);
+
+    assert_eq!(session.verify(uploaded_by_id, chrono::Utc::now()), expected);
+}
+
+#[rstest::rstest]
+#[case::valid(&"0f".repeat(32), true)]
+#[case::short("abc", false)]
+#[case::not_hex(&"zz".repeat(32), false)]
+fn validate_checksum_cases(#[case] checksum: &str, #[case] expected: bool) {
+    assert_eq!(validate_checksum(checksum).is_ok(), expected);
+}
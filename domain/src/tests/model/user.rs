@@ -1,4 +1,5 @@
-use crate::model::user::{Admin, Member, User};
+use crate::model::role::{Permission, PermissionAction, PermissionResource, Role};
+use crate::model::user::User;
 
 use shared::logger::logger_init_info;
 
@@ -6,16 +7,42 @@ fn before_each() {
     logger_init_info();
 }
 
+fn build_role() -> Role {
+    Role::try_new(
+        "role".into(),
+        "member".into(),
+        "description".into(),
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Read,
+            vec![],
+        )],
+    )
+    .unwrap()
+}
+
 #[tracing::instrument]
 #[rstest::rstest]
 #[case::all_fill("a".to_string().repeat(50), "a".to_string().repeat(50), "https://example.com".to_string())]
 #[case::without("a".to_string().repeat(50), "a".to_string().repeat(50), "https://example.com".into())]
 fn user_new_success(#[case] id: String, #[case] name: String, #[case] icon_url: String) {
     before_each();
-    let result = User::<Admin>::try_new(id, name, icon_url);
-    assert_eq!(result.is_ok(), true);
+    let result = User::try_new(id, name, icon_url, build_role());
+    assert!(result.is_ok());
 
     let user = result.unwrap();
 
-    tracing::info!("{:?}", user.permissions().get("contents"));
+    assert!(user.can(PermissionResource::Contents, PermissionAction::Read, None));
+    assert!(!user.can(PermissionResource::Contents, PermissionAction::Delete, None));
+}
+
+#[rstest::rstest]
+#[case::empty_name("".into(), "https://example.com".into())]
+#[case::too_long_name("a".repeat(51), "https://example.com".into())]
+#[case::invalid_icon_url("user1".into(), "example".into())]
+fn user_new_failure(#[case] name: String, #[case] icon_url: String) {
+    let result = User::try_new("id".into(), name, icon_url, build_role());
+
+    assert!(result.is_err());
 }
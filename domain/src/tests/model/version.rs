@@ -0,0 +1,33 @@
+use crate::model::version::{etag, parse_if_match, PreconditionError, VersionConflict};
+
+#[test]
+fn etag_quotes_version() {
+    assert_eq!(etag(3), "\"3\"");
+}
+
+#[rstest::rstest]
+#[case::etag("\"3\"", Ok(Some(3)))]
+#[case::padded(" \"3\" ", Ok(Some(3)))]
+#[case::any("*", Ok(None))]
+#[case::weak("W/\"3\"", Err(PreconditionError::Invalid))]
+#[case::unquoted("3", Err(PreconditionError::Invalid))]
+#[case::list("\"3\", \"4\"", Err(PreconditionError::Invalid))]
+#[case::not_a_version("\"abc\"", Err(PreconditionError::Invalid))]
+fn parse_if_match_value(
+    #[case] value: &str,
+    #[case] expected: Result<Option<i32>, PreconditionError>,
+) {
+    assert_eq!(parse_if_match(value), expected);
+}
+
+#[test]
+fn version_conflict_carries_current() {
+    let current = serde_json::json!({"id": "id", "version": 4});
+    let conflict = VersionConflict::try_new(3, &current).unwrap();
+
+    assert_eq!(conflict.to_string(), "Version 3 is outdated");
+    assert_eq!(
+        serde_json::to_value(conflict).unwrap(),
+        serde_json::json!({"expected_version": 3, "current": {"id": "id", "version": 4}})
+    );
+}
@@ -0,0 +1,181 @@
+use std::str::FromStr;
+
+use crate::model::content::ContentStatus;
+use crate::model::webhook::{
+    sign, validate_secret, validate_url, WebhookDelivery, WebhookDeliveryStatus, WebhookError,
+    WebhookEvent, WebhookPayload, WebhookRequest, WebhookResponse, WEBHOOK_DELIVERY_HEADER,
+    WEBHOOK_EVENT_HEADER, WEBHOOK_IDEMPOTENCY_KEY_HEADER, WEBHOOK_SIGNATURE_HEADER,
+};
+
+fn delivery(attempts: i32) -> WebhookDelivery {
+    let payload = WebhookPayload::new(
+        WebhookEvent::CategoryDeleted,
+        serde_json::json!({"id": "id"}),
+        chrono::Utc::now(),
+    );
+
+    WebhookDelivery::new(
+        uuid::Uuid::now_v7(),
+        uuid::Uuid::now_v7(),
+        payload.event,
+        payload,
+        WebhookDeliveryStatus::Pending,
+        attempts,
+        Some(chrono::Utc::now()),
+        None,
+        None,
+        None,
+        chrono::Utc::now(),
+        chrono::Utc::now(),
+    )
+}
+
+#[rstest::rstest]
+#[case::content_published(WebhookEvent::ContentPublished, "content.published")]
+#[case::category_deleted(WebhookEvent::CategoryDeleted, "category.deleted")]
+#[case::media_updated(WebhookEvent::MediaUpdated, "media.updated")]
+#[case::tag_restored(WebhookEvent::TagRestored, "tag.restored")]
+fn event_round_trip(#[case] event: WebhookEvent, #[case] expected: &str) {
+    assert_eq!(event.as_str(), expected);
+    assert_eq!(WebhookEvent::from_str(expected).unwrap(), event);
+    assert_eq!(
+        serde_json::to_value(event).unwrap(),
+        serde_json::json!(expected)
+    );
+}
+
+#[rstest::rstest]
+#[case::publish(
+    ContentStatus::Draft,
+    ContentStatus::Published,
+    WebhookEvent::ContentPublished
+)]
+#[case::reserved_publish(
+    ContentStatus::Reserved,
+    ContentStatus::Published,
+    WebhookEvent::ContentPublished
+)]
+#[case::unpublish(
+    ContentStatus::Published,
+    ContentStatus::Unpublished,
+    WebhookEvent::ContentUnpublished
+)]
+#[case::reserve(
+    ContentStatus::Draft,
+    ContentStatus::Reserved,
+    WebhookEvent::ContentUpdated
+)]
+fn status_change(
+    #[case] from: ContentStatus,
+    #[case] to: ContentStatus,
+    #[case] expected: WebhookEvent,
+) {
+    assert_eq!(WebhookEvent::status_change(&from, &to), expected);
+}
+
+#[rstest::rstest]
+#[case::https("https://example.com/hook", Ok(()))]
+#[case::http_with_port("http://example.com:8080/hook", Ok(()))]
+#[case::public_address("http://93.184.216.34/hook", Ok(()))]
+#[case::other_scheme("ftp://example.com/hook", Err(WebhookError::InvalidUrl))]
+#[case::relative("/hook", Err(WebhookError::InvalidUrl))]
+#[case::too_long(&format!("https://example.com/{}", "a".repeat(2048)), Err(WebhookError::InvalidUrl))]
+#[case::localhost("http://localhost:8080/hook", Err(WebhookError::PrivateUrl))]
+#[case::localhost_subdomain("http://api.localhost/hook", Err(WebhookError::PrivateUrl))]
+#[case::loopback("http://127.0.0.1/hook", Err(WebhookError::PrivateUrl))]
+#[case::link_local("http://169.254.169.254/latest", Err(WebhookError::PrivateUrl))]
+#[case::private_10("http://10.0.0.1/hook", Err(WebhookError::PrivateUrl))]
+#[case::private_172("http://172.16.0.1/hook", Err(WebhookError::PrivateUrl))]
+#[case::private_192("http://192.168.1.1/hook", Err(WebhookError::PrivateUrl))]
+#[case::unspecified("http://0.0.0.0/hook", Err(WebhookError::PrivateUrl))]
+#[case::ipv6_loopback("http://[::1]/hook", Err(WebhookError::PrivateUrl))]
+#[case::ipv6_unique_local("http://[fd00::1]/hook", Err(WebhookError::PrivateUrl))]
+#[case::ipv6_link_local("http://[fe80::1]/hook", Err(WebhookError::PrivateUrl))]
+#[case::ipv4_mapped("http://[::ffff:127.0.0.1]/hook", Err(WebhookError::PrivateUrl))]
+fn validate_url_cases(#[case] url: &str, #[case] expected: Result<(), WebhookError>) {
+    assert_eq!(validate_url(url), expected);
+}
+
+#[rstest::rstest]
+#[case::short("a".repeat(15), false)]
+#[case::min("a".repeat(16), true)]
+#[case::max("a".repeat(255), true)]
+#[case::long("a".repeat(256), false)]
+fn validate_secret_cases(#[case] secret: String, #[case] expected: bool) {
+    assert_eq!(validate_secret(&secret).is_ok(), expected);
+}
+
+#[test]
+fn sign_success() {
+    let signature = sign("secret", 1700000000, "{}");
+
+    assert_eq!(
+        signature,
+        "t=1700000000,v1=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+    );
+    assert_ne!(signature, sign("other", 1700000000, "{}"));
+    assert_ne!(signature, sign("secret", 1700000001, "{}"));
+}
+
+#[test]
+fn signed_request_success() {
+    let delivery = delivery(0);
+    let now = chrono::Utc::now();
+    let request =
+        WebhookRequest::signed("https://example.com".into(), "secret", &delivery, now).unwrap();
+
+    let body: WebhookPayload = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(body, delivery.payload);
+    assert!(request
+        .headers
+        .contains(&(WEBHOOK_EVENT_HEADER.into(), "category.deleted".into())));
+    assert!(request
+        .headers
+        .contains(&(WEBHOOK_DELIVERY_HEADER.into(), delivery.id.to_string())));
+    assert!(request.headers.contains(&(
+        WEBHOOK_IDEMPOTENCY_KEY_HEADER.into(),
+        delivery.payload.id.to_string()
+    )));
+    assert!(request.headers.contains(&(
+        WEBHOOK_SIGNATURE_HEADER.into(),
+        sign("secret", now.timestamp(), &request.body)
+    )));
+}
+
+#[rstest::rstest]
+#[case::first(0, WebhookDeliveryStatus::Pending, Some(30))]
+#[case::third(2, WebhookDeliveryStatus::Pending, Some(120))]
+#[case::fifth(4, WebhookDeliveryStatus::Pending, Some(480))]
+#[case::last(5, WebhookDeliveryStatus::Failed, None)]
+fn attempt_failure_backoff(
+    #[case] attempts: i32,
+    #[case] status: WebhookDeliveryStatus,
+    #[case] delay: Option<i64>,
+) {
+    let now = chrono::Utc::now();
+    let attempt = delivery(attempts).attempt(false, now);
+
+    assert_eq!(attempt.status, status);
+    assert_eq!(attempt.attempts, attempts + 1);
+    assert_eq!(
+        attempt.next_attempt_at,
+        delay.map(|x| now + chrono::Duration::seconds(x))
+    );
+}
+
+#[test]
+fn attempt_success() {
+    let attempt = delivery(3).attempt(true, chrono::Utc::now());
+
+    assert_eq!(attempt.status, WebhookDeliveryStatus::Succeeded);
+    assert_eq!(attempt.attempts, 4);
+    assert!(attempt.next_attempt_at.is_none());
+}
+
+#[test]
+fn response_logged_body_is_truncated() {
+    let response = WebhookResponse::new(500, "あ".repeat(3000));
+
+    assert!(!response.is_success());
+    assert_eq!(response.logged_body().chars().count(), 2000);
+}
@@ -29,9 +29,10 @@ DROP TABLE IF EXISTS role_authorities;
 ALTER TABLE IF EXISTS users DROP CONSTRAINT users_role_id_fkey;
 DROP INDEX IF EXISTS idx_users_deleted_at;
 DROP TABLE IF EXISTS users;
+DROP TYPE roles;
 
 -- role table
-DROP TRIGGER IF EXISTS role ON role_updated_at_trigger;
+DROP TRIGGER IF EXISTS role_updated_at_trigger ON role;
 DROP TABLE IF EXISTS role;
 
 -- authority table
@@ -6,6 +6,18 @@ CREATE OR REPLACE FUNCTION set_updated_at() RETURNS trigger AS $$
   END;
 $$ LANGUAGE plpgsql;
 
+-- role table
+CREATE TABLE IF NOT EXISTS role (
+  id UUID PRIMARY KEY NOT NULL,
+  name VARCHAR(50) NOT NULL,
+  description VARCHAR(500) NOT NULL,
+  is_super_administrator BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+);
+CREATE TRIGGER role_updated_at_trigger
+  BEFORE UPDATE ON role FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
 -- users table
 CREATE TYPE roles AS ENUM('Admin', 'Member');
 CREATE TABLE IF NOT EXISTS users (
@@ -24,8 +36,8 @@ CREATE TRIGGER users_updated_at_trigger
   BEFORE UPDATE ON users FOR EACH ROW EXECUTE FUNCTION set_updated_at();
 CREATE INDEX IF NOT EXISTS idx_users_deleted_at ON users(deleted_at);
 
--- categories table
-CREATE TABLE IF NOT EXISTS categories (
+-- category table
+CREATE TABLE IF NOT EXISTS category (
   id UUID PRIMARY KEY NOT NULL,
   name VARCHAR(50) NOT NULL,
   api_identifier VARCHAR(64) NOT NULL UNIQUE,
@@ -0,0 +1,2 @@
+-- category table
+ALTER TABLE IF EXISTS category DROP COLUMN IF EXISTS fields;
@@ -0,0 +1,2 @@
+-- category table
+ALTER TABLE category ADD COLUMN IF NOT EXISTS fields JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,4 @@
+-- content_revisions table
+ALTER TABLE IF EXISTS content_revisions DROP CONSTRAINT content_revisions_content_id_fkey;
+ALTER TABLE IF EXISTS content_revisions DROP CONSTRAINT content_revisions_created_by_fkey;
+DROP TABLE IF EXISTS content_revisions;
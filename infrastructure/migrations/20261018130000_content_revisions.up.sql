@@ -0,0 +1,21 @@
+-- content_revisions table
+CREATE TABLE IF NOT EXISTS content_revisions (
+  id UUID PRIMARY KEY NOT NULL,
+  content_id UUID NOT NULL,
+  revision INTEGER NOT NULL,
+  title VARCHAR(50) NOT NULL,
+  category_id UUID NOT NULL,
+  category_name VARCHAR(50) NOT NULL,
+  fields JSONB NOT NULL,
+  tags JSONB NOT NULL,
+  status content_status NOT NULL,
+  created_by UUID NOT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  FOREIGN KEY (content_id) REFERENCES contents(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (created_by) REFERENCES users(id)
+    ON DELETE RESTRICT
+    ON UPDATE CASCADE,
+  UNIQUE (content_id, revision)
+);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS contents_published_unpublish_at_idx;
+DROP INDEX IF EXISTS contents_reserved_published_at_idx;
+ALTER TABLE IF EXISTS contents DROP CONSTRAINT contents_schedule_check;
+ALTER TABLE contents DROP COLUMN IF EXISTS unpublish_at;
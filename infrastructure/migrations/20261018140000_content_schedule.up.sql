@@ -0,0 +1,9 @@
+-- scheduled unpublish time
+ALTER TABLE contents ADD COLUMN unpublish_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL;
+ALTER TABLE contents ADD CONSTRAINT contents_schedule_check
+  CHECK (published_at IS NULL OR unpublish_at IS NULL OR published_at < unpublish_at);
+
+CREATE INDEX IF NOT EXISTS contents_reserved_published_at_idx
+  ON contents (published_at) WHERE status = 'Reserved';
+CREATE INDEX IF NOT EXISTS contents_published_unpublish_at_idx
+  ON contents (unpublish_at) WHERE status = 'Published';
@@ -0,0 +1,7 @@
+-- api_key_categories table
+ALTER TABLE IF EXISTS api_key_categories DROP CONSTRAINT api_key_categories_api_key_id_fkey;
+ALTER TABLE IF EXISTS api_key_categories DROP CONSTRAINT api_key_categories_category_id_fkey;
+DROP TABLE IF EXISTS api_key_categories;
+
+-- api_keys table
+DROP TABLE IF EXISTS api_keys;
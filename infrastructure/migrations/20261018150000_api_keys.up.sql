@@ -0,0 +1,27 @@
+-- api_keys table
+CREATE TABLE IF NOT EXISTS api_keys (
+  id UUID PRIMARY KEY NOT NULL,
+  name VARCHAR(100) NOT NULL,
+  prefix VARCHAR(16) NOT NULL,
+  secret_hash CHAR(64) NOT NULL UNIQUE,
+  scopes TEXT[] NOT NULL CHECK (
+    cardinality(scopes) > 0
+    AND scopes <@ ARRAY['ReadPublished', 'ReadDrafts']::TEXT[]
+  ),
+  expires_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL,
+  revoked_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+);
+
+-- api_key_categories table
+CREATE TABLE IF NOT EXISTS api_key_categories (
+  api_key_id UUID NOT NULL,
+  category_id UUID NOT NULL,
+  PRIMARY KEY (api_key_id, category_id),
+  FOREIGN KEY (api_key_id) REFERENCES api_keys(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (category_id) REFERENCES category(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
@@ -0,0 +1,14 @@
+-- sessions table
+DROP TRIGGER IF EXISTS sessions_updated_at_trigger ON sessions;
+DROP TABLE IF EXISTS sessions;
+
+-- email_verification_tokens table
+DROP TABLE IF EXISTS email_verification_tokens;
+
+-- default role for signed up users
+DELETE FROM role WHERE name = 'member' AND NOT EXISTS (SELECT 1 FROM users WHERE users.role_id = role.id);
+
+-- users credentials
+ALTER TABLE users DROP COLUMN IF EXISTS email_verified_at;
+ALTER TABLE users DROP COLUMN IF EXISTS password_hash;
+ALTER TABLE users DROP COLUMN IF EXISTS email;
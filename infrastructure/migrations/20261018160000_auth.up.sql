@@ -0,0 +1,41 @@
+-- users credentials
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email VARCHAR(255) DEFAULT NULL UNIQUE;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS password_hash VARCHAR(255) DEFAULT NULL;
+ALTER TABLE users ADD COLUMN IF NOT EXISTS email_verified_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL;
+
+-- default role for signed up users
+INSERT INTO role (id, name, description)
+SELECT gen_random_uuid(), 'member', 'Default role for signed up users'
+WHERE NOT EXISTS (SELECT 1 FROM role WHERE name = 'member');
+
+-- email_verification_tokens table
+CREATE TABLE IF NOT EXISTS email_verification_tokens (
+  id UUID PRIMARY KEY NOT NULL,
+  user_id UUID NOT NULL,
+  token_hash CHAR(64) NOT NULL UNIQUE,
+  expires_at TIMESTAMP(3) WITH TIME ZONE NOT NULL,
+  used_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  FOREIGN KEY (user_id) REFERENCES users(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+
+-- sessions table
+CREATE TABLE IF NOT EXISTS sessions (
+  id UUID PRIMARY KEY NOT NULL,
+  user_id UUID NOT NULL,
+  access_token_hash CHAR(64) NOT NULL UNIQUE,
+  access_expires_at TIMESTAMP(3) WITH TIME ZONE NOT NULL,
+  refresh_token_hash CHAR(64) NOT NULL UNIQUE,
+  refresh_expires_at TIMESTAMP(3) WITH TIME ZONE NOT NULL,
+  revoked_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  FOREIGN KEY (user_id) REFERENCES users(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+CREATE TRIGGER sessions_updated_at_trigger
+  BEFORE UPDATE ON sessions FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE INDEX IF NOT EXISTS idx_sessions_user_id ON sessions(user_id);
@@ -0,0 +1,6 @@
+-- oidc_identities table
+DROP TRIGGER IF EXISTS oidc_identities_updated_at_trigger ON oidc_identities;
+DROP TABLE IF EXISTS oidc_identities;
+
+-- oidc_login_states table
+DROP TABLE IF EXISTS oidc_login_states;
@@ -0,0 +1,25 @@
+-- oidc_login_states table
+CREATE TABLE IF NOT EXISTS oidc_login_states (
+  state VARCHAR(64) PRIMARY KEY NOT NULL,
+  nonce VARCHAR(64) NOT NULL,
+  code_verifier VARCHAR(128) NOT NULL,
+  expires_at TIMESTAMP(3) WITH TIME ZONE NOT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+);
+CREATE INDEX IF NOT EXISTS idx_oidc_login_states_expires_at ON oidc_login_states(expires_at);
+
+-- oidc_identities table
+CREATE TABLE IF NOT EXISTS oidc_identities (
+  issuer VARCHAR(500) NOT NULL,
+  subject VARCHAR(255) NOT NULL,
+  user_id UUID NOT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  PRIMARY KEY (issuer, subject),
+  FOREIGN KEY (user_id) REFERENCES users(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+CREATE TRIGGER oidc_identities_updated_at_trigger
+  BEFORE UPDATE ON oidc_identities FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE INDEX IF NOT EXISTS idx_oidc_identities_user_id ON oidc_identities(user_id);
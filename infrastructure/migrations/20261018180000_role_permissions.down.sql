@@ -0,0 +1,9 @@
+-- role_permission_categories table
+DROP TABLE IF EXISTS role_permission_categories;
+
+-- role_permissions table
+DROP TABLE IF EXISTS role_permissions;
+
+CREATE TYPE roles AS ENUM('Admin', 'Member');
+
+DROP INDEX IF EXISTS idx_role_name;
@@ -0,0 +1,39 @@
+-- role names are referenced by signup and OIDC role mapping
+CREATE UNIQUE INDEX IF NOT EXISTS idx_role_name ON role(name);
+
+-- unused since roles are stored in the role table
+DROP TYPE IF EXISTS roles;
+
+-- role_permissions table
+CREATE TABLE IF NOT EXISTS role_permissions (
+  id UUID PRIMARY KEY NOT NULL,
+  role_id UUID NOT NULL,
+  resource VARCHAR(32) NOT NULL,
+  action VARCHAR(32) NOT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  UNIQUE (role_id, resource, action),
+  FOREIGN KEY (role_id) REFERENCES role(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+
+-- role_permission_categories table
+CREATE TABLE IF NOT EXISTS role_permission_categories (
+  role_permission_id UUID NOT NULL,
+  category_id UUID NOT NULL,
+  PRIMARY KEY (role_permission_id, category_id),
+  FOREIGN KEY (role_permission_id) REFERENCES role_permissions(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (category_id) REFERENCES category(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_role_permission_categories_category_id ON role_permission_categories(category_id);
+
+-- default permissions for signed up users
+INSERT INTO role_permissions (id, role_id, resource, action)
+SELECT gen_random_uuid(), role.id, 'Contents', 'Read'
+FROM role
+WHERE role.name = 'member'
+ON CONFLICT DO NOTHING;
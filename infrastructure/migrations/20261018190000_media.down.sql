@@ -0,0 +1,4 @@
+-- media table
+DROP TRIGGER IF EXISTS media_updated_at_trigger ON media;
+ALTER TABLE IF EXISTS media DROP CONSTRAINT media_uploaded_by_fkey;
+DROP TABLE IF EXISTS media;
@@ -0,0 +1,21 @@
+-- media table
+CREATE TABLE IF NOT EXISTS media (
+  id UUID PRIMARY KEY NOT NULL,
+  object_key VARCHAR(1024) NOT NULL UNIQUE,
+  filename VARCHAR(255) NOT NULL,
+  mime_type VARCHAR(255) NOT NULL,
+  size BIGINT NOT NULL CHECK (size >= 0),
+  checksum CHAR(64) NOT NULL,
+  width INTEGER DEFAULT NULL,
+  height INTEGER DEFAULT NULL,
+  alt_text VARCHAR(1000) DEFAULT NULL,
+  uploaded_by UUID NOT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  FOREIGN KEY (uploaded_by) REFERENCES users(id)
+    ON DELETE RESTRICT
+    ON UPDATE CASCADE
+);
+CREATE TRIGGER media_updated_at_trigger
+  BEFORE UPDATE ON media FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE INDEX IF NOT EXISTS idx_media_mime_type ON media(mime_type);
@@ -0,0 +1,3 @@
+-- upload_sessions table
+ALTER TABLE IF EXISTS upload_sessions DROP CONSTRAINT upload_sessions_uploaded_by_fkey;
+DROP TABLE IF EXISTS upload_sessions;
@@ -0,0 +1,22 @@
+-- upload_sessions table
+CREATE TABLE IF NOT EXISTS upload_sessions (
+  id UUID PRIMARY KEY NOT NULL,
+  object_key VARCHAR(1024) NOT NULL UNIQUE,
+  filename VARCHAR(255) NOT NULL,
+  mime_type VARCHAR(255) NOT NULL,
+  size BIGINT NOT NULL CHECK (size > 0),
+  checksum CHAR(64) NOT NULL,
+  alt_text VARCHAR(1000) DEFAULT NULL,
+  multipart_upload_id VARCHAR(1024) DEFAULT NULL,
+  part_size BIGINT DEFAULT NULL,
+  part_count INTEGER DEFAULT NULL,
+  uploaded_by UUID NOT NULL,
+  expires_at TIMESTAMP(3) WITH TIME ZONE NOT NULL,
+  completed_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  CHECK ((multipart_upload_id IS NULL) = (part_size IS NULL AND part_count IS NULL)),
+  FOREIGN KEY (uploaded_by) REFERENCES users(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_upload_sessions_expires_at ON upload_sessions(expires_at);
@@ -0,0 +1,4 @@
+-- content_media table
+ALTER TABLE IF EXISTS content_media DROP CONSTRAINT content_media_content_id_fkey;
+ALTER TABLE IF EXISTS content_media DROP CONSTRAINT content_media_media_id_fkey;
+DROP TABLE IF EXISTS content_media;
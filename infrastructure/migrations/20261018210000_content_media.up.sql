@@ -0,0 +1,30 @@
+-- content_media table
+CREATE TABLE IF NOT EXISTS content_media (
+  content_id UUID NOT NULL,
+  field_key VARCHAR(64) NOT NULL,
+  media_id UUID NOT NULL,
+  PRIMARY KEY (content_id, field_key),
+  FOREIGN KEY (content_id) REFERENCES contents(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (media_id) REFERENCES media(id)
+    ON DELETE RESTRICT
+    ON UPDATE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_content_media_media_id ON content_media(media_id);
+INSERT INTO content_media (content_id, field_key, media_id)
+SELECT
+  contents.id,
+  field ->> 'key',
+  media.id
+FROM
+  contents
+JOIN
+  category ON category.id = contents.category_id
+CROSS JOIN
+  jsonb_array_elements(category.fields) AS field
+JOIN
+  media ON media.id::TEXT = contents.fields ->> (field ->> 'key')
+WHERE
+  field ->> 'field_type' = 'Media'
+ON CONFLICT DO NOTHING;
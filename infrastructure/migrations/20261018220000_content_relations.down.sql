@@ -0,0 +1,4 @@
+-- content_relations table
+ALTER TABLE IF EXISTS content_relations DROP CONSTRAINT content_relations_content_id_fkey;
+ALTER TABLE IF EXISTS content_relations DROP CONSTRAINT content_relations_target_id_fkey;
+DROP TABLE IF EXISTS content_relations;
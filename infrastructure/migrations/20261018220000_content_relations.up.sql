@@ -0,0 +1,32 @@
+-- content_relations table
+CREATE TABLE IF NOT EXISTS content_relations (
+  content_id UUID NOT NULL,
+  field_key VARCHAR(64) NOT NULL,
+  position INTEGER NOT NULL CHECK (position >= 0),
+  target_id UUID NOT NULL,
+  PRIMARY KEY (content_id, field_key, position),
+  FOREIGN KEY (content_id) REFERENCES contents(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (target_id) REFERENCES contents(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_content_relations_target_id ON content_relations(target_id);
+INSERT INTO content_relations (content_id, field_key, position, target_id)
+SELECT
+  contents.id,
+  field ->> 'key',
+  0,
+  target.id
+FROM
+  contents
+JOIN
+  category ON category.id = contents.category_id
+CROSS JOIN
+  jsonb_array_elements(category.fields) AS field
+JOIN
+  contents AS target ON target.id::TEXT = contents.fields ->> (field ->> 'key')
+WHERE
+  field ->> 'field_type' = 'Content'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,8 @@
+-- contents search columns
+DROP INDEX IF EXISTS idx_contents_search_text;
+DROP INDEX IF EXISTS idx_contents_search_vector;
+ALTER TABLE contents
+  DROP COLUMN IF EXISTS search_vector,
+  DROP COLUMN IF EXISTS search_text,
+  DROP COLUMN IF EXISTS search_config;
+DROP FUNCTION IF EXISTS content_search_text(TEXT, JSONB);
@@ -0,0 +1,20 @@
+-- contents search columns
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE OR REPLACE FUNCTION content_search_text(title TEXT, fields JSONB) RETURNS TEXT AS $$
+  SELECT
+    concat_ws(' ', title, string_agg(value #>> '{}', ' '))
+  FROM
+    jsonb_path_query(fields, 'strict $.**') AS value
+  WHERE
+    jsonb_typeof(value) = 'string'
+    AND value #>> '{}' !~* '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$'
+$$ LANGUAGE SQL IMMUTABLE PARALLEL SAFE;
+
+ALTER TABLE contents
+  ADD COLUMN search_config REGCONFIG NOT NULL DEFAULT 'simple',
+  ADD COLUMN search_text TEXT GENERATED ALWAYS AS (content_search_text(title, fields)) STORED,
+  ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (to_tsvector(search_config, content_search_text(title, fields))) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_contents_search_vector ON contents USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS idx_contents_search_text ON contents USING GIN (search_text gin_trgm_ops);
@@ -0,0 +1,5 @@
+-- content_localizations table
+DROP TRIGGER IF EXISTS content_localizations_updated_at_trigger ON content_localizations;
+ALTER TABLE IF EXISTS content_localizations DROP CONSTRAINT content_localizations_content_id_fkey;
+ALTER TABLE IF EXISTS content_localizations DROP CONSTRAINT content_localizations_updated_by_fkey;
+DROP TABLE IF EXISTS content_localizations;
@@ -0,0 +1,20 @@
+-- content_localizations table
+CREATE TABLE IF NOT EXISTS content_localizations (
+  content_id UUID NOT NULL,
+  locale VARCHAR(35) NOT NULL,
+  fields JSONB NOT NULL,
+  status content_status NOT NULL DEFAULT 'Draft',
+  published_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL,
+  updated_by UUID NOT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  PRIMARY KEY (content_id, locale),
+  FOREIGN KEY (content_id) REFERENCES contents(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (updated_by) REFERENCES users(id)
+    ON DELETE RESTRICT
+    ON UPDATE CASCADE
+);
+CREATE TRIGGER content_localizations_updated_at_trigger
+  BEFORE UPDATE ON content_localizations FOR EACH ROW EXECUTE FUNCTION set_updated_at();
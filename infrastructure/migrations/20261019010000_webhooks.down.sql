@@ -0,0 +1,15 @@
+-- webhook_deliveries table
+DROP TRIGGER IF EXISTS webhook_deliveries_updated_at_trigger ON webhook_deliveries;
+DROP INDEX IF EXISTS idx_webhook_deliveries_webhook_id;
+DROP INDEX IF EXISTS idx_webhook_deliveries_due;
+ALTER TABLE IF EXISTS webhook_deliveries DROP CONSTRAINT webhook_deliveries_webhook_id_fkey;
+DROP TABLE IF EXISTS webhook_deliveries;
+
+-- webhook_categories table
+ALTER TABLE IF EXISTS webhook_categories DROP CONSTRAINT webhook_categories_webhook_id_fkey;
+ALTER TABLE IF EXISTS webhook_categories DROP CONSTRAINT webhook_categories_category_id_fkey;
+DROP TABLE IF EXISTS webhook_categories;
+
+-- webhooks table
+DROP TRIGGER IF EXISTS webhooks_updated_at_trigger ON webhooks;
+DROP TABLE IF EXISTS webhooks;
@@ -0,0 +1,48 @@
+-- webhooks table
+CREATE TABLE IF NOT EXISTS webhooks (
+  id UUID PRIMARY KEY NOT NULL,
+  url VARCHAR(2048) NOT NULL,
+  events TEXT[] NOT NULL CHECK (cardinality(events) > 0),
+  secret VARCHAR(255) NOT NULL,
+  active BOOLEAN NOT NULL DEFAULT TRUE,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+);
+CREATE TRIGGER webhooks_updated_at_trigger
+  BEFORE UPDATE ON webhooks FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+-- webhook_categories table
+CREATE TABLE IF NOT EXISTS webhook_categories (
+  webhook_id UUID NOT NULL,
+  category_id UUID NOT NULL,
+  PRIMARY KEY (webhook_id, category_id),
+  FOREIGN KEY (webhook_id) REFERENCES webhooks(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE,
+  FOREIGN KEY (category_id) REFERENCES category(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+
+-- webhook_deliveries table
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+  id UUID PRIMARY KEY NOT NULL,
+  webhook_id UUID NOT NULL,
+  event VARCHAR(64) NOT NULL,
+  payload JSONB NOT NULL,
+  status VARCHAR(16) NOT NULL DEFAULT 'Pending' CHECK (status IN ('Pending', 'Succeeded', 'Failed')),
+  attempts INTEGER NOT NULL DEFAULT 0,
+  next_attempt_at TIMESTAMP(3) WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP(3),
+  response_status INTEGER DEFAULT NULL,
+  response_body TEXT DEFAULT NULL,
+  error TEXT DEFAULT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  updated_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  FOREIGN KEY (webhook_id) REFERENCES webhooks(id)
+    ON DELETE CASCADE
+    ON UPDATE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due ON webhook_deliveries(next_attempt_at) WHERE status = 'Pending';
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_webhook_id ON webhook_deliveries(webhook_id, id);
+CREATE TRIGGER webhook_deliveries_updated_at_trigger
+  BEFORE UPDATE ON webhook_deliveries FOR EACH ROW EXECUTE FUNCTION set_updated_at();
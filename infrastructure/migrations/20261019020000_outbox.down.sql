@@ -0,0 +1,7 @@
+-- webhook_deliveries table
+DROP INDEX IF EXISTS idx_webhook_deliveries_idempotency_key;
+ALTER TABLE IF EXISTS webhook_deliveries DROP COLUMN IF EXISTS idempotency_key;
+
+-- outbox_events table
+DROP INDEX IF EXISTS idx_outbox_events_next_attempt_at;
+DROP TABLE IF EXISTS outbox_events;
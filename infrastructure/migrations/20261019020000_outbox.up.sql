@@ -0,0 +1,17 @@
+-- outbox_events table
+CREATE TABLE IF NOT EXISTS outbox_events (
+  id UUID PRIMARY KEY NOT NULL,
+  event VARCHAR(64) NOT NULL,
+  -- not a foreign key, category.deleted events outlive their category
+  category_id UUID DEFAULT NULL,
+  payload JSONB NOT NULL,
+  attempts INTEGER NOT NULL DEFAULT 0,
+  next_attempt_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3),
+  last_error TEXT DEFAULT NULL,
+  created_at TIMESTAMP(3) WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+);
+CREATE INDEX IF NOT EXISTS idx_outbox_events_next_attempt_at ON outbox_events(next_attempt_at, id);
+
+-- webhook_deliveries table
+ALTER TABLE webhook_deliveries ADD COLUMN IF NOT EXISTS idempotency_key UUID DEFAULT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_webhook_deliveries_idempotency_key ON webhook_deliveries(webhook_id, idempotency_key);
@@ -0,0 +1,8 @@
+-- tags table
+ALTER TABLE IF EXISTS tags DROP COLUMN IF EXISTS version;
+
+-- category table
+ALTER TABLE IF EXISTS category DROP COLUMN IF EXISTS version;
+
+-- contents table
+ALTER TABLE IF EXISTS contents DROP COLUMN IF EXISTS version;
@@ -0,0 +1,13 @@
+-- media table
+ALTER TABLE IF EXISTS media DROP COLUMN IF EXISTS deleted_at;
+
+-- tags table
+ALTER TABLE IF EXISTS tags DROP COLUMN IF EXISTS deleted_at;
+
+-- category table
+DROP INDEX IF EXISTS idx_category_api_identifier;
+ALTER TABLE IF EXISTS category ADD CONSTRAINT category_api_identifier_key UNIQUE (api_identifier);
+ALTER TABLE IF EXISTS category DROP COLUMN IF EXISTS deleted_at;
+
+-- contents table
+ALTER TABLE IF EXISTS contents DROP COLUMN IF EXISTS deleted_at;
@@ -0,0 +1,17 @@
+-- contents table
+ALTER TABLE contents ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL;
+CREATE INDEX IF NOT EXISTS idx_contents_deleted_at ON contents(deleted_at);
+
+-- category table
+ALTER TABLE category ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL;
+CREATE INDEX IF NOT EXISTS idx_category_deleted_at ON category(deleted_at);
+ALTER TABLE category DROP CONSTRAINT IF EXISTS category_api_identifier_key;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_category_api_identifier ON category(api_identifier) WHERE deleted_at IS NULL;
+
+-- tags table
+ALTER TABLE tags ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL;
+CREATE INDEX IF NOT EXISTS idx_tags_deleted_at ON tags(deleted_at);
+
+-- media table
+ALTER TABLE media ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMP(3) WITH TIME ZONE DEFAULT NULL;
+CREATE INDEX IF NOT EXISTS idx_media_deleted_at ON media(deleted_at);
@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use domain::model::api_key::{ApiKey, ApiKeyCategory, ApiKeyScope};
+use domain::repository::api_key::{ApiKeyRepository, CreateApiKey, GetApiKeyQuery};
+
+use crate::database::connection::ConnectionPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ApiKeyRow {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub categories: serde_json::Value,
+    pub expires_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub revoked_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<ApiKeyRow> for ApiKey {
+    type Error = anyhow::Error;
+    fn try_from(row: ApiKeyRow) -> anyhow::Result<Self> {
+        let ApiKeyRow {
+            id,
+            name,
+            prefix,
+            scopes,
+            categories,
+            expires_at,
+            revoked_at,
+            created_at,
+        } = row;
+
+        let scopes = scopes
+            .iter()
+            .map(|scope| ApiKeyScope::from_str(scope))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let categories: Vec<ApiKeyCategory> = serde_json::from_value(categories)?;
+
+        ApiKey::try_new(
+            id, name, prefix, scopes, categories, expires_at, revoked_at, created_at,
+        )
+    }
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct ApiKeyRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl ApiKeyRepository for ApiKeyRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(&self, query: GetApiKeyQuery) -> anyhow::Result<Vec<ApiKey>> {
+        let rows = sqlx::query_as::<_, ApiKeyRow>(
+            r#"
+                SELECT
+                    api_keys.id,
+                    api_keys.name,
+                    api_keys.prefix,
+                    api_keys.scopes,
+                    api_keys.expires_at,
+                    api_keys.revoked_at,
+                    api_keys.created_at,
+                    (
+                        SELECT
+                            COALESCE(json_agg(json_build_object('id', category.id, 'api_identifier', category.api_identifier)), '[]'::json)
+                        FROM
+                            category
+                        JOIN
+                            api_key_categories ON api_key_categories.category_id = category.id
+                        WHERE
+                            api_key_categories.api_key_id = api_keys.id
+                    ) AS categories
+                FROM
+                    api_keys
+                ORDER BY
+                    api_keys.created_at DESC
+                LIMIT $1
+                OFFSET $2
+            "#,
+        )
+        .bind(query.limit)
+        .bind(query.offset)
+        .fetch_all(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(ApiKey::try_from).collect()
+    }
+
+    async fn find_by_hash(&self, secret_hash: String) -> anyhow::Result<Option<ApiKey>> {
+        let row = sqlx::query_as::<_, ApiKeyRow>(
+            r#"
+                SELECT
+                    api_keys.id,
+                    api_keys.name,
+                    api_keys.prefix,
+                    api_keys.scopes,
+                    api_keys.expires_at,
+                    api_keys.revoked_at,
+                    api_keys.created_at,
+                    (
+                        SELECT
+                            COALESCE(json_agg(json_build_object('id', category.id, 'api_identifier', category.api_identifier)), '[]'::json)
+                        FROM
+                            category
+                        JOIN
+                            api_key_categories ON api_key_categories.category_id = category.id
+                        WHERE
+                            api_key_categories.api_key_id = api_keys.id
+                    ) AS categories
+                FROM
+                    api_keys
+                WHERE
+                    api_keys.secret_hash = $1
+            "#,
+        )
+        .bind(secret_hash)
+        .fetch_optional(self.db.inner_ref())
+        .await?;
+
+        row.map(ApiKey::try_from).transpose()
+    }
+
+    #[tracing::instrument]
+    async fn create(&self, data: CreateApiKey) -> anyhow::Result<ApiKey> {
+        let CreateApiKey {
+            name,
+            prefix,
+            secret_hash,
+            scopes,
+            category_ids,
+            expires_at,
+        } = data;
+
+        let uuid = uuid::Uuid::now_v7();
+        let scopes: Vec<&str> = scopes.iter().map(ApiKeyScope::as_str).collect();
+        let category_uuids: Vec<uuid::Uuid> = category_ids
+            .into_iter()
+            .map(|id| uuid::Uuid::from_str(&id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    api_keys (id, name, prefix, secret_hash, scopes, expires_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(uuid)
+        .bind(name)
+        .bind(prefix)
+        .bind(secret_hash.clone())
+        .bind(scopes)
+        .bind(expires_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    api_key_categories (api_key_id, category_id)
+                SELECT
+                    $1,
+                    category_id
+                FROM
+                    UNNEST($2) AS category_id
+            "#,
+        )
+        .bind(uuid)
+        .bind(category_uuids)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        transaction.commit().await?;
+
+        self.find_by_hash(secret_hash)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Api key {} is not found", uuid))
+    }
+
+    #[tracing::instrument]
+    async fn revoke(&self, id: String) -> anyhow::Result<()> {
+        let parsed_api_key_id = uuid::Uuid::parse_str(&id)?;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE api_keys SET
+                    revoked_at = COALESCE(revoked_at, CURRENT_TIMESTAMP)
+                WHERE
+                    id = $1
+            "#,
+        )
+        .bind(parsed_api_key_id)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Api key {} is not found", id);
+        }
+
+        Ok(())
+    }
+}
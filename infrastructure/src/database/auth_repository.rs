@@ -0,0 +1,349 @@
+use domain::model::auth::{Credential, Session};
+use domain::model::user::User;
+use domain::repository::auth::{AuthRepository, CreateSession, RotateSession, SignUpUser};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::role_repository::fetch_roles;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct CredentialRow {
+    pub id: uuid::Uuid,
+    pub password_hash: Option<String>,
+    pub email_verified_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+impl From<CredentialRow> for Credential {
+    fn from(row: CredentialRow) -> Self {
+        let CredentialRow {
+            id,
+            password_hash,
+            email_verified_at,
+        } = row;
+
+        Credential::new(id.to_string(), password_hash, email_verified_at)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct SessionRow {
+    pub id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub user_name: String,
+    pub user_icon_url: String,
+    pub role_id: uuid::Uuid,
+    pub access_expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub refresh_expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub revoked_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+const SELECT_SESSION: &str = r#"
+    SELECT
+        sessions.id,
+        users.id AS user_id,
+        users.name AS user_name,
+        users.icon_url AS user_icon_url,
+        users.role_id,
+        sessions.access_expires_at,
+        sessions.refresh_expires_at,
+        sessions.revoked_at
+    FROM
+        sessions
+    JOIN
+        users ON users.id = sessions.user_id
+"#;
+
+#[derive(derive_new::new, Debug)]
+pub struct AuthRepositoryImpl {
+    db: ConnectionPool,
+}
+
+impl AuthRepositoryImpl {
+    async fn find_session_by(
+        &self,
+        column: &str,
+        token_hash: String,
+    ) -> anyhow::Result<Option<Session>> {
+        let mut connection = self.db.inner_ref().acquire().await?;
+        let row = sqlx::query_as::<_, SessionRow>(&format!(
+            "{} WHERE sessions.{} = $1 AND users.deleted_at IS NULL",
+            SELECT_SESSION, column
+        ))
+        .bind(token_hash)
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        let Some(SessionRow {
+            id,
+            user_id,
+            user_name,
+            user_icon_url,
+            role_id,
+            access_expires_at,
+            refresh_expires_at,
+            revoked_at,
+        }) = row
+        else {
+            return Ok(None);
+        };
+
+        let role = fetch_roles(&mut connection, &[role_id])
+            .await?
+            .remove(&role_id)
+            .ok_or_else(|| anyhow::anyhow!("Role {} is not found", role_id))?;
+        let user = User::try_new(user_id.to_string(), user_name, user_icon_url, role)?;
+
+        Ok(Some(Session::new(
+            id,
+            user,
+            access_expires_at,
+            refresh_expires_at,
+            revoked_at,
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthRepository for AuthRepositoryImpl {
+    async fn find_credential(&self, email: String) -> anyhow::Result<Option<Credential>> {
+        let row = sqlx::query_as::<_, CredentialRow>(
+            r#"
+                SELECT
+                    id,
+                    password_hash,
+                    email_verified_at
+                FROM
+                    users
+                WHERE
+                    email = $1
+                    AND deleted_at IS NULL
+            "#,
+        )
+        .bind(email)
+        .fetch_optional(self.db.inner_ref())
+        .await?;
+
+        Ok(row.map(Credential::from))
+    }
+
+    async fn signup(&self, data: SignUpUser) -> anyhow::Result<String> {
+        let SignUpUser {
+            name,
+            email,
+            icon_url,
+            password_hash,
+            verification_token_hash,
+            verification_expires_at,
+        } = data;
+
+        let user_id = uuid::Uuid::now_v7();
+
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    users (id, name, icon_url, role_id, email, password_hash)
+                SELECT
+                    $1, $2, $3, role.id, $4, $5
+                FROM
+                    role
+                WHERE
+                    role.name = 'member'
+                LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(name)
+        .bind(icon_url)
+        .bind(email)
+        .bind(password_hash)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Default role member is not found");
+        }
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    email_verification_tokens (id, user_id, token_hash, expires_at)
+                VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(uuid::Uuid::now_v7())
+        .bind(user_id)
+        .bind(verification_token_hash)
+        .bind(verification_expires_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        transaction.commit().await?;
+
+        Ok(user_id.to_string())
+    }
+
+    async fn verify_email(
+        &self,
+        token_hash: String,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    ) -> anyhow::Result<bool> {
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let user_id = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+                UPDATE email_verification_tokens SET
+                    used_at = $2
+                WHERE
+                    token_hash = $1
+                    AND used_at IS NULL
+                    AND expires_at > $2
+                RETURNING
+                    user_id
+            "#,
+        )
+        .bind(token_hash)
+        .bind(now)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(user_id) = user_id else {
+            return Ok(false);
+        };
+
+        let result = sqlx::query(
+            r#"
+                UPDATE users SET
+                    email_verified_at = COALESCE(email_verified_at, $2)
+                WHERE
+                    id = $1
+            "#,
+        )
+        .bind(user_id)
+        .bind(now)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        transaction.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn create_session(&self, data: CreateSession) -> anyhow::Result<Session> {
+        let CreateSession {
+            user_id,
+            access_token_hash,
+            access_expires_at,
+            refresh_token_hash,
+            refresh_expires_at,
+        } = data;
+
+        let parsed_user_id = uuid::Uuid::parse_str(&user_id)?;
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    sessions (id, user_id, access_token_hash, access_expires_at, refresh_token_hash, refresh_expires_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(uuid::Uuid::now_v7())
+        .bind(parsed_user_id)
+        .bind(access_token_hash.clone())
+        .bind(access_expires_at)
+        .bind(refresh_token_hash)
+        .bind(refresh_expires_at)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        self.find_session_by_access_token(access_token_hash)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Session for user {} is not found", user_id))
+    }
+
+    async fn find_session_by_access_token(
+        &self,
+        token_hash: String,
+    ) -> anyhow::Result<Option<Session>> {
+        self.find_session_by("access_token_hash", token_hash).await
+    }
+
+    async fn find_session_by_refresh_token(
+        &self,
+        token_hash: String,
+    ) -> anyhow::Result<Option<Session>> {
+        self.find_session_by("refresh_token_hash", token_hash).await
+    }
+
+    async fn rotate_session(&self, data: RotateSession) -> anyhow::Result<Option<Session>> {
+        let RotateSession {
+            id,
+            previous_refresh_token_hash,
+            access_token_hash,
+            access_expires_at,
+            refresh_token_hash,
+            refresh_expires_at,
+        } = data;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE sessions SET
+                    access_token_hash = $3,
+                    access_expires_at = $4,
+                    refresh_token_hash = $5,
+                    refresh_expires_at = $6
+                WHERE
+                    id = $1
+                    AND refresh_token_hash = $2
+                    AND revoked_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(previous_refresh_token_hash)
+        .bind(access_token_hash.clone())
+        .bind(access_expires_at)
+        .bind(refresh_token_hash)
+        .bind(refresh_expires_at)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        self.find_session_by_access_token(access_token_hash).await
+    }
+
+    #[tracing::instrument]
+    async fn revoke_session(&self, id: uuid::Uuid) -> anyhow::Result<()> {
+        let result = sqlx::query(
+            r#"
+                UPDATE sessions SET
+                    revoked_at = COALESCE(revoked_at, CURRENT_TIMESTAMP)
+                WHERE
+                    id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Session {} is not found", id);
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,19 @@
 use std::str::FromStr;
 
 use domain::model::category::Category;
+use domain::model::field::{validate_schema, Field};
+use domain::model::page::Page;
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
+use domain::model::webhook::WebhookEvent;
 use domain::repository::category::{
     CategoryRepository, CreateCategory, GetCategoryQuery, UpdateCategory,
 };
 
 use crate::database::connection::ConnectionPool;
+use crate::database::contents_repository::parse_ids;
+use crate::database::outbox_repository::insert_outbox_event;
+use crate::database::pagination::{fetch_total, push_keyset};
 
 #[derive(Debug, sqlx::FromRow)]
 struct CategoryRow {
@@ -13,6 +21,8 @@ struct CategoryRow {
     pub name: String,
     pub api_identifier: String,
     pub description: String,
+    pub fields: sqlx::types::Json<Vec<Field>>,
+    pub version: i32,
     #[sqlx(skip)]
     #[allow(unused)]
     pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
@@ -28,6 +38,8 @@ impl From<CategoryRow> for Category {
             name,
             api_identifier,
             description,
+            fields,
+            version,
             ..
         } = row;
 
@@ -36,10 +48,25 @@ impl From<CategoryRow> for Category {
             name,
             api_identifier,
             description: Some(description),
+            fields: fields.0,
+            version,
         }
     }
 }
 
+fn push_category_filter(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    category_ids: Option<Vec<uuid::Uuid>>,
+) {
+    query_builder.push(" AND deleted_at IS NULL");
+
+    if let Some(category_ids) = category_ids {
+        query_builder.push(" AND id = ANY(");
+        query_builder.push_bind(category_ids);
+        query_builder.push(")");
+    }
+}
+
 #[derive(derive_new::new, Debug)]
 pub struct CategoryRepositoryImpl {
     db: ConnectionPool,
@@ -48,18 +75,37 @@ pub struct CategoryRepositoryImpl {
 #[async_trait::async_trait]
 impl CategoryRepository for CategoryRepositoryImpl {
     #[tracing::instrument]
-    async fn get(&self, query: GetCategoryQuery) -> anyhow::Result<Vec<Category>> {
-        let rows: Vec<CategoryRow> = sqlx::query_as::<_, CategoryRow>(
-            r#"SELECT * FROM category ORDER BY id LIMIT $1 OFFSET $2"#,
-        )
-        .bind(query.limit)
-        .bind(query.offset)
-        .fetch_all(self.db.inner_ref())
-        .await?;
+    async fn get(&self, query: GetCategoryQuery) -> anyhow::Result<Page<Category>> {
+        let GetCategoryQuery { page, category_ids } = query;
+        let category_ids = parse_ids(category_ids)?;
+
+        let mut conn = self.db.acquire().await?;
+
+        let total = match page.with_total {
+            true => {
+                let mut count_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+                    "SELECT COUNT(*) FROM category WHERE TRUE",
+                );
+                push_category_filter(&mut count_builder, category_ids.clone());
+
+                Some(fetch_total(&mut *conn, count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder =
+            sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT * FROM category WHERE TRUE");
+        push_category_filter(&mut query_builder, category_ids);
+        push_keyset(&mut query_builder, "id", &page, false)?;
+
+        let rows = query_builder
+            .build_query_as::<CategoryRow>()
+            .fetch_all(&mut *conn)
+            .await?;
 
         tracing::info!("{:?}", rows);
 
-        Ok(rows.into_iter().map(Category::from).collect())
+        Ok(Page::from_keyset_rows(rows, &page, |row| row.id, total).map(Category::from))
     }
 
     #[tracing::instrument]
@@ -68,8 +114,11 @@ impl CategoryRepository for CategoryRepositoryImpl {
             name,
             api_identifier,
             description,
+            fields,
         } = data;
 
+        validate_schema(&fields)?;
+
         let id = uuid::Uuid::now_v7();
 
         let description = match description {
@@ -77,6 +126,8 @@ impl CategoryRepository for CategoryRepositoryImpl {
             None => "".into(),
         };
 
+        let mut transaction = self.db.begin().await?;
+
         let category_row = sqlx::query_as::<_, CategoryRow>(
             r#"
                 INSERT INTO
@@ -84,9 +135,10 @@ impl CategoryRepository for CategoryRepositoryImpl {
                         id,
                         name,
                         api_identifier,
-                        description
+                        description,
+                        fields
                     )
-                VALUES ($1, $2, $3, $4)
+                VALUES ($1, $2, $3, $4, $5)
                 RETURNING *
             "#,
         )
@@ -94,12 +146,23 @@ impl CategoryRepository for CategoryRepositoryImpl {
         .bind(name)
         .bind(api_identifier)
         .bind(description)
-        .fetch_one(self.db.inner_ref())
+        .bind(sqlx::types::Json(fields))
+        .fetch_one(&mut *transaction)
         .await?;
 
         tracing::info!("{:?}", category_row);
 
-        Ok(Category::from(category_row))
+        let category = Category::from(category_row);
+        insert_outbox_event(
+            &mut *transaction,
+            WebhookEvent::CategoryCreated,
+            Some(id),
+            &category,
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(category)
     }
 
     #[tracing::instrument]
@@ -109,6 +172,8 @@ impl CategoryRepository for CategoryRepositoryImpl {
             name,
             api_identifier,
             description,
+            fields,
+            expected_version,
         } = data;
 
         let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("UPDATE category SET ");
@@ -129,6 +194,14 @@ impl CategoryRepository for CategoryRepositoryImpl {
             separated.push_bind_unseparated(description);
         }
 
+        if let Some(fields) = fields {
+            validate_schema(&fields)?;
+            separated.push("fields = ");
+            separated.push_bind_unseparated(sqlx::types::Json(fields));
+        }
+
+        separated.push("version = version + 1");
+
         let category_id = uuid::Uuid::from_str(&id)?;
         query_builder.push(" WHERE id = ");
         query_builder.push_bind(category_id);
@@ -137,27 +210,185 @@ impl CategoryRepository for CategoryRepositoryImpl {
 
         tracing::info!("{:?}", query_builder.sql());
 
+        let mut transaction = self.db.begin().await?;
+
+        let current_version = sqlx::query_scalar::<_, i32>(
+            r#"SELECT version FROM category WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+        .bind(category_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(current_version) = current_version else {
+            anyhow::bail!("Category {} is not found", id);
+        };
+
+        if let Some(expected_version) = expected_version.filter(|v| *v != current_version) {
+            let current_row =
+                sqlx::query_as::<_, CategoryRow>(r#"SELECT * FROM category WHERE id = $1"#)
+                    .bind(category_id)
+                    .fetch_one(&mut *transaction)
+                    .await?;
+            let current = Category::from(current_row);
+
+            return Err(VersionConflict::try_new(expected_version, &current)?.into());
+        }
+
         let category_row = query_builder
             .build_query_as::<CategoryRow>()
-            .fetch_one(self.db.inner_ref())
+            .fetch_one(&mut *transaction)
             .await?;
 
         tracing::info!("{:?}", category_row);
 
-        Ok(Category::from(category_row))
+        let category = Category::from(category_row);
+        insert_outbox_event(
+            &mut *transaction,
+            WebhookEvent::CategoryUpdated,
+            Some(category_id),
+            &category,
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(category)
     }
 
     #[tracing::instrument]
     async fn delete(&self, id: String) -> anyhow::Result<()> {
         let category_id = uuid::Uuid::from_str(&id)?;
 
-        let result = sqlx::query(r#"DELETE FROM category WHERE id = $1"#)
-            .bind(category_id)
-            .execute(self.db.inner_ref())
-            .await?;
+        let mut transaction = self.db.begin().await?;
+
+        let found = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"SELECT id FROM category WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+        .bind(category_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if found.is_none() {
+            return Ok(());
+        }
+
+        let in_use = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS (SELECT 1 FROM contents WHERE category_id = $1 AND deleted_at IS NULL)"#,
+        )
+        .bind(category_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if in_use {
+            return Err(TrashError::CategoryInUse.into());
+        }
+
+        let result =
+            sqlx::query(r#"UPDATE category SET deleted_at = CURRENT_TIMESTAMP(3) WHERE id = $1"#)
+                .bind(category_id)
+                .execute(&mut *transaction)
+                .await?;
 
         tracing::info!("{:?}", result);
 
+        insert_outbox_event(
+            &mut *transaction,
+            WebhookEvent::CategoryDeleted,
+            Some(category_id),
+            &serde_json::json!({ "id": id }),
+        )
+        .await?;
+        transaction.commit().await?;
+
         Ok(())
     }
+
+    #[tracing::instrument]
+    async fn restore(&self, id: String) -> anyhow::Result<Category> {
+        let category_id = uuid::Uuid::from_str(&id)?;
+
+        let mut transaction = self.db.begin().await?;
+
+        let category_row = sqlx::query_as::<_, CategoryRow>(
+            r#"
+                UPDATE category SET
+                    deleted_at = NULL
+                WHERE
+                    id = $1
+                    AND deleted_at IS NOT NULL
+                RETURNING *
+            "#,
+        )
+        .bind(category_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|error| match error {
+            sqlx::Error::Database(error) if error.is_unique_violation() => {
+                anyhow::Error::from(TrashError::CategoryIdentifierInUse)
+            }
+            error => error.into(),
+        })?;
+
+        tracing::info!("{:?}", category_row);
+
+        let Some(category_row) = category_row else {
+            return Err(TrashError::NotInTrash.into());
+        };
+
+        let category = Category::from(category_row);
+        insert_outbox_event(
+            &mut *transaction,
+            WebhookEvent::CategoryRestored,
+            Some(category_id),
+            &category,
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(category)
+    }
+
+    #[tracing::instrument]
+    async fn purge(
+        &self,
+        before: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        let mut conn = self.db.acquire().await?;
+
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+                WITH expired AS (
+                    SELECT
+                        id
+                    FROM
+                        category
+                    WHERE
+                        deleted_at < $1
+                        AND NOT EXISTS (
+                            SELECT 1 FROM contents WHERE contents.category_id = category.id
+                        )
+                    ORDER BY
+                        deleted_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                DELETE FROM
+                    category
+                USING
+                    expired
+                WHERE
+                    category.id = expired.id
+                RETURNING
+                    category.id
+            "#,
+        )
+        .bind(before)
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        tracing::info!("{:?}", ids);
+
+        Ok(ids)
+    }
 }
@@ -1,5 +1,12 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use anyhow::Context;
 use shared::config::DatabaseConfig;
 
+pub(crate) type SharedTransaction =
+    Arc<tokio::sync::Mutex<Option<sqlx::Transaction<'static, sqlx::Postgres>>>>;
+
 fn make_pg_connect_options(cfg: DatabaseConfig) -> sqlx::postgres::PgConnectOptions {
     sqlx::postgres::PgConnectOptions::new()
         .host(&cfg.host)
@@ -9,17 +16,98 @@ fn make_pg_connect_options(cfg: DatabaseConfig) -> sqlx::postgres::PgConnectOpti
         .database(&cfg.database)
 }
 
-#[derive(Clone, derive_new::new, Debug)]
-pub struct ConnectionPool(sqlx::PgPool);
+#[derive(Clone, derive_new::new)]
+pub struct ConnectionPool {
+    pool: sqlx::PgPool,
+    #[new(default)]
+    transaction: Option<SharedTransaction>,
+}
+
+impl std::fmt::Debug for ConnectionPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionPool")
+            .field("pool", &self.pool)
+            .field("unit_of_work", &self.transaction.is_some())
+            .finish()
+    }
+}
 
 impl ConnectionPool {
     pub fn inner_ref(&self) -> &sqlx::PgPool {
-        &self.0
+        &self.pool
+    }
+
+    pub(crate) fn with_transaction(&self, transaction: SharedTransaction) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            transaction: Some(transaction),
+        }
+    }
+
+    pub async fn acquire(&self) -> anyhow::Result<DbConnection> {
+        match &self.transaction {
+            Some(transaction) => Self::lock(transaction).await,
+            None => Ok(DbConnection::Pooled(self.pool.acquire().await?)),
+        }
+    }
+
+    pub async fn begin(&self) -> anyhow::Result<DbConnection> {
+        match &self.transaction {
+            Some(transaction) => Self::lock(transaction).await,
+            None => Ok(DbConnection::Transaction(self.pool.begin().await?)),
+        }
+    }
+
+    async fn lock(transaction: &SharedTransaction) -> anyhow::Result<DbConnection> {
+        let guard = transaction.clone().lock_owned().await;
+        guard
+            .as_ref()
+            .context("Unit of work is already committed")?;
+
+        Ok(DbConnection::Shared(guard))
+    }
+}
+
+pub enum DbConnection {
+    Pooled(sqlx::pool::PoolConnection<sqlx::Postgres>),
+    Transaction(sqlx::Transaction<'static, sqlx::Postgres>),
+    Shared(tokio::sync::OwnedMutexGuard<Option<sqlx::Transaction<'static, sqlx::Postgres>>>),
+}
+
+impl DbConnection {
+    pub async fn commit(self) -> anyhow::Result<()> {
+        if let Self::Transaction(transaction) = self {
+            transaction.commit().await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Deref for DbConnection {
+    type Target = sqlx::PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Transaction(transaction) => transaction,
+            Self::Shared(guard) => guard.as_ref().expect("checked when locked"),
+        }
+    }
+}
+
+impl DerefMut for DbConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Pooled(connection) => connection,
+            Self::Transaction(transaction) => transaction,
+            Self::Shared(guard) => guard.as_mut().expect("checked when locked"),
+        }
     }
 }
 
 pub fn connect_database_with(cfg: DatabaseConfig) -> ConnectionPool {
     let pool = sqlx::PgPool::connect_lazy_with(make_pg_connect_options(cfg));
 
-    ConnectionPool(pool)
+    ConnectionPool::new(pool)
 }
@@ -0,0 +1,238 @@
+use domain::model::content::UpdatedBy;
+use domain::model::field::{validate_localized_fields, Field};
+use domain::model::locale::ContentLocalization;
+use domain::repository::localization::{
+    ContentLocalizationRepository, UpdateContentLocalizationStatus, UpsertContentLocalization,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::contents_repository::ContentRowStatus;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ContentLocalizationRow {
+    pub content_id: uuid::Uuid,
+    pub locale: String,
+    pub fields: serde_json::Value,
+    pub status: ContentRowStatus,
+    pub published_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub updated_by_id: uuid::Uuid,
+    pub updated_by_name: String,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl From<ContentLocalizationRow> for ContentLocalization {
+    fn from(row: ContentLocalizationRow) -> Self {
+        let ContentLocalizationRow {
+            content_id,
+            locale,
+            fields,
+            status,
+            published_at,
+            updated_by_id,
+            updated_by_name,
+            created_at,
+            updated_at,
+        } = row;
+
+        ContentLocalization::new(
+            content_id,
+            locale,
+            status.into(),
+            fields,
+            UpdatedBy::new(updated_by_id.into(), updated_by_name),
+            published_at,
+            created_at,
+            updated_at,
+        )
+    }
+}
+
+pub async fn fetch_localizations(
+    db: &ConnectionPool,
+    content_ids: Vec<uuid::Uuid>,
+    include_drafts: bool,
+) -> anyhow::Result<Vec<ContentLocalization>> {
+    let mut conn = db.acquire().await?;
+
+    let rows = sqlx::query_as::<_, ContentLocalizationRow>(
+        r#"
+            SELECT
+                content_localizations.content_id,
+                content_localizations.locale,
+                content_localizations.fields,
+                content_localizations.status,
+                content_localizations.published_at,
+                content_localizations.updated_by AS updated_by_id,
+                users.name AS updated_by_name,
+                content_localizations.created_at,
+                content_localizations.updated_at
+            FROM
+                content_localizations
+            JOIN
+                users ON users.id = content_localizations.updated_by
+            WHERE
+                content_localizations.content_id = ANY($1)
+                AND ($2 OR content_localizations.status = 'Published')
+            ORDER BY
+                content_localizations.content_id, content_localizations.locale
+        "#,
+    )
+    .bind(content_ids)
+    .bind(include_drafts)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    tracing::info!("{:?}", rows);
+
+    Ok(rows.into_iter().map(ContentLocalization::from).collect())
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CategoryFieldsRow {
+    fields: sqlx::types::Json<Vec<Field>>,
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct ContentLocalizationRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl ContentLocalizationRepository for ContentLocalizationRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(&self, content_id: String) -> anyhow::Result<Vec<ContentLocalization>> {
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+
+        fetch_localizations(&self.db, vec![parsed_content_id], true).await
+    }
+
+    #[tracing::instrument]
+    async fn find(
+        &self,
+        content_id: String,
+        locale: String,
+    ) -> anyhow::Result<ContentLocalization> {
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+
+        fetch_localizations(&self.db, vec![parsed_content_id], true)
+            .await?
+            .into_iter()
+            .find(|localization| localization.locale == locale)
+            .ok_or_else(|| anyhow::anyhow!("Content {} has no {} localization", content_id, locale))
+    }
+
+    #[tracing::instrument]
+    async fn upsert(&self, data: UpsertContentLocalization) -> anyhow::Result<ContentLocalization> {
+        let UpsertContentLocalization {
+            content_id,
+            locale,
+            fields,
+            updated_by_id,
+        } = data;
+
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+        let parsed_updated_by = uuid::Uuid::parse_str(&updated_by_id)?;
+
+        let category_row = sqlx::query_as::<_, CategoryFieldsRow>(
+            r#"
+                SELECT
+                    category.fields
+                FROM
+                    contents
+                JOIN
+                    category ON category.id = contents.category_id
+                WHERE
+                    contents.id = $1
+                    AND contents.deleted_at IS NULL
+            "#,
+        )
+        .bind(parsed_content_id)
+        .fetch_one(self.db.inner_ref())
+        .await?;
+
+        validate_localized_fields(&category_row.fields.0, &fields)?;
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    content_localizations (content_id, locale, fields, updated_by)
+                VALUES
+                    ($1, $2, $3, $4)
+                ON CONFLICT (content_id, locale) DO UPDATE SET
+                    fields = EXCLUDED.fields,
+                    updated_by = EXCLUDED.updated_by
+            "#,
+        )
+        .bind(parsed_content_id)
+        .bind(&locale)
+        .bind(fields)
+        .bind(parsed_updated_by)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        self.find(content_id, locale).await
+    }
+
+    #[tracing::instrument]
+    async fn update_status(
+        &self,
+        data: UpdateContentLocalizationStatus,
+    ) -> anyhow::Result<ContentLocalization> {
+        let UpdateContentLocalizationStatus {
+            content_id,
+            locale,
+            change,
+            updated_by_id,
+        } = data;
+
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+        let parsed_updated_by = uuid::Uuid::parse_str(&updated_by_id)?;
+        let status: ContentRowStatus = change.status.into();
+
+        let result = sqlx::query(
+            r#"
+                UPDATE content_localizations SET
+                    status = $1,
+                    published_at = $2,
+                    updated_by = $3
+                WHERE
+                    content_id = $4
+                    AND locale = $5
+            "#,
+        )
+        .bind(status)
+        .bind(change.published_at)
+        .bind(parsed_updated_by)
+        .bind(parsed_content_id)
+        .bind(&locale)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        if result.rows_affected() == 0 {
+            anyhow::bail!("Content {} has no {} localization", content_id, locale);
+        }
+
+        self.find(content_id, locale).await
+    }
+
+    async fn delete(&self, content_id: String, locale: String) -> anyhow::Result<()> {
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+
+        let result = sqlx::query(
+            r#"DELETE FROM content_localizations WHERE content_id = $1 AND locale = $2"#,
+        )
+        .bind(parsed_content_id)
+        .bind(locale)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+pub(crate) const CONTENT_TAGS: &str = r#"
+    (
+        SELECT
+            COALESCE(json_agg(json_build_object('id', tags.id, 'name', tags.name))
+            FILTER (WHERE tags.id IS NOT NULL), '[]'::json)
+        FROM
+            tags
+        JOIN
+            content_tags ON content_tags.tag_id = tags.id
+        WHERE
+            content_tags.content_id = contents.id
+            AND tags.deleted_at IS NULL
+    )
+"#;
+
+pub(crate) fn push_content_select(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    from: &str,
+) {
+    query_builder.push(
+        r#"
+            contents.*,
+            category.name AS category_name,
+            created_by.id AS created_by_id,
+            created_by.name AS created_by_name,
+            updated_by.id AS updated_by_id,
+            updated_by.name AS updated_by_name,
+        "#,
+    );
+    query_builder.push(CONTENT_TAGS);
+    query_builder.push(" AS tags FROM ");
+    query_builder.push(from);
+    query_builder.push(
+        r#"
+            JOIN
+                category ON contents.category_id = category.id
+            JOIN
+                users AS created_by ON created_by.id = contents.created_by
+            JOIN
+                users AS updated_by ON updated_by.id = contents.updated_by
+            WHERE
+                contents.deleted_at IS NULL
+        "#,
+    );
+}
@@ -0,0 +1,190 @@
+use domain::model::content::{ContentCategory, ContentTag, CreatedBy};
+use domain::model::revision::ContentRevision;
+use domain::repository::revision::{ContentRevisionRepository, GetRevisionQuery};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::content_query::CONTENT_TAGS;
+use crate::database::contents_repository::ContentRowStatus;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ContentRevisionRow {
+    pub id: uuid::Uuid,
+    pub content_id: uuid::Uuid,
+    pub revision: i32,
+    pub title: String,
+    pub category_id: uuid::Uuid,
+    pub category_name: String,
+    pub fields: serde_json::Value,
+    pub tags: serde_json::Value,
+    pub status: ContentRowStatus,
+    pub created_by: uuid::Uuid,
+    pub created_by_name: String,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<ContentRevisionRow> for ContentRevision {
+    type Error = anyhow::Error;
+    fn try_from(row: ContentRevisionRow) -> anyhow::Result<Self> {
+        let ContentRevisionRow {
+            id,
+            content_id,
+            revision,
+            title,
+            category_id,
+            category_name,
+            fields,
+            tags,
+            status,
+            created_by,
+            created_by_name,
+            created_at,
+        } = row;
+
+        let category = ContentCategory::new(category_id.into(), category_name);
+        let deserialized_tags: Vec<ContentTag> = serde_json::from_value(tags)?;
+        let created_by = CreatedBy::new(created_by.into(), created_by_name);
+
+        Ok(ContentRevision::new(
+            id,
+            content_id,
+            revision,
+            title,
+            category,
+            status.into(),
+            fields,
+            deserialized_tags,
+            created_by,
+            created_at,
+        ))
+    }
+}
+
+pub async fn insert_revision(
+    conn: &mut sqlx::PgConnection,
+    content_id: uuid::Uuid,
+) -> anyhow::Result<()> {
+    let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+        r#"
+            INSERT INTO
+                content_revisions (
+                    id,
+                    content_id,
+                    revision,
+                    title,
+                    category_id,
+                    category_name,
+                    fields,
+                    tags,
+                    status,
+                    created_by
+                )
+            SELECT
+        "#,
+    );
+    query_builder.push_bind(uuid::Uuid::now_v7());
+    query_builder.push(
+        r#",
+                contents.id,
+                COALESCE(
+                    (SELECT MAX(revision) FROM content_revisions WHERE content_id = contents.id),
+                    0
+                ) + 1,
+                contents.title,
+                contents.category_id,
+                category.name,
+                contents.fields,
+        "#,
+    );
+    query_builder.push(CONTENT_TAGS);
+    query_builder.push(
+        r#"::jsonb,
+                contents.status,
+                contents.updated_by
+            FROM
+                contents
+            JOIN
+                category ON category.id = contents.category_id
+            WHERE
+                contents.id =
+        "#,
+    );
+    query_builder.push_bind(content_id);
+
+    let result = query_builder.build().execute(conn).await?;
+
+    tracing::info!("{:?}", result);
+
+    Ok(())
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct ContentRevisionRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl ContentRevisionRepository for ContentRevisionRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(
+        &self,
+        content_id: String,
+        query: GetRevisionQuery,
+    ) -> anyhow::Result<Vec<ContentRevision>> {
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+
+        let rows = sqlx::query_as::<_, ContentRevisionRow>(
+            r#"
+                SELECT
+                    content_revisions.*,
+                    users.name AS created_by_name
+                FROM
+                    content_revisions
+                JOIN
+                    users ON users.id = content_revisions.created_by
+                WHERE
+                    content_revisions.content_id = $1
+                ORDER BY
+                    content_revisions.revision DESC
+                LIMIT $2
+                OFFSET $3
+            "#,
+        )
+        .bind(parsed_content_id)
+        .bind(query.limit)
+        .bind(query.offset)
+        .fetch_all(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(ContentRevision::try_from).collect()
+    }
+
+    #[tracing::instrument]
+    async fn find(&self, content_id: String, revision: i32) -> anyhow::Result<ContentRevision> {
+        let parsed_content_id = uuid::Uuid::parse_str(&content_id)?;
+
+        let row = sqlx::query_as::<_, ContentRevisionRow>(
+            r#"
+                SELECT
+                    content_revisions.*,
+                    users.name AS created_by_name
+                FROM
+                    content_revisions
+                JOIN
+                    users ON users.id = content_revisions.created_by
+                WHERE
+                    content_revisions.content_id = $1
+                    AND content_revisions.revision = $2
+            "#,
+        )
+        .bind(parsed_content_id)
+        .bind(revision)
+        .fetch_one(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", row);
+
+        ContentRevision::try_from(row)
+    }
+}
@@ -0,0 +1,123 @@
+use domain::model::webhook::WebhookEvent;
+use domain::repository::schedule::ContentScheduleRepository;
+
+use crate::database::connection::ConnectionPool;
+use crate::database::content_revision_repository::insert_revision;
+use crate::database::contents_repository::{fetch_content, insert_content_event};
+
+#[derive(derive_new::new, Debug)]
+pub struct ContentScheduleRepositoryImpl {
+    db: ConnectionPool,
+}
+
+impl ContentScheduleRepositoryImpl {
+    async fn transition_due(
+        &self,
+        sql: &str,
+        event: WebhookEvent,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(sql)
+            .bind(now)
+            .bind(limit)
+            .fetch_all(&mut *transaction)
+            .await?;
+
+        tracing::info!("{:?}", ids);
+
+        for id in ids.iter() {
+            insert_revision(&mut transaction, *id).await?;
+
+            let content = fetch_content(&mut *transaction, *id).await?;
+            insert_content_event(&mut transaction, event, &content).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(ids)
+    }
+}
+
+#[async_trait::async_trait]
+impl ContentScheduleRepository for ContentScheduleRepositoryImpl {
+    #[tracing::instrument]
+    async fn publish_due(
+        &self,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        self.transition_due(
+            r#"
+                WITH due AS (
+                    SELECT
+                        id
+                    FROM
+                        contents
+                    WHERE
+                        status = 'Reserved'
+                        AND published_at <= $1
+                        AND deleted_at IS NULL
+                    ORDER BY
+                        published_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                UPDATE contents SET
+                    status = 'Published',
+                    version = contents.version + 1
+                FROM
+                    due
+                WHERE
+                    contents.id = due.id
+                RETURNING
+                    contents.id
+            "#,
+            WebhookEvent::ContentPublished,
+            now,
+            limit,
+        )
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn unpublish_due(
+        &self,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        self.transition_due(
+            r#"
+                WITH due AS (
+                    SELECT
+                        id
+                    FROM
+                        contents
+                    WHERE
+                        status = 'Published'
+                        AND unpublish_at <= $1
+                        AND deleted_at IS NULL
+                    ORDER BY
+                        unpublish_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                UPDATE contents SET
+                    status = 'Unpublished',
+                    version = contents.version + 1
+                FROM
+                    due
+                WHERE
+                    contents.id = due.id
+                RETURNING
+                    contents.id
+            "#,
+            WebhookEvent::ContentUnpublished,
+            now,
+            limit,
+        )
+        .await
+    }
+}
@@ -1,13 +1,34 @@
 use std::str::FromStr;
 
 use domain::model::content::{
-    Categories, Content, ContentStatus, CreatedBy, Field, Tags, UpdatedBy,
+    Content, ContentCategory, ContentStatus, ContentStatusError, ContentTag, CreatedBy, UpdatedBy,
 };
+use domain::model::field::{
+    field_references, validate_fields, Field, FieldError, FieldType, FieldValidationError,
+};
+use domain::model::locale::ContentLocalization;
+use domain::model::page::Page;
+use domain::model::query::{
+    ContentFilter, DateRange, FieldFilter, FilterOperator, SortKey, SortOrder, TagMatch,
+};
+use domain::model::relation::{ContentReferrer, RelatedContent};
+use domain::model::search::{
+    highlight, search_terms, SearchLanguage, SearchMatch, SNIPPET_CONTEXT,
+};
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
+use domain::model::webhook::WebhookEvent;
 use domain::repository::content::{
-    ContentRepository, CreateContent, GetContentQuery, UpdateContent,
+    ContentRepository, CreateContent, FindRelatedQuery, GetContentQuery, UpdateContent,
+    UpdateContentStatus,
 };
 
 use crate::database::connection::ConnectionPool;
+use crate::database::content_localization_repository::fetch_localizations;
+use crate::database::content_query::push_content_select;
+use crate::database::content_revision_repository::insert_revision;
+use crate::database::outbox_repository::insert_outbox_event;
+use crate::database::pagination::{fetch_total, push_keyset};
 
 #[derive(Debug, sqlx::Type)]
 #[sqlx(type_name = "content_status")]
@@ -48,12 +69,14 @@ pub struct ContentsRow {
     pub tags: serde_json::Value,
     pub status: ContentRowStatus,
     pub published_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub unpublish_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
     pub created_by_id: uuid::Uuid,
     pub created_by_name: String,
     pub updated_by_id: uuid::Uuid,
     pub updated_by_name: String,
     pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
     pub updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub version: i32,
     pub category_id: uuid::Uuid,
     pub category_name: String,
 }
@@ -68,8 +91,10 @@ impl TryFrom<ContentsRow> for Content {
             tags,
             status,
             published_at,
+            unpublish_at,
             created_at,
             updated_at,
+            version,
             category_id,
             category_name,
             created_by_id,
@@ -79,39 +104,351 @@ impl TryFrom<ContentsRow> for Content {
             ..
         } = row;
 
-        let categories = Categories::new(category_id.into(), category_name);
-        let deserialized_fields: Vec<Field> = serde_json::from_value(fields)?;
-        let deserialized_tags: Vec<Tags> = serde_json::from_value(tags)?;
-        let published_at_str: Option<String> = match published_at {
-            Some(datetime) => Some(datetime.to_string()),
-            None => None,
-        };
+        let category = ContentCategory::new(category_id.into(), category_name);
+        let deserialized_tags: Vec<ContentTag> = serde_json::from_value(tags)?;
         let created_by = CreatedBy::new(created_by_id.into(), created_by_name);
         let updated_by = UpdatedBy::new(updated_by_id.into(), updated_by_name);
 
-        Ok(Self {
-            id: id.to_string(),
+        let mut content = Content::try_new(
+            id,
             title,
-            categories,
-            status: status.into(),
-            fields: deserialized_fields,
-            tags: deserialized_tags,
-            published_at: published_at_str,
-            created_at: created_at.to_string(),
-            updated_at: updated_at.to_string(),
+            category,
+            status.into(),
+            fields,
+            deserialized_tags,
             created_by,
             updated_by,
+            published_at,
+            unpublish_at,
+            created_at,
+            updated_at,
+        )?;
+        content.version = version;
+
+        Ok(content)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct SearchContentRow {
+    #[sqlx(flatten)]
+    pub content: ContentsRow,
+    pub search_text: String,
+    pub search_rank: Option<f32>,
+    pub search_snippet: Option<String>,
+}
+
+const HEADLINE_OPTIONS: &str = "StartSel=<mark>, StopSel=</mark>, MaxWords=30, MinWords=10, MaxFragments=2, FragmentDelimiter=…";
+
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{}%", escaped)
+}
+
+fn push_search_columns(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    language: &SearchLanguage,
+    keyword: &Option<String>,
+) {
+    let Some(keyword) = keyword else {
+        query_builder.push("NULL::REAL AS search_rank, NULL::TEXT AS search_snippet");
+        return;
+    };
+
+    match language {
+        SearchLanguage::TextSearch(config) => {
+            query_builder.push("ts_rank_cd(contents.search_vector, websearch_to_tsquery(");
+            query_builder.push_bind(config.clone());
+            query_builder.push("::REGCONFIG, ");
+            query_builder.push_bind(keyword.clone());
+            query_builder.push(")) AS search_rank, ts_headline(");
+            query_builder.push_bind(config.clone());
+            query_builder.push("::REGCONFIG, contents.search_text, websearch_to_tsquery(");
+            query_builder.push_bind(config.clone());
+            query_builder.push("::REGCONFIG, ");
+            query_builder.push_bind(keyword.clone());
+            query_builder.push("), ");
+            query_builder.push_bind(HEADLINE_OPTIONS);
+            query_builder.push(") AS search_snippet");
+        }
+        SearchLanguage::Trigram => {
+            query_builder.push("word_similarity(");
+            query_builder.push_bind(keyword.clone());
+            query_builder
+                .push(", contents.search_text) AS search_rank, NULL::TEXT AS search_snippet");
+        }
+    }
+}
+
+fn push_search_filter(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    language: &SearchLanguage,
+    keyword: &str,
+) {
+    match language {
+        SearchLanguage::TextSearch(config) => {
+            query_builder.push(" AND contents.search_vector @@ websearch_to_tsquery(");
+            query_builder.push_bind(config.clone());
+            query_builder.push("::REGCONFIG, ");
+            query_builder.push_bind(keyword.to_string());
+            query_builder.push(")");
+        }
+        SearchLanguage::Trigram => {
+            let patterns: Vec<String> = search_terms(keyword)
+                .iter()
+                .map(|term| like_pattern(term))
+                .collect();
+            query_builder.push(" AND contents.search_text ILIKE ALL(");
+            query_builder.push_bind(patterns);
+            query_builder.push(")");
+        }
+    }
+}
+
+fn push_tag_filter(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    tag_ids: Vec<uuid::Uuid>,
+    tag_match: TagMatch,
+) {
+    match tag_match {
+        TagMatch::Any => {
+            query_builder.push(
+                " AND EXISTS (SELECT 1 FROM content_tags WHERE content_tags.content_id = contents.id AND content_tags.tag_id = ANY(",
+            );
+            query_builder.push_bind(tag_ids);
+            query_builder.push("))");
+        }
+        TagMatch::All => {
+            query_builder.push(
+                " AND (SELECT COUNT(DISTINCT content_tags.tag_id) FROM content_tags WHERE content_tags.content_id = contents.id AND content_tags.tag_id = ANY(",
+            );
+            query_builder.push_bind(tag_ids.clone());
+            query_builder.push(")) = CARDINALITY(ARRAY(SELECT DISTINCT UNNEST(");
+            query_builder.push_bind(tag_ids);
+            query_builder.push("::UUID[])))");
+        }
+    }
+}
+
+fn push_date_range(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    column: &str,
+    range: DateRange,
+) {
+    let DateRange { gt, gte, lt, lte } = range;
+
+    for (operator, value) in [(">", gt), (">=", gte), ("<", lt), ("<=", lte)] {
+        if let Some(value) = value {
+            query_builder.push(format!(" AND {} {} ", column, operator));
+            query_builder.push_bind(value);
+        }
+    }
+}
+
+fn push_field_filter(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    field_filter: FieldFilter,
+) {
+    let FieldFilter {
+        key,
+        operator,
+        value,
+    } = field_filter;
+
+    let path = match operator {
+        FilterOperator::Eq | FilterOperator::Ne => "$[*] ? (@ == $value)",
+        FilterOperator::Gt => "$[*] ? (@ > $value)",
+        FilterOperator::Gte => "$[*] ? (@ >= $value)",
+        FilterOperator::Lt => "$[*] ? (@ < $value)",
+        FilterOperator::Lte => "$[*] ? (@ <= $value)",
+        FilterOperator::In => "$[*] ? (@ == $value[*])",
+        FilterOperator::Contains => {
+            query_builder.push(" AND contents.fields ->> ");
+            query_builder.push_bind(key);
+            query_builder.push(" ILIKE ");
+            query_builder.push_bind(like_pattern(value.as_str().unwrap_or_default()));
+            return;
+        }
+        FilterOperator::Exists => {
+            query_builder.push(match value.as_bool() {
+                Some(false) => " AND NOT contents.fields ? ",
+                _ => " AND contents.fields ? ",
+            });
+            query_builder.push_bind(key);
+            return;
+        }
+    };
+
+    query_builder.push(match operator {
+        FilterOperator::Ne => " AND NOT COALESCE(jsonb_path_exists(contents.fields -> ",
+        _ => " AND COALESCE(jsonb_path_exists(contents.fields -> ",
+    });
+    query_builder.push_bind(key);
+    query_builder.push(", ");
+    query_builder.push_bind(path);
+    query_builder.push("::JSONPATH, jsonb_build_object('value', ");
+    query_builder.push_bind(value);
+    query_builder.push("::JSONB)), FALSE)");
+}
+
+#[derive(Debug, Clone)]
+struct ContentConditions {
+    category_ids: Option<Vec<uuid::Uuid>>,
+    keyword: Option<String>,
+    category_id: Option<uuid::Uuid>,
+    tag_ids: Option<Vec<uuid::Uuid>>,
+    tag_match: TagMatch,
+    statuses: Option<Vec<ContentStatus>>,
+    created_by_ids: Option<Vec<uuid::Uuid>>,
+    filter: ContentFilter,
+}
+
+fn push_content_conditions(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    language: &SearchLanguage,
+    conditions: ContentConditions,
+) {
+    let ContentConditions {
+        category_ids,
+        keyword,
+        category_id,
+        tag_ids,
+        tag_match,
+        statuses,
+        created_by_ids,
+        filter,
+    } = conditions;
+
+    if let Some(category_ids) = category_ids {
+        query_builder.push(" AND contents.category_id = ANY(");
+        query_builder.push_bind(category_ids);
+        query_builder.push(")");
+    }
+
+    if let Some(keyword) = &keyword {
+        push_search_filter(query_builder, language, keyword);
+    }
+
+    if let Some(category_id) = category_id {
+        query_builder.push(" AND contents.category_id = ");
+        query_builder.push_bind(category_id);
+    }
+
+    if let Some(tag_ids) = tag_ids {
+        push_tag_filter(query_builder, tag_ids, tag_match);
+    }
+
+    if let Some(statuses) = statuses {
+        let statuses: Vec<String> = statuses
+            .into_iter()
+            .map(|status| format!("{:?}", status))
+            .collect();
+        query_builder.push(" AND contents.status::TEXT = ANY(");
+        query_builder.push_bind(statuses);
+        query_builder.push(")");
+    }
+
+    if let Some(created_by_ids) = created_by_ids {
+        query_builder.push(" AND contents.created_by = ANY(");
+        query_builder.push_bind(created_by_ids);
+        query_builder.push(")");
+    }
+
+    push_date_range(query_builder, "contents.published_at", filter.published_at);
+    push_date_range(query_builder, "contents.updated_at", filter.updated_at);
+
+    for field_filter in filter.fields {
+        push_field_filter(query_builder, field_filter);
+    }
+}
+
+fn push_sort_order(query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>, order: SortOrder) {
+    match order.key {
+        SortKey::Title => query_builder.push("contents.title"),
+        SortKey::Status => query_builder.push("contents.status"),
+        SortKey::PublishedAt => query_builder.push("contents.published_at"),
+        SortKey::UpdatedAt => query_builder.push("contents.updated_at"),
+        SortKey::CreatedAt => query_builder.push("contents.created_at"),
+        SortKey::Field(key) => {
+            query_builder.push("contents.fields -> ");
+            query_builder.push_bind(key)
+        }
+    };
+
+    query_builder.push(match order.descending {
+        true => " DESC NULLS LAST",
+        false => " ASC NULLS LAST",
+    });
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct RelatedContentRow {
+    pub source_id: uuid::Uuid,
+    pub field_key: String,
+    pub position: i32,
+    #[sqlx(flatten)]
+    pub content: ContentsRow,
+}
+
+impl TryFrom<RelatedContentRow> for RelatedContent {
+    type Error = anyhow::Error;
+    fn try_from(row: RelatedContentRow) -> anyhow::Result<Self> {
+        let RelatedContentRow {
+            source_id,
+            field_key,
+            position,
+            content,
+        } = row;
+
+        Ok(RelatedContent {
+            content_id: source_id,
+            field_key,
+            position,
+            content: Content::try_from(content)?,
         })
     }
 }
 
+#[derive(Debug, sqlx::FromRow)]
+pub struct ContentReferrerRow {
+    pub content_id: uuid::Uuid,
+    pub content_title: String,
+    pub field_key: String,
+}
+
+impl From<ContentReferrerRow> for ContentReferrer {
+    fn from(row: ContentReferrerRow) -> Self {
+        let ContentReferrerRow {
+            content_id,
+            content_title,
+            field_key,
+        } = row;
+
+        ContentReferrer::new(content_id, content_title, field_key)
+    }
+}
+
+pub(crate) fn parse_ids(ids: Option<Vec<String>>) -> anyhow::Result<Option<Vec<uuid::Uuid>>> {
+    ids.map(|ids| {
+        ids.iter()
+            .map(|id| uuid::Uuid::parse_str(id))
+            .collect::<Result<Vec<_>, _>>()
+    })
+    .transpose()
+    .map_err(anyhow::Error::from)
+}
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct TagRow {
     pub id: uuid::Uuid,
     pub name: String,
 }
 
-impl From<TagRow> for Tags {
+impl From<TagRow> for ContentTag {
     fn from(value: TagRow) -> Self {
         let TagRow { id, name } = value;
 
@@ -129,12 +466,14 @@ pub struct ContentRow {
     pub fields: serde_json::Value,
     pub status: ContentRowStatus,
     pub published_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    pub unpublish_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
     pub created_by_id: uuid::Uuid,
     pub created_by_name: String,
     pub updated_by_id: uuid::Uuid,
     pub updated_by_name: String,
     pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
     pub updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub version: i32,
     pub category_id: uuid::Uuid,
     pub category_name: String,
 }
@@ -146,8 +485,10 @@ fn try_new_content(content_row: ContentRow, tag_rows: Vec<TagRow>) -> anyhow::Re
         fields,
         status,
         published_at,
+        unpublish_at,
         created_at,
         updated_at,
+        version,
         category_id,
         category_name,
         created_by_id,
@@ -157,81 +498,429 @@ fn try_new_content(content_row: ContentRow, tag_rows: Vec<TagRow>) -> anyhow::Re
         ..
     } = content_row;
 
-    let categories = Categories::new(category_id.into(), category_name);
-    let deserialized_fields: Vec<Field> = serde_json::from_value(fields)?;
-    let published_at_str: Option<String> = match published_at {
-        Some(datetime) => Some(datetime.to_string()),
-        None => None,
-    };
+    let category = ContentCategory::new(category_id.into(), category_name);
+    let tags = tag_rows.into_iter().map(ContentTag::from).collect();
     let created_by = CreatedBy::new(created_by_id.into(), created_by_name);
     let updated_by = UpdatedBy::new(updated_by_id.into(), updated_by_name);
 
-    Ok(Content {
-        id: id.to_string(),
+    let mut content = Content::try_new(
+        id,
         title,
-        categories,
-        status: status.into(),
-        fields: deserialized_fields,
-        tags: tag_rows.into_iter().map(Tags::from).collect(),
-        published_at: published_at_str,
-        created_at: created_at.to_string(),
-        updated_at: updated_at.to_string(),
+        category,
+        status.into(),
+        fields,
+        tags,
         created_by,
         updated_by,
-    })
+        published_at,
+        unpublish_at,
+        created_at,
+        updated_at,
+    )?;
+    content.version = version;
+
+    Ok(content)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct CategoryFieldsRow {
+    fields: sqlx::types::Json<Vec<Field>>,
+}
+
+async fn validate_content_fields(
+    conn: &mut sqlx::PgConnection,
+    content_id: uuid::Uuid,
+    category_id: uuid::Uuid,
+    fields: &serde_json::Value,
+) -> anyhow::Result<Vec<Field>> {
+    let category_row = sqlx::query_as::<_, CategoryFieldsRow>(
+        r#"SELECT fields FROM category WHERE id = $1 AND deleted_at IS NULL FOR SHARE"#,
+    )
+    .bind(category_id)
+    .fetch_one(&mut *conn)
+    .await?;
+    let schema = category_row.fields.0;
+
+    let mut errors = match validate_fields(&schema, fields) {
+        Ok(()) => vec![],
+        Err(error) => error.errors,
+    };
+
+    for field in schema.iter().filter(|field| field.unique) {
+        let value = match fields.get(&field.key) {
+            Some(value) if !value.is_null() => value,
+            _ => continue,
+        };
+
+        if errors.iter().any(|error| error.key == field.key) {
+            continue;
+        }
+
+        sqlx::query(r#"SELECT pg_advisory_xact_lock(hashtext($1))"#)
+            .bind(format!("{}:{}:{}", category_id, field.key, value))
+            .execute(&mut *conn)
+            .await?;
+
+        let duplicated: bool = sqlx::query_scalar(
+            r#"
+                SELECT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        contents
+                    WHERE
+                        category_id = $1
+                        AND fields -> $2 = $3
+                        AND id <> $4
+                )
+            "#,
+        )
+        .bind(category_id)
+        .bind(&field.key)
+        .bind(value)
+        .bind(content_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if duplicated {
+            errors.push(FieldError::new(field.key.clone(), "must be unique".into()));
+        }
+    }
+
+    let references = field_references(&schema, fields, FieldType::Media);
+    let media_ids: Vec<uuid::Uuid> = references.iter().map(|reference| reference.id).collect();
+    let existing_ids: Vec<uuid::Uuid> =
+        sqlx::query_scalar(r#"SELECT id FROM media WHERE id = ANY($1) AND deleted_at IS NULL"#)
+            .bind(&media_ids)
+            .fetch_all(&mut *conn)
+            .await?;
+
+    for reference in references {
+        if !existing_ids.contains(&reference.id) {
+            errors.push(FieldError::new(
+                reference.key,
+                "references unknown media".into(),
+            ));
+        }
+    }
+
+    let references = field_references(&schema, fields, FieldType::Content);
+    let content_ids: Vec<uuid::Uuid> = references.iter().map(|reference| reference.id).collect();
+    let targets = sqlx::query_as::<_, (uuid::Uuid, uuid::Uuid)>(
+        r#"SELECT id, category_id FROM contents WHERE id = ANY($1) AND deleted_at IS NULL"#,
+    )
+    .bind(&content_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for reference in references {
+        if errors.iter().any(|error| error.key == reference.key) {
+            continue;
+        }
+
+        let target_category_id = targets
+            .iter()
+            .find(|(id, _)| *id == reference.id)
+            .map(|(_, category_id)| category_id.to_string());
+        let allowed_categories = schema
+            .iter()
+            .find(|field| field.key == reference.key)
+            .map(|field| field.categories.as_slice())
+            .unwrap_or_default();
+
+        match target_category_id {
+            None => errors.push(FieldError::new(
+                reference.key,
+                "references unknown content".into(),
+            )),
+            Some(category_id)
+                if !allowed_categories.is_empty() && !allowed_categories.contains(&category_id) =>
+            {
+                errors.push(FieldError::new(
+                    reference.key,
+                    "references content outside the allowed categories".into(),
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(FieldValidationError { errors }.into());
+    }
+
+    Ok(schema)
+}
+
+async fn sync_content_relations(
+    conn: &mut sqlx::PgConnection,
+    content_id: uuid::Uuid,
+    schema: &[Field],
+    fields: &serde_json::Value,
+) -> anyhow::Result<()> {
+    let references = field_references(schema, fields, FieldType::Content);
+    let mut field_keys: Vec<String> = vec![];
+    let mut positions: Vec<i32> = vec![];
+    let mut target_ids: Vec<uuid::Uuid> = vec![];
+
+    for reference in references {
+        let position = field_keys
+            .iter()
+            .filter(|key| **key == reference.key)
+            .count();
+        field_keys.push(reference.key);
+        positions.push(position as i32);
+        target_ids.push(reference.id);
+    }
+
+    let delete_content_relations =
+        sqlx::query(r#"DELETE FROM content_relations WHERE content_id = $1"#)
+            .bind(content_id)
+            .execute(&mut *conn)
+            .await?;
+
+    tracing::info!("{:?}", delete_content_relations);
+
+    let insert_content_relations = sqlx::query(
+        r#"
+            INSERT INTO
+                content_relations (content_id, field_key, position, target_id)
+            SELECT
+                $1,
+                field_key,
+                position,
+                target_id
+            FROM
+                UNNEST($2::VARCHAR[], $3::INTEGER[], $4::UUID[]) AS relation(field_key, position, target_id)
+        "#,
+    )
+    .bind(content_id)
+    .bind(field_keys)
+    .bind(positions)
+    .bind(target_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    tracing::info!("{:?}", insert_content_relations);
+
+    Ok(())
+}
+
+async fn sync_media_references(
+    conn: &mut sqlx::PgConnection,
+    content_id: uuid::Uuid,
+    schema: &[Field],
+    fields: &serde_json::Value,
+) -> anyhow::Result<()> {
+    let (field_keys, media_ids): (Vec<String>, Vec<uuid::Uuid>) =
+        field_references(schema, fields, FieldType::Media)
+            .into_iter()
+            .map(|reference| (reference.key, reference.id))
+            .unzip();
+
+    let delete_content_media = sqlx::query(r#"DELETE FROM content_media WHERE content_id = $1"#)
+        .bind(content_id)
+        .execute(&mut *conn)
+        .await?;
+
+    tracing::info!("{:?}", delete_content_media);
+
+    let insert_content_media = sqlx::query(
+        r#"
+            INSERT INTO
+                content_media (content_id, field_key, media_id)
+            SELECT
+                $1,
+                field_key,
+                media_id
+            FROM
+                UNNEST($2::VARCHAR[], $3::UUID[]) AS reference(field_key, media_id)
+        "#,
+    )
+    .bind(content_id)
+    .bind(field_keys)
+    .bind(media_ids)
+    .execute(&mut *conn)
+    .await?;
+
+    tracing::info!("{:?}", insert_content_media);
+
+    Ok(())
+}
+
+async fn validate_content_tags(
+    conn: &mut sqlx::PgConnection,
+    tag_ids: &[uuid::Uuid],
+) -> anyhow::Result<()> {
+    let trashed = sqlx::query_scalar::<_, bool>(
+        r#"SELECT deleted_at IS NOT NULL FROM tags WHERE id = ANY($1) FOR SHARE"#,
+    )
+    .bind(tag_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    if trashed.into_iter().any(|trashed| trashed) {
+        return Err(TrashError::TagInTrash.into());
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn fetch_content(
+    executor: impl sqlx::PgExecutor<'_>,
+    id: uuid::Uuid,
+) -> anyhow::Result<Content> {
+    let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT ");
+    push_content_select(&mut query_builder, "contents");
+    query_builder.push(" AND contents.id = ");
+    query_builder.push_bind(id);
+
+    let row = query_builder
+        .build_query_as::<ContentsRow>()
+        .fetch_one(executor)
+        .await?;
+
+    tracing::info!("{:?}", row);
+
+    Content::try_from(row)
+}
+
+pub(crate) async fn insert_content_event(
+    conn: &mut sqlx::PgConnection,
+    event: WebhookEvent,
+    content: &Content,
+) -> anyhow::Result<()> {
+    let category_id = uuid::Uuid::parse_str(&content.category.id)?;
+    insert_outbox_event(conn, event, Some(category_id), content).await?;
+
+    Ok(())
 }
 
 #[derive(derive_new::new, Debug)]
 pub struct ContentRepositoryImpl {
     db: ConnectionPool,
+    search_language: SearchLanguage,
 }
 
 #[async_trait::async_trait]
 impl ContentRepository for ContentRepositoryImpl {
     #[tracing::instrument]
-    async fn get(&self, query: GetContentQuery) -> anyhow::Result<Vec<Content>> {
-        let rows = sqlx::query_as::<_, ContentsRow>(
-            r#"
-                SELECT
-                    contents.*,
-                    category.name AS category_name,
-                    created_by.id AS created_by_id,
-                    created_by.name AS created_by_name,
-                    updated_by.id AS updated_by_id,
-                    updated_by.name AS updated_by_name,
-                    (
-                        SELECT
-                            COALESCE(json_agg(json_build_object('id', tags.id, 'name', tags.name)) 
-                            FILTER (WHERE tags.id IS NOT NULL), '[]'::json)
-                        FROM
-                            tags
-                        JOIN
-                            content_tags ON content_tags.tag_id = tags.id
-                        WHERE
-                            content_tags.content_id = contents.id
-                    ) AS tags
-                FROM
-                    contents 
-                JOIN
-                    category ON contents.category_id = category.id
-                JOIN
-                    users AS created_by ON created_by.id = contents.created_by
-                JOIN
-                    users AS updated_by ON updated_by.id = contents.updated_by
-               ORDER BY
-                    contents.created_at DESC
-                LIMIT $1
-                OFFSET $2
-            "#,
-        )
-        .bind(query.limit)
-        .bind(query.offset)
-        .fetch_all(self.db.inner_ref())
-        .await?;
+    async fn get(&self, query: GetContentQuery) -> anyhow::Result<Page<Content>> {
+        let GetContentQuery {
+            page,
+            keyword,
+            category_id,
+            tag_ids,
+            tag_match,
+            statuses,
+            created_by_ids,
+            filter,
+            sort,
+            category_ids,
+        } = query;
+        let keyword = keyword.filter(|keyword| !search_terms(keyword).is_empty());
+        let conditions = ContentConditions {
+            category_ids: parse_ids(category_ids)?,
+            keyword: keyword.clone(),
+            category_id: category_id
+                .map(|id| uuid::Uuid::parse_str(&id))
+                .transpose()?,
+            tag_ids: parse_ids(tag_ids)?,
+            tag_match,
+            statuses,
+            created_by_ids: parse_ids(created_by_ids)?,
+            filter,
+        };
+        let keyset = keyword.is_none() && sort.is_empty();
+
+        let mut conn = self.db.acquire().await?;
+
+        let total = match page.with_total {
+            true => {
+                let mut count_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+                    "SELECT COUNT(*) FROM contents WHERE contents.deleted_at IS NULL",
+                );
+                push_content_conditions(
+                    &mut count_builder,
+                    &self.search_language,
+                    conditions.clone(),
+                );
+
+                Some(fetch_total(&mut *conn, count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT ");
+        push_search_columns(&mut query_builder, &self.search_language, &keyword);
+        query_builder.push(", ");
+        push_content_select(&mut query_builder, "contents");
+        push_content_conditions(&mut query_builder, &self.search_language, conditions);
+
+        let offset = match keyset {
+            true => {
+                push_keyset(&mut query_builder, "contents.id", &page, true)?;
+                None
+            }
+            false => {
+                let offset = page.offset()?;
+                query_builder.push(" ORDER BY ");
+                if keyword.is_some() {
+                    query_builder.push("search_rank DESC NULLS LAST, ");
+                }
+                for order in sort {
+                    push_sort_order(&mut query_builder, order);
+                    query_builder.push(", ");
+                }
+                query_builder.push("contents.created_at DESC, contents.id DESC LIMIT ");
+                query_builder.push_bind(page.fetch_limit());
+                query_builder.push(" OFFSET ");
+                query_builder.push_bind(offset);
+                Some(offset)
+            }
+        };
+
+        tracing::info!("{:?}", query_builder.sql());
+
+        let rows = query_builder
+            .build_query_as::<SearchContentRow>()
+            .fetch_all(&mut *conn)
+            .await?;
 
         tracing::info!("{:?}", rows);
 
-        rows.into_iter().map(Content::try_from).collect()
+        let rows = match offset {
+            Some(offset) => Page::from_offset_rows(rows, &page, offset, total),
+            None => Page::from_keyset_rows(rows, &page, |row| row.content.id, total),
+        };
+
+        rows.try_map(|row| {
+            let SearchContentRow {
+                content,
+                search_text,
+                search_rank,
+                search_snippet,
+            } = row;
+            let mut content = Content::try_from(content)?;
+
+            if let (Some(keyword), Some(rank)) = (&keyword, search_rank) {
+                let snippet = search_snippet
+                    .or_else(|| highlight(&search_text, keyword, SNIPPET_CONTEXT))
+                    .unwrap_or_default();
+                content.search = Some(SearchMatch::new(rank, snippet));
+            }
+
+            Ok(content)
+        })
+    }
+
+    #[tracing::instrument]
+    async fn find(&self, id: String) -> anyhow::Result<Content> {
+        let parsed_content_id = uuid::Uuid::parse_str(&id)?;
+
+        let mut conn = self.db.acquire().await?;
+
+        fetch_content(&mut *conn, parsed_content_id).await
     }
 
     #[tracing::instrument]
@@ -242,6 +931,8 @@ impl ContentRepository for ContentRepositoryImpl {
             fields,
             status,
             tag_ids,
+            published_at,
+            unpublish_at,
             created_by_id,
             updated_by_id,
         } = data;
@@ -256,6 +947,10 @@ impl ContentRepository for ContentRepositoryImpl {
             .map(|id| uuid::Uuid::from_str(&id))
             .collect::<Result<Vec<_>, _>>()?;
 
+        let mut transaction = self.db.begin().await?;
+        let schema = validate_content_fields(&mut transaction, uuid, category_id, &fields).await?;
+        validate_content_tags(&mut transaction, &tag_uuids).await?;
+
         let content_row = sqlx::query_as::<_, ContentRow>(
             r#"
                 WITH inserted AS (
@@ -266,14 +961,17 @@ impl ContentRepository for ContentRepositoryImpl {
                             category_id,
                             fields,
                             status,
+                            published_at,
+                            unpublish_at,
                             created_by,
-                            updated_by
+                            updated_by,
+                            search_config
                         )
-                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10::REGCONFIG)
                     RETURNING *
                 )
                 SELECT
-                    *,
+                    inserted.*,
                     category.name AS category_name,
                     created_by.id AS created_by_id,
                     created_by.name AS created_by_name,
@@ -292,18 +990,21 @@ impl ContentRepository for ContentRepositoryImpl {
         .bind(uuid)
         .bind(title)
         .bind(category_id)
-        .bind(fields)
+        .bind(&fields)
         .bind(status)
+        .bind(published_at)
+        .bind(unpublish_at)
         .bind(created_by)
         .bind(updated_by)
-        .fetch_one(self.db.inner_ref())
+        .bind(self.search_language.text_search_config())
+        .fetch_one(&mut *transaction)
         .await?;
 
         tracing::info!("{:?}", content_row);
 
         let delete_content_tags = sqlx::query(r#"DELETE FROM content_tags WHERE content_id = $1"#)
             .bind(uuid)
-            .execute(self.db.inner_ref())
+            .execute(&mut *transaction)
             .await?;
 
         tracing::info!("{:?}", delete_content_tags);
@@ -331,12 +1032,26 @@ impl ContentRepository for ContentRepositoryImpl {
         )
         .bind(uuid)
         .bind(tag_uuids)
-        .fetch_all(self.db.inner_ref())
+        .fetch_all(&mut *transaction)
         .await?;
 
         tracing::info!("{:?}", tag_rows);
 
-        try_new_content(content_row, tag_rows)
+        sync_media_references(&mut transaction, uuid, &schema, &fields).await?;
+        sync_content_relations(&mut transaction, uuid, &schema, &fields).await?;
+        insert_revision(&mut transaction, uuid).await?;
+
+        let content = try_new_content(content_row, tag_rows)?;
+        insert_content_event(&mut transaction, WebhookEvent::ContentCreated, &content).await?;
+
+        if content.status == ContentStatus::Published {
+            insert_content_event(&mut transaction, WebhookEvent::ContentPublished, &content)
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(content)
     }
 
     #[tracing::instrument]
@@ -347,12 +1062,57 @@ impl ContentRepository for ContentRepositoryImpl {
             category_id,
             fields,
             tag_ids,
-            status,
+            unpublish_at,
             updated_by_id,
+            expected_version,
+            clear_unpublish_at,
         } = data;
 
         let parsed_content_id = uuid::Uuid::parse_str(&id)?;
-        let mut transaction = self.db.inner_ref().begin().await?;
+        let parsed_category_id = category_id
+            .map(|id| uuid::Uuid::parse_str(&id))
+            .transpose()?;
+        let mut transaction = self.db.begin().await?;
+
+        let current_version = sqlx::query_scalar::<_, i32>(
+            r#"SELECT version FROM contents WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+        .bind(parsed_content_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(current_version) = current_version else {
+            anyhow::bail!("Content {} is not found", id);
+        };
+
+        if let Some(expected_version) = expected_version.filter(|v| *v != current_version) {
+            let current = fetch_content(&mut *transaction, parsed_content_id).await?;
+
+            return Err(VersionConflict::try_new(expected_version, &current)?.into());
+        }
+
+        if fields.is_some() || parsed_category_id.is_some() {
+            let (current_category_id, current_fields) =
+                sqlx::query_as::<_, (uuid::Uuid, serde_json::Value)>(
+                    r#"SELECT category_id, fields FROM contents WHERE id = $1 FOR UPDATE"#,
+                )
+                .bind(parsed_content_id)
+                .fetch_one(&mut *transaction)
+                .await?;
+
+            let next_fields = fields.as_ref().unwrap_or(&current_fields);
+            let schema = validate_content_fields(
+                &mut transaction,
+                parsed_content_id,
+                parsed_category_id.unwrap_or(current_category_id),
+                next_fields,
+            )
+            .await?;
+            sync_media_references(&mut transaction, parsed_content_id, &schema, next_fields)
+                .await?;
+            sync_content_relations(&mut transaction, parsed_content_id, &schema, next_fields)
+                .await?;
+        }
 
         let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
             "
@@ -367,8 +1127,7 @@ impl ContentRepository for ContentRepositoryImpl {
             separated.push_bind_unseparated(title);
         }
 
-        if let Some(category_id) = category_id {
-            let parsed_category_id = uuid::Uuid::parse_str(&category_id)?;
+        if let Some(parsed_category_id) = parsed_category_id {
             separated.push("category_id = ");
             separated.push_bind_unseparated(parsed_category_id);
         }
@@ -378,15 +1137,20 @@ impl ContentRepository for ContentRepositoryImpl {
             separated.push_bind_unseparated(fields);
         }
 
-        if let Some(status) = status {
-            separated.push("status = ");
-            let content_row_status: ContentRowStatus = status.into();
-            separated.push_bind_unseparated(content_row_status);
+        if let Some(unpublish_at) = unpublish_at {
+            separated.push("unpublish_at = ");
+            separated.push_bind_unseparated(unpublish_at);
+        } else if clear_unpublish_at {
+            separated.push("unpublish_at = NULL");
         }
 
         let parsed_updated_by = uuid::Uuid::parse_str(&updated_by_id)?;
         separated.push("updated_by = ");
         separated.push_bind_unseparated(parsed_updated_by);
+        separated.push("search_config = ");
+        separated.push_bind_unseparated(self.search_language.text_search_config());
+        separated.push_unseparated("::REGCONFIG");
+        separated.push("version = version + 1");
 
         query_builder.push(" WHERE id = ");
         query_builder.push_bind(parsed_content_id);
@@ -396,7 +1160,7 @@ impl ContentRepository for ContentRepositoryImpl {
                     RETURNING *
                 )
                 SELECT
-                    *,
+                    updated.*,
                     category.name AS category_name,
                     created_by.id AS created_by_id,
                     created_by.name AS created_by_name,
@@ -428,6 +1192,8 @@ impl ContentRepository for ContentRepositoryImpl {
                 .map(|id| uuid::Uuid::from_str(&id))
                 .collect::<Result<Vec<_>, _>>()?;
 
+            validate_content_tags(&mut transaction, &tag_uuids).await?;
+
             let delete_content_tags =
                 sqlx::query(r#"DELETE FROM content_tags WHERE content_id = $1"#)
                     .bind(parsed_content_id)
@@ -465,6 +1231,7 @@ impl ContentRepository for ContentRepositoryImpl {
                     tags ON tags.id = content_tags.tag_id 
                 WHERE
                     content_tags.content_id = $1
+                    AND tags.deleted_at IS NULL
             "#,
         )
         .bind(parsed_content_id)
@@ -473,19 +1240,290 @@ impl ContentRepository for ContentRepositoryImpl {
 
         tracing::info!("{:?}", tag_rows);
 
-        try_new_content(content_row, tag_rows)
+        insert_revision(&mut transaction, parsed_content_id).await?;
+
+        let content = try_new_content(content_row, tag_rows)?;
+        insert_content_event(&mut transaction, WebhookEvent::ContentUpdated, &content).await?;
+        transaction.commit().await?;
+
+        Ok(content)
+    }
+
+    #[tracing::instrument]
+    async fn update_status(&self, data: UpdateContentStatus) -> anyhow::Result<Content> {
+        let UpdateContentStatus {
+            id,
+            from,
+            change,
+            updated_by_id,
+        } = data;
+
+        let parsed_content_id = uuid::Uuid::parse_str(&id)?;
+        let parsed_updated_by = uuid::Uuid::parse_str(&updated_by_id)?;
+        let mut transaction = self.db.begin().await?;
+
+        let current_status = sqlx::query_scalar::<_, ContentRowStatus>(
+            r#"SELECT status FROM contents WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+        .bind(parsed_content_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(current_status) = current_status else {
+            anyhow::bail!("Content {} is not found", id);
+        };
+        let current_status: ContentStatus = current_status.into();
+
+        if current_status != from {
+            anyhow::bail!(ContentStatusError::InvalidTransition {
+                from: current_status,
+                to: change.status,
+            });
+        }
+
+        let status: ContentRowStatus = change.status.into();
+
+        let result = sqlx::query(
+            r#"
+                UPDATE contents SET
+                    status = $1,
+                    published_at = $2,
+                    unpublish_at = $3,
+                    updated_by = $4,
+                    version = version + 1
+                WHERE
+                    id = $5
+            "#,
+        )
+        .bind(status)
+        .bind(change.published_at)
+        .bind(change.unpublish_at)
+        .bind(parsed_updated_by)
+        .bind(parsed_content_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        insert_revision(&mut transaction, parsed_content_id).await?;
+
+        let content = fetch_content(&mut *transaction, parsed_content_id).await?;
+        let event = WebhookEvent::status_change(&current_status, &content.status);
+        insert_content_event(&mut transaction, event, &content).await?;
+        transaction.commit().await?;
+
+        Ok(content)
     }
 
     async fn delete(&self, id: String) -> anyhow::Result<()> {
         let parsed_content_id = uuid::Uuid::parse_str(&id)?;
+        let mut transaction = self.db.begin().await?;
+
+        let content = fetch_content(&mut *transaction, parsed_content_id).await?;
+
+        let result =
+            sqlx::query(r#"UPDATE contents SET deleted_at = CURRENT_TIMESTAMP(3) WHERE id = $1"#)
+                .bind(parsed_content_id)
+                .execute(&mut *transaction)
+                .await?;
+
+        tracing::info!("{:?}", result);
+
+        insert_content_event(&mut transaction, WebhookEvent::ContentDeleted, &content).await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn restore(
+        &self,
+        id: String,
+        category_ids: Option<Vec<String>>,
+    ) -> anyhow::Result<Content> {
+        let parsed_content_id = uuid::Uuid::parse_str(&id)?;
+        let category_ids = parse_ids(category_ids)?;
+        let mut transaction = self.db.begin().await?;
+
+        let category_deleted = sqlx::query_scalar::<_, bool>(
+            r#"
+                SELECT
+                    category.deleted_at IS NOT NULL
+                FROM
+                    contents
+                JOIN
+                    category ON category.id = contents.category_id
+                WHERE
+                    contents.id = $1
+                    AND contents.deleted_at IS NOT NULL
+                    AND ($2::UUID[] IS NULL OR contents.category_id = ANY($2))
+                FOR UPDATE OF contents
+            "#,
+        )
+        .bind(parsed_content_id)
+        .bind(category_ids)
+        .fetch_optional(&mut *transaction)
+        .await?;
 
-        let result = sqlx::query(r#"DELETE FROM contents WHERE id = $1"#)
+        match category_deleted {
+            None => return Err(TrashError::NotInTrash.into()),
+            Some(true) => return Err(TrashError::CategoryInTrash.into()),
+            Some(false) => {}
+        }
+
+        let result = sqlx::query(r#"UPDATE contents SET deleted_at = NULL WHERE id = $1"#)
             .bind(parsed_content_id)
-            .execute(self.db.inner_ref())
+            .execute(&mut *transaction)
             .await?;
 
         tracing::info!("{:?}", result);
 
-        Ok(())
+        let content = fetch_content(&mut *transaction, parsed_content_id).await?;
+        insert_content_event(&mut transaction, WebhookEvent::ContentRestored, &content).await?;
+        transaction.commit().await?;
+
+        Ok(content)
+    }
+
+    #[tracing::instrument]
+    async fn purge(
+        &self,
+        before: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        let mut conn = self.db.acquire().await?;
+
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+                WITH expired AS (
+                    SELECT
+                        id
+                    FROM
+                        contents
+                    WHERE
+                        deleted_at < $1
+                    ORDER BY
+                        deleted_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                DELETE FROM
+                    contents
+                USING
+                    expired
+                WHERE
+                    contents.id = expired.id
+                RETURNING
+                    contents.id
+            "#,
+        )
+        .bind(before)
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        tracing::info!("{:?}", ids);
+
+        Ok(ids)
+    }
+
+    #[tracing::instrument]
+    async fn find_related(&self, query: FindRelatedQuery) -> anyhow::Result<Vec<RelatedContent>> {
+        let FindRelatedQuery {
+            content_ids,
+            field_keys,
+            category_ids,
+        } = query;
+
+        let category_ids = parse_ids(category_ids)?;
+
+        let mut conn = self.db.acquire().await?;
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+            r#"
+                SELECT
+                    content_relations.content_id AS source_id,
+                    content_relations.field_key,
+                    content_relations.position,
+            "#,
+        );
+        push_content_select(
+            &mut query_builder,
+            "content_relations JOIN contents ON contents.id = content_relations.target_id",
+        );
+        query_builder.push(" AND content_relations.content_id = ANY(");
+        query_builder.push_bind(content_ids);
+        query_builder.push(") AND content_relations.field_key = ANY(");
+        query_builder.push_bind(field_keys);
+        query_builder.push(")");
+        if let Some(category_ids) = category_ids {
+            query_builder.push(" AND contents.category_id = ANY(");
+            query_builder.push_bind(category_ids);
+            query_builder.push(")");
+        }
+        query_builder.push(
+            r#"
+                ORDER BY
+                    content_relations.content_id,
+                    content_relations.field_key,
+                    content_relations.position
+            "#,
+        );
+
+        let rows = query_builder
+            .build_query_as::<RelatedContentRow>()
+            .fetch_all(&mut *conn)
+            .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(RelatedContent::try_from).collect()
+    }
+
+    #[tracing::instrument]
+    async fn referrers(
+        &self,
+        id: String,
+        category_ids: Option<Vec<String>>,
+    ) -> anyhow::Result<Vec<ContentReferrer>> {
+        let parsed_content_id = uuid::Uuid::parse_str(&id)?;
+        let category_ids = parse_ids(category_ids)?;
+
+        let mut conn = self.db.acquire().await?;
+
+        let rows = sqlx::query_as::<_, ContentReferrerRow>(
+            r#"
+                SELECT DISTINCT
+                    contents.id AS content_id,
+                    contents.title AS content_title,
+                    content_relations.field_key
+                FROM
+                    content_relations
+                JOIN
+                    contents ON contents.id = content_relations.content_id
+                WHERE
+                    content_relations.target_id = $1
+                    AND ($2::UUID[] IS NULL OR contents.category_id = ANY($2))
+                    AND contents.deleted_at IS NULL
+                ORDER BY
+                    contents.id, content_relations.field_key
+            "#,
+        )
+        .bind(parsed_content_id)
+        .bind(category_ids)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        tracing::info!("{:?}", rows);
+
+        Ok(rows.into_iter().map(ContentReferrer::from).collect())
+    }
+
+    #[tracing::instrument]
+    async fn find_localizations(
+        &self,
+        content_ids: Vec<uuid::Uuid>,
+    ) -> anyhow::Result<Vec<ContentLocalization>> {
+        fetch_localizations(&self.db, content_ids, true).await
     }
 }
@@ -0,0 +1,226 @@
+use domain::model::content::Content;
+use domain::model::locale::ContentLocalization;
+use domain::model::media::{ContentMedia, Media};
+use domain::model::relation::RelatedContent;
+use domain::repository::delivery::{
+    DeliveryRepository, FindDeliveryRelatedQuery, GetDeliveryQuery,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::content_localization_repository::fetch_localizations;
+use crate::database::content_query::push_content_select;
+use crate::database::contents_repository::{ContentsRow, RelatedContentRow};
+use crate::storage::media::MediaRow;
+
+#[derive(Debug, sqlx::FromRow)]
+struct ContentMediaRow {
+    content_id: uuid::Uuid,
+    field_key: String,
+    #[sqlx(flatten)]
+    media: MediaRow,
+}
+
+impl TryFrom<ContentMediaRow> for ContentMedia {
+    type Error = anyhow::Error;
+    fn try_from(row: ContentMediaRow) -> anyhow::Result<Self> {
+        let ContentMediaRow {
+            content_id,
+            field_key,
+            media,
+        } = row;
+
+        Ok(ContentMedia {
+            content_id,
+            field_key,
+            media: Media::try_from(media)?,
+        })
+    }
+}
+
+fn push_visibility(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    include_drafts: bool,
+) {
+    query_builder.push(" AND category.deleted_at IS NULL AND (");
+    query_builder.push_bind(include_drafts);
+    query_builder.push(
+        r#"
+            OR (
+                contents.status = 'Published'
+                AND (contents.unpublish_at IS NULL OR contents.unpublish_at > CURRENT_TIMESTAMP)
+            )
+        )
+        "#,
+    );
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct DeliveryRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl DeliveryRepository for DeliveryRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(
+        &self,
+        api_identifier: String,
+        query: GetDeliveryQuery,
+    ) -> anyhow::Result<Vec<Content>> {
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT ");
+        push_content_select(&mut query_builder, "contents");
+        query_builder.push(" AND category.api_identifier = ");
+        query_builder.push_bind(api_identifier);
+        push_visibility(&mut query_builder, query.include_drafts);
+        query_builder.push(
+            r#"
+                ORDER BY
+                    contents.published_at DESC NULLS FIRST,
+                    contents.updated_at DESC
+                LIMIT
+            "#,
+        );
+        query_builder.push_bind(query.limit);
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(query.offset);
+
+        let rows = query_builder
+            .build_query_as::<ContentsRow>()
+            .fetch_all(self.db.inner_ref())
+            .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(Content::try_from).collect()
+    }
+
+    #[tracing::instrument]
+    async fn find(
+        &self,
+        api_identifier: String,
+        id: String,
+        include_drafts: bool,
+    ) -> anyhow::Result<Content> {
+        let parsed_content_id = uuid::Uuid::parse_str(&id)?;
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT ");
+        push_content_select(&mut query_builder, "contents");
+        query_builder.push(" AND category.api_identifier = ");
+        query_builder.push_bind(api_identifier);
+        query_builder.push(" AND contents.id = ");
+        query_builder.push_bind(parsed_content_id);
+        push_visibility(&mut query_builder, include_drafts);
+
+        let row = query_builder
+            .build_query_as::<ContentsRow>()
+            .fetch_one(self.db.inner_ref())
+            .await?;
+
+        tracing::info!("{:?}", row);
+
+        Content::try_from(row)
+    }
+
+    #[tracing::instrument]
+    async fn find_related(
+        &self,
+        query: FindDeliveryRelatedQuery,
+    ) -> anyhow::Result<Vec<RelatedContent>> {
+        let FindDeliveryRelatedQuery {
+            content_ids,
+            field_keys,
+            include_drafts,
+            api_identifiers,
+        } = query;
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+            r#"
+                SELECT
+                    content_relations.content_id AS source_id,
+                    content_relations.field_key,
+                    content_relations.position,
+            "#,
+        );
+        push_content_select(
+            &mut query_builder,
+            "content_relations JOIN contents ON contents.id = content_relations.target_id",
+        );
+        query_builder.push(" AND content_relations.content_id = ANY(");
+        query_builder.push_bind(content_ids);
+        query_builder.push(") AND content_relations.field_key = ANY(");
+        query_builder.push_bind(field_keys);
+        query_builder.push(")");
+        if let Some(api_identifiers) = api_identifiers {
+            query_builder.push(" AND category.api_identifier = ANY(");
+            query_builder.push_bind(api_identifiers);
+            query_builder.push(")");
+        }
+        push_visibility(&mut query_builder, include_drafts);
+        query_builder.push(
+            r#"
+                ORDER BY
+                    content_relations.content_id,
+                    content_relations.field_key,
+                    content_relations.position
+            "#,
+        );
+
+        let rows = query_builder
+            .build_query_as::<RelatedContentRow>()
+            .fetch_all(self.db.inner_ref())
+            .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(RelatedContent::try_from).collect()
+    }
+
+    #[tracing::instrument]
+    async fn find_media(&self, content_ids: Vec<uuid::Uuid>) -> anyhow::Result<Vec<ContentMedia>> {
+        let rows = sqlx::query_as::<_, ContentMediaRow>(
+            r#"
+                SELECT
+                    content_media.content_id,
+                    content_media.field_key,
+                    media.id,
+                    media.object_key,
+                    media.filename,
+                    media.mime_type,
+                    media.size,
+                    media.checksum,
+                    media.width,
+                    media.height,
+                    media.alt_text,
+                    media.uploaded_by AS uploaded_by_id,
+                    users.name AS uploaded_by_name,
+                    media.created_at,
+                    media.updated_at
+                FROM
+                    content_media
+                JOIN
+                    media ON media.id = content_media.media_id
+                JOIN
+                    users ON users.id = media.uploaded_by
+                WHERE
+                    content_media.content_id = ANY($1)
+                    AND media.deleted_at IS NULL
+            "#,
+        )
+        .bind(content_ids)
+        .fetch_all(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(ContentMedia::try_from).collect()
+    }
+
+    #[tracing::instrument]
+    async fn find_localizations(
+        &self,
+        content_ids: Vec<uuid::Uuid>,
+        include_drafts: bool,
+    ) -> anyhow::Result<Vec<ContentLocalization>> {
+        fetch_localizations(&self.db, content_ids, include_drafts).await
+    }
+}
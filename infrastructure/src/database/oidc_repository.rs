@@ -0,0 +1,256 @@
+use domain::model::auth::AuthError;
+use domain::model::oidc::OidcLoginState;
+use domain::repository::oidc::{LinkOidcIdentity, OidcRepository};
+
+use crate::database::connection::ConnectionPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct OidcLoginStateRow {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl From<OidcLoginStateRow> for OidcLoginState {
+    fn from(row: OidcLoginStateRow) -> Self {
+        let OidcLoginStateRow {
+            state,
+            nonce,
+            code_verifier,
+            expires_at,
+        } = row;
+
+        OidcLoginState::new(state, nonce, code_verifier, expires_at)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct LinkedUserRow {
+    id: uuid::Uuid,
+    deleted_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct EmailUserRow {
+    id: uuid::Uuid,
+    linkable: bool,
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct OidcRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl OidcRepository for OidcRepositoryImpl {
+    async fn save_login_state(&self, login_state: OidcLoginState) -> anyhow::Result<()> {
+        let OidcLoginState {
+            state,
+            nonce,
+            code_verifier,
+            expires_at,
+        } = login_state;
+
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let result =
+            sqlx::query(r#"DELETE FROM oidc_login_states WHERE expires_at <= CURRENT_TIMESTAMP"#)
+                .execute(&mut *transaction)
+                .await?;
+
+        tracing::info!("{:?}", result);
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    oidc_login_states (state, nonce, code_verifier, expires_at)
+                VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(state)
+        .bind(nonce)
+        .bind(code_verifier)
+        .bind(expires_at)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn take_login_state(
+        &self,
+        state: String,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    ) -> anyhow::Result<Option<OidcLoginState>> {
+        let row = sqlx::query_as::<_, OidcLoginStateRow>(
+            r#"
+                DELETE FROM
+                    oidc_login_states
+                WHERE
+                    state = $1
+                RETURNING
+                    state,
+                    nonce,
+                    code_verifier,
+                    expires_at
+            "#,
+        )
+        .bind(state)
+        .fetch_optional(self.db.inner_ref())
+        .await?;
+
+        Ok(row
+            .filter(|row| row.expires_at > now)
+            .map(OidcLoginState::from))
+    }
+
+    #[tracing::instrument]
+    async fn link_identity(&self, data: LinkOidcIdentity) -> anyhow::Result<String> {
+        let LinkOidcIdentity {
+            issuer,
+            subject,
+            email,
+            name,
+            icon_url,
+            role,
+            default_role,
+        } = data;
+
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let linked = sqlx::query_as::<_, LinkedUserRow>(
+            r#"
+                SELECT
+                    users.id,
+                    users.deleted_at
+                FROM
+                    oidc_identities
+                JOIN
+                    users ON users.id = oidc_identities.user_id
+                WHERE
+                    oidc_identities.issuer = $1
+                    AND oidc_identities.subject = $2
+                FOR UPDATE
+            "#,
+        )
+        .bind(&issuer)
+        .bind(&subject)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let user_id = match linked {
+            Some(LinkedUserRow {
+                deleted_at: Some(_),
+                ..
+            }) => anyhow::bail!("User linked to {} is deleted", subject),
+            Some(LinkedUserRow { id, .. }) => id,
+            None => {
+                let existing = match &email {
+                    Some(email) => {
+                        sqlx::query_as::<_, EmailUserRow>(
+                            r#"
+                                SELECT
+                                    id,
+                                    email_verified_at IS NOT NULL AND deleted_at IS NULL AS linkable
+                                FROM
+                                    users
+                                WHERE
+                                    email = $1
+                                FOR UPDATE
+                            "#,
+                        )
+                        .bind(email)
+                        .fetch_optional(&mut *transaction)
+                        .await?
+                    }
+                    None => None,
+                };
+
+                let user_id = match existing {
+                    Some(EmailUserRow {
+                        linkable: false, ..
+                    }) => return Err(AuthError::EmailNotLinkable.into()),
+                    Some(EmailUserRow { id, .. }) => id,
+                    None => {
+                        let user_id = uuid::Uuid::now_v7();
+                        let result = sqlx::query(
+                            r#"
+                                INSERT INTO
+                                    users (id, name, icon_url, role_id, email, email_verified_at)
+                                SELECT
+                                    $1, $2, $3, role.id, $4, CASE WHEN $4::VARCHAR IS NULL THEN NULL ELSE CURRENT_TIMESTAMP END
+                                FROM
+                                    role
+                                WHERE
+                                    role.name = $5
+                                LIMIT 1
+                            "#,
+                        )
+                        .bind(user_id)
+                        .bind(&name)
+                        .bind(&icon_url)
+                        .bind(&email)
+                        .bind(&default_role)
+                        .execute(&mut *transaction)
+                        .await?;
+
+                        tracing::info!("{:?}", result);
+
+                        if result.rows_affected() == 0 {
+                            anyhow::bail!("Default role {} is not found", default_role);
+                        }
+
+                        user_id
+                    }
+                };
+
+                let result = sqlx::query(
+                    r#"
+                        INSERT INTO
+                            oidc_identities (issuer, subject, user_id)
+                        VALUES ($1, $2, $3)
+                    "#,
+                )
+                .bind(&issuer)
+                .bind(&subject)
+                .bind(user_id)
+                .execute(&mut *transaction)
+                .await?;
+
+                tracing::info!("{:?}", result);
+
+                user_id
+            }
+        };
+
+        if let Some(role) = role {
+            let result = sqlx::query(
+                r#"
+                    UPDATE users SET
+                        role_id = role.id
+                    FROM
+                        role
+                    WHERE
+                        users.id = $1
+                        AND role.name = $2
+                        AND users.role_id <> role.id
+                "#,
+            )
+            .bind(user_id)
+            .bind(&role)
+            .execute(&mut *transaction)
+            .await?;
+
+            tracing::info!("{:?}", result);
+        }
+
+        transaction.commit().await?;
+
+        Ok(user_id.to_string())
+    }
+}
@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use domain::model::outbox::OutboxEvent;
+use domain::model::webhook::{WebhookEvent, WebhookPayload};
+use domain::repository::outbox::{AppendOutboxEvent, OutboxRepository, RecordOutboxFailure};
+
+use crate::database::connection::ConnectionPool;
+
+const CLAIM_LEASE_SECONDS: f64 = 300.0;
+
+#[derive(sqlx::FromRow, Debug)]
+struct OutboxEventRow {
+    id: uuid::Uuid,
+    category_id: Option<uuid::Uuid>,
+    payload: sqlx::types::Json<WebhookPayload>,
+    attempts: i32,
+    last_error: Option<String>,
+    created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl From<OutboxEventRow> for OutboxEvent {
+    fn from(row: OutboxEventRow) -> Self {
+        let OutboxEventRow {
+            id,
+            category_id,
+            payload,
+            attempts,
+            last_error,
+            created_at,
+        } = row;
+
+        OutboxEvent::new(
+            id,
+            category_id.map(|id| id.into()),
+            payload.0,
+            attempts,
+            last_error,
+            created_at,
+        )
+    }
+}
+
+async fn insert_payload(
+    executor: impl sqlx::PgExecutor<'_>,
+    category_id: Option<uuid::Uuid>,
+    payload: &WebhookPayload,
+) -> anyhow::Result<uuid::Uuid> {
+    let result = sqlx::query(
+        r#"INSERT INTO outbox_events (id, event, category_id, payload) VALUES ($1, $2, $3, $4)"#,
+    )
+    .bind(payload.id)
+    .bind(payload.event.as_str())
+    .bind(category_id)
+    .bind(sqlx::types::Json(payload))
+    .execute(executor)
+    .await?;
+
+    tracing::info!("{:?}", result);
+
+    Ok(payload.id)
+}
+
+pub async fn insert_outbox_event(
+    executor: impl sqlx::PgExecutor<'_>,
+    event: WebhookEvent,
+    category_id: Option<uuid::Uuid>,
+    data: &impl serde::Serialize,
+) -> anyhow::Result<uuid::Uuid> {
+    let payload = WebhookPayload::new(
+        event,
+        serde_json::to_value(data)?,
+        sqlx::types::chrono::Utc::now(),
+    );
+
+    insert_payload(executor, category_id, &payload).await
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct OutboxRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl OutboxRepository for OutboxRepositoryImpl {
+    #[tracing::instrument]
+    async fn append(&self, data: AppendOutboxEvent) -> anyhow::Result<uuid::Uuid> {
+        let AppendOutboxEvent {
+            category_id,
+            payload,
+        } = data;
+
+        let category_id = category_id
+            .map(|id| uuid::Uuid::from_str(&id))
+            .transpose()?;
+
+        let mut conn = self.db.acquire().await?;
+
+        insert_payload(&mut *conn, category_id, &payload).await
+    }
+
+    #[tracing::instrument]
+    async fn claim_due(
+        &self,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<OutboxEvent>> {
+        let mut conn = self.db.acquire().await?;
+
+        let mut rows = sqlx::query_as::<_, OutboxEventRow>(
+            r#"
+                WITH due AS (
+                    SELECT
+                        id
+                    FROM
+                        outbox_events
+                    WHERE
+                        next_attempt_at <= $1
+                    ORDER BY
+                        id
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                UPDATE outbox_events SET
+                    next_attempt_at = $1 + make_interval(secs => $3)
+                FROM
+                    due
+                WHERE
+                    outbox_events.id = due.id
+                RETURNING
+                    outbox_events.*
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .bind(CLAIM_LEASE_SECONDS)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        rows.sort_by_key(|row| row.id);
+
+        tracing::info!("{:?}", rows.iter().map(|row| row.id).collect::<Vec<_>>());
+
+        Ok(rows.into_iter().map(OutboxEvent::from).collect())
+    }
+
+    #[tracing::instrument]
+    async fn complete(&self, id: uuid::Uuid) -> anyhow::Result<()> {
+        let mut conn = self.db.acquire().await?;
+
+        let result = sqlx::query(r#"DELETE FROM outbox_events WHERE id = $1"#)
+            .bind(id)
+            .execute(&mut *conn)
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn record_failure(&self, data: RecordOutboxFailure) -> anyhow::Result<()> {
+        let RecordOutboxFailure {
+            id,
+            attempts,
+            next_attempt_at,
+            error,
+        } = data;
+
+        let mut conn = self.db.acquire().await?;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE outbox_events SET
+                    attempts = $2,
+                    next_attempt_at = $3,
+                    last_error = $4
+                WHERE
+                    id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(attempts)
+        .bind(next_attempt_at)
+        .bind(error)
+        .execute(&mut *conn)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use domain::model::page::{Cursor, PageQuery};
+
+pub(crate) fn push_keyset(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    column: &str,
+    query: &PageQuery,
+    descending: bool,
+) -> anyhow::Result<()> {
+    let cursor = query.keyset()?;
+    let descending = match cursor {
+        Some(Cursor::Before(_)) => !descending,
+        _ => descending,
+    };
+
+    if let Some(Cursor::After(id) | Cursor::Before(id)) = cursor {
+        query_builder.push(match descending {
+            true => format!(" AND {} < ", column),
+            false => format!(" AND {} > ", column),
+        });
+        query_builder.push_bind(id);
+    }
+
+    query_builder.push(match descending {
+        true => format!(" ORDER BY {} DESC LIMIT ", column),
+        false => format!(" ORDER BY {} ASC LIMIT ", column),
+    });
+    query_builder.push_bind(query.fetch_limit());
+
+    Ok(())
+}
+
+pub(crate) async fn fetch_total(
+    executor: impl sqlx::PgExecutor<'_>,
+    mut query_builder: sqlx::QueryBuilder<'_, sqlx::Postgres>,
+) -> anyhow::Result<i64> {
+    let total = query_builder
+        .build_query_scalar::<i64>()
+        .fetch_one(executor)
+        .await?;
+
+    Ok(total)
+}
@@ -0,0 +1,344 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use domain::model::role::{Permission, PermissionAction, PermissionResource, Role, RoleError};
+use domain::repository::role::{CreateRole, GetRoleQuery, RoleRepository, UpdateRole};
+
+use crate::database::connection::ConnectionPool;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct RoleRow {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub description: String,
+    pub is_super_administrator: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PermissionRow {
+    pub role_id: uuid::Uuid,
+    pub resource: String,
+    pub action: String,
+    pub category_ids: Vec<uuid::Uuid>,
+}
+
+impl TryFrom<PermissionRow> for Permission {
+    type Error = anyhow::Error;
+
+    fn try_from(row: PermissionRow) -> anyhow::Result<Self> {
+        let PermissionRow {
+            resource,
+            action,
+            category_ids,
+            ..
+        } = row;
+
+        Ok(Permission::new(
+            PermissionResource::from_str(&resource)?,
+            PermissionAction::from_str(&action)?,
+            category_ids.iter().map(uuid::Uuid::to_string).collect(),
+        ))
+    }
+}
+
+pub(crate) async fn fetch_roles(
+    connection: &mut sqlx::PgConnection,
+    role_ids: &[uuid::Uuid],
+) -> anyhow::Result<HashMap<uuid::Uuid, Role>> {
+    let roles = sqlx::query_as::<_, RoleRow>(
+        r#"
+            SELECT
+                id,
+                name,
+                description,
+                is_super_administrator
+            FROM
+                role
+            WHERE
+                id = ANY($1)
+        "#,
+    )
+    .bind(role_ids)
+    .fetch_all(&mut *connection)
+    .await?;
+
+    let permissions = sqlx::query_as::<_, PermissionRow>(
+        r#"
+            SELECT
+                role_permissions.role_id,
+                role_permissions.resource,
+                role_permissions.action,
+                COALESCE(
+                    array_agg(role_permission_categories.category_id)
+                        FILTER (WHERE role_permission_categories.category_id IS NOT NULL),
+                    '{}'
+                ) AS category_ids
+            FROM
+                role_permissions
+            LEFT JOIN
+                role_permission_categories ON role_permission_categories.role_permission_id = role_permissions.id
+            WHERE
+                role_permissions.role_id = ANY($1)
+            GROUP BY
+                role_permissions.id
+            ORDER BY
+                role_permissions.id
+        "#,
+    )
+    .bind(role_ids)
+    .fetch_all(&mut *connection)
+    .await?;
+
+    let mut grouped: HashMap<uuid::Uuid, Vec<Permission>> = HashMap::new();
+    for row in permissions {
+        grouped
+            .entry(row.role_id)
+            .or_default()
+            .push(Permission::try_from(row)?);
+    }
+
+    roles
+        .into_iter()
+        .map(|row| {
+            let RoleRow {
+                id,
+                name,
+                description,
+                is_super_administrator,
+            } = row;
+            let permissions = grouped.remove(&id).unwrap_or_default();
+            let role = Role::try_new(
+                id.to_string(),
+                name,
+                description,
+                is_super_administrator,
+                permissions,
+            )?;
+
+            Ok((id, role))
+        })
+        .collect()
+}
+
+async fn insert_permissions(
+    connection: &mut sqlx::PgConnection,
+    role_id: uuid::Uuid,
+    permissions: Vec<Permission>,
+) -> anyhow::Result<()> {
+    for permission in permissions {
+        let Permission {
+            resource,
+            action,
+            category_ids,
+        } = permission;
+
+        let permission_id = uuid::Uuid::now_v7();
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    role_permissions (id, role_id, resource, action)
+                VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(permission_id)
+        .bind(role_id)
+        .bind(resource.as_str())
+        .bind(action.as_str())
+        .execute(&mut *connection)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        if category_ids.is_empty() {
+            continue;
+        }
+
+        let category_ids = category_ids
+            .iter()
+            .map(|id| uuid::Uuid::parse_str(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    role_permission_categories (role_permission_id, category_id)
+                SELECT
+                    $1, category_id
+                FROM
+                    UNNEST($2::UUID[]) AS category_id
+            "#,
+        )
+        .bind(permission_id)
+        .bind(category_ids)
+        .execute(&mut *connection)
+        .await?;
+
+        tracing::info!("{:?}", result);
+    }
+
+    Ok(())
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct RoleRepositoryImpl {
+    db: ConnectionPool,
+}
+
+impl RoleRepositoryImpl {
+    async fn find_by_id(
+        &self,
+        connection: &mut sqlx::PgConnection,
+        id: uuid::Uuid,
+    ) -> anyhow::Result<Option<Role>> {
+        Ok(fetch_roles(connection, &[id]).await?.remove(&id))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleRepository for RoleRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(&self, query: GetRoleQuery) -> anyhow::Result<Vec<Role>> {
+        let mut connection = self.db.inner_ref().acquire().await?;
+
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"SELECT id FROM role ORDER BY name LIMIT $1 OFFSET $2"#,
+        )
+        .bind(query.limit)
+        .bind(query.offset)
+        .fetch_all(&mut *connection)
+        .await?;
+
+        let mut roles = fetch_roles(&mut connection, &ids).await?;
+        let result = ids.iter().filter_map(|id| roles.remove(id)).collect();
+        tracing::info!("{:?}", result);
+
+        Ok(result)
+    }
+
+    #[tracing::instrument]
+    async fn find(&self, id: String) -> anyhow::Result<Option<Role>> {
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut connection = self.db.inner_ref().acquire().await?;
+
+        self.find_by_id(&mut connection, parsed_id).await
+    }
+
+    #[tracing::instrument]
+    async fn create(&self, data: CreateRole) -> anyhow::Result<Role> {
+        let CreateRole {
+            name,
+            description,
+            permissions,
+        } = data;
+
+        let id = uuid::Uuid::now_v7();
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let result = sqlx::query(
+            r#"
+                INSERT INTO
+                    role (id, name, description)
+                VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(id)
+        .bind(name)
+        .bind(description)
+        .execute(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        insert_permissions(&mut transaction, id, permissions).await?;
+        let role = self
+            .find_by_id(&mut transaction, id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Role {} is not found", id))?;
+
+        transaction.commit().await?;
+
+        Ok(role)
+    }
+
+    #[tracing::instrument]
+    async fn update(&self, data: UpdateRole) -> anyhow::Result<Role> {
+        let UpdateRole {
+            id,
+            name,
+            description,
+            permissions,
+        } = data;
+
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        if name.is_some() || description.is_some() {
+            let mut query_builder =
+                sqlx::QueryBuilder::<'_, sqlx::Postgres>::new("UPDATE role SET ");
+            let mut separated = query_builder.separated(",");
+
+            if let Some(name) = name {
+                separated.push("name = ");
+                separated.push_bind_unseparated(name);
+            }
+
+            if let Some(description) = description {
+                separated.push("description = ");
+                separated.push_bind_unseparated(description);
+            }
+
+            query_builder.push(" WHERE role.id = ");
+            query_builder.push_bind(parsed_id);
+
+            let result = query_builder.build().execute(&mut *transaction).await?;
+            tracing::info!("{:?}", result);
+        }
+
+        if let Some(permissions) = permissions {
+            let result = sqlx::query(r#"DELETE FROM role_permissions WHERE role_id = $1"#)
+                .bind(parsed_id)
+                .execute(&mut *transaction)
+                .await?;
+
+            tracing::info!("{:?}", result);
+
+            insert_permissions(&mut transaction, parsed_id, permissions).await?;
+        }
+
+        let role = self
+            .find_by_id(&mut transaction, parsed_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Role {} is not found", id))?;
+
+        transaction.commit().await?;
+
+        Ok(role)
+    }
+
+    #[tracing::instrument]
+    async fn delete(&self, id: String) -> anyhow::Result<()> {
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let assigned = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS (SELECT 1 FROM users WHERE role_id = $1)"#,
+        )
+        .bind(parsed_id)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if assigned {
+            return Err(RoleError::InUse.into());
+        }
+
+        let result = sqlx::query(r#"DELETE FROM role WHERE id = $1"#)
+            .bind(parsed_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
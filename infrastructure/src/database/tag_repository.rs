@@ -1,15 +1,20 @@
 use std::str::FromStr;
 
+use domain::model::page::Page;
 use domain::model::tag::Tag;
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
 use domain::repository::tag::{CreateTag, GetTagQuery, TagRepository, UpdateTag};
 
 use crate::database::connection::ConnectionPool;
+use crate::database::pagination::{fetch_total, push_keyset};
 
 #[derive(sqlx::FromRow, Debug)]
 pub struct TagRow {
     id: uuid::Uuid,
     name: String,
     description: String,
+    version: i32,
     #[sqlx(skip)]
     #[allow(unused)]
     created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
@@ -24,6 +29,7 @@ impl From<TagRow> for Tag {
             id,
             name,
             description,
+            version,
             ..
         } = row;
 
@@ -31,6 +37,7 @@ impl From<TagRow> for Tag {
             id: id.into(),
             name,
             description,
+            version,
         }
     }
 }
@@ -43,17 +50,34 @@ pub struct TagRepositoryImpl {
 #[async_trait::async_trait]
 impl TagRepository for TagRepositoryImpl {
     #[tracing::instrument]
-    async fn get(&self, query: GetTagQuery) -> anyhow::Result<Vec<Tag>> {
-        let rows =
-            sqlx::query_as::<_, TagRow>(r#"SELECT * FROM tags ORDER BY id LIMIT $1 OFFSET $2"#)
-                .bind(query.limit)
-                .bind(query.offset)
-                .fetch_all(self.db.inner_ref())
-                .await?;
+    async fn get(&self, query: GetTagQuery) -> anyhow::Result<Page<Tag>> {
+        let GetTagQuery { page } = query;
+
+        let mut conn = self.db.acquire().await?;
+
+        let total = match page.with_total {
+            true => {
+                let count_builder =
+                    sqlx::QueryBuilder::new("SELECT COUNT(*) FROM tags WHERE deleted_at IS NULL");
+
+                Some(fetch_total(&mut *conn, count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+            "SELECT * FROM tags WHERE deleted_at IS NULL",
+        );
+        push_keyset(&mut query_builder, "id", &page, false)?;
+
+        let rows = query_builder
+            .build_query_as::<TagRow>()
+            .fetch_all(&mut *conn)
+            .await?;
 
         tracing::info!("{:?}", rows);
 
-        Ok(rows.into_iter().map(Tag::from).collect())
+        Ok(Page::from_keyset_rows(rows, &page, |row| row.id, total).map(Tag::from))
     }
 
     #[tracing::instrument]
@@ -66,13 +90,15 @@ impl TagRepository for TagRepositoryImpl {
             None => "".into(),
         };
 
+        let mut conn = self.db.acquire().await?;
+
         let row = sqlx::query_as::<_, TagRow>(
             r#"INSERT INTO tags (id, name, description) VALUES ($1, $2, $3) RETURNING *"#,
         )
         .bind(uuid)
         .bind(name)
         .bind(description)
-        .fetch_one(self.db.inner_ref())
+        .fetch_one(&mut *conn)
         .await?;
 
         tracing::info!("{:?}", row);
@@ -86,6 +112,7 @@ impl TagRepository for TagRepositoryImpl {
             id,
             name,
             description,
+            expected_version,
         } = tag;
 
         let mut query_builder =
@@ -103,6 +130,8 @@ impl TagRepository for TagRepositoryImpl {
             separated.push_bind_unseparated(description);
         }
 
+        separated.push("version = version + 1");
+
         let uuid = uuid::Uuid::from_str(&id)?;
         query_builder.push(" WHERE id = ");
         query_builder.push_bind(uuid);
@@ -111,10 +140,34 @@ impl TagRepository for TagRepositoryImpl {
 
         tracing::info!("{:?}", query_builder.sql());
 
+        let mut transaction = self.db.begin().await?;
+
+        let current_version = sqlx::query_scalar::<_, i32>(
+            r#"SELECT version FROM tags WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#,
+        )
+        .bind(uuid)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let Some(current_version) = current_version else {
+            anyhow::bail!("Tag {} is not found", id);
+        };
+
+        if let Some(expected_version) = expected_version.filter(|v| *v != current_version) {
+            let current_row = sqlx::query_as::<_, TagRow>(r#"SELECT * FROM tags WHERE id = $1"#)
+                .bind(uuid)
+                .fetch_one(&mut *transaction)
+                .await?;
+            let current = Tag::from(current_row);
+
+            return Err(VersionConflict::try_new(expected_version, &current)?.into());
+        }
+
         let row = query_builder
             .build_query_as::<TagRow>()
-            .fetch_one(self.db.inner_ref())
+            .fetch_one(&mut *transaction)
             .await?;
+        transaction.commit().await?;
 
         tracing::info!("{:?}", row);
 
@@ -124,13 +177,80 @@ impl TagRepository for TagRepositoryImpl {
     async fn delete(&self, id: String) -> anyhow::Result<()> {
         let uuid = uuid::Uuid::from_str(&id)?;
 
-        let result = sqlx::query(r#"DELETE FROM tags WHERE id = $1"#)
-            .bind(uuid)
-            .execute(self.db.inner_ref())
-            .await?;
+        let mut conn = self.db.acquire().await?;
+
+        let result = sqlx::query(
+            r#"UPDATE tags SET deleted_at = CURRENT_TIMESTAMP(3) WHERE id = $1 AND deleted_at IS NULL"#,
+        )
+        .bind(uuid)
+        .execute(&mut *conn)
+        .await?;
 
         tracing::info!("{:?}", result);
 
         Ok(())
     }
+
+    #[tracing::instrument]
+    async fn restore(&self, id: String) -> anyhow::Result<Tag> {
+        let uuid = uuid::Uuid::from_str(&id)?;
+
+        let mut conn = self.db.acquire().await?;
+
+        let row = sqlx::query_as::<_, TagRow>(
+            r#"UPDATE tags SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL RETURNING *"#,
+        )
+        .bind(uuid)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        tracing::info!("{:?}", row);
+
+        match row {
+            Some(row) => Ok(Tag::from(row)),
+            None => Err(TrashError::NotInTrash.into()),
+        }
+    }
+
+    #[tracing::instrument]
+    async fn purge(
+        &self,
+        before: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        let mut conn = self.db.acquire().await?;
+
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+                WITH expired AS (
+                    SELECT
+                        id
+                    FROM
+                        tags
+                    WHERE
+                        deleted_at < $1
+                    ORDER BY
+                        deleted_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                DELETE FROM
+                    tags
+                USING
+                    expired
+                WHERE
+                    tags.id = expired.id
+                RETURNING
+                    tags.id
+            "#,
+        )
+        .bind(before)
+        .bind(limit)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        tracing::info!("{:?}", ids);
+
+        Ok(ids)
+    }
 }
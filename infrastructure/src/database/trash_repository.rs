@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use domain::model::page::Page;
+use domain::model::trash::{TrashKind, TrashedItem};
+use domain::repository::trash::{GetTrashQuery, TrashRepository, TrashScope};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::contents_repository::parse_ids;
+use crate::database::pagination::fetch_total;
+
+#[derive(Debug, sqlx::FromRow)]
+struct TrashedItemRow {
+    id: uuid::Uuid,
+    kind: String,
+    name: String,
+    deleted_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<TrashedItemRow> for TrashedItem {
+    type Error = anyhow::Error;
+
+    fn try_from(row: TrashedItemRow) -> anyhow::Result<Self> {
+        let TrashedItemRow {
+            id,
+            kind,
+            name,
+            deleted_at,
+        } = row;
+
+        Ok(Self {
+            id: id.into(),
+            kind: TrashKind::from_str(&kind)?,
+            name,
+            deleted_at,
+        })
+    }
+}
+
+fn push_trash(
+    query_builder: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    scopes: &[(TrashKind, Option<Vec<uuid::Uuid>>)],
+) {
+    query_builder.push("(");
+    let mut separated = query_builder.separated(" UNION ALL ");
+
+    for (kind, category_ids) in scopes {
+        let (name, table, category_column) = match kind {
+            TrashKind::Content => ("title", "contents", Some("category_id")),
+            TrashKind::Category => ("name", "category", Some("id")),
+            TrashKind::Tag => ("name", "tags", None),
+            TrashKind::Media => ("filename", "media", None),
+        };
+
+        separated.push(format!(
+            "SELECT id, '{}'::VARCHAR AS kind, {} AS name, deleted_at FROM {} WHERE deleted_at IS NOT NULL",
+            kind.as_str(),
+            name,
+            table
+        ));
+
+        if let (Some(category_column), Some(category_ids)) = (category_column, category_ids) {
+            separated.push_unseparated(format!(" AND {} = ANY(", category_column));
+            separated.push_bind_unseparated(category_ids.clone());
+            separated.push_unseparated(")");
+        }
+    }
+
+    query_builder.push(") AS trash");
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct TrashRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl TrashRepository for TrashRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(&self, query: GetTrashQuery) -> anyhow::Result<Page<TrashedItem>> {
+        let GetTrashQuery { page, scopes } = query;
+        let scopes = scopes
+            .into_iter()
+            .map(|TrashScope { kind, category_ids }| Ok((kind, parse_ids(category_ids)?)))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let offset = page.offset()?;
+
+        if scopes.is_empty() {
+            let total = page.with_total.then_some(0);
+
+            return Ok(Page::from_offset_rows(vec![], &page, offset, total));
+        }
+
+        let mut conn = self.db.acquire().await?;
+
+        let total = match page.with_total {
+            true => {
+                let mut count_builder =
+                    sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT COUNT(*) FROM ");
+                push_trash(&mut count_builder, &scopes);
+
+                Some(fetch_total(&mut *conn, count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT * FROM ");
+        push_trash(&mut query_builder, &scopes);
+        query_builder.push(" ORDER BY deleted_at DESC, id DESC LIMIT ");
+        query_builder.push_bind(page.fetch_limit());
+        query_builder.push(" OFFSET ");
+        query_builder.push_bind(offset);
+
+        tracing::info!("{:?}", query_builder.sql());
+
+        let rows = query_builder
+            .build_query_as::<TrashedItemRow>()
+            .fetch_all(&mut *conn)
+            .await?;
+
+        tracing::info!("{:?}", rows);
+
+        Page::from_offset_rows(rows, &page, offset, total).try_map(TrashedItem::try_from)
+    }
+}
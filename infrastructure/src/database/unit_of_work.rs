@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use domain::model::search::SearchLanguage;
+use domain::repository::category::CategoryRepository;
+use domain::repository::content::ContentRepository;
+use domain::repository::outbox::OutboxRepository;
+use domain::repository::tag::TagRepository;
+use domain::repository::unit_of_work::{UnitOfWork, UnitOfWorkFactory};
+
+use crate::database::category_repository::CategoryRepositoryImpl;
+use crate::database::connection::{ConnectionPool, SharedTransaction};
+use crate::database::contents_repository::ContentRepositoryImpl;
+use crate::database::outbox_repository::OutboxRepositoryImpl;
+use crate::database::tag_repository::TagRepositoryImpl;
+
+#[derive(derive_new::new, Debug)]
+pub struct UnitOfWorkFactoryImpl {
+    db: ConnectionPool,
+    search_language: SearchLanguage,
+}
+
+#[async_trait::async_trait]
+impl UnitOfWorkFactory for UnitOfWorkFactoryImpl {
+    #[tracing::instrument]
+    async fn begin(&self) -> anyhow::Result<Box<dyn UnitOfWork>> {
+        let transaction = self.db.inner_ref().begin().await?;
+        let transaction = Arc::new(tokio::sync::Mutex::new(Some(transaction)));
+
+        Ok(Box::new(UnitOfWorkImpl {
+            db: self.db.with_transaction(transaction.clone()),
+            transaction,
+            search_language: self.search_language.clone(),
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub struct UnitOfWorkImpl {
+    db: ConnectionPool,
+    transaction: SharedTransaction,
+    search_language: SearchLanguage,
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for UnitOfWorkImpl {
+    fn categories(&self) -> Arc<dyn CategoryRepository> {
+        Arc::new(CategoryRepositoryImpl::new(self.db.clone()))
+    }
+
+    fn contents(&self) -> Arc<dyn ContentRepository> {
+        Arc::new(ContentRepositoryImpl::new(
+            self.db.clone(),
+            self.search_language.clone(),
+        ))
+    }
+
+    fn tags(&self) -> Arc<dyn TagRepository> {
+        Arc::new(TagRepositoryImpl::new(self.db.clone()))
+    }
+
+    fn outbox(&self) -> Arc<dyn OutboxRepository> {
+        Arc::new(OutboxRepositoryImpl::new(self.db.clone()))
+    }
+
+    #[tracing::instrument]
+    async fn commit(self: Box<Self>) -> anyhow::Result<()> {
+        let transaction = self
+            .transaction
+            .lock()
+            .await
+            .take()
+            .context("Unit of work is already committed")?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
@@ -1,19 +1,17 @@
-use domain::model::user::{Admin, Member, User};
+use domain::model::page::Page;
+use domain::model::user::User;
 use domain::repository::user::{CreateUser, GetUserQuery, UpdateUser, UserRepository};
 
 use crate::database::connection::ConnectionPool;
-
-enum Role {
-    Admin,
-    Memeber,
-}
+use crate::database::pagination::{fetch_total, push_keyset};
+use crate::database::role_repository::fetch_roles;
 
 #[derive(sqlx::FromRow, Debug)]
 pub struct UserRow {
     id: uuid::Uuid,
     name: String,
     icon_url: String,
-    role: Role,
+    role_id: uuid::Uuid,
     #[sqlx(skip)]
     #[allow(unused)]
     deleted_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
@@ -25,21 +23,37 @@ pub struct UserRow {
     updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
 }
 
-impl TryFrom<UserRow> for User {
-    type Error = anyhow::Error;
-
-    fn try_from(row: UserRow) -> anyhow::Result<Self> {
-        let UserRow {
-            id, name, icon_url, ..
-        } = row;
-
-        Ok(Self {
-            id: id.to_string(),
-            name,
-            icon_url,
-            _role: std::marker::PhantomData,
+async fn into_users(
+    connection: &mut sqlx::PgConnection,
+    rows: Vec<UserRow>,
+) -> anyhow::Result<Vec<User>> {
+    let role_ids = rows.iter().map(|row| row.role_id).collect::<Vec<_>>();
+    let roles = fetch_roles(connection, &role_ids).await?;
+
+    rows.into_iter()
+        .map(|row| {
+            let UserRow {
+                id,
+                name,
+                icon_url,
+                role_id,
+                ..
+            } = row;
+            let role = roles
+                .get(&role_id)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Role {} is not found", role_id))?;
+
+            User::try_new(id.to_string(), name, icon_url, role)
         })
-    }
+        .collect()
+}
+
+async fn into_user(connection: &mut sqlx::PgConnection, row: UserRow) -> anyhow::Result<User> {
+    into_users(connection, vec![row])
+        .await?
+        .pop()
+        .ok_or_else(|| anyhow::anyhow!("User is not found"))
 }
 
 #[derive(derive_new::new, Debug)]
@@ -50,13 +64,36 @@ pub struct UserRepositoryImpl {
 #[async_trait::async_trait]
 impl UserRepository for UserRepositoryImpl {
     #[tracing::instrument]
-    async fn get(&self, query: GetUserQuery) -> anyhow::Result<Vec<User>> {
-        let rows = sqlx::query_as::<_, UserRow>(r#"SELECT * FROM users LIMIT $1 OFFSET $2"#)
-            .bind(query.limit)
-            .bind(query.offset)
-            .fetch_all(self.db.inner_ref())
+    async fn get(&self, query: GetUserQuery) -> anyhow::Result<Page<User>> {
+        let GetUserQuery { page } = query;
+        let mut connection = self.db.inner_ref().acquire().await?;
+
+        let total = match page.with_total {
+            true => {
+                let count_builder = sqlx::QueryBuilder::new("SELECT COUNT(*) FROM users");
+
+                Some(fetch_total(&mut *connection, count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder =
+            sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT * FROM users WHERE TRUE");
+        push_keyset(&mut query_builder, "id", &page, false)?;
+
+        let rows = query_builder
+            .build_query_as::<UserRow>()
+            .fetch_all(&mut *connection)
             .await?;
-        let result = rows.into_iter().map(User::try_from).collect();
+        let Page {
+            items,
+            next_cursor,
+            prev_cursor,
+            total,
+        } = Page::from_keyset_rows(rows, &page, |row| row.id, total);
+        let result = into_users(&mut connection, items)
+            .await
+            .map(|users| Page::new(users, next_cursor, prev_cursor, total));
         tracing::info!("{:?}", result);
 
         result
@@ -64,11 +101,13 @@ impl UserRepository for UserRepositoryImpl {
 
     #[tracing::instrument]
     async fn find(&self, id: String) -> anyhow::Result<User> {
-        let row = sqlx::query_as::<_, UserRow>(r#"SELECT * FROM users WHERE user.id = $1"#)
-            .bind(id)
-            .fetch_one(self.db.inner_ref())
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut connection = self.db.inner_ref().acquire().await?;
+        let row = sqlx::query_as::<_, UserRow>(r#"SELECT * FROM users WHERE users.id = $1"#)
+            .bind(parsed_id)
+            .fetch_one(&mut *connection)
             .await?;
-        let result = User::try_from(row);
+        let result = into_user(&mut connection, row).await;
         tracing::info!("{:?}", result);
 
         result
@@ -82,16 +121,18 @@ impl UserRepository for UserRepositoryImpl {
             role_id,
         } = create_user;
         let uuid = uuid::Uuid::now_v7();
+        let parsed_role_id = uuid::Uuid::parse_str(&role_id)?;
+        let mut connection = self.db.inner_ref().acquire().await?;
         let row = sqlx::query_as::<_, UserRow>(
-            r#"INSERT INTO users (id, name, icon_url, role) VALUES ($1, $2, $3, $4) RETURNING *"#,
+            r#"INSERT INTO users (id, name, icon_url, role_id) VALUES ($1, $2, $3, $4) RETURNING *"#,
         )
         .bind(uuid)
         .bind(name)
         .bind(icon_url)
-        .bind(role)
-        .fetch_one(self.db.inner_ref())
+        .bind(parsed_role_id)
+        .fetch_one(&mut *connection)
         .await?;
-        let result = User::try_from(row);
+        let result = into_user(&mut connection, row).await;
         tracing::info!("{:?}", result);
 
         result
@@ -106,7 +147,7 @@ impl UserRepository for UserRepositoryImpl {
             role_id,
         } = update_user;
 
-        let mut query_builder = sqlx::QueryBuilder::<'_, sqlx::Postgres>::new("UPDATE users SET");
+        let mut query_builder = sqlx::QueryBuilder::<'_, sqlx::Postgres>::new("UPDATE users SET ");
         let mut separated = query_builder.separated(",");
 
         if let Some(name) = name {
@@ -120,8 +161,9 @@ impl UserRepository for UserRepositoryImpl {
         }
 
         if let Some(role_id) = role_id {
-            separated.push("role = ");
-            separated.push_bind_unseparated(role_id);
+            let parsed_role_id = uuid::Uuid::parse_str(&role_id)?;
+            separated.push("role_id = ");
+            separated.push_bind_unseparated(parsed_role_id);
         }
 
         let parsed_user_id = uuid::Uuid::parse_str(&id)?;
@@ -129,11 +171,12 @@ impl UserRepository for UserRepositoryImpl {
         query_builder.push_bind(parsed_user_id);
         query_builder.push(" RETURNING *");
 
+        let mut connection = self.db.inner_ref().acquire().await?;
         let row = query_builder
             .build_query_as::<UserRow>()
-            .fetch_one(self.db.inner_ref())
+            .fetch_one(&mut *connection)
             .await?;
-        let result = User::try_from(row);
+        let result = into_user(&mut connection, row).await;
         tracing::info!("{:?}", result);
 
         result
@@ -143,7 +186,7 @@ impl UserRepository for UserRepositoryImpl {
     async fn delete(&self, id: String) -> anyhow::Result<()> {
         let parsed_id = uuid::Uuid::parse_str(&id)?;
 
-        let result = sqlx::query(r#"DELETE FROM users id = $1"#)
+        let result = sqlx::query(r#"DELETE FROM users WHERE id = $1"#)
             .bind(parsed_id)
             .execute(self.db.inner_ref())
             .await?;
@@ -0,0 +1,562 @@
+use std::str::FromStr;
+
+use domain::model::page::Page;
+use domain::model::webhook::{
+    Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent, WebhookPayload,
+};
+use domain::repository::webhook::{
+    CreateWebhook, DueWebhookDelivery, EnqueueWebhookEvent, GetWebhookDeliveryQuery,
+    RecordWebhookAttempt, UpdateWebhook, WebhookRepository,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::pagination::{fetch_total, push_keyset};
+
+const CLAIM_LEASE_SECONDS: f64 = 300.0;
+
+const WEBHOOK_COLUMNS: &str = r#"
+    webhooks.id,
+    webhooks.url,
+    webhooks.events,
+    webhooks.active,
+    webhooks.created_at,
+    webhooks.updated_at,
+    ARRAY(
+        SELECT
+            category_id
+        FROM
+            webhook_categories
+        WHERE
+            webhook_categories.webhook_id = webhooks.id
+        ORDER BY
+            category_id
+    ) AS category_ids
+"#;
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct WebhookRow {
+    id: uuid::Uuid,
+    url: String,
+    events: Vec<String>,
+    category_ids: Vec<uuid::Uuid>,
+    active: bool,
+    created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<WebhookRow> for Webhook {
+    type Error = anyhow::Error;
+
+    fn try_from(row: WebhookRow) -> anyhow::Result<Self> {
+        let WebhookRow {
+            id,
+            url,
+            events,
+            category_ids,
+            active,
+            created_at,
+            updated_at,
+        } = row;
+
+        let events = events
+            .iter()
+            .map(|event| WebhookEvent::from_str(event))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let category_ids = category_ids.into_iter().map(|id| id.into()).collect();
+
+        Webhook::try_new(
+            id,
+            url,
+            events,
+            category_ids,
+            active,
+            created_at,
+            updated_at,
+        )
+    }
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct WebhookDeliveryRow {
+    id: uuid::Uuid,
+    webhook_id: uuid::Uuid,
+    event: String,
+    payload: sqlx::types::Json<WebhookPayload>,
+    status: String,
+    attempts: i32,
+    next_attempt_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+    response_status: Option<i32>,
+    response_body: Option<String>,
+    error: Option<String>,
+    created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = anyhow::Error;
+
+    fn try_from(row: WebhookDeliveryRow) -> anyhow::Result<Self> {
+        let WebhookDeliveryRow {
+            id,
+            webhook_id,
+            event,
+            payload,
+            status,
+            attempts,
+            next_attempt_at,
+            response_status,
+            response_body,
+            error,
+            created_at,
+            updated_at,
+        } = row;
+
+        Ok(WebhookDelivery::new(
+            id,
+            webhook_id,
+            WebhookEvent::from_str(&event)?,
+            payload.0,
+            WebhookDeliveryStatus::from_str(&status)?,
+            attempts,
+            next_attempt_at,
+            response_status,
+            response_body,
+            error,
+            created_at,
+            updated_at,
+        ))
+    }
+}
+
+#[derive(sqlx::FromRow, Debug)]
+struct DueWebhookDeliveryRow {
+    #[sqlx(flatten)]
+    delivery: WebhookDeliveryRow,
+    url: String,
+    secret: String,
+}
+
+async fn fetch_webhook(
+    executor: impl sqlx::PgExecutor<'_>,
+    id: uuid::Uuid,
+) -> anyhow::Result<Webhook> {
+    let row = sqlx::query_as::<_, WebhookRow>(&format!(
+        "SELECT {} FROM webhooks WHERE webhooks.id = $1",
+        WEBHOOK_COLUMNS
+    ))
+    .bind(id)
+    .fetch_one(executor)
+    .await?;
+
+    tracing::info!("{:?}", row);
+
+    Webhook::try_from(row)
+}
+
+async fn insert_categories(
+    executor: impl sqlx::PgExecutor<'_>,
+    webhook_id: uuid::Uuid,
+    category_ids: Vec<String>,
+) -> anyhow::Result<()> {
+    let category_ids = category_ids
+        .iter()
+        .map(|id| uuid::Uuid::from_str(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    sqlx::query(
+        r#"
+            INSERT INTO webhook_categories (webhook_id, category_id)
+            SELECT $1, category_id FROM UNNEST($2::uuid[]) AS category_id
+        "#,
+    )
+    .bind(webhook_id)
+    .bind(category_ids)
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+fn event_names(events: &[WebhookEvent]) -> Vec<&'static str> {
+    events.iter().map(|event| event.as_str()).collect()
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct WebhookRepositoryImpl {
+    db: ConnectionPool,
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for WebhookRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(&self) -> anyhow::Result<Vec<Webhook>> {
+        let rows = sqlx::query_as::<_, WebhookRow>(&format!(
+            "SELECT {} FROM webhooks ORDER BY webhooks.id",
+            WEBHOOK_COLUMNS
+        ))
+        .fetch_all(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", rows);
+
+        rows.into_iter().map(Webhook::try_from).collect()
+    }
+
+    #[tracing::instrument]
+    async fn find(&self, id: String) -> anyhow::Result<Webhook> {
+        let uuid = uuid::Uuid::from_str(&id)?;
+
+        fetch_webhook(self.db.inner_ref(), uuid).await
+    }
+
+    #[tracing::instrument(skip(data))]
+    async fn create(&self, data: CreateWebhook) -> anyhow::Result<Webhook> {
+        let CreateWebhook {
+            url,
+            events,
+            category_ids,
+            secret,
+            active,
+        } = data;
+
+        let uuid = uuid::Uuid::now_v7();
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        sqlx::query(
+            r#"INSERT INTO webhooks (id, url, events, secret, active) VALUES ($1, $2, $3, $4, $5)"#,
+        )
+        .bind(uuid)
+        .bind(url)
+        .bind(event_names(&events))
+        .bind(secret)
+        .bind(active)
+        .execute(&mut *transaction)
+        .await?;
+
+        insert_categories(&mut *transaction, uuid, category_ids).await?;
+        let webhook = fetch_webhook(&mut *transaction, uuid).await?;
+
+        transaction.commit().await?;
+
+        Ok(webhook)
+    }
+
+    #[tracing::instrument(skip(data))]
+    async fn update(&self, data: UpdateWebhook) -> anyhow::Result<Webhook> {
+        let UpdateWebhook {
+            id,
+            url,
+            events,
+            category_ids,
+            secret,
+            active,
+        } = data;
+
+        let uuid = uuid::Uuid::from_str(&id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+            "UPDATE webhooks SET updated_at = CURRENT_TIMESTAMP(3)",
+        );
+
+        if let Some(url) = url {
+            query_builder.push(", url = ");
+            query_builder.push_bind(url);
+        }
+
+        if let Some(events) = events {
+            query_builder.push(", events = ");
+            query_builder.push_bind(event_names(&events));
+        }
+
+        if let Some(secret) = secret {
+            query_builder.push(", secret = ");
+            query_builder.push_bind(secret);
+        }
+
+        if let Some(active) = active {
+            query_builder.push(", active = ");
+            query_builder.push_bind(active);
+        }
+
+        query_builder.push(" WHERE id = ");
+        query_builder.push_bind(uuid);
+        query_builder.push(" RETURNING id");
+
+        query_builder
+            .build_query_scalar::<uuid::Uuid>()
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        if let Some(category_ids) = category_ids {
+            sqlx::query(r#"DELETE FROM webhook_categories WHERE webhook_id = $1"#)
+                .bind(uuid)
+                .execute(&mut *transaction)
+                .await?;
+            insert_categories(&mut *transaction, uuid, category_ids).await?;
+        }
+
+        let webhook = fetch_webhook(&mut *transaction, uuid).await?;
+
+        transaction.commit().await?;
+
+        Ok(webhook)
+    }
+
+    #[tracing::instrument]
+    async fn delete(&self, id: String) -> anyhow::Result<()> {
+        let uuid = uuid::Uuid::from_str(&id)?;
+
+        let result = sqlx::query(r#"DELETE FROM webhooks WHERE id = $1"#)
+            .bind(uuid)
+            .execute(self.db.inner_ref())
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn get_deliveries(
+        &self,
+        query: GetWebhookDeliveryQuery,
+    ) -> anyhow::Result<Page<WebhookDelivery>> {
+        let GetWebhookDeliveryQuery { webhook_id, page } = query;
+
+        let uuid = uuid::Uuid::from_str(&webhook_id)?;
+
+        let total = match page.with_total {
+            true => {
+                let mut count_builder = sqlx::QueryBuilder::new(
+                    "SELECT COUNT(*) FROM webhook_deliveries WHERE webhook_id = ",
+                );
+                count_builder.push_bind(uuid);
+
+                Some(fetch_total(self.db.inner_ref(), count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(
+            "SELECT * FROM webhook_deliveries WHERE webhook_id = ",
+        );
+        query_builder.push_bind(uuid);
+        push_keyset(&mut query_builder, "id", &page, true)?;
+
+        let rows = query_builder
+            .build_query_as::<WebhookDeliveryRow>()
+            .fetch_all(self.db.inner_ref())
+            .await?;
+
+        tracing::info!("{:?}", rows);
+
+        Page::from_keyset_rows(rows, &page, |row| row.id, total).try_map(WebhookDelivery::try_from)
+    }
+
+    #[tracing::instrument]
+    async fn enqueue(&self, data: EnqueueWebhookEvent) -> anyhow::Result<Vec<uuid::Uuid>> {
+        let EnqueueWebhookEvent {
+            category_id,
+            payload,
+        } = data;
+
+        let category_id = category_id
+            .map(|id| uuid::Uuid::from_str(&id))
+            .transpose()?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let webhook_ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+                SELECT
+                    id
+                FROM
+                    webhooks
+                WHERE
+                    active
+                    AND $1 = ANY(events)
+                    AND (
+                        NOT EXISTS (
+                            SELECT 1 FROM webhook_categories WHERE webhook_id = webhooks.id
+                        )
+                        OR EXISTS (
+                            SELECT
+                                1
+                            FROM
+                                webhook_categories
+                            WHERE
+                                webhook_id = webhooks.id
+                                AND category_id = $2
+                        )
+                    )
+            "#,
+        )
+        .bind(payload.event.as_str())
+        .bind(category_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        let delivery_ids: Vec<uuid::Uuid> =
+            webhook_ids.iter().map(|_| uuid::Uuid::now_v7()).collect();
+
+        let ids = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+                INSERT INTO webhook_deliveries (id, webhook_id, event, payload, idempotency_key)
+                SELECT
+                    id, webhook_id, $3, $4, $5
+                FROM
+                    UNNEST($1::uuid[], $2::uuid[]) AS t(id, webhook_id)
+                ON CONFLICT (webhook_id, idempotency_key) DO NOTHING
+                RETURNING
+                    id
+            "#,
+        )
+        .bind(&delivery_ids)
+        .bind(&webhook_ids)
+        .bind(payload.event.as_str())
+        .bind(sqlx::types::Json(&payload))
+        .bind(payload.id)
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        tracing::info!("{:?}", ids);
+
+        Ok(ids)
+    }
+
+    #[tracing::instrument]
+    async fn claim_due(
+        &self,
+        now: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<DueWebhookDelivery>> {
+        let rows = sqlx::query_as::<_, DueWebhookDeliveryRow>(
+            r#"
+                WITH due AS (
+                    SELECT
+                        webhook_deliveries.id
+                    FROM
+                        webhook_deliveries
+                    JOIN
+                        webhooks ON webhooks.id = webhook_deliveries.webhook_id
+                    WHERE
+                        webhook_deliveries.status = 'Pending'
+                        AND webhook_deliveries.next_attempt_at <= $1
+                        AND webhooks.active
+                    ORDER BY
+                        webhook_deliveries.next_attempt_at
+                    LIMIT $2
+                    FOR UPDATE OF webhook_deliveries SKIP LOCKED
+                )
+                UPDATE webhook_deliveries SET
+                    next_attempt_at = $1 + make_interval(secs => $3)
+                FROM
+                    due,
+                    webhooks
+                WHERE
+                    webhook_deliveries.id = due.id
+                    AND webhooks.id = webhook_deliveries.webhook_id
+                RETURNING
+                    webhook_deliveries.*,
+                    webhooks.url,
+                    webhooks.secret
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .bind(CLAIM_LEASE_SECONDS)
+        .fetch_all(self.db.inner_ref())
+        .await?;
+
+        tracing::info!(
+            "{:?}",
+            rows.iter().map(|row| row.delivery.id).collect::<Vec<_>>()
+        );
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(DueWebhookDelivery::new(
+                    WebhookDelivery::try_from(row.delivery)?,
+                    row.url,
+                    row.secret,
+                ))
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(skip(data))]
+    async fn record_attempt(&self, data: RecordWebhookAttempt) -> anyhow::Result<()> {
+        let RecordWebhookAttempt {
+            id,
+            status,
+            attempts,
+            next_attempt_at,
+            response_status,
+            response_body,
+            error,
+        } = data;
+
+        let result = sqlx::query(
+            r#"
+                UPDATE webhook_deliveries SET
+                    status = $2,
+                    attempts = $3,
+                    next_attempt_at = $4,
+                    response_status = $5,
+                    response_body = $6,
+                    error = $7
+                WHERE
+                    id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(status.as_str())
+        .bind(attempts)
+        .bind(next_attempt_at)
+        .bind(response_status)
+        .bind(response_body)
+        .bind(error)
+        .execute(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn redeliver(
+        &self,
+        webhook_id: String,
+        delivery_id: String,
+    ) -> anyhow::Result<WebhookDelivery> {
+        let webhook_id = uuid::Uuid::from_str(&webhook_id)?;
+        let delivery_id = uuid::Uuid::from_str(&delivery_id)?;
+
+        let row = sqlx::query_as::<_, WebhookDeliveryRow>(
+            r#"
+                INSERT INTO webhook_deliveries (id, webhook_id, event, payload)
+                SELECT
+                    $1, webhook_id, event, payload
+                FROM
+                    webhook_deliveries
+                WHERE
+                    id = $2
+                    AND webhook_id = $3
+                RETURNING
+                    *
+            "#,
+        )
+        .bind(uuid::Uuid::now_v7())
+        .bind(delivery_id)
+        .bind(webhook_id)
+        .fetch_one(self.db.inner_ref())
+        .await?;
+
+        tracing::info!("{:?}", row);
+
+        WebhookDelivery::try_from(row)
+    }
+}
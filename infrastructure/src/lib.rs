@@ -1,5 +1,8 @@
 pub mod database;
+pub mod mailer;
+pub mod oidc;
 pub mod storage;
+pub mod webhook;
 
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,17 @@
+use domain::repository::mailer::Mailer;
+
+#[derive(derive_new::new, Debug, Default)]
+pub struct LogMailerImpl;
+
+#[async_trait::async_trait]
+impl Mailer for LogMailerImpl {
+    async fn send_verification(&self, email: String, token: String) -> anyhow::Result<()> {
+        tracing::info!(
+            "Send email verification to {}: POST /auth/verify {{\"token\": \"{}\"}}",
+            email,
+            token
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use shared::config::OidcConfig;
+
+use domain::model::auth::AuthError;
+use domain::model::oidc::{OidcClaims, OidcLoginState};
+use domain::repository::oidc::OidcClient;
+
+const ID_TOKEN_LEEWAY_SECONDS: u64 = 60;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Debug)]
+pub struct OidcClientImpl {
+    config: OidcConfig,
+    http: reqwest::Client,
+    metadata: tokio::sync::OnceCell<ProviderMetadata>,
+    jwks: tokio::sync::RwLock<Option<JwkSet>>,
+}
+
+impl OidcClientImpl {
+    pub fn new(config: OidcConfig) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::new(),
+            metadata: tokio::sync::OnceCell::new(),
+            jwks: tokio::sync::RwLock::new(None),
+        }
+    }
+
+    async fn metadata(&self) -> anyhow::Result<&ProviderMetadata> {
+        self.metadata
+            .get_or_try_init(|| async {
+                let url = format!(
+                    "{}/.well-known/openid-configuration",
+                    self.config.issuer_url.trim_end_matches('/')
+                );
+                let metadata = self
+                    .http
+                    .get(url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<ProviderMetadata>()
+                    .await?;
+
+                tracing::info!("{:?}", metadata);
+
+                Ok(metadata)
+            })
+            .await
+    }
+
+    async fn decoding_key(&self, kid: Option<&str>) -> anyhow::Result<DecodingKey> {
+        let find = |jwks: &JwkSet| match kid {
+            Some(kid) => jwks.find(kid).cloned(),
+            None if jwks.keys.len() == 1 => jwks.keys.first().cloned(),
+            None => None,
+        };
+
+        if let Some(jwk) = self.jwks.read().await.as_ref().and_then(find) {
+            return Ok(DecodingKey::from_jwk(&jwk)?);
+        }
+
+        // The provider may have rotated its signing keys since the last fetch.
+        let metadata = self.metadata().await?;
+        let jwks = self
+            .http
+            .get(&metadata.jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<JwkSet>()
+            .await?;
+        let jwk = find(&jwks).ok_or(AuthError::InvalidIdToken)?;
+        *self.jwks.write().await = Some(jwks);
+
+        Ok(DecodingKey::from_jwk(&jwk)?)
+    }
+
+    async fn validate_id_token(
+        &self,
+        id_token: &str,
+        nonce: &str,
+    ) -> anyhow::Result<serde_json::Value> {
+        let metadata = self.metadata().await?;
+        let header =
+            jsonwebtoken::decode_header(id_token).map_err(|_| AuthError::InvalidIdToken)?;
+
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(AuthError::InvalidIdToken.into());
+        }
+
+        let key = self.decoding_key(header.kid.as_deref()).await?;
+        let mut validation = Validation::new(header.alg);
+        validation.leeway = ID_TOKEN_LEEWAY_SECONDS;
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[&self.config.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let claims = jsonwebtoken::decode::<serde_json::Value>(id_token, &key, &validation)
+            .map_err(|error| {
+                tracing::warn!("{:?}", error);
+                AuthError::InvalidIdToken
+            })?
+            .claims;
+
+        if claims.get("nonce").and_then(|value| value.as_str()) != Some(nonce) {
+            return Err(AuthError::InvalidIdToken.into());
+        }
+
+        Ok(claims)
+    }
+
+    fn to_claims(&self, claims: &serde_json::Value) -> anyhow::Result<OidcClaims> {
+        let string = |key: &str| {
+            claims
+                .get(key)
+                .and_then(|value| value.as_str())
+                .map(String::from)
+        };
+
+        let subject = string("sub").ok_or(AuthError::InvalidIdToken)?;
+        let email_verified = match claims.get("email_verified") {
+            Some(serde_json::Value::Bool(value)) => *value,
+            Some(serde_json::Value::String(value)) => value == "true",
+            _ => false,
+        };
+        let groups = self
+            .config
+            .role_claim
+            .split('.')
+            .try_fold(claims, |value, key| value.get(key))
+            .map(|value| match value {
+                serde_json::Value::Array(values) => values
+                    .iter()
+                    .filter_map(|value| value.as_str().map(String::from))
+                    .collect(),
+                serde_json::Value::String(value) => vec![value.clone()],
+                _ => vec![],
+            })
+            .unwrap_or_default();
+
+        Ok(OidcClaims {
+            issuer: string("iss").ok_or(AuthError::InvalidIdToken)?,
+            subject,
+            email: string("email"),
+            email_verified,
+            name: string("name").or_else(|| string("preferred_username")),
+            picture: string("picture"),
+            groups,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcClient for OidcClientImpl {
+    async fn authorization_url(&self, login_state: OidcLoginState) -> anyhow::Result<String> {
+        let metadata = self.metadata().await?;
+        let mut url = reqwest::Url::parse(&metadata.authorization_endpoint)?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &self.config.client_id)
+            .append_pair("redirect_uri", &self.config.redirect_url)
+            .append_pair("scope", "openid email profile")
+            .append_pair("state", &login_state.state)
+            .append_pair("nonce", &login_state.nonce)
+            .append_pair("code_challenge", &login_state.code_challenge())
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(url.into())
+    }
+
+    async fn exchange(
+        &self,
+        code: String,
+        login_state: OidcLoginState,
+    ) -> anyhow::Result<OidcClaims> {
+        let metadata = self.metadata().await?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", &code),
+            ("redirect_uri", &self.config.redirect_url),
+            ("client_id", &self.config.client_id),
+            ("code_verifier", &login_state.code_verifier),
+        ];
+        if let Some(client_secret) = &self.config.client_secret {
+            form.push(("client_secret", client_secret));
+        }
+
+        let response = self
+            .http
+            .post(&metadata.token_endpoint)
+            .form(&form)
+            .send()
+            .await?;
+
+        if response.status().is_client_error() {
+            tracing::warn!("{:?}", response.text().await);
+            return Err(AuthError::InvalidLoginState.into());
+        }
+
+        let token = response.error_for_status()?.json::<TokenResponse>().await?;
+        let claims = self
+            .validate_id_token(&token.id_token, &login_state.nonce)
+            .await?;
+
+        self.to_claims(&claims)
+    }
+}
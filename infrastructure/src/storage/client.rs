@@ -1,6 +1,6 @@
 use shared::config::StorageConfig;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StorageClient(aws_sdk_s3::Client);
 
 impl StorageClient {
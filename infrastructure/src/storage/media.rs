@@ -1,5 +1,19 @@
 use aws_sdk_s3::operation::create_bucket::CreateBucketOutput;
+use aws_sdk_s3::primitives::ByteStream;
 
+use domain::model::media::{
+    object_key, Media, MediaInUseError, MediaMetadata, MediaUploader, MediaUsage,
+};
+use domain::model::page::Page;
+use domain::model::trash::TrashError;
+use domain::model::webhook::WebhookEvent;
+use domain::repository::media::{
+    CreateMedia, GetMediaQuery, MediaObject, MediaRepository, UpdateMedia,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::outbox_repository::insert_outbox_event;
+use crate::database::pagination::{fetch_total, push_keyset};
 use crate::storage::client::StorageClient;
 
 #[derive(Debug)]
@@ -19,9 +33,205 @@ impl TryFrom<CreateBucketOutput> for Bucket {
     }
 }
 
+#[derive(Debug)]
+pub struct StorageObject {
+    pub key: String,
+    pub size: i64,
+    pub content_type: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct MediaRow {
+    pub id: uuid::Uuid,
+    pub object_key: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub alt_text: Option<String>,
+    pub uploaded_by_id: uuid::Uuid,
+    pub uploaded_by_name: String,
+    pub created_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub updated_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+}
+
+impl TryFrom<MediaRow> for Media {
+    type Error = anyhow::Error;
+    fn try_from(row: MediaRow) -> anyhow::Result<Self> {
+        let MediaRow {
+            id,
+            object_key,
+            filename,
+            mime_type,
+            size,
+            checksum,
+            width,
+            height,
+            alt_text,
+            uploaded_by_id,
+            uploaded_by_name,
+            created_at,
+            updated_at,
+        } = row;
+
+        Media::try_new(
+            id,
+            object_key,
+            filename,
+            mime_type,
+            size,
+            checksum,
+            width,
+            height,
+            alt_text,
+            MediaUploader::new(uploaded_by_id.to_string(), uploaded_by_name),
+            created_at,
+            updated_at,
+        )
+    }
+}
+
+const SELECT_MEDIA: &str = r#"
+    SELECT
+        media.id,
+        media.object_key,
+        media.filename,
+        media.mime_type,
+        media.size,
+        media.checksum,
+        media.width,
+        media.height,
+        media.alt_text,
+        media.uploaded_by AS uploaded_by_id,
+        users.name AS uploaded_by_name,
+        media.created_at,
+        media.updated_at
+    FROM
+        media
+    JOIN
+        users ON users.id = media.uploaded_by
+"#;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct MediaUsageRow {
+    pub content_id: uuid::Uuid,
+    pub content_title: String,
+    pub field_key: String,
+}
+
+impl From<MediaUsageRow> for MediaUsage {
+    fn from(row: MediaUsageRow) -> Self {
+        let MediaUsageRow {
+            content_id,
+            content_title,
+            field_key,
+        } = row;
+
+        MediaUsage::new(content_id, content_title, field_key)
+    }
+}
+
+async fn fetch_usages(
+    connection: &mut sqlx::PgConnection,
+    id: uuid::Uuid,
+) -> anyhow::Result<Vec<MediaUsage>> {
+    let rows = sqlx::query_as::<_, MediaUsageRow>(
+        r#"
+            SELECT
+                contents.id AS content_id,
+                contents.title AS content_title,
+                content_media.field_key
+            FROM
+                content_media
+            JOIN
+                contents ON contents.id = content_media.content_id
+            WHERE
+                content_media.media_id = $1
+            ORDER BY
+                contents.id, content_media.field_key
+        "#,
+    )
+    .bind(id)
+    .fetch_all(&mut *connection)
+    .await?;
+
+    Ok(rows.into_iter().map(MediaUsage::from).collect())
+}
+
+pub(crate) async fn fetch_media(
+    connection: &mut sqlx::PgConnection,
+    id: uuid::Uuid,
+) -> anyhow::Result<Media> {
+    let row = sqlx::query_as::<_, MediaRow>(&format!(
+        "{} WHERE media.id = $1 AND media.deleted_at IS NULL",
+        SELECT_MEDIA
+    ))
+    .bind(id)
+    .fetch_one(&mut *connection)
+    .await?;
+
+    Media::try_from(row)
+}
+
+pub(crate) struct InsertMedia {
+    pub id: uuid::Uuid,
+    pub object_key: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub metadata: MediaMetadata,
+    pub alt_text: Option<String>,
+    pub uploaded_by_id: uuid::Uuid,
+}
+
+pub(crate) async fn insert_media(
+    connection: &mut sqlx::PgConnection,
+    data: InsertMedia,
+) -> anyhow::Result<Media> {
+    let InsertMedia {
+        id,
+        object_key,
+        filename,
+        mime_type,
+        metadata,
+        alt_text,
+        uploaded_by_id,
+    } = data;
+
+    let result = sqlx::query(
+        r#"
+            INSERT INTO
+                media (id, object_key, filename, mime_type, size, checksum, width, height, alt_text, uploaded_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "#,
+    )
+    .bind(id)
+    .bind(object_key)
+    .bind(filename)
+    .bind(mime_type)
+    .bind(metadata.size)
+    .bind(metadata.checksum)
+    .bind(metadata.width)
+    .bind(metadata.height)
+    .bind(alt_text)
+    .bind(uploaded_by_id)
+    .execute(&mut *connection)
+    .await?;
+
+    tracing::info!("{:?}", result);
+
+    let media = fetch_media(connection, id).await?;
+    insert_outbox_event(&mut *connection, WebhookEvent::MediaCreated, None, &media).await?;
+
+    Ok(media)
+}
+
 #[derive(derive_new::new, Debug)]
 pub struct MediaRepositoryImpl {
+    db: ConnectionPool,
     client: StorageClient,
+    bucket: String,
 }
 
 impl MediaRepositoryImpl {
@@ -60,11 +270,367 @@ impl MediaRepositoryImpl {
         Ok(())
     }
 
-    fn create_object() {}
+    #[tracing::instrument(skip(body))]
+    pub async fn create_object(
+        &self,
+        key: &str,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        let result = self
+            .client
+            .inner_ref()
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(ByteStream::from(body))
+            .send()
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    pub async fn get_object(&self, key: &str) -> anyhow::Result<StorageObject> {
+        let result = self
+            .client
+            .inner_ref()
+            .head_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(StorageObject {
+            key: key.into(),
+            size: result.content_length().unwrap_or_default(),
+            content_type: result.content_type().map(String::from),
+        })
+    }
+
+    #[tracing::instrument]
+    pub async fn download_object(&self, key: &str) -> anyhow::Result<Vec<u8>> {
+        let result = self
+            .client
+            .inner_ref()
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await?;
+
+        let body = result.body.collect().await?.into_bytes().to_vec();
+
+        Ok(body)
+    }
+
+    #[tracing::instrument]
+    pub async fn delete_variants(&self, id: &uuid::Uuid) -> anyhow::Result<()> {
+        let mut continuation_token: Option<String> = None;
+
+        loop {
+            let result = self
+                .client
+                .inner_ref()
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(format!("variants/{}/", id))
+                .set_continuation_token(continuation_token)
+                .send()
+                .await?;
+
+            for object in result.contents() {
+                if let Some(key) = object.key() {
+                    self.delete_object(key).await?;
+                }
+            }
+
+            if !result.is_truncated().unwrap_or_default() {
+                break;
+            }
+
+            continuation_token = result.next_continuation_token().map(String::from);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    pub async fn delete_object(&self, key: &str) -> anyhow::Result<()> {
+        let result = self
+            .client
+            .inner_ref()
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaRepository for MediaRepositoryImpl {
+    #[tracing::instrument]
+    async fn get(&self, query: GetMediaQuery) -> anyhow::Result<Page<Media>> {
+        let GetMediaQuery { page } = query;
+
+        let total = match page.with_total {
+            true => {
+                let count_builder =
+                    sqlx::QueryBuilder::new("SELECT COUNT(*) FROM media WHERE deleted_at IS NULL");
+
+                Some(fetch_total(self.db.inner_ref(), count_builder).await?)
+            }
+            false => None,
+        };
+
+        let mut query_builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(SELECT_MEDIA);
+        query_builder.push(" WHERE media.deleted_at IS NULL");
+        push_keyset(&mut query_builder, "media.id", &page, true)?;
+
+        let rows = query_builder
+            .build_query_as::<MediaRow>()
+            .fetch_all(self.db.inner_ref())
+            .await?;
+
+        let result =
+            Page::from_keyset_rows(rows, &page, |row| row.id, total).try_map(Media::try_from);
+        tracing::info!("{:?}", result);
+
+        result
+    }
+
+    #[tracing::instrument]
+    async fn find(&self, id: String) -> anyhow::Result<Media> {
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut connection = self.db.inner_ref().acquire().await?;
+
+        fetch_media(&mut connection, parsed_id).await
+    }
+
+    #[tracing::instrument(skip(data), fields(filename = %data.filename))]
+    async fn create(&self, data: CreateMedia) -> anyhow::Result<Media> {
+        let CreateMedia {
+            filename,
+            mime_type,
+            body,
+            metadata,
+            alt_text,
+            uploaded_by_id,
+        } = data;
+
+        let id = uuid::Uuid::now_v7();
+        let key = object_key(&id, &filename);
+        let parsed_uploaded_by_id = uuid::Uuid::parse_str(&uploaded_by_id)?;
+
+        self.create_object(&key, &mime_type, body).await?;
+
+        let data = InsertMedia {
+            id,
+            object_key: key.clone(),
+            filename,
+            mime_type,
+            metadata,
+            alt_text,
+            uploaded_by_id: parsed_uploaded_by_id,
+        };
+        let result: anyhow::Result<Media> = async {
+            let mut transaction = self.db.inner_ref().begin().await?;
+            let media = insert_media(&mut transaction, data).await?;
+            transaction.commit().await?;
+
+            Ok(media)
+        }
+        .await;
+
+        if result.is_err() {
+            tracing::error!("failed insert media, removing object {}", key);
+            self.delete_object(&key).await?;
+        }
+
+        result
+    }
+
+    #[tracing::instrument]
+    async fn update(&self, data: UpdateMedia) -> anyhow::Result<Media> {
+        let UpdateMedia {
+            id,
+            filename,
+            alt_text,
+        } = data;
+
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        if filename.is_some() || alt_text.is_some() {
+            let mut query_builder =
+                sqlx::QueryBuilder::<'_, sqlx::Postgres>::new("UPDATE media SET ");
+            let mut separated = query_builder.separated(",");
+
+            if let Some(filename) = filename {
+                separated.push("filename = ");
+                separated.push_bind_unseparated(filename);
+            }
+
+            if let Some(alt_text) = alt_text {
+                separated.push("alt_text = ");
+                separated.push_bind_unseparated(Some(alt_text).filter(|x| !x.is_empty()));
+            }
+
+            query_builder.push(" WHERE media.id = ");
+            query_builder.push_bind(parsed_id);
+
+            let result = query_builder.build().execute(&mut *transaction).await?;
+            tracing::info!("{:?}", result);
+        }
+
+        let media = fetch_media(&mut transaction, parsed_id).await?;
+        insert_outbox_event(&mut *transaction, WebhookEvent::MediaUpdated, None, &media).await?;
+        transaction.commit().await?;
+
+        Ok(media)
+    }
+
+    #[tracing::instrument]
+    async fn delete(&self, id: String, force: bool) -> anyhow::Result<()> {
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        sqlx::query(r#"SELECT id FROM media WHERE id = $1 AND deleted_at IS NULL FOR UPDATE"#)
+            .bind(parsed_id)
+            .fetch_one(&mut *transaction)
+            .await?;
+
+        let usages = fetch_usages(&mut transaction, parsed_id).await?;
+
+        if !usages.is_empty() {
+            if !force {
+                return Err(MediaInUseError { usages }.into());
+            }
+
+            let result = sqlx::query(r#"DELETE FROM content_media WHERE media_id = $1"#)
+                .bind(parsed_id)
+                .execute(&mut *transaction)
+                .await?;
+            tracing::info!("{:?}", result);
+        }
+
+        let result =
+            sqlx::query(r#"UPDATE media SET deleted_at = CURRENT_TIMESTAMP(3) WHERE id = $1"#)
+                .bind(parsed_id)
+                .execute(&mut *transaction)
+                .await?;
+        tracing::info!("{:?}", result);
+
+        insert_outbox_event(
+            &mut *transaction,
+            WebhookEvent::MediaDeleted,
+            None,
+            &serde_json::json!({ "id": id }),
+        )
+        .await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn restore(&self, id: String) -> anyhow::Result<Media> {
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let restored = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"UPDATE media SET deleted_at = NULL WHERE id = $1 AND deleted_at IS NOT NULL RETURNING id"#,
+        )
+        .bind(parsed_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        if restored.is_none() {
+            return Err(TrashError::NotInTrash.into());
+        }
+
+        let media = fetch_media(&mut transaction, parsed_id).await?;
+        insert_outbox_event(&mut *transaction, WebhookEvent::MediaRestored, None, &media).await?;
+        transaction.commit().await?;
+
+        Ok(media)
+    }
+
+    #[tracing::instrument]
+    async fn purge(
+        &self,
+        before: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<uuid::Uuid>> {
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let rows = sqlx::query_as::<_, (uuid::Uuid, String)>(
+            r#"
+                WITH expired AS (
+                    SELECT
+                        id
+                    FROM
+                        media
+                    WHERE
+                        deleted_at < $1
+                        AND NOT EXISTS (
+                            SELECT 1 FROM content_media WHERE content_media.media_id = media.id
+                        )
+                    ORDER BY
+                        deleted_at
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                DELETE FROM
+                    media
+                USING
+                    expired
+                WHERE
+                    media.id = expired.id
+                RETURNING
+                    media.id, media.object_key
+            "#,
+        )
+        .bind(before)
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        tracing::info!("{:?}", rows);
+
+        for (id, key) in rows.iter() {
+            self.delete_object(key).await?;
+            self.delete_variants(id).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(rows.into_iter().map(|(id, _)| id).collect())
+    }
+
+    #[tracing::instrument]
+    async fn usages(&self, id: String) -> anyhow::Result<Vec<MediaUsage>> {
+        let parsed_id = uuid::Uuid::parse_str(&id)?;
+        let mut connection = self.db.inner_ref().acquire().await?;
 
-    fn get_object() {}
+        fetch_usages(&mut connection, parsed_id).await
+    }
 
-    fn download_object() {}
+    #[tracing::instrument]
+    async fn download(&self, id: String) -> anyhow::Result<MediaObject> {
+        let media = self.find(id).await?;
+        let body = self.download_object(&media.object_key).await?;
 
-    fn delete_object() {}
+        Ok(MediaObject { media, body })
+    }
 }
@@ -1,2 +1,4 @@
 pub mod client;
 pub mod media;
+pub mod render;
+pub mod upload;
@@ -0,0 +1,141 @@
+use aws_sdk_s3::primitives::ByteStream;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ExtendedColorType, ImageEncoder};
+
+use domain::model::media::Media;
+use domain::model::render::{RenderError, RenderFit, RenderFormat, RenderOptions, RenderedImage};
+use domain::repository::render::MediaRenderRepository;
+
+use crate::storage::client::StorageClient;
+
+fn resize(image: DynamicImage, options: &RenderOptions) -> DynamicImage {
+    let filter = FilterType::Lanczos3;
+
+    match (options.width, options.height) {
+        (Some(width), Some(height)) => match options.fit {
+            RenderFit::Cover => image.resize_to_fill(width, height, filter),
+            RenderFit::Contain => image.resize(width, height, filter),
+            RenderFit::Fill => image.resize_exact(width, height, filter),
+        },
+        (Some(width), None) => image.resize(width, u32::MAX, filter),
+        (None, Some(height)) => image.resize(u32::MAX, height, filter),
+        (None, None) => image,
+    }
+}
+
+pub fn transform(body: &[u8], options: &RenderOptions) -> anyhow::Result<Vec<u8>> {
+    let image = image::load_from_memory(body).map_err(|_| RenderError::NotAnImage)?;
+    let image = resize(image, options);
+    let mut buffer = vec![];
+
+    match options.format {
+        RenderFormat::Jpeg => {
+            let image = image.to_rgb8();
+            JpegEncoder::new_with_quality(&mut buffer, options.quality).write_image(
+                &image,
+                image.width(),
+                image.height(),
+                ExtendedColorType::Rgb8,
+            )?;
+        }
+        RenderFormat::Png => {
+            let image = image.to_rgba8();
+            PngEncoder::new(&mut buffer).write_image(
+                &image,
+                image.width(),
+                image.height(),
+                ExtendedColorType::Rgba8,
+            )?;
+        }
+        RenderFormat::Webp => {
+            let image = image.to_rgba8();
+            WebPEncoder::new_lossless(&mut buffer).write_image(
+                &image,
+                image.width(),
+                image.height(),
+                ExtendedColorType::Rgba8,
+            )?;
+        }
+    }
+
+    Ok(buffer)
+}
+
+#[derive(derive_new::new, Debug)]
+pub struct MediaRenderRepositoryImpl {
+    client: StorageClient,
+    bucket: String,
+}
+
+#[async_trait::async_trait]
+impl MediaRenderRepository for MediaRenderRepositoryImpl {
+    #[tracing::instrument]
+    async fn find_variant(&self, key: String) -> anyhow::Result<Option<RenderedImage>> {
+        let result = self
+            .client
+            .inner_ref()
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .send()
+            .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_no_such_key()) =>
+            {
+                return Ok(None);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let content_type = output
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let body = output.body.collect().await?.into_bytes().to_vec();
+
+        Ok(Some(RenderedImage { content_type, body }))
+    }
+
+    #[tracing::instrument(skip(media), fields(media_id = %media.id))]
+    async fn render(
+        &self,
+        media: Media,
+        options: RenderOptions,
+        key: String,
+    ) -> anyhow::Result<RenderedImage> {
+        let output = self
+            .client
+            .inner_ref()
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&media.object_key)
+            .send()
+            .await?;
+        let original = output.body.collect().await?.into_bytes();
+
+        let body = tokio::task::spawn_blocking(move || transform(&original, &options)).await??;
+        let content_type = options.format.content_type().to_string();
+
+        let result = self
+            .client
+            .inner_ref()
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .content_type(&content_type)
+            .body(ByteStream::from(body.clone()))
+            .send()
+            .await?;
+        tracing::info!("{:?}", result);
+
+        Ok(RenderedImage { content_type, body })
+    }
+}
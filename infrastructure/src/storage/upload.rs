@@ -0,0 +1,442 @@
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
+
+use domain::model::media::{object_key, Media, MediaInspector, MediaMetadata};
+use domain::model::upload::{PresignedPart, PresignedUrl, UploadError, UploadPlan, UploadSession};
+use domain::repository::upload::{CreateUploadSession, MediaUploadRepository};
+
+use crate::database::connection::ConnectionPool;
+use crate::storage::client::StorageClient;
+use crate::storage::media::{insert_media, InsertMedia};
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct UploadSessionRow {
+    pub id: uuid::Uuid,
+    pub object_key: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+    pub multipart_upload_id: Option<String>,
+    pub part_size: Option<i64>,
+    pub part_count: Option<i32>,
+    pub uploaded_by: uuid::Uuid,
+    pub expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+    pub completed_at: Option<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>,
+}
+
+impl From<UploadSessionRow> for UploadSession {
+    fn from(row: UploadSessionRow) -> Self {
+        let plan = match (row.part_size, row.part_count) {
+            (Some(part_size), Some(part_count)) => Some(UploadPlan {
+                part_size,
+                part_count,
+            }),
+            _ => None,
+        };
+
+        Self {
+            id: row.id,
+            object_key: row.object_key,
+            filename: row.filename,
+            mime_type: row.mime_type,
+            size: row.size,
+            checksum: row.checksum,
+            alt_text: row.alt_text,
+            multipart_upload_id: row.multipart_upload_id,
+            plan,
+            uploaded_by_id: row.uploaded_by.to_string(),
+            expires_at: row.expires_at,
+            completed_at: row.completed_at,
+        }
+    }
+}
+
+const SELECT_UPLOAD_SESSION: &str = r#"
+    SELECT
+        id,
+        object_key,
+        filename,
+        mime_type,
+        size,
+        checksum,
+        alt_text,
+        multipart_upload_id,
+        part_size,
+        part_count,
+        uploaded_by,
+        expires_at,
+        completed_at
+    FROM
+        upload_sessions
+"#;
+
+#[derive(derive_new::new, Debug)]
+pub struct MediaUploadRepositoryImpl {
+    db: ConnectionPool,
+    client: StorageClient,
+    bucket: String,
+}
+
+impl MediaUploadRepositoryImpl {
+    #[tracing::instrument]
+    async fn completed_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+    ) -> anyhow::Result<Vec<CompletedPart>> {
+        let mut parts = vec![];
+        let mut marker: Option<String> = None;
+
+        loop {
+            let result = self
+                .client
+                .inner_ref()
+                .list_parts()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .set_part_number_marker(marker)
+                .send()
+                .await?;
+
+            parts.extend(result.parts().iter().map(|part| {
+                CompletedPart::builder()
+                    .set_part_number(part.part_number())
+                    .set_e_tag(part.e_tag().map(String::from))
+                    .build()
+            }));
+
+            if !result.is_truncated().unwrap_or_default() {
+                break;
+            }
+
+            marker = result.next_part_number_marker().map(String::from);
+        }
+
+        Ok(parts)
+    }
+}
+
+#[async_trait::async_trait]
+impl MediaUploadRepository for MediaUploadRepositoryImpl {
+    #[tracing::instrument]
+    async fn create_session(&self, data: CreateUploadSession) -> anyhow::Result<UploadSession> {
+        let CreateUploadSession {
+            filename,
+            mime_type,
+            size,
+            checksum,
+            alt_text,
+            plan,
+            uploaded_by_id,
+            expires_at,
+        } = data;
+
+        let id = uuid::Uuid::now_v7();
+        let key = object_key(&id, &filename);
+        let parsed_uploaded_by_id = uuid::Uuid::parse_str(&uploaded_by_id)?;
+
+        let multipart_upload_id = match plan {
+            Some(_) => {
+                let result = self
+                    .client
+                    .inner_ref()
+                    .create_multipart_upload()
+                    .bucket(&self.bucket)
+                    .key(&key)
+                    .content_type(&mime_type)
+                    .send()
+                    .await?;
+                tracing::info!("{:?}", result);
+
+                let upload_id = result
+                    .upload_id()
+                    .ok_or_else(|| anyhow::anyhow!("None CreateMultipartUploadOutput.upload_id"))?;
+
+                Some(upload_id.to_string())
+            }
+            None => None,
+        };
+
+        let row = sqlx::query_as::<_, UploadSessionRow>(
+            r#"
+                INSERT INTO
+                    upload_sessions (id, object_key, filename, mime_type, size, checksum, alt_text, multipart_upload_id, part_size, part_count, uploaded_by, expires_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                RETURNING
+                    id, object_key, filename, mime_type, size, checksum, alt_text, multipart_upload_id, part_size, part_count, uploaded_by, expires_at, completed_at
+            "#,
+        )
+        .bind(id)
+        .bind(&key)
+        .bind(filename)
+        .bind(mime_type)
+        .bind(size)
+        .bind(checksum)
+        .bind(alt_text)
+        .bind(&multipart_upload_id)
+        .bind(plan.as_ref().map(|plan| plan.part_size))
+        .bind(plan.as_ref().map(|plan| plan.part_count))
+        .bind(parsed_uploaded_by_id)
+        .bind(expires_at)
+        .fetch_one(self.db.inner_ref())
+        .await;
+
+        match row {
+            Ok(row) => Ok(UploadSession::from(row)),
+            Err(error) => {
+                if let Some(upload_id) = multipart_upload_id {
+                    tracing::error!("failed insert upload session, aborting upload {}", key);
+                    self.client
+                        .inner_ref()
+                        .abort_multipart_upload()
+                        .bucket(&self.bucket)
+                        .key(&key)
+                        .upload_id(upload_id)
+                        .send()
+                        .await?;
+                }
+
+                Err(error.into())
+            }
+        }
+    }
+
+    #[tracing::instrument]
+    async fn find_session(&self, id: String) -> anyhow::Result<Option<UploadSession>> {
+        let Ok(parsed_id) = uuid::Uuid::parse_str(&id) else {
+            return Ok(None);
+        };
+
+        let row = sqlx::query_as::<_, UploadSessionRow>(&format!(
+            "{} WHERE id = $1",
+            SELECT_UPLOAD_SESSION
+        ))
+        .bind(parsed_id)
+        .fetch_optional(self.db.inner_ref())
+        .await?;
+
+        Ok(row.map(UploadSession::from))
+    }
+
+    #[tracing::instrument]
+    async fn presign_upload(
+        &self,
+        session: UploadSession,
+        expires_in: std::time::Duration,
+    ) -> anyhow::Result<Vec<PresignedPart>> {
+        let config = PresigningConfig::expires_in(expires_in)?;
+
+        let (Some(upload_id), Some(plan)) = (&session.multipart_upload_id, &session.plan) else {
+            let request = self
+                .client
+                .inner_ref()
+                .put_object()
+                .bucket(&self.bucket)
+                .key(&session.object_key)
+                .content_type(&session.mime_type)
+                .presigned(config)
+                .await?;
+
+            return Ok(vec![PresignedPart::new(
+                1,
+                request.uri().to_string(),
+                session.size,
+            )]);
+        };
+
+        let mut parts = vec![];
+        for part_number in 1..=plan.part_count {
+            let request = self
+                .client
+                .inner_ref()
+                .upload_part()
+                .bucket(&self.bucket)
+                .key(&session.object_key)
+                .upload_id(upload_id)
+                .part_number(part_number)
+                .presigned(config.clone())
+                .await?;
+
+            parts.push(PresignedPart::new(
+                part_number,
+                request.uri().to_string(),
+                plan.part_length(session.size, part_number),
+            ));
+        }
+
+        Ok(parts)
+    }
+
+    #[tracing::instrument]
+    async fn finish_upload(&self, session: UploadSession) -> anyhow::Result<()> {
+        let Some(upload_id) = &session.multipart_upload_id else {
+            return Ok(());
+        };
+
+        let parts = self.completed_parts(&session.object_key, upload_id).await?;
+        let expected = session.plan.as_ref().map(|plan| plan.part_count);
+
+        if parts.is_empty() || expected != i32::try_from(parts.len()).ok() {
+            return Err(UploadError::ObjectMissing.into());
+        }
+
+        let result = self
+            .client
+            .inner_ref()
+            .complete_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&session.object_key)
+            .upload_id(upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build(),
+            )
+            .send()
+            .await?;
+
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn inspect_object(&self, object_key: String) -> anyhow::Result<MediaMetadata> {
+        let result = self
+            .client
+            .inner_ref()
+            .get_object()
+            .bucket(&self.bucket)
+            .key(&object_key)
+            .send()
+            .await;
+
+        let mut output = match result {
+            Ok(output) => output,
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_no_such_key()) =>
+            {
+                return Err(UploadError::ObjectMissing.into());
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut inspector = MediaInspector::default();
+        while let Some(chunk) = output.body.try_next().await? {
+            inspector.update(&chunk);
+        }
+
+        Ok(inspector.finish())
+    }
+
+    #[tracing::instrument]
+    async fn register(
+        &self,
+        session: UploadSession,
+        metadata: MediaMetadata,
+    ) -> anyhow::Result<Media> {
+        let UploadSession {
+            id,
+            object_key,
+            filename,
+            mime_type,
+            alt_text,
+            uploaded_by_id,
+            ..
+        } = session;
+
+        let parsed_uploaded_by_id = uuid::Uuid::parse_str(&uploaded_by_id)?;
+        let mut transaction = self.db.inner_ref().begin().await?;
+
+        let result = sqlx::query(
+            r#"UPDATE upload_sessions SET completed_at = CURRENT_TIMESTAMP(3) WHERE id = $1 AND completed_at IS NULL"#,
+        )
+        .bind(id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(UploadError::AlreadyCompleted.into());
+        }
+
+        let data = InsertMedia {
+            id,
+            object_key,
+            filename,
+            mime_type,
+            metadata,
+            alt_text,
+            uploaded_by_id: parsed_uploaded_by_id,
+        };
+        let media = insert_media(&mut transaction, data).await?;
+        transaction.commit().await?;
+
+        Ok(media)
+    }
+
+    #[tracing::instrument]
+    async fn discard(&self, session: UploadSession) -> anyhow::Result<()> {
+        match &session.multipart_upload_id {
+            Some(upload_id) if session.completed_at.is_none() => {
+                let result = self
+                    .client
+                    .inner_ref()
+                    .abort_multipart_upload()
+                    .bucket(&self.bucket)
+                    .key(&session.object_key)
+                    .upload_id(upload_id)
+                    .send()
+                    .await;
+
+                if let Err(error) = result {
+                    tracing::warn!("failed abort multipart upload: {:?}", error);
+                }
+            }
+            _ => {}
+        }
+
+        let result = self
+            .client
+            .inner_ref()
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(&session.object_key)
+            .send()
+            .await?;
+        tracing::info!("{:?}", result);
+
+        let result = sqlx::query(r#"DELETE FROM upload_sessions WHERE id = $1"#)
+            .bind(session.id)
+            .execute(self.db.inner_ref())
+            .await?;
+        tracing::info!("{:?}", result);
+
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    async fn presign_download(
+        &self,
+        object_key: String,
+        expires_in: std::time::Duration,
+    ) -> anyhow::Result<PresignedUrl> {
+        let config = PresigningConfig::expires_in(expires_in)?;
+        let expires_at = sqlx::types::chrono::Utc::now() + expires_in;
+
+        let request = self
+            .client
+            .inner_ref()
+            .get_object()
+            .bucket(&self.bucket)
+            .key(object_key)
+            .presigned(config)
+            .await?;
+
+        Ok(PresignedUrl::new(request.uri().to_string(), expires_at))
+    }
+}
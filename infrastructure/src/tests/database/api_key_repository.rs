@@ -0,0 +1,97 @@
+use domain::model::api_key::{ApiKeyScope, ApiKeySecret};
+use domain::repository::api_key::{ApiKeyRepository, CreateApiKey, GetApiKeyQuery};
+
+use crate::database::api_key_repository::ApiKeyRepositoryImpl;
+use crate::database::connection::ConnectionPool;
+
+fn build_repository(pool: &sqlx::PgPool) -> ApiKeyRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    ApiKeyRepositoryImpl::new(connection_pool)
+}
+
+async fn get_category_id(pool: &sqlx::PgPool) -> String {
+    let id: uuid::Uuid = sqlx::query_scalar(r#"SELECT id FROM category LIMIT 1"#)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+    id.to_string()
+}
+
+fn build_create_api_key(secret: &ApiKeySecret, category_ids: Vec<String>) -> CreateApiKey {
+    CreateApiKey::new(
+        "frontend".into(),
+        secret.prefix.clone(),
+        secret.hash.clone(),
+        vec![ApiKeyScope::ReadPublished, ApiKeyScope::ReadDrafts],
+        category_ids,
+        None,
+    )
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn create_success(pool: sqlx::PgPool) {
+    let category_id = get_category_id(&pool).await;
+    let secret = ApiKeySecret::generate();
+
+    let repository = build_repository(&pool);
+    let result = repository
+        .create(build_create_api_key(&secret, vec![category_id.clone()]))
+        .await;
+
+    tracing::info!("{:?}", result);
+
+    let api_key = result.unwrap();
+    assert_eq!(api_key.prefix, secret.prefix);
+    assert_eq!(
+        api_key.scopes,
+        vec![ApiKeyScope::ReadPublished, ApiKeyScope::ReadDrafts]
+    );
+    assert_eq!(api_key.categories.len(), 1);
+    assert_eq!(api_key.categories[0].id, category_id);
+
+    let stored: String = sqlx::query_scalar(r#"SELECT secret_hash FROM api_keys"#)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(stored, secret.hash);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn find_by_hash_success(pool: sqlx::PgPool) {
+    let secret = ApiKeySecret::generate();
+    let repository = build_repository(&pool);
+    repository
+        .create(build_create_api_key(&secret, vec![]))
+        .await
+        .unwrap();
+
+    let found = repository.find_by_hash(secret.hash.clone()).await.unwrap();
+    let missing = repository
+        .find_by_hash(ApiKeySecret::hash("hcms_unknown"))
+        .await
+        .unwrap();
+
+    assert!(found.is_some());
+    assert!(missing.is_none());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn revoke_success(pool: sqlx::PgPool) {
+    let secret = ApiKeySecret::generate();
+    let repository = build_repository(&pool);
+    let api_key = repository
+        .create(build_create_api_key(&secret, vec![]))
+        .await
+        .unwrap();
+
+    let result = repository.revoke(api_key.id.to_string()).await;
+    assert!(result.is_ok());
+
+    let api_keys = repository.get(GetApiKeyQuery::default()).await.unwrap();
+    assert!(api_keys[0].revoked_at.is_some());
+}
@@ -0,0 +1,191 @@
+use domain::model::auth::{AuthToken, SessionSecret};
+use domain::repository::auth::{AuthRepository, CreateSession, RotateSession, SignUpUser};
+
+use crate::database::auth_repository::AuthRepositoryImpl;
+use crate::database::connection::ConnectionPool;
+
+fn build_repository(pool: &sqlx::PgPool) -> AuthRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    AuthRepositoryImpl::new(connection_pool)
+}
+
+fn build_signup_user(
+    email: &str,
+    verification: &AuthToken,
+    verification_expires_at: sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
+) -> SignUpUser {
+    SignUpUser::new(
+        "user1".into(),
+        email.into(),
+        "https://example.com/icon.png".into(),
+        "$argon2id$hash".into(),
+        verification.hash.clone(),
+        verification_expires_at,
+    )
+}
+
+fn build_create_session(user_id: String, secret: &SessionSecret) -> CreateSession {
+    CreateSession::new(
+        user_id,
+        secret.access.hash.clone(),
+        secret.access_expires_at,
+        secret.refresh.hash.clone(),
+        secret.refresh_expires_at,
+    )
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn signup_and_verify_success(pool: sqlx::PgPool) {
+    let now = sqlx::types::chrono::Utc::now();
+    let verification = AuthToken::verification();
+    let repository = build_repository(&pool);
+
+    let user_id = repository
+        .signup(build_signup_user(
+            "user@example.com",
+            &verification,
+            now + std::time::Duration::from_secs(3600),
+        ))
+        .await
+        .unwrap();
+
+    let credential = repository
+        .find_credential("user@example.com".into())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(credential.user_id, user_id);
+    assert_eq!(credential.password_hash.as_deref(), Some("$argon2id$hash"));
+    assert!(credential.email_verified_at.is_none());
+
+    let verified = repository
+        .verify_email(verification.hash.clone(), now)
+        .await
+        .unwrap();
+    let reused = repository
+        .verify_email(verification.hash.clone(), now)
+        .await
+        .unwrap();
+    assert!(verified);
+    assert!(!reused);
+
+    let credential = repository
+        .find_credential("user@example.com".into())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(credential.email_verified_at.is_some());
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn signup_duplicate_email_failure(pool: sqlx::PgPool) {
+    let expires_at = sqlx::types::chrono::Utc::now() + std::time::Duration::from_secs(3600);
+    let repository = build_repository(&pool);
+    repository
+        .signup(build_signup_user(
+            "user@example.com",
+            &AuthToken::verification(),
+            expires_at,
+        ))
+        .await
+        .unwrap();
+
+    let result = repository
+        .signup(build_signup_user(
+            "user@example.com",
+            &AuthToken::verification(),
+            expires_at,
+        ))
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn verify_expired_token_failure(pool: sqlx::PgPool) {
+    let now = sqlx::types::chrono::Utc::now();
+    let verification = AuthToken::verification();
+    let repository = build_repository(&pool);
+    repository
+        .signup(build_signup_user(
+            "user@example.com",
+            &verification,
+            now - std::time::Duration::from_secs(1),
+        ))
+        .await
+        .unwrap();
+
+    let verified = repository
+        .verify_email(verification.hash, now)
+        .await
+        .unwrap();
+
+    assert!(!verified);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn session_lifecycle_success(pool: sqlx::PgPool) {
+    let user_id: uuid::Uuid = sqlx::query_scalar(r#"SELECT id FROM users LIMIT 1"#)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let now = sqlx::types::chrono::Utc::now();
+    let secret = SessionSecret::generate(now);
+    let repository = build_repository(&pool);
+
+    let session = repository
+        .create_session(build_create_session(user_id.to_string(), &secret))
+        .await
+        .unwrap();
+    assert_eq!(session.user.id, user_id.to_string());
+    assert!(session.user.role.is_super_administrator);
+
+    let found = repository
+        .find_session_by_refresh_token(secret.refresh.hash.clone())
+        .await
+        .unwrap();
+    assert_eq!(found.unwrap().id, session.id);
+
+    let rotated = SessionSecret::generate(now);
+    let rotate = |previous: &str| {
+        RotateSession::new(
+            session.id,
+            previous.into(),
+            rotated.access.hash.clone(),
+            rotated.access_expires_at,
+            rotated.refresh.hash.clone(),
+            rotated.refresh_expires_at,
+        )
+    };
+    let result = repository
+        .rotate_session(rotate(&secret.refresh.hash))
+        .await
+        .unwrap();
+    assert!(result.is_some());
+
+    let replayed = repository
+        .rotate_session(rotate(&secret.refresh.hash))
+        .await
+        .unwrap();
+    assert!(replayed.is_none());
+
+    let previous = repository
+        .find_session_by_access_token(secret.access.hash.clone())
+        .await
+        .unwrap();
+    assert!(previous.is_none());
+
+    repository.revoke_session(session.id).await.unwrap();
+
+    let revoked = repository
+        .find_session_by_access_token(rotated.access.hash.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(revoked.revoked_at.is_some());
+}
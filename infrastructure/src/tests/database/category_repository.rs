@@ -1,7 +1,13 @@
+use std::time::Duration;
+
+use domain::model::field::{Field, FieldType};
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
 use domain::repository::category::{
     CategoryRepository, CreateCategory, GetCategoryQuery, UpdateCategory,
 };
 use shared::logger::logger_init_info;
+use sqlx::types::chrono::Utc;
 
 use crate::database::category_repository::CategoryRepositoryImpl;
 use crate::database::connection::ConnectionPool;
@@ -22,7 +28,7 @@ fn get_success(pool: sqlx::PgPool) {
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[tracing::instrument]
@@ -34,12 +40,13 @@ fn create_success(pool: sqlx::PgPool) {
         "sample1".into(),
         "sample1".into(),
         Some("sample1 content model".into()),
+        vec![Field::new(FieldType::Text, "body".into())],
     );
     let result = repo.create(create_data).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[tracing::instrument]
@@ -48,20 +55,45 @@ fn update_success(pool: sqlx::PgPool) {
     logger_init_info();
     let repo = build_repository(&pool);
     let query = GetCategoryQuery::default();
-    let categories = repo.get(query).await.unwrap();
-    let category = categories.get(0).unwrap();
+    let categories = repo.get(query).await.unwrap().items;
+    let category = categories.first().unwrap();
 
     let data = UpdateCategory::new(
         category.id.to_string(),
         Some("update-test".into()),
         None,
         None,
+        None,
+        None,
     );
     let result = repo.update(data).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn update_failure_with_outdated_version(pool: sqlx::PgPool) {
+    logger_init_info();
+    let repo = build_repository(&pool);
+    let categories = repo.get(GetCategoryQuery::default()).await.unwrap().items;
+    let category = categories.first().unwrap();
+
+    let data = UpdateCategory::new(
+        category.id.to_string(),
+        Some("update-test".into()),
+        None,
+        None,
+        None,
+        Some(category.version + 1),
+    );
+    let error = repo.update(data).await.unwrap_err();
+
+    let conflict = error.downcast_ref::<VersionConflict>().unwrap();
+    assert_eq!(conflict.current["name"], category.name.as_str());
+    assert_eq!(conflict.current["version"], category.version);
 }
 
 #[tracing::instrument]
@@ -70,12 +102,167 @@ fn delete_success(pool: sqlx::PgPool) {
     logger_init_info();
     let repository = build_repository(&pool);
     let query = GetCategoryQuery::default();
-    let categories = repository.get(query).await.unwrap();
-    let category = categories.get(0).unwrap();
+    let categories = repository.get(query).await.unwrap().items;
+    let category = categories.first().unwrap();
 
     let result = repository.delete(category.id.to_string()).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn delete_and_restore(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let categories = repository
+        .get(GetCategoryQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let category = categories.first().unwrap();
+
+    repository.delete(category.id.to_string()).await.unwrap();
+
+    let remaining = repository
+        .get(GetCategoryQuery::default())
+        .await
+        .unwrap()
+        .items;
+    assert_eq!(remaining.len(), categories.len() - 1);
+
+    let restored = repository.restore(category.id.to_string()).await.unwrap();
+    assert_eq!(restored.id, category.id);
+
+    let error = repository
+        .restore(category.id.to_string())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::NotInTrash)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn create_success_with_identifier_in_trash(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let categories = repository
+        .get(GetCategoryQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let category = categories.first().unwrap();
+
+    repository.delete(category.id.to_string()).await.unwrap();
+
+    let create_data = CreateCategory::new(
+        "replacement".into(),
+        category.api_identifier.clone(),
+        None,
+        vec![Field::new(FieldType::Text, "body".into())],
+    );
+    let created = repository.create(create_data).await.unwrap();
+    assert_eq!(created.api_identifier, category.api_identifier);
+
+    let duplicate = CreateCategory::new(
+        "duplicate".into(),
+        category.api_identifier.clone(),
+        None,
+        vec![Field::new(FieldType::Text, "body".into())],
+    );
+    assert!(repository.create(duplicate).await.is_err());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn restore_failure_with_identifier_in_use(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let categories = repository
+        .get(GetCategoryQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let category = categories.first().unwrap();
+
+    repository.delete(category.id.to_string()).await.unwrap();
+
+    let create_data = CreateCategory::new(
+        "replacement".into(),
+        category.api_identifier.clone(),
+        None,
+        vec![Field::new(FieldType::Text, "body".into())],
+    );
+    repository.create(create_data).await.unwrap();
+
+    let error = repository
+        .restore(category.id.to_string())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::CategoryIdentifierInUse)
+    );
+
+    let trashed = sqlx::query_scalar::<_, bool>(
+        r#"SELECT deleted_at IS NOT NULL FROM category WHERE id = $1"#,
+    )
+    .bind(uuid::Uuid::parse_str(&category.id.to_string()).unwrap())
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert!(trashed);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn delete_failure_with_contents(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let categories = repository
+        .get(GetCategoryQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let category = categories.first().unwrap();
+
+    let error = repository
+        .delete(category.id.to_string())
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::CategoryInUse)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn purge_skips_categories_with_contents(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let categories = repository
+        .get(GetCategoryQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let category = categories.first().unwrap();
+    sqlx::query(r#"UPDATE contents SET deleted_at = CURRENT_TIMESTAMP(3)"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+    repository.delete(category.id.to_string()).await.unwrap();
+
+    let before = Utc::now() + Duration::from_secs(1);
+    assert!(repository.purge(before, 10).await.unwrap().is_empty());
+
+    sqlx::query(r#"DELETE FROM contents"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let purged = repository.purge(before, 10).await.unwrap();
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].to_string(), category.id);
 }
@@ -0,0 +1,145 @@
+use domain::model::content::{ContentStatus, ContentStatusChange};
+use domain::model::field::FieldValidationError;
+use domain::repository::localization::{
+    ContentLocalizationRepository, UpdateContentLocalizationStatus, UpsertContentLocalization,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::content_localization_repository::{
+    fetch_localizations, ContentLocalizationRepositoryImpl,
+};
+
+async fn localizable_content(pool: &sqlx::PgPool) -> (uuid::Uuid, String) {
+    sqlx::query(
+        r#"UPDATE category SET fields = '[{"field_type": "Text", "key": "body", "required": true, "max": 100, "localizable": true}]'"#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    let content_id: uuid::Uuid = sqlx::query_scalar("SELECT id FROM contents LIMIT 1")
+        .fetch_one(pool)
+        .await
+        .unwrap();
+    let user_id: uuid::Uuid = sqlx::query_scalar("SELECT id FROM users LIMIT 1")
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+    (content_id, user_id.to_string())
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn upsert_success(pool: sqlx::PgPool) {
+    let (content_id, user_id) = localizable_content(&pool).await;
+    let repository = ContentLocalizationRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+
+    let first = UpsertContentLocalization::new(
+        content_id.to_string(),
+        "en".into(),
+        serde_json::json!({"body": "first"}),
+        user_id.clone(),
+    );
+    repository.upsert(first).await.unwrap();
+    let second = UpsertContentLocalization::new(
+        content_id.to_string(),
+        "en".into(),
+        serde_json::json!({"body": "second"}),
+        user_id,
+    );
+    let result = repository.upsert(second).await;
+
+    tracing::info!("{:?}", result);
+
+    let localization = result.unwrap();
+    assert_eq!(localization.fields, serde_json::json!({"body": "second"}));
+    assert_eq!(localization.status, ContentStatus::Draft);
+    assert_eq!(
+        repository.get(content_id.to_string()).await.unwrap().len(),
+        1
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn upsert_failure_with_shared_field(pool: sqlx::PgPool) {
+    let content_id: uuid::Uuid = sqlx::query_scalar("SELECT id FROM contents LIMIT 1")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let user_id: uuid::Uuid = sqlx::query_scalar("SELECT id FROM users LIMIT 1")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let repository = ContentLocalizationRepositoryImpl::new(ConnectionPool::new(pool));
+
+    let input = UpsertContentLocalization::new(
+        content_id.to_string(),
+        "en".into(),
+        serde_json::json!({"body": "text"}),
+        user_id.to_string(),
+    );
+    let result = repository.upsert(input).await;
+
+    assert!(result.unwrap_err().is::<FieldValidationError>());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn update_status_success(pool: sqlx::PgPool) {
+    let (content_id, user_id) = localizable_content(&pool).await;
+    let connection_pool = ConnectionPool::new(pool.clone());
+    let repository = ContentLocalizationRepositoryImpl::new(connection_pool.clone());
+    let input = UpsertContentLocalization::new(
+        content_id.to_string(),
+        "en".into(),
+        serde_json::json!({"body": "text"}),
+        user_id.clone(),
+    );
+    repository.upsert(input).await.unwrap();
+
+    let drafts = fetch_localizations(&connection_pool, vec![content_id], false)
+        .await
+        .unwrap();
+    assert!(drafts.is_empty());
+
+    let now = sqlx::types::chrono::Utc::now();
+    let input = UpdateContentLocalizationStatus::new(
+        content_id.to_string(),
+        "en".into(),
+        ContentStatusChange::new(ContentStatus::Published, Some(now), None),
+        user_id,
+    );
+    let result = repository.update_status(input).await;
+
+    tracing::info!("{:?}", result);
+
+    assert_eq!(result.unwrap().status, ContentStatus::Published);
+    let published = fetch_localizations(&connection_pool, vec![content_id], false)
+        .await
+        .unwrap();
+    assert_eq!(published.len(), 1);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn delete_success(pool: sqlx::PgPool) {
+    let (content_id, user_id) = localizable_content(&pool).await;
+    let repository = ContentLocalizationRepositoryImpl::new(ConnectionPool::new(pool));
+    let input = UpsertContentLocalization::new(
+        content_id.to_string(),
+        "en".into(),
+        serde_json::json!({"body": "text"}),
+        user_id,
+    );
+    repository.upsert(input).await.unwrap();
+
+    repository
+        .delete(content_id.to_string(), "en".into())
+        .await
+        .unwrap();
+
+    let result = repository.find(content_id.to_string(), "en".into()).await;
+    assert!(result.is_err());
+}
@@ -1,14 +1,24 @@
+use std::time::Duration;
+
 use domain::model::category::Category;
-use domain::model::content::{ContentStatus, Field, FieldType};
+use domain::model::content::{ContentStatus, ContentStatusError};
+use domain::model::field::FieldValidationError;
+use domain::model::page::PageQuery;
+use domain::model::query::{ContentFilter, SortOrder, TagMatch};
+use domain::model::relation::ContentReferrer;
+use domain::model::search::SearchLanguage;
 use domain::model::tag::Tag;
+use domain::model::trash::TrashError;
 use domain::model::user::User;
 use domain::repository::category::{CategoryRepository, GetCategoryQuery};
 use domain::repository::content::{
-    ContentRepository, CreateContent, GetContentQuery, UpdateContent,
+    ContentRepository, CreateContent, FindRelatedQuery, GetContentQuery, UpdateContent,
+    UpdateContentStatus,
 };
 use domain::repository::tag::{GetTagQuery, TagRepository};
 use domain::repository::user::{GetUserQuery, UserRepository};
 use shared::logger::logger_init_info;
+use sqlx::types::chrono::Utc;
 
 use crate::database::category_repository::CategoryRepositoryImpl;
 use crate::database::connection::ConnectionPool;
@@ -19,19 +29,19 @@ use crate::database::user_repository::UserRepositoryImpl;
 async fn get_user(pool: &sqlx::PgPool) -> User {
     let connection_pool = ConnectionPool::new(pool.clone());
     let repo = UserRepositoryImpl::new(connection_pool);
-    let query = GetUserQuery::new(1, 0);
-    let rows = repo.get(query).await.unwrap();
+    let query = GetUserQuery::new(PageQuery::new(1, None, false));
+    let rows = repo.get(query).await.unwrap().items;
 
-    rows.get(0).unwrap().clone()
+    rows.first().unwrap().clone()
 }
 
 async fn get_category(pool: &sqlx::PgPool) -> Category {
     let connection_pool = ConnectionPool::new(pool.clone());
     let repo = CategoryRepositoryImpl::new(connection_pool);
     let query = GetCategoryQuery::default();
-    let categories = repo.get(query).await.unwrap();
+    let categories = repo.get(query).await.unwrap().items;
 
-    categories.get(0).unwrap().clone()
+    categories.first().unwrap().clone()
 }
 
 async fn get_tags(pool: &sqlx::PgPool) -> Vec<Tag> {
@@ -39,13 +49,13 @@ async fn get_tags(pool: &sqlx::PgPool) -> Vec<Tag> {
     let repo = TagRepositoryImpl::new(connection_pool);
     let query = GetTagQuery::default();
 
-    repo.get(query).await.unwrap()
+    repo.get(query).await.unwrap().items
 }
 
 fn build_repository(pool: &sqlx::PgPool) -> ContentRepositoryImpl {
     let connection_pool = ConnectionPool::new(pool.clone());
 
-    ContentRepositoryImpl::new(connection_pool)
+    ContentRepositoryImpl::new(connection_pool, SearchLanguage::default())
 }
 
 #[tracing::instrument]
@@ -61,7 +71,264 @@ fn get_success(pool: sqlx::PgPool) {
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn get_with_category_ids(pool: sqlx::PgPool) {
+    logger_init_info();
+    let repository = build_repository(&pool);
+    let category = get_category(&pool).await;
+
+    let query = GetContentQuery {
+        category_ids: Some(vec![category.id.clone()]),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert!(!result.is_empty());
+    assert!(result.iter().all(|x| x.category.id == category.id));
+
+    let query = GetContentQuery {
+        category_ids: Some(vec![uuid::Uuid::now_v7().to_string()]),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert!(result.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn get_with_filters(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let category = get_category(&pool).await;
+    let tag_ids: Vec<String> = get_tags(&pool).await.into_iter().map(|x| x.id).collect();
+
+    let query = GetContentQuery {
+        category_id: Some(category.id.clone()),
+        tag_ids: Some(tag_ids[..2].to_vec()),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert_eq!(result.len(), 1);
+    assert!(result[0].search.is_none());
+
+    let query = GetContentQuery {
+        tag_ids: Some(vec![tag_ids[0].clone(), uuid::Uuid::now_v7().to_string()]),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert!(result.is_empty());
+
+    let query = GetContentQuery {
+        category_id: Some(uuid::Uuid::now_v7().to_string()),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert!(result.is_empty());
+}
+
+async fn insert_priced_contents(pool: &sqlx::PgPool) {
+    sqlx::query(
+        r#"
+            INSERT INTO
+                contents (id, title, category_id, fields, status, published_at, created_by, updated_by)
+            SELECT
+                gen_random_uuid(),
+                item.title,
+                contents.category_id,
+                item.fields,
+                item.status::content_status,
+                item.published_at,
+                contents.created_by,
+                contents.updated_by
+            FROM
+                contents,
+                (
+                    VALUES
+                        ('cheap', '{"body": "a", "price": 50, "colors": ["red"]}'::JSONB, 'Published', '2024-01-10T00:00:00Z'::TIMESTAMPTZ),
+                        ('middle', '{"body": "b", "price": 100, "colors": ["blue", "red"]}'::JSONB, 'Published', '2024-02-10T00:00:00Z'::TIMESTAMPTZ),
+                        ('expensive', '{"body": "c", "price": 300}'::JSONB, 'Draft', NULL)
+                ) AS item(title, fields, status, published_at)
+        "#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn get_titles(repository: &ContentRepositoryImpl, query: GetContentQuery) -> Vec<String> {
+    repository
+        .get(query)
+        .await
+        .unwrap()
+        .items
+        .into_iter()
+        .map(|x| x.title)
+        .collect()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn get_with_query_filters_and_sort(pool: sqlx::PgPool) {
+    insert_priced_contents(&pool).await;
+    let repository = build_repository(&pool);
+    let user = get_user(&pool).await;
+    let build_query = |values: &[(&str, &str)], sort: &str| GetContentQuery {
+        filter: ContentFilter::parse(
+            &values
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap(),
+        sort: SortOrder::parse_list(sort).unwrap(),
+        ..Default::default()
+    };
+
+    let titles = get_titles(
+        &repository,
+        build_query(&[("fields.price[gte]", "100")], "-fields.price"),
+    )
+    .await;
+    assert_eq!(titles, vec!["expensive", "middle"]);
+
+    let titles = get_titles(
+        &repository,
+        build_query(&[("fields.price[in]", "50,300")], "title"),
+    )
+    .await;
+    assert_eq!(titles, vec!["cheap", "expensive"]);
+
+    let titles = get_titles(
+        &repository,
+        build_query(&[("fields.colors", "red")], "-title"),
+    )
+    .await;
+    assert_eq!(titles, vec!["middle", "cheap"]);
+
+    let titles = get_titles(
+        &repository,
+        build_query(
+            &[
+                ("fields.price[ne]", "100"),
+                ("fields.price[exists]", "true"),
+            ],
+            "fields.price",
+        ),
+    )
+    .await;
+    assert_eq!(titles, vec!["cheap", "expensive"]);
+
+    let titles = get_titles(
+        &repository,
+        build_query(&[("fields.body[contains]", "blog")], ""),
+    )
+    .await;
+    assert_eq!(titles, vec!["title"]);
+
+    let titles = get_titles(
+        &repository,
+        build_query(
+            &[
+                ("published_at[gte]", "2024-01-01T00:00:00Z"),
+                ("published_at[lt]", "2024-02-01T00:00:00Z"),
+            ],
+            "",
+        ),
+    )
+    .await;
+    assert_eq!(titles, vec!["cheap"]);
+
+    let query = GetContentQuery {
+        statuses: Some(vec![ContentStatus::Published]),
+        created_by_ids: Some(vec![user.id.clone()]),
+        sort: SortOrder::parse_list("-published_at").unwrap(),
+        ..Default::default()
+    };
+    assert_eq!(
+        get_titles(&repository, query).await,
+        vec!["middle", "cheap"]
+    );
+
+    let query = GetContentQuery {
+        created_by_ids: Some(vec![uuid::Uuid::now_v7().to_string()]),
+        ..Default::default()
+    };
+    assert!(get_titles(&repository, query).await.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn get_with_any_tags(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let tag_id = get_tags(&pool).await.remove(0).id;
+
+    let query = GetContentQuery {
+        tag_ids: Some(vec![tag_id, uuid::Uuid::now_v7().to_string()]),
+        tag_match: TagMatch::Any,
+        ..Default::default()
+    };
+    assert_eq!(get_titles(&repository, query).await, vec!["title"]);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn get_with_keyword(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    let query = GetContentQuery {
+        keyword: Some("blog".into()),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert_eq!(result.len(), 1);
+    let search = result[0].search.as_ref().unwrap();
+    assert!(search.rank > 0.0);
+    assert!(search.snippet.contains("<mark>Blog</mark>"));
+
+    let query = GetContentQuery {
+        keyword: Some("missing".into()),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert!(result.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn get_with_keyword_trigram(pool: sqlx::PgPool) {
+    sqlx::query(r#"UPDATE contents SET fields = '{"body": "今日は良い天気です"}'"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let repository =
+        ContentRepositoryImpl::new(ConnectionPool::new(pool.clone()), SearchLanguage::Trigram);
+
+    let query = GetContentQuery {
+        keyword: Some("天気".into()),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert_eq!(result.len(), 1);
+    let search = result[0].search.as_ref().unwrap();
+    assert!(search.snippet.contains("良い<mark>天気</mark>です"));
+
+    let query = GetContentQuery {
+        keyword: Some("天気 雨".into()),
+        ..Default::default()
+    };
+    let result = repository.get(query).await.unwrap().items;
+    assert!(result.is_empty());
 }
 
 #[tracing::instrument]
@@ -82,14 +349,15 @@ fn create_success(pool: sqlx::PgPool) {
     tracing::info!("{:?}", tag_ids);
 
     let content_repository = build_repository(&pool);
-    let field = Field::new(FieldType::ShortText, "title".into());
-    let fields = serde_json::to_value(vec![field]).unwrap();
+    let fields = serde_json::json!({"body": "Hello World"});
     let create_content = CreateContent::new(
         "title".into(),
         category.id.to_string(),
         fields,
         tag_ids,
         ContentStatus::Draft,
+        None,
+        None,
         user.id.clone(),
         user.id.clone(),
     );
@@ -97,7 +365,110 @@ fn create_success(pool: sqlx::PgPool) {
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category", "tags")))]
+fn create_failure_with_invalid_fields(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+
+    let content_repository = build_repository(&pool);
+    let fields = serde_json::json!({"body": "a".repeat(101), "unknown": true});
+    let create_content = CreateContent::new(
+        "title".into(),
+        category.id.to_string(),
+        fields,
+        vec![],
+        ContentStatus::Draft,
+        None,
+        None,
+        user.id.clone(),
+        user.id.clone(),
+    );
+    let result = content_repository.create(create_content).await;
+
+    tracing::info!("{:?}", result);
+
+    let error = result.unwrap_err();
+    let error = error.downcast_ref::<FieldValidationError>().unwrap();
+    let keys: Vec<&str> = error.errors.iter().map(|e| e.key.as_str()).collect();
+    assert_eq!(keys, vec!["body", "unknown"]);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category", "tags")))]
+fn create_failure_with_tag_in_trash(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    let tag_ids: Vec<String> = get_tags(&pool).await.into_iter().map(|x| x.id).collect();
+
+    let tag_repository = TagRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    tag_repository.delete(tag_ids[0].clone()).await.unwrap();
+
+    let content_repository = build_repository(&pool);
+    let create_content = CreateContent::new(
+        "title".into(),
+        category.id.to_string(),
+        serde_json::json!({"body": "Hello World"}),
+        tag_ids[..2].to_vec(),
+        ContentStatus::Draft,
+        None,
+        None,
+        user.id.clone(),
+        user.id.clone(),
+    );
+    let error = content_repository.create(create_content).await.unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::TagInTrash)
+    );
+
+    let content_tags = sqlx::query_scalar::<_, i64>(r#"SELECT COUNT(*) FROM content_tags"#)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(content_tags, 0);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn update_failure_with_tag_in_trash(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let tag_ids: Vec<String> = get_tags(&pool).await.into_iter().map(|x| x.id).collect();
+
+    let tag_repository = TagRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    tag_repository.delete(tag_ids[0].clone()).await.unwrap();
+
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+
+    let update_content = UpdateContent::new(
+        content.id.to_string(),
+        None,
+        None,
+        None,
+        Some(tag_ids[..2].to_vec()),
+        None,
+        user.id,
+        None,
+    );
+    let error = repository.update(update_content).await.unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::TagInTrash)
+    );
 }
 
 #[tracing::instrument]
@@ -111,8 +482,8 @@ fn update_success(pool: sqlx::PgPool) {
 
     let repository = build_repository(&pool);
     let query = GetContentQuery::default();
-    let contents = repository.get(query).await.unwrap();
-    let content = contents.get(0).unwrap();
+    let contents = repository.get(query).await.unwrap().items;
+    let content = contents.first().unwrap();
     tracing::info!("{:?}", content);
 
     let update_content = UpdateContent::new(
@@ -121,15 +492,59 @@ fn update_success(pool: sqlx::PgPool) {
         None,
         None,
         None,
-        Some(ContentStatus::Reserved),
+        None,
         user.id,
+        None,
     );
 
     let result = repository.update(update_content).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn update_clears_unpublish_at(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+
+    let repository = build_repository(&pool);
+    let query = GetContentQuery::default();
+    let contents = repository.get(query).await.unwrap().items;
+    let content = contents.first().unwrap();
+
+    let unpublish_at = Utc::now() + Duration::from_secs(3600);
+    let update_content = UpdateContent::new(
+        content.id.to_string(),
+        None,
+        None,
+        None,
+        None,
+        Some(unpublish_at),
+        user.id.clone(),
+        None,
+    );
+    let updated = repository.update(update_content).await.unwrap();
+    assert!(updated.unpublish_at.is_some());
+
+    let mut update_content = UpdateContent::new(
+        content.id.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        user.id.clone(),
+        None,
+    );
+    update_content.clear_unpublish_at = true;
+    let updated = repository.update(update_content).await.unwrap();
+
+    assert!(updated.unpublish_at.is_none());
 }
 
 #[tracing::instrument]
@@ -140,13 +555,613 @@ fn update_success(pool: sqlx::PgPool) {
 fn delete_success(pool: sqlx::PgPool) {
     let repository = build_repository(&pool);
     let query = GetContentQuery::default();
-    let contents = repository.get(query).await.unwrap();
-    let content = contents.get(0).unwrap();
+    let contents = repository.get(query).await.unwrap().items;
+    let content = contents.first().unwrap();
     tracing::info!("{:?}", content);
 
-    let result = repository.delete(content.id.clone()).await;
+    let result = repository.delete(content.id.to_string()).await;
+
+    tracing::info!("{:?}", result);
+
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn update_failure_with_missing_required_field(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+
+    let repository = build_repository(&pool);
+    let query = GetContentQuery::default();
+    let contents = repository.get(query).await.unwrap().items;
+    let content = contents.first().unwrap();
+
+    let update_content = UpdateContent::new(
+        content.id.to_string(),
+        None,
+        None,
+        Some(serde_json::json!({})),
+        None,
+        None,
+        user.id,
+        None,
+    );
+
+    let result = repository.update(update_content).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    let error = result.unwrap_err();
+    assert!(error.downcast_ref::<FieldValidationError>().is_some());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn find_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+
+    let result = repository.find(content.id.to_string()).await;
+
+    tracing::info!("{:?}", result);
+
+    let found = result.unwrap();
+    assert_eq!(found.id, content.id);
+    assert_eq!(found.tags.len(), content.tags.len());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn update_status_success(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+
+    let now = sqlx::types::chrono::Utc::now();
+    let change = content.publish(now).unwrap();
+    let update_content_status = UpdateContentStatus::new(
+        content.id.to_string(),
+        content.status.clone(),
+        change,
+        user.id.clone(),
+    );
+
+    let result = repository.update_status(update_content_status).await;
+
+    tracing::info!("{:?}", result);
+
+    let updated = result.unwrap();
+    assert_eq!(updated.status, ContentStatus::Published);
+    assert!(updated.published_at.is_some());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn update_status_failure_with_changed_status(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = repository
+        .find(contents.first().unwrap().id.to_string())
+        .await
+        .unwrap();
+    let change = content.publish(Utc::now()).unwrap();
+
+    sqlx::query(r#"UPDATE contents SET status = 'Published', published_at = $1 WHERE id = $2"#)
+        .bind(Utc::now())
+        .bind(content.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let update_content_status = UpdateContentStatus::new(
+        content.id.to_string(),
+        content.status.clone(),
+        change,
+        user.id.clone(),
+    );
+    let result = repository.update_status(update_content_status).await;
+
+    let error = result.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ContentStatusError>(),
+        Some(&ContentStatusError::InvalidTransition {
+            from: ContentStatus::Published,
+            to: ContentStatus::Published,
+        })
+    );
+}
+
+async fn add_media_field(pool: &sqlx::PgPool, category: &Category) {
+    sqlx::query(
+        r#"
+            UPDATE category SET
+                fields = fields || '[{"field_type": "Media", "key": "thumbnail"}]'::jsonb
+            WHERE
+                id = $1
+        "#,
+    )
+    .bind(uuid::Uuid::parse_str(&category.id).unwrap())
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn insert_media(pool: &sqlx::PgPool, user: &User) -> uuid::Uuid {
+    let id = uuid::Uuid::now_v7();
+
+    sqlx::query(
+        r#"
+            INSERT INTO
+                media (id, object_key, filename, mime_type, size, checksum, uploaded_by)
+            VALUES ($1, $2, 'photo.png', 'image/png', 1, $3, $4)
+        "#,
+    )
+    .bind(id)
+    .bind(format!("media/{}.png", id))
+    .bind("0".repeat(64))
+    .bind(uuid::Uuid::parse_str(&user.id).unwrap())
+    .execute(pool)
+    .await
+    .unwrap();
+
+    id
+}
+
+async fn get_media_references(pool: &sqlx::PgPool, content_id: uuid::Uuid) -> Vec<uuid::Uuid> {
+    sqlx::query_scalar(r#"SELECT media_id FROM content_media WHERE content_id = $1"#)
+        .bind(content_id)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn create_and_update_sync_media_references(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    add_media_field(&pool, &category).await;
+    let media_id = insert_media(&pool, &user).await;
+
+    let content_repository = build_repository(&pool);
+    let fields = serde_json::json!({"body": "Hello", "thumbnail": media_id.to_string()});
+    let create_content = CreateContent::new(
+        "title".into(),
+        category.id.to_string(),
+        fields,
+        vec![],
+        ContentStatus::Draft,
+        None,
+        None,
+        user.id.clone(),
+        user.id.clone(),
+    );
+    let content = content_repository.create(create_content).await.unwrap();
+
+    assert_eq!(
+        get_media_references(&pool, content.id).await,
+        vec![media_id]
+    );
+
+    let update_content = UpdateContent::new(
+        content.id.to_string(),
+        None,
+        None,
+        Some(serde_json::json!({"body": "Hello"})),
+        None,
+        None,
+        user.id.clone(),
+        None,
+    );
+    content_repository.update(update_content).await.unwrap();
+
+    assert!(get_media_references(&pool, content.id).await.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn create_failure_with_unknown_media(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    add_media_field(&pool, &category).await;
+
+    let content_repository = build_repository(&pool);
+    let fields =
+        serde_json::json!({"body": "Hello", "thumbnail": uuid::Uuid::now_v7().to_string()});
+    let create_content = CreateContent::new(
+        "title".into(),
+        category.id.to_string(),
+        fields,
+        vec![],
+        ContentStatus::Draft,
+        None,
+        None,
+        user.id.clone(),
+        user.id.clone(),
+    );
+    let result = content_repository.create(create_content).await;
+
+    let error = result.unwrap_err();
+    let error = error.downcast_ref::<FieldValidationError>().unwrap();
+    assert_eq!(error.errors[0].key, "thumbnail");
+    assert_eq!(error.errors[0].message, "references unknown media");
+}
+
+async fn add_unique_field(pool: &sqlx::PgPool, category: &Category) {
+    let fields = serde_json::json!([{"field_type": "Text", "key": "slug", "unique": true}]);
+
+    sqlx::query(r#"UPDATE category SET fields = fields || $1 WHERE id = $2"#)
+        .bind(fields)
+        .bind(uuid::Uuid::parse_str(&category.id).unwrap())
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+fn unique_field_errors(result: anyhow::Result<impl std::fmt::Debug>) -> Vec<String> {
+    let error = result.unwrap_err();
+    let error = error.downcast_ref::<FieldValidationError>().unwrap();
+    error
+        .errors
+        .iter()
+        .map(|e| format!("{} {}", e.key, e.message))
+        .collect()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn create_failure_with_duplicated_unique_field(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    add_unique_field(&pool, &category).await;
+
+    let repository = build_repository(&pool);
+    let fields = serde_json::json!({"body": "Hello", "slug": "hello"});
+    repository
+        .create(build_create_content(&category, &user, fields.clone()))
+        .await
+        .unwrap();
+    let result = repository
+        .create(build_create_content(&category, &user, fields))
+        .await;
+
+    assert_eq!(unique_field_errors(result), vec!["slug must be unique"]);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn create_failure_with_unique_field_in_trash(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    add_unique_field(&pool, &category).await;
+
+    let repository = build_repository(&pool);
+    let fields = serde_json::json!({"body": "Hello", "slug": "hello"});
+    let content = repository
+        .create(build_create_content(&category, &user, fields.clone()))
+        .await
+        .unwrap();
+    repository.delete(content.id.to_string()).await.unwrap();
+    let result = repository
+        .create(build_create_content(&category, &user, fields))
+        .await;
+
+    assert_eq!(unique_field_errors(result), vec!["slug must be unique"]);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn create_concurrently_with_unique_field(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    add_unique_field(&pool, &category).await;
+
+    let repository = build_repository(&pool);
+    let fields = serde_json::json!({"body": "Hello", "slug": "hello"});
+    let (first, second) = tokio::join!(
+        repository.create(build_create_content(&category, &user, fields.clone())),
+        repository.create(build_create_content(&category, &user, fields)),
+    );
+
+    assert!(first.is_ok() != second.is_ok());
+    let count: i64 =
+        sqlx::query_scalar(r#"SELECT COUNT(*) FROM contents WHERE fields ->> 'slug' = 'hello'"#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(count, 1);
+}
+
+async fn add_reference_fields(pool: &sqlx::PgPool, category: &Category, categories: Vec<String>) {
+    let fields = serde_json::json!([
+        {"field_type": "Content", "key": "author", "categories": categories},
+        {"field_type": "Content", "key": "related", "multiple": true},
+    ]);
+
+    sqlx::query(r#"UPDATE category SET fields = fields || $1 WHERE id = $2"#)
+        .bind(fields)
+        .bind(uuid::Uuid::parse_str(&category.id).unwrap())
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+async fn get_content_id(pool: &sqlx::PgPool) -> uuid::Uuid {
+    sqlx::query_scalar(r#"SELECT id FROM contents LIMIT 1"#)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+fn build_create_content(
+    category: &Category,
+    user: &User,
+    fields: serde_json::Value,
+) -> CreateContent {
+    CreateContent::new(
+        "referrer".into(),
+        category.id.to_string(),
+        fields,
+        vec![],
+        ContentStatus::Draft,
+        None,
+        None,
+        user.id.clone(),
+        user.id.clone(),
+    )
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn create_success_with_content_references(pool: sqlx::PgPool) {
+    let user = get_user(&pool).await;
+    let category = get_category(&pool).await;
+    add_reference_fields(&pool, &category, vec![category.id.clone()]).await;
+    let target_id = get_content_id(&pool).await;
+
+    let repository = build_repository(&pool);
+    let fields = serde_json::json!({
+        "body": "Hello",
+        "author": target_id.to_string(),
+        "related": [target_id.to_string(), target_id.to_string()],
+    });
+    let content = repository
+        .create(build_create_content(&category, &user, fields))
+        .await
+        .unwrap();
+
+    let query = FindRelatedQuery::new(
+        vec![content.id],
+        vec!["author".into(), "related".into()],
+        None,
+    );
+    let related = repository.find_related(query).await.unwrap();
+    let keys: Vec<(&str, i32)> = related
+        .iter()
+        .map(|x| (x.field_key.as_str(), x.position))
+        .collect();
+    assert_eq!(keys, vec![("author", 0), ("related", 0), ("related", 1)]);
+    assert!(related
+        .iter()
+        .all(|x| x.content_id == content.id && x.content.id == target_id));
+
+    let query = FindRelatedQuery::new(
+        vec![content.id],
+        vec!["author".into()],
+        Some(vec![uuid::Uuid::now_v7().to_string()]),
+    );
+    assert!(repository.find_related(query).await.unwrap().is_empty());
+
+    let referrers = repository
+        .referrers(target_id.to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(
+        referrers,
+        vec![
+            ContentReferrer::new(content.id, "referrer".into(), "author".into()),
+            ContentReferrer::new(content.id, "referrer".into(), "related".into()),
+        ]
+    );
+
+    let update_content = UpdateContent::new(
+        content.id.to_string(),
+        None,
+        None,
+        Some(serde_json::json!({"body": "Hello", "related": []})),
+        None,
+        None,
+        user.id.clone(),
+        None,
+    );
+    repository.update(update_content).await.unwrap();
+
+    let referrers = repository
+        .referrers(target_id.to_string(), None)
+        .await
+        .unwrap();
+    assert!(referrers.is_empty());
+}
+
+async fn create_with_author(
+    pool: &sqlx::PgPool,
+    categories: Vec<String>,
+    author: uuid::Uuid,
+) -> Vec<String> {
+    let user = get_user(pool).await;
+    let category = get_category(pool).await;
+    add_reference_fields(pool, &category, categories).await;
+
+    let repository = build_repository(pool);
+    let fields = serde_json::json!({"body": "Hello", "author": author.to_string()});
+    let result = repository
+        .create(build_create_content(&category, &user, fields))
+        .await;
+
+    let error = result.unwrap_err();
+    let error = error.downcast_ref::<FieldValidationError>().unwrap();
+    error
+        .errors
+        .iter()
+        .map(|e| format!("{} {}", e.key, e.message))
+        .collect()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn create_failure_with_unknown_content(pool: sqlx::PgPool) {
+    let messages = create_with_author(&pool, vec![], uuid::Uuid::now_v7()).await;
+
+    assert_eq!(messages, vec!["author references unknown content"]);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn create_failure_with_content_outside_categories(pool: sqlx::PgPool) {
+    let target_id = get_content_id(&pool).await;
+    let categories = vec![uuid::Uuid::now_v7().to_string()];
+    let messages = create_with_author(&pool, categories, target_id).await;
+
+    assert_eq!(
+        messages,
+        vec!["author references content outside the allowed categories"]
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn delete_and_restore(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+
+    repository.delete(content.id.to_string()).await.unwrap();
+
+    assert!(repository.find(content.id.to_string()).await.is_err());
+    assert!(repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items
+        .is_empty());
+
+    let restored = repository
+        .restore(content.id.to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(restored.id, content.id);
+    assert_eq!(restored.tags.len(), content.tags.len());
+
+    let error = repository
+        .restore(content.id.to_string(), None)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::NotInTrash)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+fn restore_failure_with_category_in_trash(pool: sqlx::PgPool) {
+    let category = get_category(&pool).await;
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+    repository.delete(content.id.to_string()).await.unwrap();
+
+    let out_of_scope = repository
+        .restore(
+            content.id.to_string(),
+            Some(vec![uuid::Uuid::now_v7().to_string()]),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        out_of_scope.downcast_ref::<TrashError>(),
+        Some(&TrashError::NotInTrash)
+    );
+
+    CategoryRepositoryImpl::new(ConnectionPool::new(pool.clone()))
+        .delete(category.id.to_string())
+        .await
+        .unwrap();
+    let error = repository
+        .restore(content.id.to_string(), None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::CategoryInTrash)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn purge_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let contents = repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+    repository.delete(content.id.to_string()).await.unwrap();
+
+    let before = Utc::now() - Duration::from_secs(24 * 60 * 60);
+    assert!(repository.purge(before, 10).await.unwrap().is_empty());
+
+    let before = Utc::now() + Duration::from_secs(1);
+    let purged = repository.purge(before, 10).await.unwrap();
+
+    assert_eq!(purged, vec![content.id]);
+    assert!(repository
+        .restore(content.id.to_string(), None)
+        .await
+        .is_err());
 }
@@ -0,0 +1,103 @@
+use domain::model::page::PageQuery;
+use domain::model::search::SearchLanguage;
+use domain::model::user::User;
+use domain::repository::content::{ContentRepository, GetContentQuery, UpdateContent};
+use domain::repository::revision::{ContentRevisionRepository, GetRevisionQuery};
+use domain::repository::user::{GetUserQuery, UserRepository};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::content_revision_repository::ContentRevisionRepositoryImpl;
+use crate::database::contents_repository::ContentRepositoryImpl;
+use crate::database::user_repository::UserRepositoryImpl;
+
+async fn update_content(pool: &sqlx::PgPool, title: &str) -> String {
+    let connection_pool = ConnectionPool::new(pool.clone());
+    let user_repository = UserRepositoryImpl::new(connection_pool.clone());
+    let users: Vec<User> = user_repository
+        .get(GetUserQuery::new(PageQuery::new(1, None, false)))
+        .await
+        .unwrap()
+        .items;
+    let user = users.first().unwrap();
+
+    let content_repository = ContentRepositoryImpl::new(connection_pool, SearchLanguage::default());
+    let contents = content_repository
+        .get(GetContentQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let content = contents.first().unwrap();
+
+    let update_content = UpdateContent::new(
+        content.id.to_string(),
+        Some(title.into()),
+        None,
+        None,
+        None,
+        None,
+        user.id.clone(),
+        None,
+    );
+    content_repository.update(update_content).await.unwrap();
+
+    content.id.to_string()
+}
+
+fn build_repository(pool: &sqlx::PgPool) -> ContentRevisionRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    ContentRevisionRepositoryImpl::new(connection_pool)
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn get_success(pool: sqlx::PgPool) {
+    update_content(&pool, "first").await;
+    let content_id = update_content(&pool, "second").await;
+
+    let repository = build_repository(&pool);
+    let result = repository
+        .get(content_id, GetRevisionQuery::default())
+        .await;
+
+    tracing::info!("{:?}", result);
+
+    let revisions = result.unwrap();
+    let numbers: Vec<i32> = revisions.iter().map(|r| r.revision).collect();
+    assert_eq!(numbers, vec![2, 1]);
+    assert_eq!(revisions[0].title, "second");
+    assert!(!revisions[0].tags.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn find_success(pool: sqlx::PgPool) {
+    let content_id = update_content(&pool, "first").await;
+
+    let repository = build_repository(&pool);
+    let result = repository.find(content_id, 1).await;
+
+    tracing::info!("{:?}", result);
+
+    assert_eq!(result.unwrap().title, "first");
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "content", "tags", "content_tags")
+))]
+fn find_failure_with_unknown_revision(pool: sqlx::PgPool) {
+    let content_id = update_content(&pool, "first").await;
+
+    let repository = build_repository(&pool);
+    let result = repository.find(content_id, 2).await;
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,94 @@
+use domain::repository::schedule::ContentScheduleRepository;
+
+use crate::database::connection::ConnectionPool;
+use crate::database::content_schedule_repository::ContentScheduleRepositoryImpl;
+
+fn build_repository(pool: &sqlx::PgPool) -> ContentScheduleRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    ContentScheduleRepositoryImpl::new(connection_pool)
+}
+
+async fn get_status(pool: &sqlx::PgPool, title: &str) -> String {
+    sqlx::query_scalar(r#"SELECT status::text FROM contents WHERE title = $1"#)
+        .bind(title)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn publish_due_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let result = repository
+        .publish_due(sqlx::types::chrono::Utc::now(), 100)
+        .await;
+
+    tracing::info!("{:?}", result);
+
+    assert_eq!(result.unwrap().len(), 1);
+    assert_eq!(get_status(&pool, "due").await, "Published");
+    assert_eq!(get_status(&pool, "not due").await, "Reserved");
+
+    let revisions: i64 = sqlx::query_scalar(
+        r#"SELECT COUNT(*) FROM content_revisions WHERE status = 'Published' AND title = 'due'"#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(revisions, 1);
+
+    let events: Vec<String> = sqlx::query_scalar(r#"SELECT event FROM outbox_events"#)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(events, vec!["content.published"]);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn unpublish_due_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let result = repository
+        .unpublish_due(sqlx::types::chrono::Utc::now(), 100)
+        .await;
+
+    tracing::info!("{:?}", result);
+
+    assert_eq!(result.unwrap().len(), 1);
+    assert_eq!(get_status(&pool, "expired").await, "Unpublished");
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn publish_due_skips_locked_rows(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    let mut transaction = pool.begin().await.unwrap();
+    sqlx::query(r#"SELECT id FROM contents WHERE title = 'due' FOR UPDATE"#)
+        .execute(&mut *transaction)
+        .await
+        .unwrap();
+
+    let locked = repository
+        .publish_due(sqlx::types::chrono::Utc::now(), 100)
+        .await;
+    assert!(locked.unwrap().is_empty());
+
+    transaction.rollback().await.unwrap();
+
+    let unlocked = repository
+        .publish_due(sqlx::types::chrono::Utc::now(), 100)
+        .await;
+    assert_eq!(unlocked.unwrap().len(), 1);
+}
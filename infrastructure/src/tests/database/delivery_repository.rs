@@ -0,0 +1,201 @@
+use domain::model::content::ContentStatus;
+use domain::repository::delivery::{
+    DeliveryRepository, FindDeliveryRelatedQuery, GetDeliveryQuery,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::delivery_repository::DeliveryRepositoryImpl;
+
+fn build_repository(pool: &sqlx::PgPool) -> DeliveryRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    DeliveryRepositoryImpl::new(connection_pool)
+}
+
+async fn get_content_id(pool: &sqlx::PgPool, title: &str) -> String {
+    let id: uuid::Uuid = sqlx::query_scalar(r#"SELECT id FROM contents WHERE title = $1"#)
+        .bind(title)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+    id.to_string()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn get_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let result = repository
+        .get("sample1".into(), GetDeliveryQuery::default())
+        .await;
+
+    tracing::info!("{:?}", result);
+
+    let contents = result.unwrap();
+    let titles: Vec<&str> = contents.iter().map(|c| c.title.as_str()).collect();
+    assert_eq!(titles, vec!["live"]);
+    assert_eq!(contents[0].status, ContentStatus::Published);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn get_success_with_unknown_api_identifier(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let result = repository
+        .get("unknown".into(), GetDeliveryQuery::default())
+        .await;
+
+    assert!(result.unwrap().is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn find_success(pool: sqlx::PgPool) {
+    let id = get_content_id(&pool, "live").await;
+
+    let repository = build_repository(&pool);
+    let result = repository.find("sample1".into(), id, false).await;
+
+    tracing::info!("{:?}", result);
+
+    assert_eq!(result.unwrap().title, "live");
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn find_failure_with_unpublished_content(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    for title in ["due", "not due", "expired"] {
+        let id = get_content_id(&pool, title).await;
+        let result = repository.find("sample1".into(), id, false).await;
+
+        assert!(result.is_err(), "{} must not be delivered", title);
+    }
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn get_success_with_drafts(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let query = GetDeliveryQuery::new(100, 0, true);
+    let result = repository.get("sample1".into(), query).await;
+
+    assert_eq!(result.unwrap().len(), 4);
+
+    let id = get_content_id(&pool, "not due").await;
+    let result = repository.find("sample1".into(), id, true).await;
+
+    assert_eq!(result.unwrap().title, "not due");
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn find_media_success(pool: sqlx::PgPool) {
+    let id = uuid::Uuid::parse_str(&get_content_id(&pool, "live").await).unwrap();
+    let media_id = uuid::Uuid::now_v7();
+    sqlx::query(
+        r#"
+            INSERT INTO
+                media (id, object_key, filename, mime_type, size, checksum, width, height, alt_text, uploaded_by)
+            VALUES ($1, 'media/photo.png', 'photo.png', 'image/png', 1, $2, 400, 200, 'A photo', (SELECT id FROM users LIMIT 1))
+        "#,
+    )
+    .bind(media_id)
+    .bind("0".repeat(64))
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        r#"INSERT INTO content_media (content_id, field_key, media_id) VALUES ($1, 'thumbnail', $2)"#,
+    )
+    .bind(id)
+    .bind(media_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let repository = build_repository(&pool);
+    let result = repository
+        .find_media(vec![id, uuid::Uuid::now_v7()])
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].content_id, id);
+    assert_eq!(result[0].field_key, "thumbnail");
+    assert_eq!(result[0].media.id, media_id);
+    assert_eq!(result[0].media.alt_text.as_deref(), Some("A photo"));
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(
+    path = "../fixtures",
+    scripts("users", "category", "scheduled_content")
+))]
+fn find_related_success(pool: sqlx::PgPool) {
+    let id = uuid::Uuid::parse_str(&get_content_id(&pool, "live").await).unwrap();
+    let draft_id = uuid::Uuid::parse_str(&get_content_id(&pool, "not due").await).unwrap();
+    sqlx::query(
+        r#"
+            INSERT INTO
+                content_relations (content_id, field_key, position, target_id)
+            VALUES ($1, 'related', 0, $2), ($1, 'related', 1, $1)
+        "#,
+    )
+    .bind(id)
+    .bind(draft_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let repository = build_repository(&pool);
+    let build_query = |include_drafts: bool, api_identifiers: Option<Vec<String>>| {
+        FindDeliveryRelatedQuery::new(
+            vec![id],
+            vec!["related".into()],
+            include_drafts,
+            api_identifiers,
+        )
+    };
+
+    let published = repository
+        .find_related(build_query(false, None))
+        .await
+        .unwrap();
+    assert_eq!(published.len(), 1);
+    assert_eq!(published[0].content.id, id);
+    assert_eq!(published[0].position, 1);
+
+    let all = repository
+        .find_related(build_query(true, Some(vec!["sample1".into()])))
+        .await
+        .unwrap();
+    let ids: Vec<uuid::Uuid> = all.iter().map(|x| x.content.id).collect();
+    assert_eq!(ids, vec![draft_id, id]);
+
+    let other = repository
+        .find_related(build_query(true, Some(vec!["other".into()])))
+        .await
+        .unwrap();
+    assert!(other.is_empty());
+}
@@ -1,3 +1,15 @@
+pub mod api_key_repository;
+pub mod auth_repository;
 pub mod category_repository;
+pub mod content_localization_repository;
 pub mod content_repository;
+pub mod content_revision_repository;
+pub mod content_schedule_repository;
+pub mod delivery_repository;
+pub mod oidc_repository;
+pub mod outbox_repository;
+pub mod role_repository;
 pub mod tag_repository;
+pub mod trash_repository;
+pub mod unit_of_work;
+pub mod webhook_repository;
@@ -0,0 +1,233 @@
+use domain::model::auth::AuthError;
+use domain::model::oidc::OidcLoginState;
+use domain::repository::oidc::{LinkOidcIdentity, OidcRepository};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::oidc_repository::OidcRepositoryImpl;
+
+fn build_repository(pool: &sqlx::PgPool) -> OidcRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    OidcRepositoryImpl::new(connection_pool)
+}
+
+fn build_identity(subject: &str, email: Option<&str>, role: Option<&str>) -> LinkOidcIdentity {
+    LinkOidcIdentity::new(
+        "https://idp.example.com".into(),
+        subject.into(),
+        email.map(String::from),
+        "user2".into(),
+        "https://example.com/icon.png".into(),
+        role.map(String::from),
+        "member".into(),
+    )
+}
+
+async fn find_user(pool: &sqlx::PgPool, user_id: &str) -> (String, String, bool) {
+    sqlx::query_as::<_, (String, String, bool)>(
+        r#"
+            SELECT
+                users.name,
+                role.name,
+                users.email_verified_at IS NOT NULL
+            FROM
+                users
+            JOIN
+                role ON role.id = users.role_id
+            WHERE
+                users.id = $1
+        "#,
+    )
+    .bind(uuid::Uuid::parse_str(user_id).unwrap())
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn take_login_state_success(pool: sqlx::PgPool) {
+    let now = sqlx::types::chrono::Utc::now();
+    let login_state = OidcLoginState::generate(now);
+    let repository = build_repository(&pool);
+
+    repository
+        .save_login_state(login_state.clone())
+        .await
+        .unwrap();
+
+    let taken = repository
+        .take_login_state(login_state.state.clone(), now)
+        .await
+        .unwrap();
+    assert_eq!(
+        taken.map(|x| x.code_verifier),
+        Some(login_state.code_verifier)
+    );
+
+    let taken_again = repository
+        .take_login_state(login_state.state, now)
+        .await
+        .unwrap();
+    assert!(taken_again.is_none());
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn take_login_state_failure_with_expired_state(pool: sqlx::PgPool) {
+    let now = sqlx::types::chrono::Utc::now();
+    let login_state = OidcLoginState::generate(now);
+    let repository = build_repository(&pool);
+
+    repository
+        .save_login_state(login_state.clone())
+        .await
+        .unwrap();
+
+    let taken = repository
+        .take_login_state(
+            login_state.state,
+            now + std::time::Duration::from_secs(3600),
+        )
+        .await
+        .unwrap();
+    assert!(taken.is_none());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn link_identity_provisions_user(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    let user_id = repository
+        .link_identity(build_identity("subject", Some("user2@example.com"), None))
+        .await
+        .unwrap();
+    assert_eq!(
+        find_user(&pool, &user_id).await,
+        ("user2".into(), "member".into(), true)
+    );
+
+    let relinked_user_id = repository
+        .link_identity(build_identity("subject", None, Some("admin")))
+        .await
+        .unwrap();
+    assert_eq!(relinked_user_id, user_id);
+    assert_eq!(
+        find_user(&pool, &user_id).await,
+        ("user2".into(), "admin".into(), true)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn link_identity_links_user_by_verified_email(pool: sqlx::PgPool) {
+    let user_id = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+            UPDATE users SET
+                email = 'user1@example.com',
+                email_verified_at = CURRENT_TIMESTAMP
+            WHERE
+                name = 'user1'
+            RETURNING
+                id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let repository = build_repository(&pool);
+
+    let linked_user_id = repository
+        .link_identity(build_identity("subject", Some("user1@example.com"), None))
+        .await
+        .unwrap();
+
+    assert_eq!(linked_user_id, user_id.to_string());
+    assert_eq!(
+        find_user(&pool, &linked_user_id).await,
+        ("user1".into(), "admin".into(), true)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn link_identity_failure_with_deleted_user(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let user_id = repository
+        .link_identity(build_identity("subject", None, None))
+        .await
+        .unwrap();
+    sqlx::query(r#"UPDATE users SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1"#)
+        .bind(uuid::Uuid::parse_str(&user_id).unwrap())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let result = repository
+        .link_identity(build_identity("subject", None, None))
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn link_identity_provisions_user_with_default_role(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let data = LinkOidcIdentity {
+        default_role: "admin".into(),
+        ..build_identity("subject", None, None)
+    };
+
+    let user_id = repository.link_identity(data).await.unwrap();
+
+    assert_eq!(
+        find_user(&pool, &user_id).await,
+        ("user2".into(), "admin".into(), false)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn link_identity_demotes_user(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let user_id = repository
+        .link_identity(build_identity("subject", None, Some("admin")))
+        .await
+        .unwrap();
+
+    repository
+        .link_identity(build_identity("subject", None, Some("member")))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        find_user(&pool, &user_id).await,
+        ("user2".into(), "member".into(), false)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn link_identity_failure_with_unverified_email(pool: sqlx::PgPool) {
+    sqlx::query(r#"UPDATE users SET email = 'user1@example.com' WHERE name = 'user1'"#)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let repository = build_repository(&pool);
+
+    let result = repository
+        .link_identity(build_identity("subject", Some("user1@example.com"), None))
+        .await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::EmailNotLinkable
+    );
+    let count: i64 = sqlx::query_scalar(r#"SELECT COUNT(*) FROM oidc_identities"#)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,192 @@
+use domain::model::content::ContentStatus;
+use domain::model::field::{Field, FieldType};
+use domain::model::page::PageQuery;
+use domain::model::search::SearchLanguage;
+use domain::model::webhook::{WebhookEvent, WebhookPayload};
+use domain::repository::category::{
+    CategoryRepository, CreateCategory, GetCategoryQuery, UpdateCategory,
+};
+use domain::repository::content::{ContentRepository, CreateContent, UpdateContentStatus};
+use domain::repository::outbox::{AppendOutboxEvent, OutboxRepository, RecordOutboxFailure};
+use domain::repository::user::{GetUserQuery, UserRepository};
+
+use crate::database::category_repository::CategoryRepositoryImpl;
+use crate::database::connection::ConnectionPool;
+use crate::database::contents_repository::ContentRepositoryImpl;
+use crate::database::outbox_repository::OutboxRepositoryImpl;
+use crate::database::user_repository::UserRepositoryImpl;
+
+fn build_repository(pool: &sqlx::PgPool) -> OutboxRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    OutboxRepositoryImpl::new(connection_pool)
+}
+
+async fn outbox_events(pool: &sqlx::PgPool) -> Vec<(String, Option<uuid::Uuid>)> {
+    sqlx::query_as(r#"SELECT event, category_id FROM outbox_events ORDER BY id"#)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+async fn create_content(
+    pool: &sqlx::PgPool,
+    fields: serde_json::Value,
+    status: ContentStatus,
+) -> anyhow::Result<domain::model::content::Content> {
+    let connection_pool = ConnectionPool::new(pool.clone());
+    let query = GetUserQuery::new(PageQuery::new(1, None, false));
+    let user = UserRepositoryImpl::new(connection_pool.clone())
+        .get(query)
+        .await?
+        .items
+        .remove(0);
+    let category = CategoryRepositoryImpl::new(connection_pool.clone())
+        .get(GetCategoryQuery::default())
+        .await?
+        .items
+        .remove(0);
+    let published_at = match status {
+        ContentStatus::Published => Some(sqlx::types::chrono::Utc::now()),
+        _ => None,
+    };
+    let input = CreateContent::new(
+        "title".into(),
+        category.id,
+        fields,
+        vec![],
+        status,
+        published_at,
+        None,
+        user.id.clone(),
+        user.id,
+    );
+
+    ContentRepositoryImpl::new(connection_pool, SearchLanguage::default())
+        .create(input)
+        .await
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn content_changes_write_events(pool: sqlx::PgPool) {
+    let repository =
+        ContentRepositoryImpl::new(ConnectionPool::new(pool.clone()), SearchLanguage::default());
+    let content = create_content(
+        &pool,
+        serde_json::json!({"body": "Hello World"}),
+        ContentStatus::Published,
+    )
+    .await
+    .unwrap();
+
+    let change = content.unpublish().unwrap();
+    let input = UpdateContentStatus::new(
+        content.id.to_string(),
+        content.status.clone(),
+        change,
+        content.updated_by.id.clone(),
+    );
+    repository.update_status(input).await.unwrap();
+    repository.delete(content.id.to_string()).await.unwrap();
+
+    let category_id = Some(uuid::Uuid::parse_str(&content.category.id).unwrap());
+    assert_eq!(
+        outbox_events(&pool).await,
+        vec![
+            ("content.created".to_string(), category_id),
+            ("content.published".to_string(), category_id),
+            ("content.unpublished".to_string(), category_id),
+            ("content.deleted".to_string(), category_id),
+        ]
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+fn content_change_rolled_back_writes_no_event(pool: sqlx::PgPool) {
+    let result = create_content(
+        &pool,
+        serde_json::json!({"unknown": true}),
+        ContentStatus::Draft,
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert!(outbox_events(&pool).await.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn category_changes_write_events(pool: sqlx::PgPool) {
+    let repository = CategoryRepositoryImpl::new(ConnectionPool::new(pool.clone()));
+    let input = CreateCategory::new(
+        "sample".into(),
+        "sample".into(),
+        None,
+        vec![Field::new(FieldType::Text, "body".into())],
+    );
+    let category = repository.create(input).await.unwrap();
+    let input = UpdateCategory::new(
+        category.id.clone(),
+        Some("changed".into()),
+        None,
+        None,
+        None,
+        None,
+    );
+    repository.update(input).await.unwrap();
+    repository.delete(category.id.clone()).await.unwrap();
+
+    let category_id = Some(uuid::Uuid::parse_str(&category.id).unwrap());
+    assert_eq!(
+        outbox_events(&pool).await,
+        vec![
+            ("category.created".to_string(), category_id),
+            ("category.updated".to_string(), category_id),
+            ("category.deleted".to_string(), category_id),
+        ]
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn claim_due_record_failure_and_complete(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let payload = WebhookPayload::new(
+        WebhookEvent::TagCreated,
+        serde_json::json!({"id": "id"}),
+        sqlx::types::chrono::Utc::now(),
+    );
+    let id = repository
+        .append(AppendOutboxEvent::new(None, payload.clone()))
+        .await
+        .unwrap();
+
+    let now = sqlx::types::chrono::Utc::now();
+    let claimed = repository.claim_due(now, 100).await.unwrap();
+
+    assert_eq!(id, payload.id);
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].payload, payload);
+    assert!(repository.claim_due(now, 100).await.unwrap().is_empty());
+
+    let input = RecordOutboxFailure::new(
+        id,
+        1,
+        now - std::time::Duration::from_secs(1),
+        "webhooks: connection closed".into(),
+    );
+    repository.record_failure(input).await.unwrap();
+
+    let claimed = repository.claim_due(now, 100).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].attempts, 1);
+    assert_eq!(
+        claimed[0].last_error.as_deref(),
+        Some("webhooks: connection closed")
+    );
+
+    repository.complete(id).await.unwrap();
+    assert!(outbox_events(&pool).await.is_empty());
+}
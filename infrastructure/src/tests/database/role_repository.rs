@@ -0,0 +1,121 @@
+use domain::model::role::{Permission, PermissionAction, PermissionResource, RoleError};
+use domain::repository::role::{CreateRole, GetRoleQuery, RoleRepository, UpdateRole};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::role_repository::RoleRepositoryImpl;
+
+fn build_repository(pool: &sqlx::PgPool) -> RoleRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    RoleRepositoryImpl::new(connection_pool)
+}
+
+async fn get_category_ids(pool: &sqlx::PgPool) -> Vec<String> {
+    sqlx::query_scalar::<_, uuid::Uuid>(r#"SELECT id FROM category ORDER BY name"#)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+        .iter()
+        .map(uuid::Uuid::to_string)
+        .collect()
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn get_success_with_default_role(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    let roles = repository.get(GetRoleQuery::default()).await.unwrap();
+
+    let member = roles.iter().find(|role| role.name == "member").unwrap();
+    assert!(!member.is_super_administrator);
+    assert_eq!(
+        member.permissions,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Read,
+            vec![]
+        )]
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn role_lifecycle_success(pool: sqlx::PgPool) {
+    let category_ids = get_category_ids(&pool).await;
+    let repository = build_repository(&pool);
+
+    let created = repository
+        .create(CreateRole::new(
+            "editor".into(),
+            "description".into(),
+            vec![
+                Permission::new(
+                    PermissionResource::Contents,
+                    PermissionAction::Update,
+                    vec![category_ids[0].clone()],
+                ),
+                Permission::new(PermissionResource::Tags, PermissionAction::Read, vec![]),
+            ],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(created.name, "editor");
+    assert!(created.allows(
+        PermissionResource::Contents,
+        PermissionAction::Update,
+        Some(&category_ids[0])
+    ));
+    assert!(!created.allows(
+        PermissionResource::Contents,
+        PermissionAction::Update,
+        Some(&category_ids[1])
+    ));
+    assert!(created.allows(PermissionResource::Tags, PermissionAction::Read, None));
+
+    let updated = repository
+        .update(UpdateRole::new(
+            created.id.clone(),
+            Some("writer".into()),
+            None,
+            Some(vec![Permission::new(
+                PermissionResource::Contents,
+                PermissionAction::Update,
+                category_ids.clone(),
+            )]),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(updated.name, "writer");
+    assert_eq!(updated.description, "description");
+    assert_eq!(updated.permissions.len(), 1);
+    assert_eq!(
+        updated.permissions[0].category_ids.len(),
+        category_ids.len()
+    );
+    assert!(!updated.allows(PermissionResource::Tags, PermissionAction::Read, None));
+
+    let found = repository.find(created.id.clone()).await.unwrap();
+    assert_eq!(found, Some(updated));
+
+    repository.delete(created.id.clone()).await.unwrap();
+    assert_eq!(repository.find(created.id).await.unwrap(), None);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+fn delete_failure_with_assigned_role(pool: sqlx::PgPool) {
+    let role_id =
+        sqlx::query_scalar::<_, uuid::Uuid>(r#"SELECT id FROM role WHERE name = 'admin'"#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    let repository = build_repository(&pool);
+
+    let result = repository.delete(role_id.to_string()).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<RoleError>().unwrap(),
+        RoleError::InUse
+    );
+}
@@ -1,4 +1,11 @@
+use std::time::Duration;
+
+use domain::model::page::PageQuery;
+use domain::model::tag::Tag;
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
 use domain::repository::tag::{CreateTag, GetTagQuery, TagRepository, UpdateTag};
+use sqlx::types::chrono::Utc;
 
 use crate::database::connection::ConnectionPool;
 use crate::database::tag_repository::TagRepositoryImpl;
@@ -14,13 +21,40 @@ fn build_repository(pool: &sqlx::PgPool) -> TagRepositoryImpl {
 async fn get_success(pool: sqlx::PgPool) {
     let repository = build_repository(&pool);
 
-    let query = GetTagQuery::new(10, 0);
+    let query = GetTagQuery::new(PageQuery::new(10, None, false));
 
     let result = repository.get(query).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("tags")))]
+async fn get_with_cursor(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    let first = repository
+        .get(GetTagQuery::new(PageQuery::new(3, None, true)))
+        .await
+        .unwrap();
+    assert_eq!(first.items.len(), 3);
+    assert_eq!(first.total, Some(4));
+    assert!(first.prev_cursor.is_none());
+
+    let cursor = first.next_cursor.unwrap();
+    let page = PageQuery::try_new(Some(3), Some(&cursor), false).unwrap();
+    let second = repository.get(GetTagQuery::new(page)).await.unwrap();
+    assert_eq!(second.items.len(), 1);
+    assert!(second.next_cursor.is_none());
+    assert!(second.total.is_none());
+
+    let cursor = second.prev_cursor.unwrap();
+    let page = PageQuery::try_new(Some(3), Some(&cursor), false).unwrap();
+    let previous = repository.get(GetTagQuery::new(page)).await.unwrap();
+    let ids = |tags: Vec<Tag>| tags.into_iter().map(|x| x.id).collect::<Vec<_>>();
+    assert_eq!(ids(previous.items), ids(first.items));
 }
 
 #[tracing::instrument]
@@ -34,7 +68,7 @@ async fn create_success(pool: sqlx::PgPool) {
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 }
 
 #[tracing::instrument]
@@ -42,22 +76,53 @@ async fn create_success(pool: sqlx::PgPool) {
 async fn update_success(pool: sqlx::PgPool) {
     let repository = build_repository(&pool);
 
-    let query = GetTagQuery::new(10, 0);
-    let rows = repository.get(query).await.unwrap();
-    let row = rows.get(0).unwrap();
+    let query = GetTagQuery::new(PageQuery::new(10, None, false));
+    let rows = repository.get(query).await.unwrap().items;
+    let row = rows.first().unwrap();
     tracing::info!("{:?}", row);
 
     let tag = UpdateTag::new(
         row.id.clone(),
         Some("NoRust".into()),
         Some("No Rust Tags".into()),
+        None,
     );
 
     let result = repository.update(tag).await;
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("tags")))]
+async fn update_with_version(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+
+    let query = GetTagQuery::new(PageQuery::new(10, None, false));
+    let row = repository.get(query).await.unwrap().items.remove(0);
+
+    let tag = UpdateTag::new(
+        row.id.clone(),
+        Some("NoRust".into()),
+        None,
+        Some(row.version),
+    );
+    let updated = repository.update(tag).await.unwrap();
+    assert_eq!(updated.version, row.version + 1);
+
+    let stale = UpdateTag::new(
+        row.id.clone(),
+        Some("Stale".into()),
+        None,
+        Some(row.version),
+    );
+    let error = repository.update(stale).await.unwrap_err();
+    let conflict = error.downcast_ref::<VersionConflict>().unwrap();
+    assert_eq!(conflict.expected_version, row.version);
+    assert_eq!(conflict.current["name"], "NoRust");
+    assert_eq!(conflict.current["version"], updated.version);
 }
 
 #[tracing::instrument]
@@ -65,9 +130,9 @@ async fn update_success(pool: sqlx::PgPool) {
 async fn delete_success(pool: sqlx::PgPool) {
     let repository = build_repository(&pool);
 
-    let query = GetTagQuery::new(10, 0);
-    let rows = repository.get(query).await.unwrap();
-    let row = rows.get(0).unwrap();
+    let query = GetTagQuery::new(PageQuery::new(10, None, false));
+    let rows = repository.get(query).await.unwrap().items;
+    let row = rows.first().unwrap();
 
     tracing::info!("{:?}", row);
 
@@ -75,5 +140,46 @@ async fn delete_success(pool: sqlx::PgPool) {
 
     tracing::info!("{:?}", result);
 
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("tags")))]
+async fn delete_and_restore(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let rows = repository.get(GetTagQuery::default()).await.unwrap().items;
+    let row = rows.first().unwrap();
+
+    repository.delete(row.id.clone()).await.unwrap();
+
+    let remaining = repository.get(GetTagQuery::default()).await.unwrap().items;
+    assert!(remaining.iter().all(|tag| tag.id != row.id));
+
+    let restored = repository.restore(row.id.clone()).await.unwrap();
+    assert_eq!(restored.name, row.name);
+
+    let error = repository.restore(row.id.clone()).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<TrashError>(),
+        Some(&TrashError::NotInTrash)
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("tags")))]
+async fn purge_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let rows = repository.get(GetTagQuery::default()).await.unwrap().items;
+    let row = rows.first().unwrap();
+    repository.delete(row.id.clone()).await.unwrap();
+
+    let before = Utc::now() - Duration::from_secs(24 * 60 * 60);
+    assert!(repository.purge(before, 10).await.unwrap().is_empty());
+
+    let before = Utc::now() + Duration::from_secs(1);
+    let purged = repository.purge(before, 10).await.unwrap();
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].to_string(), row.id);
+    assert!(repository.restore(row.id.clone()).await.is_err());
 }
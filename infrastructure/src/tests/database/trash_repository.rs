@@ -0,0 +1,94 @@
+use domain::model::page::PageQuery;
+use domain::model::trash::TrashKind;
+use domain::repository::trash::{GetTrashQuery, TrashRepository, TrashScope};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::trash_repository::TrashRepositoryImpl;
+
+fn build_repository(pool: &sqlx::PgPool) -> TrashRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    TrashRepositoryImpl::new(connection_pool)
+}
+
+async fn trash_records(pool: &sqlx::PgPool) {
+    sqlx::query(r#"UPDATE contents SET deleted_at = CURRENT_TIMESTAMP(3) - INTERVAL '1 day'"#)
+        .execute(pool)
+        .await
+        .unwrap();
+    sqlx::query(
+        r#"UPDATE tags SET deleted_at = CURRENT_TIMESTAMP(3) WHERE name IN ('tag1', 'tag2')"#,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+fn all_scopes() -> Vec<TrashScope> {
+    TrashKind::ALL
+        .into_iter()
+        .map(|kind| TrashScope::new(kind, None))
+        .collect()
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content", "tags")))]
+async fn get_success(pool: sqlx::PgPool) {
+    trash_records(&pool).await;
+    let repository = build_repository(&pool);
+
+    let first = repository
+        .get(GetTrashQuery::new(
+            PageQuery::new(2, None, true),
+            all_scopes(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(first.total, Some(3));
+    assert_eq!(first.items.len(), 2);
+    assert!(first.items.iter().all(|item| item.kind == TrashKind::Tag));
+
+    let cursor = first.next_cursor.unwrap();
+    let page = PageQuery::try_new(Some(2), Some(&cursor), false).unwrap();
+    let second = repository
+        .get(GetTrashQuery::new(page, all_scopes()))
+        .await
+        .unwrap();
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.items[0].kind, TrashKind::Content);
+    assert_eq!(second.items[0].name, "title");
+    assert!(second.next_cursor.is_none());
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content", "tags")))]
+async fn get_with_scopes(pool: sqlx::PgPool) {
+    trash_records(&pool).await;
+    let repository = build_repository(&pool);
+    let page = PageQuery::new(10, None, true);
+
+    let scopes = vec![TrashScope::new(
+        TrashKind::Content,
+        Some(vec![uuid::Uuid::now_v7().to_string()]),
+    )];
+    let result = repository
+        .get(GetTrashQuery::new(page.clone(), scopes))
+        .await
+        .unwrap();
+    assert!(result.items.is_empty());
+    assert_eq!(result.total, Some(0));
+
+    let scopes = vec![TrashScope::new(TrashKind::Tag, None)];
+    let result = repository
+        .get(GetTrashQuery::new(page.clone(), scopes))
+        .await
+        .unwrap();
+    assert_eq!(result.total, Some(2));
+
+    let result = repository
+        .get(GetTrashQuery::new(page, vec![]))
+        .await
+        .unwrap();
+    assert!(result.items.is_empty());
+    assert_eq!(result.total, Some(0));
+}
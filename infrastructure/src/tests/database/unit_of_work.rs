@@ -0,0 +1,192 @@
+use domain::model::content::ContentStatus;
+use domain::model::page::PageQuery;
+use domain::model::search::SearchLanguage;
+use domain::model::webhook::{WebhookEvent, WebhookPayload};
+use domain::repository::content::{CreateContent, UpdateContent, UpdateContentStatus};
+use domain::repository::outbox::AppendOutboxEvent;
+use domain::repository::tag::{CreateTag, GetTagQuery};
+use domain::repository::unit_of_work::UnitOfWorkFactory;
+
+use crate::database::connection::ConnectionPool;
+use crate::database::unit_of_work::UnitOfWorkFactoryImpl;
+
+fn build_factory(pool: &sqlx::PgPool) -> UnitOfWorkFactoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    UnitOfWorkFactoryImpl::new(connection_pool, SearchLanguage::default())
+}
+
+fn build_append(event: WebhookEvent) -> AppendOutboxEvent {
+    let payload = WebhookPayload::new(
+        event,
+        serde_json::json!({"name": "Rust"}),
+        sqlx::types::chrono::Utc::now(),
+    );
+
+    AppendOutboxEvent::new(None, payload)
+}
+
+async fn count(pool: &sqlx::PgPool, table: &str) -> i64 {
+    sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+async fn commit_saves_changes_together(pool: sqlx::PgPool) {
+    let unit_of_work = build_factory(&pool).begin().await.unwrap();
+
+    let tag = unit_of_work
+        .tags()
+        .create(CreateTag::new("Rust".into(), None))
+        .await
+        .unwrap();
+    unit_of_work
+        .outbox()
+        .append(build_append(WebhookEvent::TagCreated))
+        .await
+        .unwrap();
+
+    let tags = unit_of_work
+        .tags()
+        .get(GetTagQuery::new(PageQuery::new(10, None, false)))
+        .await
+        .unwrap();
+    assert_eq!(tags.items.len(), 1);
+    assert_eq!(tags.items[0].id, tag.id);
+    assert_eq!(count(&pool, "tags").await, 0);
+    assert_eq!(count(&pool, "outbox_events").await, 0);
+
+    unit_of_work.commit().await.unwrap();
+
+    assert_eq!(count(&pool, "tags").await, 1);
+    assert_eq!(count(&pool, "outbox_events").await, 1);
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+async fn drop_discards_changes(pool: sqlx::PgPool) {
+    let unit_of_work = build_factory(&pool).begin().await.unwrap();
+
+    unit_of_work
+        .tags()
+        .create(CreateTag::new("Rust".into(), None))
+        .await
+        .unwrap();
+    unit_of_work
+        .outbox()
+        .append(build_append(WebhookEvent::TagCreated))
+        .await
+        .unwrap();
+    drop(unit_of_work);
+
+    assert_eq!(count(&pool, "tags").await, 0);
+    assert_eq!(count(&pool, "outbox_events").await, 0);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+async fn failure_discards_earlier_changes(pool: sqlx::PgPool) {
+    let user_id: uuid::Uuid = sqlx::query_scalar(r#"SELECT id FROM users LIMIT 1"#)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let unit_of_work = build_factory(&pool).begin().await.unwrap();
+
+    unit_of_work
+        .tags()
+        .create(CreateTag::new("Rust".into(), None))
+        .await
+        .unwrap();
+    let input = CreateContent::new(
+        "title".into(),
+        uuid::Uuid::now_v7().to_string(),
+        serde_json::json!({}),
+        vec![],
+        ContentStatus::Draft,
+        None,
+        None,
+        user_id.to_string(),
+        user_id.to_string(),
+    );
+    let result = unit_of_work.contents().create(input).await;
+    assert!(result.is_err());
+    drop(unit_of_work);
+
+    assert_eq!(count(&pool, "tags").await, 0);
+    assert_eq!(count(&pool, "contents").await, 0);
+    assert_eq!(count(&pool, "outbox_events").await, 0);
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+async fn repository_fails_after_commit(pool: sqlx::PgPool) {
+    let unit_of_work = build_factory(&pool).begin().await.unwrap();
+    let tags = unit_of_work.tags();
+
+    unit_of_work.commit().await.unwrap();
+    let result = tags.create(CreateTag::new("Rust".into(), None)).await;
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Unit of work is already committed"
+    );
+    assert_eq!(count(&pool, "tags").await, 0);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "category")))]
+async fn content_writes_share_transaction(pool: sqlx::PgPool) {
+    let (user_id, category_id): (uuid::Uuid, uuid::Uuid) = sqlx::query_as(
+        r#"SELECT users.id, category.id FROM users, category ORDER BY category.name LIMIT 1"#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let unit_of_work = build_factory(&pool).begin().await.unwrap();
+    let contents = unit_of_work.contents();
+
+    let input = CreateContent::new(
+        "title".into(),
+        category_id.to_string(),
+        serde_json::json!({"body": "Hello"}),
+        vec![],
+        ContentStatus::Draft,
+        None,
+        None,
+        user_id.to_string(),
+        user_id.to_string(),
+    );
+    let content = contents.create(input).await.unwrap();
+    let id = content.id.to_string();
+    let input = UpdateContent::new(
+        id.clone(),
+        Some("updated".into()),
+        None,
+        None,
+        None,
+        None,
+        user_id.to_string(),
+        None,
+    );
+    contents.update(input).await.unwrap();
+    let found = contents.find(id.clone()).await.unwrap();
+    let change = found.publish(sqlx::types::chrono::Utc::now()).unwrap();
+    let input = UpdateContentStatus::new(id.clone(), found.status, change, user_id.to_string());
+    contents.update_status(input).await.unwrap();
+    contents.delete(id.clone()).await.unwrap();
+    contents.restore(id.clone(), None).await.unwrap();
+    assert_eq!(count(&pool, "contents").await, 0);
+
+    unit_of_work.commit().await.unwrap();
+
+    let (title, status): (String, String) =
+        sqlx::query_as(r#"SELECT title, status::TEXT FROM contents WHERE deleted_at IS NULL"#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!((title.as_str(), status.as_str()), ("updated", "Published"));
+    assert_eq!(count(&pool, "outbox_events").await, 5);
+}
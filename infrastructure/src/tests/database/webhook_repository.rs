@@ -0,0 +1,294 @@
+use domain::model::page::PageQuery;
+use domain::model::webhook::{WebhookDeliveryStatus, WebhookEvent, WebhookPayload};
+use domain::repository::webhook::{
+    CreateWebhook, EnqueueWebhookEvent, GetWebhookDeliveryQuery, RecordWebhookAttempt,
+    UpdateWebhook, WebhookRepository,
+};
+
+use crate::database::connection::ConnectionPool;
+use crate::database::webhook_repository::WebhookRepositoryImpl;
+
+const SECRET: &str = "0123456789abcdef";
+
+fn build_repository(pool: &sqlx::PgPool) -> WebhookRepositoryImpl {
+    let connection_pool = ConnectionPool::new(pool.clone());
+
+    WebhookRepositoryImpl::new(connection_pool)
+}
+
+async fn category_ids(pool: &sqlx::PgPool) -> Vec<String> {
+    sqlx::query_scalar::<_, uuid::Uuid>(r#"SELECT id FROM category ORDER BY name"#)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+fn payload(event: WebhookEvent) -> WebhookPayload {
+    WebhookPayload::new(
+        event,
+        serde_json::json!({"id": "id"}),
+        sqlx::types::chrono::Utc::now(),
+    )
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn create_success(pool: sqlx::PgPool) {
+    let categories = category_ids(&pool).await;
+    let repository = build_repository(&pool);
+
+    let input = CreateWebhook::new(
+        "https://example.com/hook".into(),
+        vec![
+            WebhookEvent::ContentPublished,
+            WebhookEvent::CategoryDeleted,
+        ],
+        vec![categories[0].clone()],
+        SECRET.into(),
+        true,
+    );
+    let result = repository.create(input).await;
+
+    tracing::info!("{:?}", result);
+
+    let webhook = result.unwrap();
+    assert_eq!(webhook.category_ids, vec![categories[0].clone()]);
+    assert_eq!(
+        webhook.events,
+        vec![
+            WebhookEvent::ContentPublished,
+            WebhookEvent::CategoryDeleted
+        ]
+    );
+    assert_eq!(repository.get().await.unwrap().len(), 1);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn update_success(pool: sqlx::PgPool) {
+    let categories = category_ids(&pool).await;
+    let repository = build_repository(&pool);
+    let input = CreateWebhook::new(
+        "https://example.com/hook".into(),
+        vec![WebhookEvent::ContentPublished],
+        vec![categories[0].clone()],
+        SECRET.into(),
+        true,
+    );
+    let webhook = repository.create(input).await.unwrap();
+
+    let input = UpdateWebhook::new(
+        webhook.id.to_string(),
+        None,
+        Some(vec![WebhookEvent::TagCreated]),
+        Some(vec![]),
+        None,
+        Some(false),
+    );
+    let result = repository.update(input).await;
+
+    tracing::info!("{:?}", result);
+
+    let webhook = result.unwrap();
+    assert_eq!(webhook.url, "https://example.com/hook");
+    assert_eq!(webhook.events, vec![WebhookEvent::TagCreated]);
+    assert!(webhook.category_ids.is_empty());
+    assert!(!webhook.active);
+}
+
+#[tracing::instrument]
+#[sqlx::test(fixtures(path = "../fixtures", scripts("category")))]
+fn enqueue_with_event_and_category_filter(pool: sqlx::PgPool) {
+    let categories = category_ids(&pool).await;
+    let repository = build_repository(&pool);
+    let create = |events: Vec<WebhookEvent>, category_ids: Vec<String>, active: bool| {
+        CreateWebhook::new(
+            "https://example.com/hook".into(),
+            events,
+            category_ids,
+            SECRET.into(),
+            active,
+        )
+    };
+    let unfiltered = repository
+        .create(create(vec![WebhookEvent::ContentPublished], vec![], true))
+        .await
+        .unwrap();
+    let filtered = repository
+        .create(create(
+            vec![WebhookEvent::ContentPublished],
+            vec![categories[0].clone()],
+            true,
+        ))
+        .await
+        .unwrap();
+    repository
+        .create(create(vec![WebhookEvent::ContentPublished], vec![], false))
+        .await
+        .unwrap();
+    repository
+        .create(create(vec![WebhookEvent::ContentDeleted], vec![], true))
+        .await
+        .unwrap();
+
+    let matching = repository
+        .enqueue(EnqueueWebhookEvent::new(
+            Some(categories[0].clone()),
+            payload(WebhookEvent::ContentPublished),
+        ))
+        .await
+        .unwrap();
+    let other_category = repository
+        .enqueue(EnqueueWebhookEvent::new(
+            Some(categories[1].clone()),
+            payload(WebhookEvent::ContentPublished),
+        ))
+        .await
+        .unwrap();
+    let without_category = repository
+        .enqueue(EnqueueWebhookEvent::new(
+            None,
+            payload(WebhookEvent::ContentPublished),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(matching.len(), 2);
+    assert_eq!(other_category.len(), 1);
+    assert_eq!(without_category.len(), 1);
+
+    let query = GetWebhookDeliveryQuery::new(filtered.id.to_string(), PageQuery::default());
+    assert_eq!(
+        repository.get_deliveries(query).await.unwrap().items.len(),
+        1
+    );
+    let query = GetWebhookDeliveryQuery::new(
+        unfiltered.id.to_string(),
+        PageQuery::try_new(Some(2), None, true).unwrap(),
+    );
+    let page = repository.get_deliveries(query).await.unwrap();
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.total, Some(3));
+    assert!(page.items[0].id > page.items[1].id);
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn claim_due_and_record_attempt(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let input = CreateWebhook::new(
+        "https://example.com/hook".into(),
+        vec![WebhookEvent::TagCreated],
+        vec![],
+        SECRET.into(),
+        true,
+    );
+    repository.create(input).await.unwrap();
+    let ids = repository
+        .enqueue(EnqueueWebhookEvent::new(
+            None,
+            payload(WebhookEvent::TagCreated),
+        ))
+        .await
+        .unwrap();
+
+    let now = sqlx::types::chrono::Utc::now();
+    let claimed = repository.claim_due(now, 100).await.unwrap();
+
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].delivery.id, ids[0]);
+    assert_eq!(claimed[0].secret, SECRET);
+    assert!(repository.claim_due(now, 100).await.unwrap().is_empty());
+
+    let input = RecordWebhookAttempt::new(
+        ids[0],
+        WebhookDeliveryStatus::Pending,
+        1,
+        Some(now - std::time::Duration::from_secs(1)),
+        Some(500),
+        Some("error".into()),
+        None,
+    );
+    repository.record_attempt(input).await.unwrap();
+
+    let claimed = repository.claim_due(now, 100).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].delivery.attempts, 1);
+    assert_eq!(claimed[0].delivery.response_status, Some(500));
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn enqueue_skips_queued_payload(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let input = CreateWebhook::new(
+        "https://example.com/hook".into(),
+        vec![WebhookEvent::TagCreated],
+        vec![],
+        SECRET.into(),
+        true,
+    );
+    repository.create(input).await.unwrap();
+    let payload = payload(WebhookEvent::TagCreated);
+
+    let first = repository
+        .enqueue(EnqueueWebhookEvent::new(None, payload.clone()))
+        .await
+        .unwrap();
+    let second = repository
+        .enqueue(EnqueueWebhookEvent::new(None, payload))
+        .await
+        .unwrap();
+
+    assert_eq!(first.len(), 1);
+    assert!(second.is_empty());
+}
+
+#[tracing::instrument]
+#[sqlx::test]
+fn redeliver_success(pool: sqlx::PgPool) {
+    let repository = build_repository(&pool);
+    let input = CreateWebhook::new(
+        "https://example.com/hook".into(),
+        vec![WebhookEvent::TagCreated],
+        vec![],
+        SECRET.into(),
+        true,
+    );
+    let webhook = repository.create(input).await.unwrap();
+    let payload = payload(WebhookEvent::TagCreated);
+    let ids = repository
+        .enqueue(EnqueueWebhookEvent::new(None, payload.clone()))
+        .await
+        .unwrap();
+    let input = RecordWebhookAttempt::new(
+        ids[0],
+        WebhookDeliveryStatus::Failed,
+        6,
+        None,
+        None,
+        None,
+        Some("connection refused".into()),
+    );
+    repository.record_attempt(input).await.unwrap();
+
+    let result = repository
+        .redeliver(webhook.id.to_string(), ids[0].to_string())
+        .await;
+
+    tracing::info!("{:?}", result);
+
+    let delivery = result.unwrap();
+    assert_ne!(delivery.id, ids[0]);
+    assert_eq!(delivery.payload, payload);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.attempts, 0);
+
+    let result = repository
+        .redeliver(uuid::Uuid::now_v7().to_string(), ids[0].to_string())
+        .await;
+    assert!(result.is_err());
+}
@@ -2,25 +2,29 @@ INSERT INTO category (
   id,
   name,
   api_identifier,
-  description
+  description,
+  fields
 )
 VALUES(
   gen_random_uuid(), 
   'sample1',
   'sample1',
-  'sample1 category mock data'
+  'sample1 category mock data',
+  '[{"field_type": "Text", "key": "body", "required": true, "max": 100}]'
 );
 
 INSERT INTO category (
   id,
   name,
   api_identifier,
-  description
+  description,
+  fields
 )
 VALUES(
   gen_random_uuid(), 
   'sample2',
   'sample2',
-  'sample2 category mock data'
+  'sample2 category mock data',
+  '[{"field_type": "Text", "key": "body", "required": true, "max": 100}]'
 );
 
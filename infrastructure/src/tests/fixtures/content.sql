@@ -2,13 +2,15 @@ INSERT INTO category (
   id,
   name,
   api_identifier,
-  description
+  description,
+  fields
 )
 VALUES(
   gen_random_uuid(), 
   'sample1',
   'sample1',
-  'sample1 category mock data'
+  'sample1 category mock data',
+  '[{"field_type": "Text", "key": "body", "required": true, "max": 100}]'
 );
 
 INSERT INTO contents (
@@ -24,7 +26,7 @@ VALUES(
   gen_random_uuid(), 
   'title',
   (SELECT id FROM category LIMIT 1),
-  '[{"field_type": "ShortText", "value": "Blog Title"},{"field_type": "LongText","value": "Blog Content"}]',
+  '{"body": "Blog Content"}',
   'Draft',
   (SELECT id FROM users LIMIT 1),
   (SELECT id FROM users LIMIT 1)
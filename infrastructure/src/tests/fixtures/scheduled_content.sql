@@ -0,0 +1,56 @@
+INSERT INTO contents (
+  id,
+  title,
+  category_id,
+  fields,
+  status,
+  published_at,
+  unpublish_at,
+  created_by,
+  updated_by
+)
+VALUES
+(
+  gen_random_uuid(),
+  'due',
+  (SELECT id FROM category LIMIT 1),
+  '{}',
+  'Reserved',
+  CURRENT_TIMESTAMP - INTERVAL '1 hour',
+  NULL,
+  (SELECT id FROM users LIMIT 1),
+  (SELECT id FROM users LIMIT 1)
+),
+(
+  gen_random_uuid(),
+  'not due',
+  (SELECT id FROM category LIMIT 1),
+  '{}',
+  'Reserved',
+  CURRENT_TIMESTAMP + INTERVAL '1 hour',
+  NULL,
+  (SELECT id FROM users LIMIT 1),
+  (SELECT id FROM users LIMIT 1)
+),
+(
+  gen_random_uuid(),
+  'expired',
+  (SELECT id FROM category LIMIT 1),
+  '{}',
+  'Published',
+  CURRENT_TIMESTAMP - INTERVAL '2 hour',
+  CURRENT_TIMESTAMP - INTERVAL '1 minute',
+  (SELECT id FROM users LIMIT 1),
+  (SELECT id FROM users LIMIT 1)
+),
+(
+  gen_random_uuid(),
+  'live',
+  (SELECT id FROM category LIMIT 1),
+  '{}',
+  'Published',
+  CURRENT_TIMESTAMP - INTERVAL '1 day',
+  NULL,
+  (SELECT id FROM users LIMIT 1),
+  (SELECT id FROM users LIMIT 1)
+);
@@ -21,6 +21,6 @@ VALUES(
   gen_random_uuid(),
   'user1',
   'https://example/image.jpg',
-  (SELECT id FROM role LIMIT 1)
+  (SELECT id FROM role WHERE name = 'admin' LIMIT 1)
 );
 
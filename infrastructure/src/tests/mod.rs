@@ -1,2 +1,4 @@
 pub mod database;
+pub mod oidc;
 pub mod storage;
+pub mod webhook;
@@ -0,0 +1,202 @@
+use std::sync::{Arc, Mutex};
+
+use axum::{extract::State, routing, Form, Json, Router};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use shared::config::OidcConfig;
+
+use domain::model::auth::AuthError;
+use domain::model::oidc::OidcLoginState;
+use domain::repository::oidc::OidcClient;
+
+use crate::oidc::client::OidcClientImpl;
+
+const PRIVATE_KEY: &[u8] = include_bytes!("../fixtures/oidc/private_key.pem");
+const JWKS: &str = include_str!("../fixtures/oidc/jwks.json");
+const CLIENT_ID: &str = "headless-cms";
+
+#[derive(Clone)]
+struct MockProvider {
+    issuer: String,
+    header: Header,
+    key: EncodingKey,
+    claims: Arc<Mutex<serde_json::Value>>,
+    form: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+async fn discovery(State(provider): State<MockProvider>) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "issuer": provider.issuer,
+        "authorization_endpoint": format!("{}/auth", provider.issuer),
+        "token_endpoint": format!("{}/token", provider.issuer),
+        "jwks_uri": format!("{}/certs", provider.issuer),
+    }))
+}
+
+async fn certs() -> Json<serde_json::Value> {
+    Json(serde_json::from_str(JWKS).unwrap())
+}
+
+async fn token(
+    State(provider): State<MockProvider>,
+    Form(form): Form<Vec<(String, String)>>,
+) -> Json<serde_json::Value> {
+    *provider.form.lock().unwrap() = form;
+    let claims = provider.claims.lock().unwrap().clone();
+    let id_token = jsonwebtoken::encode(&provider.header, &claims, &provider.key).unwrap();
+
+    Json(serde_json::json!({
+        "access_token": "access",
+        "token_type": "Bearer",
+        "id_token": id_token,
+    }))
+}
+
+async fn start_provider(header: Header, key: EncodingKey) -> MockProvider {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let issuer = format!("http://{}", listener.local_addr().unwrap());
+    let provider = MockProvider {
+        issuer,
+        header,
+        key,
+        claims: Arc::new(Mutex::new(serde_json::Value::Null)),
+        form: Arc::new(Mutex::new(vec![])),
+    };
+
+    let app = Router::new()
+        .route("/.well-known/openid-configuration", routing::get(discovery))
+        .route("/certs", routing::get(certs))
+        .route("/token", routing::post(token))
+        .with_state(provider.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    provider
+}
+
+async fn start_rsa_provider() -> MockProvider {
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some("test-key".into());
+
+    start_provider(header, EncodingKey::from_rsa_pem(PRIVATE_KEY).unwrap()).await
+}
+
+fn build_client(provider: &MockProvider) -> OidcClientImpl {
+    OidcClientImpl::new(OidcConfig::new(
+        provider.issuer.clone(),
+        CLIENT_ID.into(),
+        Some("secret".into()),
+        "http://localhost:8000/auth/oidc/callback".into(),
+        "realm_access.roles".into(),
+        String::new(),
+        "member".into(),
+    ))
+}
+
+fn build_claims(provider: &MockProvider, login_state: &OidcLoginState) -> serde_json::Value {
+    let now = sqlx::types::chrono::Utc::now().timestamp();
+
+    serde_json::json!({
+        "iss": provider.issuer,
+        "sub": "subject",
+        "aud": CLIENT_ID,
+        "exp": now + 300,
+        "iat": now,
+        "nonce": login_state.nonce,
+        "email": "user@example.com",
+        "email_verified": true,
+        "preferred_username": "user1",
+        "realm_access": { "roles": ["cms-admins", "offline_access"] },
+    })
+}
+
+#[tokio::test]
+async fn authorization_url_success() {
+    let provider = start_rsa_provider().await;
+    let client = build_client(&provider);
+    let login_state = OidcLoginState::generate(sqlx::types::chrono::Utc::now());
+
+    let url = client.authorization_url(login_state.clone()).await.unwrap();
+    let url = reqwest::Url::parse(&url).unwrap();
+    let query: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+
+    assert_eq!(url.path(), "/auth");
+    assert_eq!(query["response_type"], "code");
+    assert_eq!(query["client_id"], CLIENT_ID);
+    assert_eq!(query["state"], login_state.state);
+    assert_eq!(query["nonce"], login_state.nonce);
+    assert_eq!(query["code_challenge"], login_state.code_challenge());
+    assert_eq!(query["code_challenge_method"], "S256");
+}
+
+#[tokio::test]
+async fn exchange_success() {
+    let provider = start_rsa_provider().await;
+    let client = build_client(&provider);
+    let login_state = OidcLoginState::generate(sqlx::types::chrono::Utc::now());
+    *provider.claims.lock().unwrap() = build_claims(&provider, &login_state);
+
+    let claims = client
+        .exchange("code".into(), login_state.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(claims.issuer, provider.issuer);
+    assert_eq!(claims.subject, "subject");
+    assert_eq!(claims.verified_email().as_deref(), Some("user@example.com"));
+    assert_eq!(claims.name.as_deref(), Some("user1"));
+    assert_eq!(claims.groups, vec!["cms-admins", "offline_access"]);
+
+    let form = provider.form.lock().unwrap().clone();
+    assert!(form.contains(&("code".into(), "code".into())));
+    assert!(form.contains(&("code_verifier".into(), login_state.code_verifier)));
+    assert!(form.contains(&("client_secret".into(), "secret".into())));
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::wrong_nonce("nonce", serde_json::json!("other"))]
+#[case::wrong_audience("aud", serde_json::json!("other-client"))]
+#[case::wrong_issuer("iss", serde_json::json!("https://evil.example.com"))]
+#[case::expired("exp", serde_json::json!(sqlx::types::chrono::Utc::now().timestamp() - 600))]
+async fn exchange_failure_with_invalid_claims(#[case] key: &str, #[case] value: serde_json::Value) {
+    let provider = start_rsa_provider().await;
+    let client = build_client(&provider);
+    let login_state = OidcLoginState::generate(sqlx::types::chrono::Utc::now());
+    let mut claims = build_claims(&provider, &login_state);
+    claims[key] = value;
+    *provider.claims.lock().unwrap() = claims;
+
+    let result = client.exchange("code".into(), login_state).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::InvalidIdToken
+    );
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::unknown_kid(Algorithm::RS256, Some("rotated-key"), EncodingKey::from_rsa_pem(PRIVATE_KEY).unwrap())]
+#[case::symmetric(
+    Algorithm::HS256,
+    Some("test-key"),
+    EncodingKey::from_secret(b"secret")
+)]
+async fn exchange_failure_with_untrusted_signature(
+    #[case] algorithm: Algorithm,
+    #[case] kid: Option<&str>,
+    #[case] key: EncodingKey,
+) {
+    let mut header = Header::new(algorithm);
+    header.kid = kid.map(String::from);
+    let provider = start_provider(header, key).await;
+    let client = build_client(&provider);
+    let login_state = OidcLoginState::generate(sqlx::types::chrono::Utc::now());
+    *provider.claims.lock().unwrap() = build_claims(&provider, &login_state);
+
+    let result = client.exchange("code".into(), login_state).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast::<AuthError>().unwrap(),
+        AuthError::InvalidIdToken
+    );
+}
@@ -1,23 +1,314 @@
+use std::time::Duration;
+
+use domain::model::media::{MediaInUseError, MediaMetadata, MediaUsage};
+use domain::model::trash::TrashError;
+use domain::repository::media::{CreateMedia, GetMediaQuery, MediaRepository, UpdateMedia};
 use shared::config::StorageConfig;
+use sqlx::types::chrono::Utc;
 
+use crate::database::connection::ConnectionPool;
 use crate::storage::client::StorageClient;
 use crate::storage::media::MediaRepositoryImpl;
+use crate::tests::storage::mock::{spawn_storage, MockStorage, StoredObject, BUCKET};
+
+async fn build_repository(pool: &sqlx::PgPool) -> (MockStorage, MediaRepositoryImpl) {
+    let (storage, client) = spawn_storage().await;
+    let repository =
+        MediaRepositoryImpl::new(ConnectionPool::new(pool.clone()), client, BUCKET.into());
+
+    (storage, repository)
+}
+
+async fn get_user_id(pool: &sqlx::PgPool) -> String {
+    sqlx::query_scalar::<_, uuid::Uuid>(r#"SELECT id FROM users LIMIT 1"#)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .to_string()
+}
+
+async fn outbox_events(pool: &sqlx::PgPool) -> Vec<String> {
+    sqlx::query_scalar(r#"SELECT event FROM outbox_events ORDER BY id"#)
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+fn build_create_media(body: &[u8], uploaded_by_id: String) -> CreateMedia {
+    CreateMedia::new(
+        "notes.txt".into(),
+        "text/plain".into(),
+        body.to_vec(),
+        MediaMetadata::inspect(body),
+        Some("Meeting notes".into()),
+        uploaded_by_id,
+    )
+}
 
-#[tokio::test]
-async fn create_bucket_test() -> anyhow::Result<()> {
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn create_bucket_test(pool: sqlx::PgPool) -> anyhow::Result<()> {
     let config = StorageConfig::new(
         std::env::var("STORAGE_ACCESS_KEY")?,
         std::env::var("STORAGE_SECRET_KEY")?,
         std::env::var("STORAGE_REGION")?,
         std::env::var("STORAGE_ENDPOINT")?,
+        BUCKET.into(),
     );
     let cli = StorageClient::new(config);
-    let repo = MediaRepositoryImpl::new(cli);
+    let repo = MediaRepositoryImpl::new(ConnectionPool::new(pool), cli, BUCKET.into());
 
     let result = repo.create_bucket("test".into()).await;
-    assert_eq!(result.is_ok(), true);
+    assert!(result.is_ok());
 
     repo.delete_bucket(result?).await?;
 
     Ok(())
 }
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn create_success(pool: sqlx::PgPool) {
+    let (storage, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+
+    let media = repository
+        .create(build_create_media(b"hello", user_id.clone()))
+        .await
+        .unwrap();
+
+    assert_eq!(media.filename, "notes.txt");
+    assert_eq!(media.size, 5);
+    assert_eq!(media.checksum, MediaMetadata::inspect(b"hello").checksum);
+    assert_eq!(media.alt_text.as_deref(), Some("Meeting notes"));
+    assert_eq!(media.uploaded_by.id, user_id);
+    assert!(media.object_key.ends_with(".txt"));
+
+    let object = storage.object(&media.object_key).unwrap();
+    assert_eq!(object.body, b"hello");
+    assert_eq!(object.content_type, "text/plain");
+
+    let stored = repository.get_object(&media.object_key).await.unwrap();
+    assert_eq!(stored.size, 5);
+    assert_eq!(outbox_events(&pool).await, vec!["media.created"]);
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn create_failure_removes_object(pool: sqlx::PgPool) {
+    let (storage, repository) = build_repository(&pool).await;
+
+    let result = repository
+        .create(build_create_media(
+            b"hello",
+            uuid::Uuid::now_v7().to_string(),
+        ))
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(storage.object_count(), 0);
+    assert!(outbox_events(&pool).await.is_empty());
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn get_and_download_success(pool: sqlx::PgPool) {
+    let (_, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+
+    let first = repository
+        .create(build_create_media(b"first", user_id.clone()))
+        .await
+        .unwrap();
+    let second = repository
+        .create(build_create_media(b"second", user_id))
+        .await
+        .unwrap();
+
+    let media = repository
+        .get(GetMediaQuery::default())
+        .await
+        .unwrap()
+        .items;
+    let ids: Vec<uuid::Uuid> = media.iter().map(|x| x.id).collect();
+    assert_eq!(ids, vec![second.id, first.id]);
+
+    let object = repository.download(first.id.to_string()).await.unwrap();
+    assert_eq!(object.media.id, first.id);
+    assert_eq!(object.body, b"first");
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn update_success(pool: sqlx::PgPool) {
+    let (_, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let media = repository
+        .create(build_create_media(b"hello", user_id))
+        .await
+        .unwrap();
+
+    let updated = repository
+        .update(UpdateMedia::new(
+            media.id.to_string(),
+            Some("renamed.txt".into()),
+            Some("".into()),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(updated.filename, "renamed.txt");
+    assert_eq!(updated.alt_text, None);
+    assert_eq!(updated.object_key, media.object_key);
+    assert_eq!(
+        outbox_events(&pool).await,
+        vec!["media.created", "media.updated"]
+    );
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn delete_success(pool: sqlx::PgPool) {
+    let (storage, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let media = repository
+        .create(build_create_media(b"hello", user_id))
+        .await
+        .unwrap();
+    let variant_key = format!("variants/{}/variant.webp", media.id);
+    let other_key = format!("variants/{}/variant.webp", uuid::Uuid::now_v7());
+    for key in [&variant_key, &other_key] {
+        let object = StoredObject {
+            body: b"variant".to_vec(),
+            content_type: "image/webp".into(),
+        };
+        storage.objects.lock().unwrap().insert(key.clone(), object);
+    }
+
+    repository
+        .delete(media.id.to_string(), false)
+        .await
+        .unwrap();
+
+    assert!(repository.find(media.id.to_string()).await.is_err());
+    assert!(storage.object(&media.object_key).is_some());
+    assert!(storage.object(&variant_key).is_some());
+
+    let restored = repository.restore(media.id.to_string()).await.unwrap();
+    assert_eq!(restored.id, media.id);
+    assert!(repository.find(media.id.to_string()).await.is_ok());
+    assert_eq!(
+        repository
+            .restore(media.id.to_string())
+            .await
+            .unwrap_err()
+            .downcast_ref::<TrashError>(),
+        Some(&TrashError::NotInTrash)
+    );
+    assert_eq!(
+        outbox_events(&pool).await,
+        vec!["media.created", "media.deleted", "media.restored"]
+    );
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn purge_success(pool: sqlx::PgPool) {
+    let (storage, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let media = repository
+        .create(build_create_media(b"hello", user_id))
+        .await
+        .unwrap();
+    let variant_key = format!("variants/{}/variant.webp", media.id);
+    let other_key = format!("variants/{}/variant.webp", uuid::Uuid::now_v7());
+    for key in [&variant_key, &other_key] {
+        let object = StoredObject {
+            body: b"variant".to_vec(),
+            content_type: "image/webp".into(),
+        };
+        storage.objects.lock().unwrap().insert(key.clone(), object);
+    }
+    repository
+        .delete(media.id.to_string(), false)
+        .await
+        .unwrap();
+
+    let before = Utc::now() - Duration::from_secs(24 * 60 * 60);
+    assert!(repository.purge(before, 10).await.unwrap().is_empty());
+
+    let before = Utc::now() + Duration::from_secs(1);
+    let purged = repository.purge(before, 10).await.unwrap();
+
+    assert_eq!(purged, vec![media.id]);
+    assert!(storage.object(&media.object_key).is_none());
+    assert!(storage.object(&variant_key).is_none());
+    assert!(storage.object(&other_key).is_some());
+    assert!(repository.restore(media.id.to_string()).await.is_err());
+}
+
+async fn reference_media(pool: &sqlx::PgPool, media_id: uuid::Uuid) -> uuid::Uuid {
+    sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+            INSERT INTO
+                content_media (content_id, field_key, media_id)
+            SELECT
+                id, 'thumbnail', $1
+            FROM
+                contents
+            LIMIT 1
+            RETURNING content_id
+        "#,
+    )
+    .bind(media_id)
+    .fetch_one(pool)
+    .await
+    .unwrap()
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+async fn delete_failure_with_usages(pool: sqlx::PgPool) {
+    let (storage, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let media = repository
+        .create(build_create_media(b"hello", user_id))
+        .await
+        .unwrap();
+    let content_id = reference_media(&pool, media.id).await;
+
+    let usages = repository.usages(media.id.to_string()).await.unwrap();
+    assert_eq!(
+        usages,
+        vec![MediaUsage::new(
+            content_id,
+            "title".into(),
+            "thumbnail".into()
+        )]
+    );
+
+    let error = repository
+        .delete(media.id.to_string(), false)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<MediaInUseError>(),
+        Some(&MediaInUseError { usages })
+    );
+    assert!(repository.find(media.id.to_string()).await.is_ok());
+    assert!(storage.object(&media.object_key).is_some());
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users", "content")))]
+async fn delete_success_with_force(pool: sqlx::PgPool) {
+    let (storage, repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let media = repository
+        .create(build_create_media(b"hello", user_id))
+        .await
+        .unwrap();
+    reference_media(&pool, media.id).await;
+
+    repository.delete(media.id.to_string(), true).await.unwrap();
+
+    assert!(repository.find(media.id.to_string()).await.is_err());
+    assert!(storage.object(&media.object_key).is_some());
+    assert!(repository
+        .usages(media.id.to_string())
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing, Router,
+};
+use shared::config::StorageConfig;
+
+use crate::storage::client::StorageClient;
+
+pub const BUCKET: &str = "media";
+
+#[derive(Debug, Clone)]
+pub struct StoredObject {
+    pub body: Vec<u8>,
+    pub content_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MultipartUpload {
+    pub key: String,
+    pub content_type: String,
+    pub parts: BTreeMap<i32, Vec<u8>>,
+}
+
+#[derive(Clone, Default)]
+pub struct MockStorage {
+    pub objects: Arc<Mutex<HashMap<String, StoredObject>>>,
+    pub uploads: Arc<Mutex<HashMap<String, MultipartUpload>>>,
+}
+
+impl MockStorage {
+    pub fn object(&self, key: &str) -> Option<StoredObject> {
+        self.objects.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn object_count(&self) -> usize {
+        self.objects.lock().unwrap().len()
+    }
+
+    pub fn upload_count(&self) -> usize {
+        self.uploads.lock().unwrap().len()
+    }
+}
+
+type Params = Query<HashMap<String, String>>;
+
+fn xml(body: String) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/xml")],
+        body,
+    )
+        .into_response()
+}
+
+fn no_such_upload() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        [(header::CONTENT_TYPE, "application/xml")],
+        "<Error><Code>NoSuchUpload</Code><Message>The specified upload does not exist.</Message></Error>",
+    )
+        .into_response()
+}
+
+fn content_type(headers: &HeaderMap) -> String {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string()
+}
+
+fn not_found() -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        [(header::CONTENT_TYPE, "application/xml")],
+        "<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>",
+    )
+        .into_response()
+}
+
+async fn post_object(
+    State(storage): State<MockStorage>,
+    Path((bucket, key)): Path<(String, String)>,
+    Query(params): Params,
+    headers: HeaderMap,
+) -> Response {
+    if params.contains_key("uploads") {
+        let upload_id = uuid::Uuid::now_v7().to_string();
+        let upload = MultipartUpload {
+            key: key.clone(),
+            content_type: content_type(&headers),
+            parts: BTreeMap::new(),
+        };
+        storage
+            .uploads
+            .lock()
+            .unwrap()
+            .insert(upload_id.clone(), upload);
+
+        return xml(format!(
+            "<InitiateMultipartUploadResult><Bucket>{}</Bucket><Key>{}</Key><UploadId>{}</UploadId></InitiateMultipartUploadResult>",
+            bucket, key, upload_id
+        ));
+    }
+
+    let Some(upload_id) = params.get("uploadId") else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let Some(upload) = storage.uploads.lock().unwrap().remove(upload_id) else {
+        return no_such_upload();
+    };
+    let object = StoredObject {
+        body: upload.parts.into_values().flatten().collect(),
+        content_type: upload.content_type,
+    };
+    storage.objects.lock().unwrap().insert(upload.key, object);
+
+    xml(format!(
+        "<CompleteMultipartUploadResult><Bucket>{}</Bucket><Key>{}</Key><ETag>\"etag\"</ETag></CompleteMultipartUploadResult>",
+        bucket, key
+    ))
+}
+
+async fn put_object(
+    State(storage): State<MockStorage>,
+    Path((_, key)): Path<(String, String)>,
+    Query(params): Params,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    if let (Some(upload_id), Some(part_number)) = (
+        params.get("uploadId"),
+        params
+            .get("partNumber")
+            .and_then(|value| value.parse::<i32>().ok()),
+    ) {
+        let mut uploads = storage.uploads.lock().unwrap();
+        let Some(upload) = uploads.get_mut(upload_id) else {
+            return no_such_upload();
+        };
+        upload.parts.insert(part_number, body.to_vec());
+        let etag = format!("\"part-{}\"", part_number);
+
+        return (StatusCode::OK, [(header::ETAG, etag)]).into_response();
+    }
+
+    let object = StoredObject {
+        body: body.to_vec(),
+        content_type: content_type(&headers),
+    };
+    storage.objects.lock().unwrap().insert(key, object);
+
+    (StatusCode::OK, [(header::ETAG, "\"etag\"")]).into_response()
+}
+
+async fn get_object(
+    State(storage): State<MockStorage>,
+    Path((bucket, key)): Path<(String, String)>,
+    Query(params): Params,
+) -> Response {
+    if let Some(upload_id) = params.get("uploadId") {
+        let uploads = storage.uploads.lock().unwrap();
+        let Some(upload) = uploads.get(upload_id) else {
+            return no_such_upload();
+        };
+        let parts = upload
+            .parts
+            .iter()
+            .fold(String::new(), |mut parts, (part_number, body)| {
+                let _ = write!(
+                    parts,
+                    "<Part><PartNumber>{}</PartNumber><ETag>\"part-{}\"</ETag><Size>{}</Size></Part>",
+                    part_number,
+                    part_number,
+                    body.len()
+                );
+                parts
+            });
+
+        return xml(format!(
+            "<ListPartsResult><Bucket>{}</Bucket><Key>{}</Key><UploadId>{}</UploadId><IsTruncated>false</IsTruncated>{}</ListPartsResult>",
+            bucket, key, upload_id, parts
+        ));
+    }
+
+    match storage.object(&key) {
+        Some(object) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, object.content_type)],
+            object.body,
+        )
+            .into_response(),
+        None => not_found(),
+    }
+}
+
+async fn head_object(
+    State(storage): State<MockStorage>,
+    Path((_, key)): Path<(String, String)>,
+) -> Response {
+    match storage.object(&key) {
+        Some(object) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, object.content_type),
+                (header::CONTENT_LENGTH, object.body.len().to_string()),
+            ],
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn delete_object(
+    State(storage): State<MockStorage>,
+    Path((_, key)): Path<(String, String)>,
+    Query(params): Params,
+) -> StatusCode {
+    if let Some(upload_id) = params.get("uploadId") {
+        storage.uploads.lock().unwrap().remove(upload_id);
+
+        return StatusCode::NO_CONTENT;
+    }
+
+    storage.objects.lock().unwrap().remove(&key);
+
+    StatusCode::NO_CONTENT
+}
+
+async fn list_objects(
+    State(storage): State<MockStorage>,
+    Path(bucket): Path<String>,
+    Query(params): Params,
+) -> Response {
+    let prefix = params.get("prefix").cloned().unwrap_or_default();
+    let contents: String = storage
+        .objects
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(key, _)| key.starts_with(&prefix))
+        .fold(String::new(), |mut contents, (key, object)| {
+            let _ = write!(
+                contents,
+                "<Contents><Key>{}</Key><Size>{}</Size></Contents>",
+                key,
+                object.body.len()
+            );
+            contents
+        });
+
+    xml(format!(
+        "<ListBucketResult><Name>{}</Name><Prefix>{}</Prefix><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+        bucket, prefix, contents
+    ))
+}
+
+pub async fn spawn_storage() -> (MockStorage, StorageClient) {
+    let storage = MockStorage::default();
+    let app = Router::new()
+        .route("/:bucket", routing::get(list_objects))
+        .route("/:bucket/", routing::get(list_objects))
+        .route(
+            "/:bucket/*key",
+            routing::put(put_object)
+                .get(get_object)
+                .head(head_object)
+                .post(post_object)
+                .delete(delete_object),
+        )
+        .layer(DefaultBodyLimit::disable())
+        .with_state(storage.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    let config = StorageConfig::new(
+        "access_key".into(),
+        "secret_key".into(),
+        "us-east-1".into(),
+        endpoint,
+        BUCKET.into(),
+    );
+
+    (storage, StorageClient::new(config))
+}
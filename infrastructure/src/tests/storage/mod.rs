@@ -1 +1,4 @@
 pub mod media;
+pub mod mock;
+pub mod render;
+pub mod upload;
@@ -0,0 +1,121 @@
+use image::{GenericImageView, ImageFormat, RgbaImage};
+
+use domain::model::media::{Media, MediaUploader};
+use domain::model::render::{RenderError, RenderFit, RenderFormat, RenderOptions};
+use domain::repository::render::MediaRenderRepository;
+
+use crate::storage::render::{transform, MediaRenderRepositoryImpl};
+use crate::tests::storage::mock::{spawn_storage, StoredObject, BUCKET};
+
+fn build_png(width: u32, height: u32) -> Vec<u8> {
+    let image = RgbaImage::from_pixel(width, height, image::Rgba([200, 40, 40, 255]));
+    let mut buffer = std::io::Cursor::new(vec![]);
+    image.write_to(&mut buffer, ImageFormat::Png).unwrap();
+
+    buffer.into_inner()
+}
+
+fn build_media(object_key: &str) -> Media {
+    Media::try_new(
+        uuid::Uuid::now_v7(),
+        object_key.into(),
+        "photo.png".into(),
+        "image/png".into(),
+        0,
+        "checksum".into(),
+        Some(400),
+        Some(200),
+        None,
+        MediaUploader::new("user_id".into(), "name".into()),
+        sqlx::types::chrono::Utc::now(),
+        sqlx::types::chrono::Utc::now(),
+    )
+    .unwrap()
+}
+
+fn build_options(
+    width: Option<u32>,
+    height: Option<u32>,
+    fit: RenderFit,
+    format: RenderFormat,
+) -> RenderOptions {
+    RenderOptions::try_new(width, height, Some(fit), Some(format), None).unwrap()
+}
+
+#[rstest::rstest]
+#[case::cover(Some(100), Some(100), RenderFit::Cover, (100, 100))]
+#[case::contain(Some(100), Some(100), RenderFit::Contain, (100, 50))]
+#[case::fill(Some(100), Some(100), RenderFit::Fill, (100, 100))]
+#[case::width_only(Some(100), None, RenderFit::Cover, (100, 50))]
+#[case::height_only(None, Some(100), RenderFit::Cover, (200, 100))]
+#[case::original(None, None, RenderFit::Cover, (400, 200))]
+fn transform_resizes(
+    #[case] width: Option<u32>,
+    #[case] height: Option<u32>,
+    #[case] fit: RenderFit,
+    #[case] expected: (u32, u32),
+) {
+    let options = build_options(width, height, fit, RenderFormat::Png);
+    let body = transform(&build_png(400, 200), &options).unwrap();
+    let image = image::load_from_memory_with_format(&body, ImageFormat::Png).unwrap();
+
+    assert_eq!(image.dimensions(), expected);
+}
+
+#[rstest::rstest]
+#[case::webp(RenderFormat::Webp, ImageFormat::WebP)]
+#[case::jpeg(RenderFormat::Jpeg, ImageFormat::Jpeg)]
+#[case::png(RenderFormat::Png, ImageFormat::Png)]
+fn transform_converts(#[case] format: RenderFormat, #[case] expected: ImageFormat) {
+    let options = build_options(Some(40), None, RenderFit::Cover, format);
+    let body = transform(&build_png(400, 200), &options).unwrap();
+
+    assert_eq!(image::guess_format(&body).unwrap(), expected);
+}
+
+#[rstest::rstest]
+fn transform_failure_with_invalid_image() {
+    let options = build_options(Some(40), None, RenderFit::Cover, RenderFormat::Webp);
+    let error = transform(b"not an image", &options).unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<RenderError>(),
+        Some(&RenderError::NotAnImage)
+    );
+}
+
+#[tokio::test]
+async fn render_stores_variant() {
+    let (storage, client) = spawn_storage().await;
+    let repository = MediaRenderRepositoryImpl::new(client, BUCKET.into());
+    let media = build_media("media/photo.png");
+    storage.objects.lock().unwrap().insert(
+        media.object_key.clone(),
+        StoredObject {
+            body: build_png(400, 200),
+            content_type: "image/png".into(),
+        },
+    );
+    let options = build_options(Some(100), Some(100), RenderFit::Cover, RenderFormat::Webp);
+    let key = options.variant_key(&media.id);
+
+    assert!(repository
+        .find_variant(key.clone())
+        .await
+        .unwrap()
+        .is_none());
+
+    let rendered = repository
+        .render(media, options, key.clone())
+        .await
+        .unwrap();
+    assert_eq!(rendered.content_type, "image/webp");
+
+    let stored = storage.object(&key).unwrap();
+    assert_eq!(stored.body, rendered.body);
+    assert_eq!(stored.content_type, "image/webp");
+
+    let cached = repository.find_variant(key).await.unwrap().unwrap();
+    assert_eq!(cached.body, rendered.body);
+    assert_eq!(cached.content_type, "image/webp");
+}
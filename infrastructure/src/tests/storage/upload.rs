@@ -0,0 +1,233 @@
+use domain::model::media::MediaMetadata;
+use domain::model::upload::{PresignedPart, UploadError, UploadPlan, UploadSession};
+use domain::repository::media::MediaRepository;
+use domain::repository::upload::{CreateUploadSession, MediaUploadRepository};
+
+use crate::database::connection::ConnectionPool;
+use crate::storage::media::MediaRepositoryImpl;
+use crate::storage::upload::MediaUploadRepositoryImpl;
+use crate::tests::storage::mock::{spawn_storage, MockStorage, BUCKET};
+
+const EXPIRES_IN: std::time::Duration = std::time::Duration::from_secs(60);
+
+async fn build_repository(
+    pool: &sqlx::PgPool,
+) -> (MockStorage, MediaUploadRepositoryImpl, MediaRepositoryImpl) {
+    let (storage, client) = spawn_storage().await;
+    let db = ConnectionPool::new(pool.clone());
+    let repository = MediaUploadRepositoryImpl::new(db.clone(), client.clone(), BUCKET.into());
+    let media_repository = MediaRepositoryImpl::new(db, client, BUCKET.into());
+
+    (storage, repository, media_repository)
+}
+
+async fn get_user_id(pool: &sqlx::PgPool) -> String {
+    sqlx::query_scalar::<_, uuid::Uuid>(r#"SELECT id FROM users LIMIT 1"#)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .to_string()
+}
+
+fn build_create_session(
+    body: &[u8],
+    plan: Option<UploadPlan>,
+    uploaded_by_id: String,
+) -> CreateUploadSession {
+    CreateUploadSession::new(
+        "video.mp4".into(),
+        "video/mp4".into(),
+        body.len() as i64,
+        MediaMetadata::inspect(body).checksum,
+        Some("Product demo".into()),
+        plan,
+        uploaded_by_id,
+        sqlx::types::chrono::Utc::now() + EXPIRES_IN,
+    )
+}
+
+async fn put_parts(parts: &[PresignedPart], body: &[u8], content_type: &str) {
+    let client = reqwest::Client::new();
+    let mut offset = 0;
+
+    for part in parts {
+        let end = offset + part.size as usize;
+        let response = client
+            .put(&part.url)
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body[offset..end].to_vec())
+            .send()
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        offset = end;
+    }
+}
+
+async fn complete(
+    repository: &MediaUploadRepositoryImpl,
+    session: UploadSession,
+) -> anyhow::Result<domain::model::media::Media> {
+    repository.finish_upload(session.clone()).await?;
+    let metadata = repository
+        .inspect_object(session.object_key.clone())
+        .await?;
+
+    repository.register(session, metadata).await
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn single_upload_success(pool: sqlx::PgPool) {
+    let (storage, repository, media_repository) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let body = b"single upload body";
+
+    let session = repository
+        .create_session(build_create_session(body, None, user_id.clone()))
+        .await
+        .unwrap();
+    assert!(!session.is_multipart());
+    assert!(session.object_key.ends_with(".mp4"));
+
+    let parts = repository
+        .presign_upload(session.clone(), EXPIRES_IN)
+        .await
+        .unwrap();
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].size, body.len() as i64);
+    assert!(parts[0].url.contains("X-Amz-Signature"));
+
+    put_parts(&parts, body, "video/mp4").await;
+
+    let media = complete(&repository, session.clone()).await.unwrap();
+    assert_eq!(media.id, session.id);
+    assert_eq!(media.size, body.len() as i64);
+    assert_eq!(media.checksum, session.checksum);
+    assert_eq!(media.uploaded_by.id, user_id);
+    assert_eq!(storage.object(&media.object_key).unwrap().body, body);
+
+    let found = repository
+        .find_session(session.id.to_string())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(found.completed_at.is_some());
+
+    let found = media_repository.find(media.id.to_string()).await.unwrap();
+    assert_eq!(found.alt_text.as_deref(), Some("Product demo"));
+
+    let events: Vec<String> = sqlx::query_scalar(r#"SELECT event FROM outbox_events"#)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(events, vec!["media.created"]);
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn multipart_upload_success(pool: sqlx::PgPool) {
+    let (storage, repository, _) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let body = b"multipart upload body";
+    let plan = UploadPlan {
+        part_size: 8,
+        part_count: 3,
+    };
+
+    let session = repository
+        .create_session(build_create_session(body, Some(plan), user_id))
+        .await
+        .unwrap();
+    assert!(session.is_multipart());
+    assert_eq!(storage.upload_count(), 1);
+
+    let parts = repository
+        .presign_upload(session.clone(), EXPIRES_IN)
+        .await
+        .unwrap();
+    let sizes: Vec<i64> = parts.iter().map(|part| part.size).collect();
+    assert_eq!(sizes, vec![8, 8, 5]);
+    assert!(parts[1].url.contains("partNumber=2"));
+
+    put_parts(&parts, body, "video/mp4").await;
+
+    let media = complete(&repository, session).await.unwrap();
+    assert_eq!(media.size, body.len() as i64);
+    assert_eq!(storage.object(&media.object_key).unwrap().body, body);
+    assert_eq!(storage.upload_count(), 0);
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn complete_without_object(pool: sqlx::PgPool) {
+    let (_, repository, _) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let body = b"never uploaded";
+    let plan = UploadPlan {
+        part_size: 8,
+        part_count: 2,
+    };
+
+    let session = repository
+        .create_session(build_create_session(body, None, user_id.clone()))
+        .await
+        .unwrap();
+    let error = complete(&repository, session).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<UploadError>(),
+        Some(&UploadError::ObjectMissing)
+    );
+
+    let session = repository
+        .create_session(build_create_session(body, Some(plan), user_id))
+        .await
+        .unwrap();
+    let error = complete(&repository, session).await.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<UploadError>(),
+        Some(&UploadError::ObjectMissing)
+    );
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn discard_success(pool: sqlx::PgPool) {
+    let (storage, repository, _) = build_repository(&pool).await;
+    let user_id = get_user_id(&pool).await;
+    let body = b"discarded upload";
+    let plan = UploadPlan {
+        part_size: 8,
+        part_count: 2,
+    };
+
+    let session = repository
+        .create_session(build_create_session(body, Some(plan), user_id))
+        .await
+        .unwrap();
+    let parts = repository
+        .presign_upload(session.clone(), EXPIRES_IN)
+        .await
+        .unwrap();
+    put_parts(&parts[..1], body, "video/mp4").await;
+
+    repository.discard(session.clone()).await.unwrap();
+
+    assert_eq!(storage.upload_count(), 0);
+    assert_eq!(storage.object_count(), 0);
+    assert!(repository
+        .find_session(session.id.to_string())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[sqlx::test(fixtures(path = "../fixtures", scripts("users")))]
+async fn presign_download_success(pool: sqlx::PgPool) {
+    let (_, repository, _) = build_repository(&pool).await;
+
+    let presigned = repository
+        .presign_download("media/object.png".into(), EXPIRES_IN)
+        .await
+        .unwrap();
+
+    assert!(presigned.url.contains("/media/media/object.png"));
+    assert!(presigned.url.contains("X-Amz-Expires=60"));
+    assert!(presigned.expires_at > sqlx::types::chrono::Utc::now());
+}
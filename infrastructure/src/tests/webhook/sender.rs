@@ -0,0 +1,175 @@
+use std::sync::{Arc, Mutex};
+
+use axum::{extract::State, http::HeaderMap, http::StatusCode, routing, Router};
+
+use domain::model::webhook::{
+    sign, WebhookDelivery, WebhookDeliveryStatus, WebhookError, WebhookEvent, WebhookPayload,
+    WebhookRequest, WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER, WEBHOOK_SIGNATURE_HEADER,
+};
+use domain::repository::webhook::WebhookSender;
+
+use crate::webhook::sender::WebhookSenderImpl;
+
+const SECRET: &str = "0123456789abcdef";
+
+#[derive(Clone, Default)]
+struct Receiver {
+    requests: Arc<Mutex<Vec<(HeaderMap, String)>>>,
+}
+
+async fn receive(
+    State(receiver): State<Receiver>,
+    headers: HeaderMap,
+    body: String,
+) -> (StatusCode, &'static str) {
+    receiver.requests.lock().unwrap().push((headers, body));
+
+    (StatusCode::ACCEPTED, "accepted")
+}
+
+async fn fail() -> (StatusCode, &'static str) {
+    (StatusCode::SERVICE_UNAVAILABLE, "unavailable")
+}
+
+async fn redirect() -> (StatusCode, [(&'static str, &'static str); 1]) {
+    (StatusCode::FOUND, [("location", "/hook")])
+}
+
+async fn slow() -> StatusCode {
+    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+
+    StatusCode::OK
+}
+
+async fn start_receiver() -> (String, Receiver) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let receiver = Receiver::default();
+
+    let app = Router::new()
+        .route("/hook", routing::post(receive))
+        .route("/fail", routing::post(fail))
+        .route("/slow", routing::post(slow))
+        .route("/redirect", routing::post(redirect))
+        .with_state(receiver.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await });
+
+    (url, receiver)
+}
+
+fn build_request(url: String) -> (WebhookRequest, WebhookDelivery) {
+    let payload = WebhookPayload::new(
+        WebhookEvent::ContentPublished,
+        serde_json::json!({"id": "id", "title": "タイトル"}),
+        sqlx::types::chrono::Utc::now(),
+    );
+    let delivery = WebhookDelivery::new(
+        uuid::Uuid::now_v7(),
+        uuid::Uuid::now_v7(),
+        payload.event,
+        payload,
+        WebhookDeliveryStatus::Pending,
+        0,
+        None,
+        None,
+        None,
+        None,
+        sqlx::types::chrono::Utc::now(),
+        sqlx::types::chrono::Utc::now(),
+    );
+    let request =
+        WebhookRequest::signed(url, SECRET, &delivery, sqlx::types::chrono::Utc::now()).unwrap();
+
+    (request, delivery)
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> &'a str {
+    headers.get(name).unwrap().to_str().unwrap()
+}
+
+#[tokio::test]
+async fn send_success() {
+    let (url, receiver) = start_receiver().await;
+    let sender = WebhookSenderImpl::new(std::time::Duration::from_secs(1), true).unwrap();
+    let (request, delivery) = build_request(format!("{}/hook", url));
+
+    let response = sender.send(request).await.unwrap();
+
+    assert_eq!(response.status, 202);
+    assert_eq!(response.body, "accepted");
+
+    let requests = receiver.requests.lock().unwrap();
+    let (headers, body) = &requests[0];
+    let signature = header(headers, WEBHOOK_SIGNATURE_HEADER);
+    let timestamp = signature
+        .strip_prefix("t=")
+        .and_then(|x| x.split(',').next())
+        .unwrap()
+        .parse::<i64>()
+        .unwrap();
+    assert_eq!(signature, sign(SECRET, timestamp, body));
+    assert_eq!(header(headers, WEBHOOK_EVENT_HEADER), "content.published");
+    assert_eq!(
+        header(headers, WEBHOOK_DELIVERY_HEADER),
+        delivery.id.to_string()
+    );
+    assert_eq!(header(headers, "content-type"), "application/json");
+    assert_eq!(
+        serde_json::from_str::<WebhookPayload>(body).unwrap(),
+        delivery.payload
+    );
+}
+
+#[tokio::test]
+async fn send_with_error_status() {
+    let (url, _) = start_receiver().await;
+    let sender = WebhookSenderImpl::new(std::time::Duration::from_secs(1), true).unwrap();
+    let (request, _) = build_request(format!("{}/fail", url));
+
+    let response = sender.send(request).await.unwrap();
+
+    assert!(!response.is_success());
+    assert_eq!(response.status, 503);
+    assert_eq!(response.body, "unavailable");
+}
+
+#[tokio::test]
+async fn send_failure_with_timeout() {
+    let (url, _) = start_receiver().await;
+    let sender = WebhookSenderImpl::new(std::time::Duration::from_millis(100), true).unwrap();
+    let (request, _) = build_request(format!("{}/slow", url));
+
+    let result = sender.send(request).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn send_without_following_redirect() {
+    let (url, receiver) = start_receiver().await;
+    let sender = WebhookSenderImpl::new(std::time::Duration::from_secs(1), true).unwrap();
+    let (request, _) = build_request(format!("{}/redirect", url));
+
+    let response = sender.send(request).await.unwrap();
+
+    assert_eq!(response.status, 302);
+    assert!(receiver.requests.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+#[rstest::rstest]
+#[case::loopback("127.0.0.1")]
+#[case::localhost("localhost")]
+async fn send_failure_with_private_host(#[case] host: &str) {
+    let (url, receiver) = start_receiver().await;
+    let sender = WebhookSenderImpl::new(std::time::Duration::from_secs(1), false).unwrap();
+    let (request, _) = build_request(format!("{}/hook", url.replace("127.0.0.1", host)));
+
+    let result = sender.send(request).await;
+
+    assert_eq!(
+        result.unwrap_err().downcast_ref::<WebhookError>(),
+        Some(&WebhookError::PrivateUrl)
+    );
+    assert!(receiver.requests.lock().unwrap().is_empty());
+}
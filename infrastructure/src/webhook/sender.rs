@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use domain::model::webhook::{
+    is_private_address, validate_url, WebhookError, WebhookRequest, WebhookResponse,
+};
+use domain::repository::webhook::WebhookSender;
+
+#[derive(Debug)]
+struct PublicAddressResolver;
+
+impl reqwest::dns::Resolve for PublicAddressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        Box::pin(async move {
+            let addresses: Vec<std::net::SocketAddr> =
+                tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+
+            if addresses
+                .iter()
+                .any(|address| is_private_address(&address.ip()))
+            {
+                return Err(WebhookError::PrivateUrl.into());
+            }
+
+            let addresses: reqwest::dns::Addrs = Box::new(addresses.into_iter());
+            Ok(addresses)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookSenderImpl {
+    http: reqwest::Client,
+    allow_private_hosts: bool,
+}
+
+impl WebhookSenderImpl {
+    pub fn new(timeout: std::time::Duration, allow_private_hosts: bool) -> anyhow::Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(concat!("hcms-webhook/", env!("CARGO_PKG_VERSION")));
+        if !allow_private_hosts {
+            builder = builder.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let http = builder.build()?;
+
+        Ok(Self {
+            http,
+            allow_private_hosts,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for WebhookSenderImpl {
+    #[tracing::instrument(skip(self, request), fields(url = %request.url))]
+    async fn send(&self, request: WebhookRequest) -> anyhow::Result<WebhookResponse> {
+        let WebhookRequest { url, headers, body } = request;
+
+        if !self.allow_private_hosts {
+            validate_url(&url)?;
+        }
+
+        let mut builder = self
+            .http
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        for (name, value) in headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.body(body).send().await?;
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+
+        tracing::info!("{}", status);
+
+        Ok(WebhookResponse::new(status, body))
+    }
+}
@@ -0,0 +1,82 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+    response::{IntoResponse, Json, Response},
+};
+
+use application::usecase::auth::AuthUsecase;
+use domain::model::user::User;
+use domain::model::version::{etag, parse_if_match, PreconditionError};
+use registry::AppRegistry;
+
+use crate::handler::error::AppError;
+
+#[derive(Debug, Clone)]
+pub struct CurrentUser {
+    pub session_id: uuid::Uuid,
+    pub user: User,
+}
+
+impl CurrentUser {
+    pub fn id(&self) -> String {
+        self.user.id.clone()
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppRegistry> for CurrentUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        registry: &AppRegistry,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or(AppError::Unauthorized)?;
+
+        let usecase = AuthUsecase::new(registry.auth_repository(), registry.mailer());
+        let session = usecase
+            .authenticate(token.into())
+            .await
+            .map_err(|_| AppError::Unauthorized)?;
+
+        Ok(Self {
+            session_id: session.id,
+            user: session.user,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IfMatch(pub Option<i32>);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let value = parts
+            .headers
+            .get(header::IF_MATCH)
+            .ok_or(PreconditionError::Missing)?
+            .to_str()
+            .map_err(|_| PreconditionError::Invalid)?;
+
+        Ok(Self(parse_if_match(value)?))
+    }
+}
+
+pub struct Versioned<T>(pub i32, pub T);
+
+impl<T: serde::Serialize> IntoResponse for Versioned<T> {
+    fn into_response(self) -> Response {
+        let Self(version, body) = self;
+
+        ([(header::ETAG, etag(version))], Json(body)).into_response()
+    }
+}
@@ -0,0 +1,668 @@
+use std::collections::{HashMap, HashSet};
+
+use graphql_parser::query::{
+    Definition, Directive, Field, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    Type, TypeCondition, Value as AstValue, VariableDefinition,
+};
+use serde_json::{Map, Value};
+
+use domain::model::relation::{Expand, MAX_EXPAND_DEPTH};
+
+use crate::graphql::resolver::{resolve_root, Context};
+use crate::graphql::schema::{Resolver, Schema, TypeRef, MUTATION, QUERY};
+
+#[derive(Debug, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLRequest {
+    #[schema(example = "{ contents(limit: 10) { items { id title } nextCursor } }")]
+    pub query: String,
+    #[schema(value_type = Option<Object>)]
+    pub variables: Option<Map<String, Value>>,
+    pub operation_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, utoipa::ToSchema)]
+pub struct GraphQLError {
+    pub message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<Object>)]
+    pub path: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub extensions: Option<Value>,
+}
+
+impl GraphQLError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            path: vec![],
+            extensions: None,
+        }
+    }
+
+    pub fn with_extensions(mut self, extensions: Value) -> Self {
+        self.extensions = Some(extensions);
+        self
+    }
+
+    fn at(mut self, path: &[Value]) -> Self {
+        self.path = path.to_vec();
+        self
+    }
+}
+
+#[derive(Debug, serde::Serialize, utoipa::ToSchema)]
+pub struct GraphQLResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub data: Option<Output>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GraphQLError>,
+}
+
+impl GraphQLResponse {
+    fn failure(errors: Vec<GraphQLError>) -> Self {
+        Self { data: None, errors }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    Value(Value),
+    List(Vec<Output>),
+    Object(Vec<(String, Output)>),
+}
+
+impl Output {
+    fn null() -> Self {
+        Self::Value(Value::Null)
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, Self::Value(Value::Null))
+    }
+}
+
+impl From<&str> for Output {
+    fn from(value: &str) -> Self {
+        Self::Value(Value::String(value.into()))
+    }
+}
+
+impl serde::Serialize for Output {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Value(value) => value.serialize(serializer),
+            Self::List(items) => serializer.collect_seq(items),
+            Self::Object(fields) => {
+                serializer.collect_map(fields.iter().map(|(key, value)| (key, value)))
+            }
+        }
+    }
+}
+
+type Document = graphql_parser::query::Document<'static, String>;
+type CollectedFields<'a> = Vec<(String, Vec<&'a Field<'static, String>>)>;
+
+struct Propagate;
+
+struct Executor<'a> {
+    schema: &'a Schema,
+    fragments: HashMap<&'a str, &'a FragmentDefinition<'static, String>>,
+    variables: Map<String, Value>,
+    errors: Vec<GraphQLError>,
+}
+
+pub async fn execute(
+    schema: &Schema,
+    context: &Context,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let document: Document = match graphql_parser::parse_query::<String>(&request.query) {
+        Ok(document) => document.into_static(),
+        Err(error) => return GraphQLResponse::failure(vec![GraphQLError::new(error.to_string())]),
+    };
+
+    let mut fragments = HashMap::new();
+    let mut operations = vec![];
+    for definition in &document.definitions {
+        match definition {
+            Definition::Fragment(fragment) => {
+                fragments.insert(fragment.name.as_str(), fragment);
+            }
+            Definition::Operation(operation) => operations.push(operation),
+        }
+    }
+
+    let operation = match select_operation(operations, request.operation_name.as_deref()) {
+        Ok(operation) => operation,
+        Err(error) => return GraphQLResponse::failure(vec![error]),
+    };
+
+    let (root_type, variable_definitions, selection_set) = match operation {
+        OperationDefinition::SelectionSet(set) => (QUERY, &[][..], set),
+        OperationDefinition::Query(query) => {
+            (QUERY, &query.variable_definitions[..], &query.selection_set)
+        }
+        OperationDefinition::Mutation(mutation) => (
+            MUTATION,
+            &mutation.variable_definitions[..],
+            &mutation.selection_set,
+        ),
+        OperationDefinition::Subscription(_) => {
+            return GraphQLResponse::failure(vec![GraphQLError::new(
+                "Subscriptions are not supported",
+            )])
+        }
+    };
+
+    let mut executor = Executor {
+        schema,
+        fragments,
+        variables: Map::new(),
+        errors: vec![],
+    };
+
+    executor.coerce_variables(variable_definitions, request.variables.unwrap_or_default());
+    executor.validate(root_type, selection_set, &mut vec![]);
+
+    if !executor.errors.is_empty() {
+        return GraphQLResponse::failure(executor.errors);
+    }
+
+    let data = executor
+        .execute_root(context, root_type, selection_set)
+        .await;
+
+    GraphQLResponse {
+        data: Some(data),
+        errors: executor.errors,
+    }
+}
+
+fn operation_name<'a>(operation: &'a OperationDefinition<'static, String>) -> Option<&'a str> {
+    match operation {
+        OperationDefinition::SelectionSet(_) => None,
+        OperationDefinition::Query(query) => query.name.as_deref(),
+        OperationDefinition::Mutation(mutation) => mutation.name.as_deref(),
+        OperationDefinition::Subscription(subscription) => subscription.name.as_deref(),
+    }
+}
+
+fn select_operation<'a>(
+    operations: Vec<&'a OperationDefinition<'static, String>>,
+    name: Option<&str>,
+) -> Result<&'a OperationDefinition<'static, String>, GraphQLError> {
+    match name {
+        Some(name) => operations
+            .into_iter()
+            .find(|operation| operation_name(operation) == Some(name))
+            .ok_or_else(|| GraphQLError::new(format!("Operation {} is not found", name))),
+        None => match operations.as_slice() {
+            [operation] => Ok(operation),
+            [] => Err(GraphQLError::new("Document has no operation")),
+            _ => Err(GraphQLError::new(
+                "Operation name is required when the document has several operations",
+            )),
+        },
+    }
+}
+
+fn merge_expand(target: &mut Expand, source: Expand) {
+    for (key, child) in source.fields {
+        merge_expand(target.fields.entry(key).or_default(), child);
+    }
+}
+
+impl<'a> Executor<'a> {
+    fn coerce_variables(
+        &mut self,
+        definitions: &[VariableDefinition<'static, String>],
+        mut provided: Map<String, Value>,
+    ) {
+        for definition in definitions {
+            let value = match provided.remove(&definition.name) {
+                Some(value) => value,
+                None => definition
+                    .default_value
+                    .as_ref()
+                    .map(|value| self.value(value))
+                    .unwrap_or(Value::Null),
+            };
+
+            if matches!(definition.var_type, Type::NonNullType(_)) && value.is_null() {
+                self.errors.push(GraphQLError::new(format!(
+                    "Variable ${} is required",
+                    definition.name
+                )));
+            }
+
+            self.variables.insert(definition.name.clone(), value);
+        }
+    }
+
+    fn value(&self, value: &AstValue<'static, String>) -> Value {
+        match value {
+            AstValue::Variable(name) => self.variables.get(name).cloned().unwrap_or(Value::Null),
+            AstValue::Int(number) => number.as_i64().map(Value::from).unwrap_or(Value::Null),
+            AstValue::Float(number) => Value::from(*number),
+            AstValue::String(value) => Value::String(value.clone()),
+            AstValue::Boolean(value) => Value::Bool(*value),
+            AstValue::Null => Value::Null,
+            AstValue::Enum(value) => Value::String(value.clone()),
+            AstValue::List(items) => Value::Array(items.iter().map(|x| self.value(x)).collect()),
+            AstValue::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), self.value(value)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn included(&self, directives: &[Directive<'static, String>]) -> bool {
+        directives.iter().all(|directive| {
+            let condition = directive
+                .arguments
+                .iter()
+                .find(|(name, _)| name == "if")
+                .map(|(_, value)| self.value(value) == Value::Bool(true));
+
+            match directive.name.as_str() {
+                "skip" => condition != Some(true),
+                "include" => condition != Some(false),
+                _ => true,
+            }
+        })
+    }
+
+    fn collect_fields(
+        &self,
+        type_name: &str,
+        set: &'a SelectionSet<'static, String>,
+        fields: &mut CollectedFields<'a>,
+        visited: &mut HashSet<&'a str>,
+    ) {
+        for selection in &set.items {
+            match selection {
+                Selection::Field(field) => {
+                    if !self.included(&field.directives) {
+                        continue;
+                    }
+
+                    let key = field.alias.as_ref().unwrap_or(&field.name);
+                    match fields.iter_mut().find(|(x, _)| x == key) {
+                        Some((_, nodes)) => nodes.push(field),
+                        None => fields.push((key.clone(), vec![field])),
+                    }
+                }
+                Selection::FragmentSpread(spread) => {
+                    if !self.included(&spread.directives)
+                        || !visited.insert(spread.fragment_name.as_str())
+                    {
+                        continue;
+                    }
+
+                    if let Some(fragment) = self.fragments.get(spread.fragment_name.as_str()) {
+                        let TypeCondition::On(on) = &fragment.type_condition;
+                        if on == type_name {
+                            self.collect_fields(
+                                type_name,
+                                &fragment.selection_set,
+                                fields,
+                                visited,
+                            );
+                        }
+                    }
+                }
+                Selection::InlineFragment(inline) => {
+                    let applies = match &inline.type_condition {
+                        Some(TypeCondition::On(on)) => on == type_name,
+                        None => true,
+                    };
+
+                    if applies && self.included(&inline.directives) {
+                        self.collect_fields(type_name, &inline.selection_set, fields, visited);
+                    }
+                }
+            }
+        }
+    }
+
+    fn collect_subfields(
+        &self,
+        type_name: &str,
+        nodes: &[&'a Field<'static, String>],
+    ) -> CollectedFields<'a> {
+        let mut fields = vec![];
+        let mut visited = HashSet::new();
+
+        for node in nodes {
+            self.collect_fields(type_name, &node.selection_set, &mut fields, &mut visited);
+        }
+
+        fields
+    }
+
+    fn validate(
+        &mut self,
+        type_name: &str,
+        set: &'a SelectionSet<'static, String>,
+        visiting: &mut Vec<&'a str>,
+    ) {
+        for selection in &set.items {
+            match selection {
+                Selection::Field(field) => self.validate_field(type_name, field, visiting),
+                Selection::FragmentSpread(spread) => {
+                    let name = spread.fragment_name.as_str();
+                    let Some(fragment) = self.fragments.get(name).copied() else {
+                        self.errors
+                            .push(GraphQLError::new(format!("Fragment {} is not found", name)));
+                        continue;
+                    };
+
+                    if visiting.contains(&name) {
+                        self.errors.push(GraphQLError::new(format!(
+                            "Fragment {} spreads itself",
+                            name
+                        )));
+                        continue;
+                    }
+
+                    let TypeCondition::On(on) = &fragment.type_condition;
+                    visiting.push(name);
+                    self.validate_condition(on, &fragment.selection_set, visiting);
+                    visiting.pop();
+                }
+                Selection::InlineFragment(inline) => match &inline.type_condition {
+                    Some(TypeCondition::On(on)) => {
+                        self.validate_condition(on, &inline.selection_set, visiting)
+                    }
+                    None => self.validate(type_name, &inline.selection_set, visiting),
+                },
+            }
+        }
+    }
+
+    fn validate_condition(
+        &mut self,
+        on: &str,
+        set: &'a SelectionSet<'static, String>,
+        visiting: &mut Vec<&'a str>,
+    ) {
+        match self.schema.object(on) {
+            Some(_) => self.validate(on, set, visiting),
+            None => self
+                .errors
+                .push(GraphQLError::new(format!("Type {} is not an object", on))),
+        }
+    }
+
+    fn validate_field(
+        &mut self,
+        type_name: &str,
+        field: &'a Field<'static, String>,
+        visiting: &mut Vec<&'a str>,
+    ) {
+        if field.name == "__typename" {
+            return;
+        }
+
+        let Some(definition) = self.schema.field(type_name, &field.name) else {
+            self.errors.push(GraphQLError::new(format!(
+                "Cannot query field {} on type {}",
+                field.name, type_name
+            )));
+            return;
+        };
+
+        for (name, _) in &field.arguments {
+            if !definition.args.iter().any(|arg| &arg.name == name) {
+                self.errors.push(GraphQLError::new(format!(
+                    "Unknown argument {} on field {}.{}",
+                    name, type_name, field.name
+                )));
+            }
+        }
+
+        let field_type = definition.ty.name();
+        match (
+            self.schema.object(field_type),
+            field.selection_set.items.is_empty(),
+        ) {
+            (Some(_), true) => self.errors.push(GraphQLError::new(format!(
+                "Field {} of type {} must have a selection of subfields",
+                field.name, field_type
+            ))),
+            (Some(_), false) => self.validate(field_type, &field.selection_set, visiting),
+            (None, false) => self.errors.push(GraphQLError::new(format!(
+                "Field {} of type {} has no subfields",
+                field.name, field_type
+            ))),
+            (None, true) => {}
+        }
+    }
+
+    fn arguments(
+        &self,
+        type_name: &str,
+        field: &Field<'static, String>,
+    ) -> Result<Map<String, Value>, GraphQLError> {
+        let definition = self.schema.field(type_name, &field.name);
+        let args: Map<String, Value> = field
+            .arguments
+            .iter()
+            .map(|(name, value)| (name.clone(), self.value(value)))
+            .collect();
+
+        for input in definition.map(|x| x.args.as_slice()).unwrap_or_default() {
+            let missing = args.get(&input.name).map_or(true, Value::is_null);
+
+            if matches!(input.ty, TypeRef::NonNull(_)) && missing {
+                return Err(GraphQLError::new(format!(
+                    "Argument {} of field {} is required",
+                    input.name, field.name
+                )));
+            }
+        }
+
+        Ok(args)
+    }
+
+    fn references(
+        &self,
+        type_name: &str,
+        nodes: &[&'a Field<'static, String>],
+        depth: usize,
+    ) -> Result<Expand, GraphQLError> {
+        let mut expand = Expand::default();
+
+        if self.schema.object(type_name).is_none() {
+            return Ok(expand);
+        }
+
+        for (_, fields) in self.collect_subfields(type_name, nodes) {
+            let Some(definition) = self.schema.field(type_name, &fields[0].name) else {
+                continue;
+            };
+
+            match &definition.resolver {
+                Resolver::Reference(key) => {
+                    if depth >= MAX_EXPAND_DEPTH {
+                        return Err(GraphQLError::new(format!(
+                            "References exceed maximum depth({})",
+                            MAX_EXPAND_DEPTH
+                        )));
+                    }
+
+                    let child = self.references(definition.ty.name(), &fields, depth + 1)?;
+                    merge_expand(expand.fields.entry(key.clone()).or_default(), child);
+                }
+                _ => {
+                    let child = self.references(definition.ty.name(), &fields, depth)?;
+                    merge_expand(&mut expand, child);
+                }
+            }
+        }
+
+        Ok(expand)
+    }
+
+    async fn execute_root(
+        &mut self,
+        context: &Context,
+        root_type: &str,
+        set: &'a SelectionSet<'static, String>,
+    ) -> Output {
+        let schema = self.schema;
+        let mut fields = vec![];
+        self.collect_fields(root_type, set, &mut fields, &mut HashSet::new());
+
+        let mut data = vec![];
+        for (key, nodes) in fields {
+            let mut path = vec![Value::String(key.clone())];
+
+            if nodes[0].name == "__typename" {
+                data.push((key, Output::from(root_type)));
+                continue;
+            }
+
+            let Some(definition) = schema.field(root_type, &nodes[0].name) else {
+                continue;
+            };
+            let Resolver::Root(root) = &definition.resolver else {
+                continue;
+            };
+
+            let result = match (
+                self.arguments(root_type, nodes[0]),
+                self.references(definition.ty.name(), &nodes, 0),
+            ) {
+                (Ok(args), Ok(expand)) => resolve_root(context, root, args, expand).await,
+                (Err(error), _) | (_, Err(error)) => Err(error),
+            };
+
+            let value = match result {
+                Ok(value) => value,
+                Err(error) => {
+                    self.errors.push(error.at(&path));
+
+                    if matches!(definition.ty, TypeRef::NonNull(_)) {
+                        return Output::null();
+                    }
+
+                    data.push((key, Output::null()));
+                    continue;
+                }
+            };
+
+            match self.complete(&definition.ty, value, &nodes, &mut path) {
+                Ok(value) => data.push((key, value)),
+                Err(Propagate) => return Output::null(),
+            }
+        }
+
+        Output::Object(data)
+    }
+
+    fn complete(
+        &mut self,
+        ty: &TypeRef,
+        value: Value,
+        nodes: &[&'a Field<'static, String>],
+        path: &mut Vec<Value>,
+    ) -> Result<Output, Propagate> {
+        match ty {
+            TypeRef::NonNull(inner) => {
+                let value = self.complete_value(inner, value, nodes, path)?;
+
+                if value.is_null() {
+                    self.errors.push(
+                        GraphQLError::new("Cannot return null for non-nullable field").at(path),
+                    );
+                    return Err(Propagate);
+                }
+
+                Ok(value)
+            }
+            ty => Ok(self
+                .complete_value(ty, value, nodes, path)
+                .unwrap_or_else(|_| Output::null())),
+        }
+    }
+
+    fn complete_value(
+        &mut self,
+        ty: &TypeRef,
+        value: Value,
+        nodes: &[&'a Field<'static, String>],
+        path: &mut Vec<Value>,
+    ) -> Result<Output, Propagate> {
+        if value.is_null() {
+            return Ok(Output::null());
+        }
+
+        match ty {
+            TypeRef::NonNull(_) => self.complete(ty, value, nodes, path),
+            TypeRef::List(item) => {
+                let items = match value {
+                    Value::Array(items) => items,
+                    value => vec![value],
+                };
+
+                let mut completed = Vec::with_capacity(items.len());
+                for (index, value) in items.into_iter().enumerate() {
+                    path.push(Value::from(index));
+                    let result = self.complete(item, value, nodes, path);
+                    path.pop();
+                    completed.push(result?);
+                }
+
+                Ok(Output::List(completed))
+            }
+            TypeRef::Named(name) => match (self.schema.object(name), value) {
+                (Some(_), Value::Object(object)) => self.complete_object(name, object, nodes, path),
+                // A reference left as an id was not readable or not expanded.
+                (Some(_), _) => Ok(Output::null()),
+                (None, value) => Ok(Output::Value(value)),
+            },
+        }
+    }
+
+    fn complete_object(
+        &mut self,
+        type_name: &str,
+        object: Map<String, Value>,
+        nodes: &[&'a Field<'static, String>],
+        path: &mut Vec<Value>,
+    ) -> Result<Output, Propagate> {
+        let schema = self.schema;
+        let mut result = vec![];
+
+        for (key, fields) in self.collect_subfields(type_name, nodes) {
+            if fields[0].name == "__typename" {
+                result.push((key, Output::from(type_name)));
+                continue;
+            }
+
+            let Some(definition) = schema.field(type_name, &fields[0].name) else {
+                continue;
+            };
+            let value = match &definition.resolver {
+                Resolver::Key(name) | Resolver::Reference(name) => {
+                    object.get(name).cloned().unwrap_or(Value::Null)
+                }
+                Resolver::Root(_) => Value::Null,
+            };
+
+            path.push(Value::String(key.clone()));
+            let completed = self.complete(&definition.ty, value, &fields, path);
+            path.pop();
+
+            result.push((key, completed?));
+        }
+
+        Ok(Output::Object(result))
+    }
+}
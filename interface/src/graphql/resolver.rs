@@ -0,0 +1,413 @@
+use std::str::FromStr;
+
+use axum::response::IntoResponse;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use application::usecase::category::{
+    CategoryUsecase, CreateCategoryInput, GetCategoryInput, UpdateCategoryInput,
+};
+use application::usecase::content::{
+    ContentUsecase, CreateContentInput, GetContentInput, UpdateContentInput,
+};
+use application::usecase::tag::{GetTagInput, TagUsecase};
+use application::usecase::user::{GetUserInput, UserUsecase};
+use domain::model::content::ContentStatus;
+use domain::model::field::Field;
+use domain::model::locale::LocaleChain;
+use domain::model::page::PageQuery;
+use domain::model::query::{ContentFilter, SortOrder, TagMatch};
+use domain::model::relation::Expand;
+use domain::model::user::User;
+use registry::AppRegistry;
+
+use crate::graphql::executor::GraphQLError;
+use crate::graphql::schema::RootField;
+use crate::handler::error::AppError;
+
+#[derive(derive_new::new)]
+pub struct Context {
+    registry: AppRegistry,
+    actor: User,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListArgs {
+    limit: Option<i32>,
+    cursor: Option<String>,
+    total: Option<bool>,
+    keyword: Option<String>,
+    category: Option<String>,
+    tags: Option<Vec<String>>,
+    tags_match: Option<String>,
+    status: Option<Vec<ContentStatus>>,
+    created_by: Option<Vec<String>>,
+    filter: Option<Vec<FilterArg>>,
+    sort: Option<String>,
+    expand: Option<String>,
+    locale: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct FilterArg {
+    key: String,
+    value: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FindArgs {
+    id: String,
+    expand: Option<String>,
+    locale: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateContentArgs {
+    title: String,
+    category_id: String,
+    fields: Value,
+    #[serde(default)]
+    tag_ids: Vec<String>,
+    status: Option<ContentStatus>,
+    published_at: Option<chrono::DateTime<chrono::Utc>>,
+    unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateContentArgs {
+    title: Option<String>,
+    category_id: Option<String>,
+    fields: Option<Value>,
+    tag_ids: Option<Vec<String>>,
+    unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    clear_unpublish_at: bool,
+    version: Option<i32>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateCategoryArgs {
+    name: String,
+    api_identifier: String,
+    description: Option<String>,
+    #[serde(default)]
+    fields: Vec<Field>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateCategoryArgs {
+    name: Option<String>,
+    api_identifier: Option<String>,
+    description: Option<String>,
+    fields: Option<Vec<Field>>,
+    version: Option<i32>,
+}
+
+impl ListArgs {
+    fn page(&self) -> Result<PageQuery, GraphQLError> {
+        PageQuery::try_new(
+            self.limit,
+            self.cursor.as_deref(),
+            self.total.unwrap_or_default(),
+        )
+        .map_err(|error| AppError::from(error).into())
+    }
+
+    fn into_content_input(self, category_id: Option<String>) -> anyhow::Result<GetContentInput> {
+        let pairs: Vec<(String, String)> = self
+            .filter
+            .unwrap_or_default()
+            .into_iter()
+            .map(|filter| (filter.key, filter.value))
+            .collect();
+
+        Ok(GetContentInput {
+            page: PageQuery::try_new(
+                self.limit,
+                self.cursor.as_deref(),
+                self.total.unwrap_or_default(),
+            )?,
+            keyword: self.keyword,
+            category_id,
+            tag_ids: self.tags,
+            tag_match: self
+                .tags_match
+                .as_deref()
+                .map(TagMatch::from_str)
+                .transpose()?
+                .unwrap_or_default(),
+            statuses: self.status,
+            created_by_ids: self.created_by,
+            filter: ContentFilter::parse(&pairs)?,
+            sort: SortOrder::parse_list(self.sort.as_deref().unwrap_or_default())?,
+            category_ids: None,
+        })
+    }
+}
+
+impl From<AppError> for GraphQLError {
+    fn from(error: AppError) -> Self {
+        let message = error.to_string();
+        let details = match &error {
+            AppError::FieldValidationError(error) => serde_json::to_value(error).ok(),
+            AppError::ContentStatusError(error) => serde_json::to_value(error).ok(),
+            AppError::MediaInUseError(error) => serde_json::to_value(error).ok(),
+            AppError::VersionConflict(error) => serde_json::to_value(error).ok(),
+            AppError::TrashError(error) => serde_json::to_value(error).ok(),
+            _ => None,
+        };
+        let status = error.into_response().status();
+        let code = status
+            .canonical_reason()
+            .unwrap_or_default()
+            .to_uppercase()
+            .replace(' ', "_");
+
+        let mut extensions = Map::new();
+        extensions.insert("code".into(), Value::String(code));
+        if let Some(details) = details {
+            extensions.insert("details".into(), details);
+        }
+
+        GraphQLError::new(message).with_extensions(Value::Object(extensions))
+    }
+}
+
+fn bad_request(error: impl ToString) -> GraphQLError {
+    AppError::BadRequest(error.to_string()).into()
+}
+
+fn parse<T: DeserializeOwned>(value: Value) -> Result<T, GraphQLError> {
+    serde_json::from_value(value).map_err(bad_request)
+}
+
+fn id(args: &Map<String, Value>) -> String {
+    args.get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn input<T: DeserializeOwned>(args: &mut Map<String, Value>) -> Result<T, GraphQLError> {
+    parse(args.remove("input").unwrap_or_default())
+}
+
+fn parse_expand(value: Option<&str>, selected: Expand) -> Result<Expand, GraphQLError> {
+    match value {
+        Some(value) => Expand::parse(value).map_err(bad_request),
+        None => Ok(selected),
+    }
+}
+
+fn parse_locale(
+    registry: &AppRegistry,
+    locale: Option<&str>,
+) -> Result<Option<LocaleChain>, GraphQLError> {
+    locale
+        .map(|locale| registry.locales().chain(locale))
+        .transpose()
+        .map_err(|error| AppError::from(error).into())
+}
+
+fn respond<T: serde::Serialize>(
+    result: anyhow::Result<T>,
+    fallback: impl FnOnce(String) -> AppError,
+) -> Result<Value, GraphQLError> {
+    match result {
+        Ok(value) => serde_json::to_value(value).map_err(bad_request),
+        Err(error) => {
+            let fallback = fallback(error.to_string());
+            Err(AppError::domain_or(error, fallback).into())
+        }
+    }
+}
+
+fn read<T: serde::Serialize>(result: anyhow::Result<T>) -> Result<Value, GraphQLError> {
+    respond(result, AppError::EntityNotFound)
+}
+
+pub async fn resolve_root(
+    context: &Context,
+    field: &RootField,
+    mut args: Map<String, Value>,
+    expand: Expand,
+) -> Result<Value, GraphQLError> {
+    let Context { registry, actor } = context;
+    let content_usecase =
+        || ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let category_usecase =
+        || CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+
+    match field {
+        RootField::Categories => {
+            let args: ListArgs = parse(Value::Object(args))?;
+            let input = GetCategoryInput::new(args.page()?);
+
+            read(category_usecase().get(actor, input).await)
+        }
+        RootField::Tags => {
+            let args: ListArgs = parse(Value::Object(args))?;
+            let usecase = TagUsecase::new(registry.tag_repository(), registry.unit_of_work());
+
+            read(usecase.get(actor, GetTagInput::new(args.page()?)).await)
+        }
+        RootField::Users => {
+            let args: ListArgs = parse(Value::Object(args))?;
+            let usecase = UserUsecase::new(registry.user_repository(), registry.role_repository());
+
+            read(usecase.get(actor, GetUserInput::new(args.page()?)).await)
+        }
+        RootField::Contents | RootField::CategoryContents(_) => {
+            let mut args: ListArgs = parse(Value::Object(args))?;
+            let expand = parse_expand(args.expand.take().as_deref(), expand)?;
+            let locale = parse_locale(registry, args.locale.take().as_deref())?;
+            let category_id = match field {
+                RootField::CategoryContents(category_id) => Some(category_id.clone()),
+                _ => args.category.take(),
+            };
+            let input = args.into_content_input(category_id).map_err(bad_request)?;
+
+            read(
+                content_usecase()
+                    .get(actor, input, &expand, locale.as_ref())
+                    .await,
+            )
+        }
+        RootField::Content | RootField::CategoryContent(_) => {
+            let args: FindArgs = parse(Value::Object(args))?;
+            let expand = parse_expand(args.expand.as_deref(), expand)?;
+            let locale = parse_locale(registry, args.locale.as_deref())?;
+            let content = content_usecase()
+                .find(actor, args.id, &expand, locale.as_ref())
+                .await;
+
+            match (field, content) {
+                (RootField::CategoryContent(category_id), Ok(content))
+                    if &content.category.id != category_id =>
+                {
+                    Ok(Value::Null)
+                }
+                (_, content) => read(content),
+            }
+        }
+        RootField::CreateContent => {
+            let CreateContentArgs {
+                title,
+                category_id,
+                fields,
+                tag_ids,
+                status,
+                published_at,
+                unpublish_at,
+            } = input(&mut args)?;
+            let data = CreateContentInput {
+                title,
+                fields,
+                tag_ids,
+                status: status.unwrap_or(ContentStatus::Draft),
+                category_id,
+                published_at,
+                unpublish_at,
+                created_by_id: actor.id.clone(),
+                updated_by_id: actor.id.clone(),
+            };
+
+            respond(content_usecase().create(actor, data).await, |_| {
+                AppError::CreateRecordError
+            })
+        }
+        RootField::UpdateContent => {
+            let UpdateContentArgs {
+                title,
+                category_id,
+                fields,
+                tag_ids,
+                unpublish_at,
+                clear_unpublish_at,
+                version,
+            } = input(&mut args)?;
+            if clear_unpublish_at && unpublish_at.is_some() {
+                return Err(bad_request("unpublishAt cannot be set and cleared at once"));
+            }
+            let mut data = UpdateContentInput::new(
+                id(&args),
+                title,
+                category_id,
+                fields,
+                tag_ids,
+                unpublish_at,
+                actor.id.clone(),
+                version,
+            );
+            data.clear_unpublish_at = clear_unpublish_at;
+
+            respond(content_usecase().update(actor, data).await, |_| {
+                AppError::UpdateRecordError
+            })
+        }
+        RootField::DeleteContent => respond(
+            content_usecase()
+                .delete(actor, id(&args))
+                .await
+                .map(|_| true),
+            |_| AppError::DeleteRecordError,
+        ),
+        RootField::PublishContent => {
+            respond(content_usecase().publish(actor, id(&args)).await, |_| {
+                AppError::UpdateRecordError
+            })
+        }
+        RootField::UnpublishContent => {
+            respond(content_usecase().unpublish(actor, id(&args)).await, |_| {
+                AppError::UpdateRecordError
+            })
+        }
+        RootField::CreateCategory => {
+            let CreateCategoryArgs {
+                name,
+                api_identifier,
+                description,
+                fields,
+            } = input(&mut args)?;
+            let data = CreateCategoryInput::new(name, api_identifier, description, fields);
+
+            respond(category_usecase().create(actor, data).await, |_| {
+                AppError::CreateRecordError
+            })
+        }
+        RootField::UpdateCategory => {
+            let UpdateCategoryArgs {
+                name,
+                api_identifier,
+                description,
+                fields,
+                version,
+            } = input(&mut args)?;
+            let data = UpdateCategoryInput::new(
+                id(&args),
+                name,
+                api_identifier,
+                description,
+                fields,
+                version,
+            );
+
+            respond(category_usecase().update(actor, data).await, |_| {
+                AppError::UpdateRecordError
+            })
+        }
+        RootField::DeleteCategory => respond(
+            category_usecase()
+                .delete(actor, id(&args))
+                .await
+                .map(|_| true),
+            |_| AppError::DeleteRecordError,
+        ),
+    }
+}
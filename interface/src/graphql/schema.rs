@@ -0,0 +1,679 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use domain::model::category::Category;
+use domain::model::field::{Field, FieldType};
+
+pub const QUERY: &str = "Query";
+pub const MUTATION: &str = "Mutation";
+pub const CONTENT: &str = "Content";
+
+const BUILTIN_SCALARS: [&str; 5] = ["ID", "String", "Int", "Float", "Boolean"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    pub fn named(name: &str) -> Self {
+        Self::Named(name.into())
+    }
+
+    pub fn required(name: &str) -> Self {
+        Self::NonNull(Box::new(Self::named(name)))
+    }
+
+    pub fn list(item: TypeRef) -> Self {
+        Self::List(Box::new(item))
+    }
+
+    pub fn non_null(self) -> Self {
+        Self::NonNull(Box::new(self))
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(item) | Self::NonNull(item) => item.name(),
+        }
+    }
+}
+
+impl fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(name) => write!(f, "{}", name),
+            Self::List(item) => write!(f, "[{}]", item),
+            Self::NonNull(item) => write!(f, "{}!", item),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RootField {
+    Categories,
+    Tags,
+    Users,
+    Contents,
+    Content,
+    CategoryContents(String),
+    CategoryContent(String),
+    CreateContent,
+    UpdateContent,
+    DeleteContent,
+    PublishContent,
+    UnpublishContent,
+    CreateCategory,
+    UpdateCategory,
+    DeleteCategory,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resolver {
+    Key(String),
+    Reference(String),
+    Root(RootField),
+}
+
+#[derive(Debug, Clone)]
+pub struct InputValue {
+    pub name: String,
+    pub ty: TypeRef,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldDef {
+    pub name: String,
+    pub ty: TypeRef,
+    pub args: Vec<InputValue>,
+    pub resolver: Resolver,
+}
+
+#[derive(Debug, Clone)]
+pub enum TypeDef {
+    Scalar,
+    Enum(Vec<String>),
+    Object(Vec<FieldDef>),
+    Input(Vec<InputValue>),
+}
+
+#[derive(Debug, Default)]
+pub struct Schema {
+    pub types: BTreeMap<String, TypeDef>,
+}
+
+fn arg(name: &str, ty: TypeRef) -> InputValue {
+    InputValue {
+        name: name.into(),
+        ty,
+    }
+}
+
+fn key(name: &str, ty: TypeRef, key: &str) -> FieldDef {
+    FieldDef {
+        name: name.into(),
+        ty,
+        args: vec![],
+        resolver: Resolver::Key(key.into()),
+    }
+}
+
+fn root(name: &str, ty: TypeRef, args: Vec<InputValue>, field: RootField) -> FieldDef {
+    FieldDef {
+        name: name.into(),
+        ty,
+        args,
+        resolver: Resolver::Root(field),
+    }
+}
+
+fn page_args() -> Vec<InputValue> {
+    vec![
+        arg("limit", TypeRef::named("Int")),
+        arg("cursor", TypeRef::named("String")),
+        arg("total", TypeRef::named("Boolean")),
+    ]
+}
+
+fn content_list_args() -> Vec<InputValue> {
+    let mut args = page_args();
+    args.extend([
+        arg("keyword", TypeRef::named("String")),
+        arg("tags", TypeRef::list(TypeRef::required("ID"))),
+        arg("tagsMatch", TypeRef::named("String")),
+        arg("status", TypeRef::list(TypeRef::required("ContentStatus"))),
+        arg("createdBy", TypeRef::list(TypeRef::required("ID"))),
+        arg("filter", TypeRef::list(TypeRef::required("FilterInput"))),
+        arg("sort", TypeRef::named("String")),
+        arg("locale", TypeRef::named("String")),
+    ]);
+    args
+}
+
+fn content_fields(fields: TypeRef) -> Vec<FieldDef> {
+    vec![
+        key("id", TypeRef::required("ID"), "id"),
+        key("title", TypeRef::required("String"), "title"),
+        key("category", TypeRef::required("ContentCategory"), "category"),
+        key("status", TypeRef::required("ContentStatus"), "status"),
+        key("fields", fields, "fields"),
+        key(
+            "tags",
+            TypeRef::list(TypeRef::required("ContentTag")).non_null(),
+            "tags",
+        ),
+        key("createdBy", TypeRef::required("ContentUser"), "created_by"),
+        key("updatedBy", TypeRef::required("ContentUser"), "updated_by"),
+        key("publishedAt", TypeRef::named("String"), "published_at"),
+        key("unpublishAt", TypeRef::named("String"), "unpublish_at"),
+        key("createdAt", TypeRef::required("String"), "created_at"),
+        key("updatedAt", TypeRef::required("String"), "updated_at"),
+        key("version", TypeRef::required("Int"), "version"),
+        key("locale", TypeRef::named("String"), "locale"),
+    ]
+}
+
+fn page_fields(item: &str) -> Vec<FieldDef> {
+    vec![
+        key(
+            "items",
+            TypeRef::list(TypeRef::required(item)).non_null(),
+            "items",
+        ),
+        key("nextCursor", TypeRef::named("String"), "next_cursor"),
+        key("prevCursor", TypeRef::named("String"), "prev_cursor"),
+        key("total", TypeRef::named("Int"), "total"),
+    ]
+}
+
+pub fn is_name(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    matches!(chars.next(), Some(x) if x == '_' || x.is_ascii_alphabetic())
+        && chars.all(|x| x == '_' || x.is_ascii_alphanumeric())
+        && !value.starts_with("__")
+}
+
+pub fn pascal_case(value: &str) -> String {
+    value
+        .split(|x: char| !x.is_ascii_alphanumeric())
+        .filter(|x| !x.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub fn camel_case(value: &str) -> String {
+    let pascal = pascal_case(value);
+    let mut chars = pascal.chars();
+
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => pascal,
+    }
+}
+
+pub fn field_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|x| match x.is_ascii_alphanumeric() {
+            true => x,
+            false => '_',
+        })
+        .collect();
+
+    match name.starts_with(|x: char| x.is_ascii_digit()) {
+        true => format!("_{}", name),
+        false => name,
+    }
+}
+
+struct ContentModel<'a> {
+    category: &'a Category,
+    name: String,
+}
+
+impl Schema {
+    pub fn build(categories: &[Category]) -> Self {
+        let mut schema = Self::default();
+
+        schema.add_static_types();
+
+        let mut query = vec![
+            root(
+                "categories",
+                TypeRef::required("CategoryPage"),
+                page_args(),
+                RootField::Categories,
+            ),
+            root(
+                "tags",
+                TypeRef::required("TagPage"),
+                page_args(),
+                RootField::Tags,
+            ),
+            root(
+                "users",
+                TypeRef::required("UserPage"),
+                page_args(),
+                RootField::Users,
+            ),
+            root(
+                "contents",
+                TypeRef::required("ContentPage"),
+                {
+                    let mut args = content_list_args();
+                    args.push(arg("category", TypeRef::named("ID")));
+                    args.push(arg("expand", TypeRef::named("String")));
+                    args
+                },
+                RootField::Contents,
+            ),
+            root(
+                "content",
+                TypeRef::named(CONTENT),
+                vec![
+                    arg("id", TypeRef::required("ID")),
+                    arg("expand", TypeRef::named("String")),
+                    arg("locale", TypeRef::named("String")),
+                ],
+                RootField::Content,
+            ),
+        ];
+
+        let models = schema.content_models(categories, &query);
+        let model_names: BTreeMap<&str, &str> = models
+            .iter()
+            .map(|model| (model.category.id.as_str(), model.name.as_str()))
+            .collect();
+
+        for model in &models {
+            schema.add_content_model(model, &model_names, &mut query);
+        }
+
+        schema.insert(QUERY, TypeDef::Object(query));
+        schema.insert(MUTATION, TypeDef::Object(mutation_fields()));
+
+        schema
+    }
+
+    pub fn object(&self, name: &str) -> Option<&[FieldDef]> {
+        match self.types.get(name) {
+            Some(TypeDef::Object(fields)) => Some(fields),
+            _ => None,
+        }
+    }
+
+    pub fn field(&self, type_name: &str, name: &str) -> Option<&FieldDef> {
+        self.object(type_name)?
+            .iter()
+            .find(|field| field.name == name)
+    }
+
+    fn insert(&mut self, name: &str, definition: TypeDef) {
+        self.types.insert(name.into(), definition);
+    }
+
+    fn add_static_types(&mut self) {
+        for name in BUILTIN_SCALARS.into_iter().chain(["JSON"]) {
+            self.insert(name, TypeDef::Scalar);
+        }
+
+        self.insert(
+            "ContentStatus",
+            TypeDef::Enum(
+                ["Draft", "Published", "Reserved", "Unpublished"]
+                    .map(String::from)
+                    .to_vec(),
+            ),
+        );
+
+        self.insert(
+            "Category",
+            TypeDef::Object(vec![
+                key("id", TypeRef::required("ID"), "id"),
+                key("name", TypeRef::required("String"), "name"),
+                key(
+                    "apiIdentifier",
+                    TypeRef::required("String"),
+                    "api_identifier",
+                ),
+                key("description", TypeRef::named("String"), "description"),
+                key("fields", TypeRef::required("JSON"), "fields"),
+                key("version", TypeRef::required("Int"), "version"),
+            ]),
+        );
+        self.insert(
+            "Tag",
+            TypeDef::Object(vec![
+                key("id", TypeRef::required("ID"), "id"),
+                key("name", TypeRef::required("String"), "name"),
+                key("description", TypeRef::required("String"), "description"),
+                key("version", TypeRef::required("Int"), "version"),
+            ]),
+        );
+        self.insert(
+            "User",
+            TypeDef::Object(vec![
+                key("id", TypeRef::required("ID"), "id"),
+                key("name", TypeRef::required("String"), "name"),
+                key("iconUrl", TypeRef::required("String"), "icon_url"),
+            ]),
+        );
+
+        for name in ["ContentCategory", "ContentTag", "ContentUser"] {
+            self.insert(
+                name,
+                TypeDef::Object(vec![
+                    key("id", TypeRef::required("ID"), "id"),
+                    key("name", TypeRef::required("String"), "name"),
+                ]),
+            );
+        }
+
+        self.insert(
+            CONTENT,
+            TypeDef::Object(content_fields(TypeRef::required("JSON"))),
+        );
+
+        for item in ["Category", "Tag", "User", CONTENT] {
+            self.insert(&format!("{}Page", item), TypeDef::Object(page_fields(item)));
+        }
+
+        self.insert(
+            "FilterInput",
+            TypeDef::Input(vec![
+                arg("key", TypeRef::required("String")),
+                arg("value", TypeRef::required("String")),
+            ]),
+        );
+        self.insert(
+            "CreateContentInput",
+            TypeDef::Input(vec![
+                arg("title", TypeRef::required("String")),
+                arg("categoryId", TypeRef::required("ID")),
+                arg("fields", TypeRef::required("JSON")),
+                arg("tagIds", TypeRef::list(TypeRef::required("ID"))),
+                arg("status", TypeRef::named("ContentStatus")),
+                arg("publishedAt", TypeRef::named("String")),
+                arg("unpublishAt", TypeRef::named("String")),
+            ]),
+        );
+        self.insert(
+            "UpdateContentInput",
+            TypeDef::Input(vec![
+                arg("title", TypeRef::named("String")),
+                arg("categoryId", TypeRef::named("ID")),
+                arg("fields", TypeRef::named("JSON")),
+                arg("tagIds", TypeRef::list(TypeRef::required("ID"))),
+                arg("unpublishAt", TypeRef::named("String")),
+                arg("clearUnpublishAt", TypeRef::named("Boolean")),
+                arg("version", TypeRef::named("Int")),
+            ]),
+        );
+        self.insert(
+            "CreateCategoryInput",
+            TypeDef::Input(vec![
+                arg("name", TypeRef::required("String")),
+                arg("apiIdentifier", TypeRef::required("String")),
+                arg("description", TypeRef::named("String")),
+                arg("fields", TypeRef::named("JSON")),
+            ]),
+        );
+        self.insert(
+            "UpdateCategoryInput",
+            TypeDef::Input(vec![
+                arg("name", TypeRef::named("String")),
+                arg("apiIdentifier", TypeRef::named("String")),
+                arg("description", TypeRef::named("String")),
+                arg("fields", TypeRef::named("JSON")),
+                arg("version", TypeRef::named("Int")),
+            ]),
+        );
+    }
+
+    fn content_models<'a>(
+        &self,
+        categories: &'a [Category],
+        query: &[FieldDef],
+    ) -> Vec<ContentModel<'a>> {
+        let mut type_names: BTreeSet<String> = self.types.keys().cloned().collect();
+        type_names.extend([QUERY.to_string(), MUTATION.to_string()]);
+        let mut root_names: BTreeSet<String> =
+            query.iter().map(|field| field.name.clone()).collect();
+
+        let mut models = vec![];
+        for category in categories {
+            let name = pascal_case(&category.api_identifier);
+            let generated = [
+                name.clone(),
+                format!("{}Fields", name),
+                format!("{}Page", name),
+            ];
+            let roots = [camel_case(&name), format!("{}List", camel_case(&name))];
+
+            if !is_name(&name)
+                || generated.iter().any(|x| type_names.contains(x))
+                || roots.iter().any(|x| root_names.contains(x))
+            {
+                tracing::warn!(
+                    "Category {} has no GraphQL type: {} is not an available name",
+                    category.api_identifier,
+                    name
+                );
+                continue;
+            }
+
+            type_names.extend(generated);
+            root_names.extend(roots);
+            models.push(ContentModel { category, name });
+        }
+
+        models
+    }
+
+    fn add_content_model(
+        &mut self,
+        model: &ContentModel,
+        model_names: &BTreeMap<&str, &str>,
+        query: &mut Vec<FieldDef>,
+    ) {
+        let ContentModel { category, name } = model;
+        let fields_name = format!("{}Fields", name);
+
+        let mut fields: Vec<FieldDef> = vec![];
+        for field in &category.fields {
+            let field_name = field_name(&field.key);
+
+            if !is_name(&field_name) || fields.iter().any(|x| x.name == field_name) {
+                tracing::warn!(
+                    "Field {} of category {} has no GraphQL field",
+                    field.key,
+                    category.api_identifier
+                );
+                continue;
+            }
+
+            fields.push(model_field(field, field_name, model_names));
+        }
+
+        // An object type needs at least one field, so a model without any keeps plain JSON.
+        let fields_type = match fields.is_empty() {
+            true => TypeRef::required("JSON"),
+            false => {
+                self.insert(&fields_name, TypeDef::Object(fields));
+                TypeRef::required(&fields_name)
+            }
+        };
+
+        self.insert(name, TypeDef::Object(content_fields(fields_type)));
+        self.insert(&format!("{}Page", name), TypeDef::Object(page_fields(name)));
+
+        query.push(root(
+            &camel_case(name),
+            TypeRef::named(name),
+            vec![
+                arg("id", TypeRef::required("ID")),
+                arg("locale", TypeRef::named("String")),
+            ],
+            RootField::CategoryContent(category.id.clone()),
+        ));
+        query.push(root(
+            &format!("{}List", camel_case(name)),
+            TypeRef::required(&format!("{}Page", name)),
+            content_list_args(),
+            RootField::CategoryContents(category.id.clone()),
+        ));
+    }
+}
+
+fn model_field(field: &Field, name: String, model_names: &BTreeMap<&str, &str>) -> FieldDef {
+    let type_name = match field.field_type {
+        FieldType::Text | FieldType::RichText | FieldType::Date | FieldType::Enum => "String",
+        FieldType::Number => "Float",
+        FieldType::Boolean => "Boolean",
+        FieldType::Json => "JSON",
+        FieldType::Media => "ID",
+        FieldType::Content => match field.categories.as_slice() {
+            [category_id] => model_names
+                .get(category_id.as_str())
+                .copied()
+                .unwrap_or(CONTENT),
+            _ => CONTENT,
+        },
+    };
+
+    let ty = match field.multiple {
+        true => TypeRef::list(TypeRef::required(type_name)),
+        false => TypeRef::named(type_name),
+    };
+
+    let resolver = match field.field_type {
+        FieldType::Content => Resolver::Reference(field.key.clone()),
+        _ => Resolver::Key(field.key.clone()),
+    };
+
+    FieldDef {
+        name,
+        ty,
+        args: vec![],
+        resolver,
+    }
+}
+
+fn mutation_fields() -> Vec<FieldDef> {
+    let id = || arg("id", TypeRef::required("ID"));
+
+    vec![
+        root(
+            "createContent",
+            TypeRef::required(CONTENT),
+            vec![arg("input", TypeRef::required("CreateContentInput"))],
+            RootField::CreateContent,
+        ),
+        root(
+            "updateContent",
+            TypeRef::required(CONTENT),
+            vec![id(), arg("input", TypeRef::required("UpdateContentInput"))],
+            RootField::UpdateContent,
+        ),
+        root(
+            "deleteContent",
+            TypeRef::required("Boolean"),
+            vec![id()],
+            RootField::DeleteContent,
+        ),
+        root(
+            "publishContent",
+            TypeRef::required(CONTENT),
+            vec![id()],
+            RootField::PublishContent,
+        ),
+        root(
+            "unpublishContent",
+            TypeRef::required(CONTENT),
+            vec![id()],
+            RootField::UnpublishContent,
+        ),
+        root(
+            "createCategory",
+            TypeRef::required("Category"),
+            vec![arg("input", TypeRef::required("CreateCategoryInput"))],
+            RootField::CreateCategory,
+        ),
+        root(
+            "updateCategory",
+            TypeRef::required("Category"),
+            vec![id(), arg("input", TypeRef::required("UpdateCategoryInput"))],
+            RootField::UpdateCategory,
+        ),
+        root(
+            "deleteCategory",
+            TypeRef::required("Boolean"),
+            vec![id()],
+            RootField::DeleteCategory,
+        ),
+    ]
+}
+
+fn write_args(f: &mut fmt::Formatter<'_>, args: &[InputValue]) -> fmt::Result {
+    if args.is_empty() {
+        return Ok(());
+    }
+
+    let args: Vec<String> = args
+        .iter()
+        .map(|arg| format!("{}: {}", arg.name, arg.ty))
+        .collect();
+    write!(f, "({})", args.join(", "))
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "schema {{\n  query: {}\n  mutation: {}\n}}",
+            QUERY, MUTATION
+        )?;
+
+        for (name, definition) in &self.types {
+            match definition {
+                TypeDef::Scalar if BUILTIN_SCALARS.contains(&name.as_str()) => continue,
+                TypeDef::Scalar => writeln!(f, "\nscalar {}", name)?,
+                TypeDef::Enum(values) => {
+                    writeln!(f, "\nenum {} {{", name)?;
+                    for value in values {
+                        writeln!(f, "  {}", value)?;
+                    }
+                    writeln!(f, "}}")?;
+                }
+                TypeDef::Object(fields) => {
+                    writeln!(f, "\ntype {} {{", name)?;
+                    for field in fields {
+                        write!(f, "  {}", field.name)?;
+                        write_args(f, &field.args)?;
+                        writeln!(f, ": {}", field.ty)?;
+                    }
+                    writeln!(f, "}}")?;
+                }
+                TypeDef::Input(fields) => {
+                    writeln!(f, "\ninput {} {{", name)?;
+                    for field in fields {
+                        writeln!(f, "  {}: {}", field.name, field.ty)?;
+                    }
+                    writeln!(f, "}}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+
+use application::usecase::api_key::{ApiKeyUsecase, GetApiKeyInput, IssueApiKeyInput};
+use domain::model::api_key::{ApiKey, ApiKeyScope};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetApiKeyRequest {
+    #[param(example = 0)]
+    pub offset: i32,
+    #[param(example = 100)]
+    pub limit: i32,
+}
+
+impl From<GetApiKeyRequest> for GetApiKeyInput {
+    fn from(value: GetApiKeyRequest) -> Self {
+        let GetApiKeyRequest { limit, offset } = value;
+
+        Self { limit, offset }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api-keys",
+    params(GetApiKeyRequest),
+    responses(
+        (status = 200, description = "Get api keys success", body = [ApiKey]),
+        (status = 403, description = "Reading api keys is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "api-keys"
+)]
+pub async fn get_api_keys(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Query(query): Query<GetApiKeyRequest>,
+) -> AppResult<Json<Vec<ApiKey>>> {
+    let usecase = ApiKeyUsecase::new(registry.api_key_repository());
+    let input = GetApiKeyInput::from(query);
+    let result = usecase.get(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct IssueApiKeyJson {
+    pub name: String,
+    pub scopes: Vec<ApiKeyScope>,
+    #[serde(default)]
+    pub category_ids: Vec<String>,
+    #[schema(value_type = Option<String>)]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<IssueApiKeyJson> for IssueApiKeyInput {
+    fn from(json: IssueApiKeyJson) -> Self {
+        let IssueApiKeyJson {
+            name,
+            scopes,
+            category_ids,
+            expires_at,
+        } = json;
+
+        Self {
+            name,
+            scopes,
+            category_ids,
+            expires_at,
+        }
+    }
+}
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+pub struct IssuedApiKeyJson {
+    pub api_key: ApiKey,
+    pub token: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/api-keys",
+    request_body = IssueApiKeyJson,
+    responses(
+        (status = 200, description = "Issue api key success. The token is only returned once.", body = IssuedApiKeyJson),
+        (status = 403, description = "Issuing api keys is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "api-keys",
+)]
+pub async fn issue_api_key(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Json(json): Json<IssueApiKeyJson>,
+) -> AppResult<Json<IssuedApiKeyJson>> {
+    let usecase = ApiKeyUsecase::new(registry.api_key_repository());
+    let input = IssueApiKeyInput::from(json);
+    let result = usecase.issue(&current_user.user, input).await;
+
+    match result {
+        Ok(issued) => Ok(Json(IssuedApiKeyJson {
+            api_key: issued.api_key,
+            token: issued.token,
+        })),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api-keys/{id}",
+    params(
+        ("id" = String, Path, description = "Api key ID"),
+    ),
+    responses(
+        (status = 200, description = "Revoke api key success"),
+        (status = 403, description = "Revoking api keys is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "api-keys",
+)]
+pub async fn revoke_api_key(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<()> {
+    let usecase = ApiKeyUsecase::new(registry.api_key_repository());
+    let result = usecase.revoke(&current_user.user, id).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
+}
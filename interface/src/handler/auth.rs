@@ -1,61 +1,256 @@
-use crate::handler::error::AppResult;
+use axum::{
+    extract::{Query, State},
+    response::{Json, Redirect},
+};
 
-#[derive(utoipa::ToSchema)]
-pub struct SignUpJson {}
+use application::usecase::auth::{AuthUsecase, IssuedSession, SignInInput, SignUpInput};
+use application::usecase::oidc::OidcUsecase;
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+fn auth_usecase(registry: &AppRegistry) -> AuthUsecase {
+    AuthUsecase::new(registry.auth_repository(), registry.mailer())
+}
+
+fn oidc_usecase(registry: &AppRegistry) -> AppResult<OidcUsecase> {
+    let client = registry.oidc_client().ok_or(AppError::EntityNotFound(
+        "OIDC login is not configured".into(),
+    ))?;
+
+    Ok(OidcUsecase::new(
+        client,
+        registry.oidc_repository(),
+        registry.auth_repository(),
+        registry.oidc_role_mapping(),
+    ))
+}
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+pub struct SessionJson {
+    pub token_type: String,
+    pub access_token: String,
+    pub refresh_token: String,
+    #[schema(value_type = String)]
+    pub access_expires_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = String)]
+    pub refresh_expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<IssuedSession> for SessionJson {
+    fn from(session: IssuedSession) -> Self {
+        let IssuedSession {
+            access_token,
+            refresh_token,
+            access_expires_at,
+            refresh_expires_at,
+        } = session;
+
+        Self {
+            token_type: "Bearer".into(),
+            access_token,
+            refresh_token,
+            access_expires_at,
+            refresh_expires_at,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct SignUpJson {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    pub icon_url: String,
+}
 
 #[utoipa::path(
     post,
     path = "/auth/signup",
     request_body = SignUpJson,
     responses(
-        (status = 200, description = "Signup success", body = ())
+        (status = 200, description = "Signup success. A verification token is sent to the email address.", body = ()),
+        (status = 409, description = "Email address is already registered"),
     ),
     tag = "auth",
 )]
-pub async fn signup() -> AppResult<()> {
-    Ok(())
+pub async fn signup(
+    State(registry): State<AppRegistry>,
+    Json(json): Json<SignUpJson>,
+) -> AppResult<()> {
+    let SignUpJson {
+        name,
+        email,
+        password,
+        icon_url,
+    } = json;
+
+    let input = SignUpInput::new(name, email, password, icon_url);
+    let result = auth_usecase(&registry).signup(input).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
 }
 
-#[derive(utoipa::ToSchema)]
-pub struct VerifyJson {}
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct VerifyJson {
+    pub token: String,
+}
 
 #[utoipa::path(
     post,
     path = "/auth/verify",
     request_body = VerifyJson,
     responses(
-        (status = 200, description = "Verify success", body = ())
+        (status = 200, description = "Verify success", body = ()),
+        (status = 401, description = "Token is invalid or expired"),
     ),
     tag = "auth",
 )]
-pub async fn verify() -> AppResult<()> {
-    Ok(())
+pub async fn verify(
+    State(registry): State<AppRegistry>,
+    Json(json): Json<VerifyJson>,
+) -> AppResult<()> {
+    let result = auth_usecase(&registry).verify(json.token).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
 
-#[derive(utoipa::ToSchema)]
-pub struct SignInJson {}
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct SignInJson {
+    pub email: String,
+    pub password: String,
+}
 
 #[utoipa::path(
     post,
     path = "/auth/signin",
     request_body = SignInJson,
     responses(
-        (status = 200, description = "Signin success", body = ())
+        (status = 200, description = "Signin success", body = SessionJson),
+        (status = 401, description = "Email or password is incorrect"),
+        (status = 403, description = "Email address is not verified"),
+    ),
+    tag = "auth",
+)]
+pub async fn signin(
+    State(registry): State<AppRegistry>,
+    Json(json): Json<SignInJson>,
+) -> AppResult<Json<SessionJson>> {
+    let SignInJson { email, password } = json;
+
+    let input = SignInInput::new(email, password);
+    let result = auth_usecase(&registry).signin(input).await;
+
+    match result {
+        Ok(session) => Ok(Json(SessionJson::from(session))),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct RefreshJson {
+    pub refresh_token: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    request_body = RefreshJson,
+    responses(
+        (status = 200, description = "Refresh success. The previous tokens are no longer valid.", body = SessionJson),
+        (status = 401, description = "Refresh token is invalid, expired or already used"),
     ),
     tag = "auth",
 )]
-pub async fn signin() -> AppResult<()> {
-    Ok(())
+pub async fn refresh(
+    State(registry): State<AppRegistry>,
+    Json(json): Json<RefreshJson>,
+) -> AppResult<Json<SessionJson>> {
+    let result = auth_usecase(&registry).refresh(json.refresh_token).await;
+
+    match result {
+        Ok(session) => Ok(Json(SessionJson::from(session))),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
 
 #[utoipa::path(
     delete,
     path = "/auth/signout",
     responses(
-        (status = 200, description = "Signout success", body = ())
+        (status = 200, description = "Signout success", body = ()),
+        (status = 401, description = "Access token is invalid or expired"),
+    ),
+    security(("bearer" = [])),
+    tag = "auth",
+)]
+pub async fn signout(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+) -> AppResult<()> {
+    let result = auth_usecase(&registry)
+        .signout(current_user.session_id)
+        .await;
+
+    if result.is_ok() {
+        return Ok(());
+    }
+
+    Err(AppError::DeleteRecordError)
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/oidc/login",
+    responses(
+        (status = 303, description = "Redirect to the identity provider"),
+        (status = 404, description = "OIDC login is not configured"),
     ),
     tag = "auth",
 )]
-pub async fn signout() -> AppResult<()> {
-    Ok(())
+pub async fn oidc_login(State(registry): State<AppRegistry>) -> AppResult<Redirect> {
+    let result = oidc_usecase(&registry)?.login().await;
+
+    match result {
+        Ok(url) => Ok(Redirect::to(&url)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct OidcCallbackRequest {
+    pub code: String,
+    pub state: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/oidc/callback",
+    params(OidcCallbackRequest),
+    responses(
+        (status = 200, description = "OIDC login success", body = SessionJson),
+        (status = 401, description = "Login state or ID token is invalid"),
+        (status = 404, description = "OIDC login is not configured"),
+    ),
+    tag = "auth",
+)]
+pub async fn oidc_callback(
+    State(registry): State<AppRegistry>,
+    Query(query): Query<OidcCallbackRequest>,
+) -> AppResult<Json<SessionJson>> {
+    let OidcCallbackRequest { code, state } = query;
+
+    let result = oidc_usecase(&registry)?.callback(code, state).await;
+
+    match result {
+        Ok(session) => Ok(Json(SessionJson::from(session))),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
 }
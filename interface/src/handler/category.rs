@@ -4,63 +4,88 @@ use axum::{
 };
 
 use application::usecase::category::{
-    CategoryUsecase, CreateCategoryInput, GetCategoryInput, UpdateCategoryInput
+    CategoryUsecase, CreateCategoryInput, GetCategoryInput, UpdateCategoryInput,
 };
 use domain::model::category::Category;
+use domain::model::field::Field;
+use domain::model::page::{Page, PageQuery};
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
 use registry::AppRegistry;
 
+use crate::extractor::{CurrentUser, IfMatch, Versioned};
 use crate::handler::error::{AppError, AppResult};
 
 #[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
 pub struct GetCategoryQuery {
-    #[param(example = 0)]
-    pub offset: i32,
     #[param(example = 100)]
-    pub limit: i32,
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
     pub keyword: Option<String>,
 }
 
 #[utoipa::path(
     get,
     path = "/categories",
-    params(GetCategoryQuery), 
+    params(GetCategoryQuery),
     responses(
-        (status = 200, description = "Get category success", body = [Category])
+        (status = 200, description = "Get category success. Only readable categories are returned. Pass next_cursor or prev_cursor as cursor to move between pages.", body = Page<Category>),
+        (status = 400, description = "Limit or cursor is invalid"),
+        (status = 403, description = "Reading categories is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "categories",
 )]
 pub async fn get_categories(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Query(query): Query<GetCategoryQuery>,
-) -> AppResult<Json<Vec<Category>>> {
-    let GetCategoryQuery { offset, limit, .. } = query;
-    let usecase = CategoryUsecase::new(registry.category_repository());
-    let input = GetCategoryInput::new(limit, offset);
-    let result = usecase.get(input).await;
-
-    if let Ok(value) = result {
-        return Ok(Json(value));
+) -> AppResult<Json<Page<Category>>> {
+    let GetCategoryQuery {
+        limit,
+        cursor,
+        total,
+        ..
+    } = query;
+    let page = PageQuery::try_new(limit, cursor.as_deref(), total.unwrap_or_default())?;
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+    let input = GetCategoryInput::new(page);
+    let result = usecase.get(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
     }
-
-    Err(AppError::EntityNotFound("".into()))
 }
 
-
 #[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
 pub struct CreateCategoryJson {
     name: String,
     api_identifier: String,
-    description: Option<String>
+    description: Option<String>,
+    #[serde(default)]
+    fields: Vec<Field>,
 }
 
 impl From<CreateCategoryJson> for CreateCategoryInput {
     fn from(json: CreateCategoryJson) -> Self {
-        let CreateCategoryJson {name, api_identifier, description} = json;
+        let CreateCategoryJson {
+            name,
+            api_identifier,
+            description,
+            fields,
+        } = json;
 
         Self {
             name,
             api_identifier,
             description,
+            fields,
         }
     }
 }
@@ -70,23 +95,26 @@ impl From<CreateCategoryJson> for CreateCategoryInput {
     path = "/categories",
     request_body = CreateCategoryJson,
     responses(
-        (status = 200, description = "Create category success", body = Category),
+        (status = 200, description = "Create category success", body = Category,
+            headers(("ETag" = String, description = "Version of the category"))),
+        (status = 403, description = "Creating categories is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "categories",
 )]
 pub async fn create_category(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Json(json): Json<CreateCategoryJson>,
-) -> AppResult<()> {
+) -> AppResult<Versioned<Category>> {
     let input = CreateCategoryInput::from(json);
-    let usecase = CategoryUsecase::new(registry.category_repository());
-    let result = usecase.create(input).await;
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+    let result = usecase.create(&current_user.user, input).await;
 
-    if result.is_ok() {
-        return Ok(());
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
     }
-
-    Err(AppError::CreateRecordError)
 }
 
 #[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
@@ -94,6 +122,7 @@ pub struct UpdateCategoryJson {
     pub name: Option<String>,
     pub api_identifier: Option<String>,
     pub description: Option<String>,
+    pub fields: Option<Vec<Field>>,
 }
 
 #[utoipa::path(
@@ -101,34 +130,50 @@ pub struct UpdateCategoryJson {
     path = "/categories/{id}",
     params(
         ("id" = String, Path, description = "Category ID"),
+        ("If-Match" = String, Header, description = "ETag of the version being edited, or * to update any version"),
     ),
     request_body = UpdateCategoryJson,
     responses(
-        (status = 200, description = "Update category success", body = Category),
+        (status = 200, description = "Update category success", body = Category,
+            headers(("ETag" = String, description = "Version of the updated category"))),
+        (status = 400, description = "If-Match is neither * nor an ETag"),
+        (status = 412, description = "Category was updated since the ETag was read, carrying its current state", body = VersionConflict),
+        (status = 428, description = "If-Match is missing"),
+        (status = 403, description = "Updating the category is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "categories",
 )]
 pub async fn update_category(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Path(id): Path<String>,
+    IfMatch(expected_version): IfMatch,
     Json(category): Json<UpdateCategoryJson>,
-) -> AppResult<()> {
-    let usecase = CategoryUsecase::new(registry.category_repository());
+) -> AppResult<Versioned<Category>> {
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
 
     let UpdateCategoryJson {
         name,
         api_identifier,
         description,
+        fields,
     } = category;
 
-    let input = UpdateCategoryInput::new(id, name, api_identifier, description);
-    let result = usecase.update(input).await;
-
-    if result.is_ok() {
-        return Ok(());
+    let input = UpdateCategoryInput::new(
+        id,
+        name,
+        api_identifier,
+        description,
+        fields,
+        expected_version,
+    );
+    let result = usecase.update(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
     }
-
-    Err(AppError::UpdateRecordError)
 }
 
 #[utoipa::path(
@@ -138,20 +183,53 @@ pub async fn update_category(
         ("id" = String, Path, description = "Category ID")
     ),
     responses(
-        (status = 200, description = "Delete category success"),
+        (status = 200, description = "Category is moved to the trash"),
+        (status = 403, description = "Deleting the category is not permitted"),
+        (status = 409, description = "Category still has contents outside the trash", body = TrashError),
     ),
+    security(("bearer" = [])),
     tag = "categories",
 )]
 pub async fn delete_category(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Path(id): Path<String>,
 ) -> AppResult<()> {
-    let usecase = CategoryUsecase::new(registry.category_repository());
-    let result = usecase.delete(id).await;
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+    let result = usecase.delete(&current_user.user, id).await;
 
-    if result.is_ok() {
-        return Ok(());
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
     }
+}
 
-    Err(AppError::DeleteRecordError)
+#[utoipa::path(
+    post,
+    path = "/categories/{id}/restore",
+    params(
+        ("id" = String, Path, description = "Category ID")
+    ),
+    responses(
+        (status = 200, description = "Restore category from the trash success", body = Category,
+            headers(("ETag" = String, description = "Version of the restored category"))),
+        (status = 403, description = "Restoring the category is not permitted"),
+        (status = 404, description = "Category is not in the trash", body = TrashError),
+        (status = 409, description = "Api identifier of the category is used by another category", body = TrashError),
+    ),
+    security(("bearer" = [])),
+    tag = "categories",
+)]
+pub async fn restore_category(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Versioned<Category>> {
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+    let result = usecase.restore(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use axum::{
     extract::{Path, Query, State},
     response::Json,
@@ -6,50 +8,236 @@ use axum::{
 use application::usecase::content::{
     ContentUsecase, CreateContentInput, GetContentInput, UpdateContentInput,
 };
-use domain::model::content::{Content, ContentStatus};
+use domain::model::content::{Content, ContentStatus, ContentStatusError};
+use domain::model::field::FieldValidationError;
+use domain::model::locale::LocaleChain;
+use domain::model::page::{Page, PageQuery};
+use domain::model::query::{ContentFilter, Projection, SortOrder, TagMatch};
+use domain::model::relation::{ContentReferrer, Expand};
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
 use registry::AppRegistry;
 
+use crate::extractor::{CurrentUser, IfMatch, Versioned};
 use crate::handler::error::{AppError, AppResult};
 
 #[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
 pub struct GetContentRequest {
-    #[param(example = 0)]
-    pub offset: i32,
     #[param(example = 100)]
-    pub limit: i32,
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
+    #[param(example = "release notes")]
     pub keyword: Option<String>,
     pub category: Option<String>,
-    pub tags: Option<Vec<String>>,
+    #[param(example = "tag-id-1,tag-id-2")]
+    pub tags: Option<String>,
+    #[param(example = "any")]
+    pub tags_match: Option<String>,
+    #[param(example = "Published,Reserved")]
+    pub status: Option<String>,
+    #[param(example = "user-id-1,user-id-2")]
+    pub created_by: Option<String>,
+    #[param(example = "-published_at,title,fields.price")]
+    pub sort: Option<String>,
+    #[param(example = "title,status,fields.price")]
+    pub select: Option<String>,
+    #[param(example = "author,related.author")]
+    pub expand: Option<String>,
+    #[param(example = "en")]
+    pub locale: Option<String>,
 }
 
-impl From<GetContentRequest> for GetContentInput {
-    fn from(value: GetContentRequest) -> Self {
-        let GetContentRequest { limit, offset, .. } = value;
+pub fn parse_expand(expand: Option<&str>) -> AppResult<Expand> {
+    Expand::parse(expand.unwrap_or_default())
+        .map_err(|error| AppError::BadRequest(error.to_string()))
+}
 
-        Self { limit, offset }
-    }
+pub fn parse_locale(
+    registry: &AppRegistry,
+    locale: Option<&str>,
+) -> AppResult<Option<LocaleChain>> {
+    locale
+        .map(|locale| registry.locales().chain(locale))
+        .transpose()
+        .map_err(AppError::from)
+}
+
+fn split_list(value: Option<String>) -> Option<Vec<String>> {
+    value.map(|value| {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(String::from)
+            .collect()
+    })
+}
+
+fn parse_get_content_input(
+    query: GetContentRequest,
+    pairs: &[(String, String)],
+) -> anyhow::Result<GetContentInput> {
+    let GetContentRequest {
+        limit,
+        cursor,
+        total,
+        keyword,
+        category,
+        tags,
+        tags_match,
+        status,
+        created_by,
+        sort,
+        ..
+    } = query;
+
+    let statuses = split_list(status)
+        .map(|statuses| {
+            statuses
+                .into_iter()
+                .map(|status| {
+                    serde_json::from_value::<ContentStatus>(serde_json::Value::String(
+                        status.clone(),
+                    ))
+                    .map_err(|_| anyhow::anyhow!("Status {} is not supported", status))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .transpose()?;
+
+    Ok(GetContentInput {
+        page: PageQuery::try_new(limit, cursor.as_deref(), total.unwrap_or_default())?,
+        keyword,
+        category_id: category,
+        tag_ids: split_list(tags),
+        tag_match: tags_match
+            .as_deref()
+            .map(TagMatch::from_str)
+            .transpose()?
+            .unwrap_or_default(),
+        statuses,
+        created_by_ids: split_list(created_by),
+        filter: ContentFilter::parse(pairs)?,
+        sort: SortOrder::parse_list(sort.as_deref().unwrap_or_default())?,
+        category_ids: None,
+    })
 }
 
 #[utoipa::path(
     get,
     path = "/contents",
     params(GetContentRequest),
-    responses((status = 200, description = "Get content success", body = [Content])),
-    tag = "contents"
+    responses(
+        (status = 200, description = "Get content success. Only contents in readable categories are returned. A keyword ranks the results and adds a highlighted snippet. Dates filter with published_at[gte] or updated_at[lt], and fields filter with fields.{key}[eq|ne|gt|gte|lt|lte|in|contains|exists]. Pass next_cursor or prev_cursor as cursor to move between pages. A locale resolves localizable fields through its fallback chain; filters, sort and search use the default locale.", body = Page<Content>),
+        (status = 400, description = "Filters, sort keys, select keys, expand paths, locale, limit or cursor are invalid"),
+        (status = 403, description = "Reading contents is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
 )]
 pub async fn get_contents(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Query(query): Query<GetContentRequest>,
-) -> AppResult<Json<Vec<Content>>> {
-    let usecase = ContentUsecase::new(registry.content_repository());
-    let input = GetContentInput::from(query);
-    let result = usecase.get(input).await;
+    Query(pairs): Query<Vec<(String, String)>>,
+) -> AppResult<Json<Page<serde_json::Value>>> {
+    let expand = parse_expand(query.expand.as_deref())?;
+    let locale = parse_locale(&registry, query.locale.as_deref())?;
+    let projection = Projection::parse(query.select.as_deref().unwrap_or_default())
+        .map_err(|error| AppError::BadRequest(error.to_string()))?;
+    let input = parse_get_content_input(query, &pairs)
+        .map_err(|error| AppError::BadRequest(error.to_string()))?;
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let result = usecase
+        .get(&current_user.user, input, &expand, locale.as_ref())
+        .await
+        .and_then(|page| projection.project(page));
 
-    if let Ok(value) = result {
-        return Ok(Json(value));
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
     }
+}
 
-    Err(AppError::EntityNotFound("".into()))
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct FindContentRequest {
+    #[param(example = "author,related.author")]
+    pub expand: Option<String>,
+    #[param(example = "en")]
+    pub locale: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/contents/{id}",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        FindContentRequest,
+    ),
+    responses(
+        (status = 200, description = "Find content success. A locale resolves localizable fields through its fallback chain", body = Content,
+            headers(("ETag" = String, description = "Version of the content, required by updates in If-Match"))),
+        (status = 400, description = "Expand paths are invalid or too deep, or the locale is not configured"),
+        (status = 403, description = "Reading the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn find_content(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Query(query): Query<FindContentRequest>,
+) -> AppResult<Versioned<Content>> {
+    let expand = parse_expand(query.expand.as_deref())?;
+    let locale = parse_locale(&registry, query.locale.as_deref())?;
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let result = usecase
+        .find(&current_user.user, id, &expand, locale.as_ref())
+        .await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/contents/{id}/referrers",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    responses(
+        (status = 200, description = "Readable contents referencing the content", body = [ContentReferrer]),
+        (status = 403, description = "Reading the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn get_content_referrers(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Vec<ContentReferrer>>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let result = usecase.referrers(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
 }
 
 #[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
@@ -59,28 +247,36 @@ pub struct CreateContentJson {
     pub tag_ids: Vec<String>,
     pub status: ContentStatus,
     pub category_id: String,
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-impl From<CreateContentJson> for CreateContentInput {
-    fn from(json: CreateContentJson) -> Self {
+impl CreateContentJson {
+    fn into_input(self, user_id: String) -> CreateContentInput {
         let CreateContentJson {
             title,
             fields,
             tag_ids,
             status,
             category_id,
-        } = json;
-        let created_by_id: String = "id".into();
-        let updated_by_id: String = "id".into();
+            published_at,
+            unpublish_at,
+        } = self;
 
-        Self {
+        CreateContentInput {
             title,
             fields,
             tag_ids,
             status,
             category_id,
-            created_by_id,
-            updated_by_id,
+            published_at,
+            unpublish_at,
+            created_by_id: user_id.clone(),
+            updated_by_id: user_id,
         }
     }
 }
@@ -90,78 +286,105 @@ impl From<CreateContentJson> for CreateContentInput {
     path = "/contents",
     request_body = CreateContentJson,
     responses(
-        (status = 200, description = "Create content success", body = Content)
+        (status = 200, description = "Create content success", body = Content,
+            headers(("ETag" = String, description = "Version of the content"))),
+        (status = 409, description = "Status is not allowed for new content, or a tag is in the trash", body = ContentStatusError),
+        (status = 422, description = "Fields do not match the category schema", body = FieldValidationError),
+        (status = 403, description = "Operation on the category is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "contents",
 )]
 pub async fn create_content(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Json(json): Json<CreateContentJson>,
-) -> AppResult<()> {
-    let input = CreateContentInput::from(json);
-    let usecase = ContentUsecase::new(registry.content_repository());
-    let result = usecase.create(input).await;
+) -> AppResult<Versioned<Content>> {
+    let input = json.into_input(current_user.id());
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let result = usecase.create(&current_user.user, input).await;
 
-    if result.is_ok() {
-        return Ok(());
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
     }
-
-    Err(AppError::CreateRecordError)
 }
 
 #[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
 pub struct UpdateContentJson {
+    pub title: Option<String>,
     pub fields: Option<serde_json::Value>,
-    pub status: Option<ContentStatus>,
+    pub tag_ids: Option<Vec<String>>,
     pub category_id: Option<String>,
-}
-
-impl From<UpdateContentJson> for UpdateContentInput {
-    fn from(value: UpdateContentJson) -> Self {
-        let UpdateContentJson {
-            fields,
-            status,
-            category_id,
-        } = value;
-
-        Self {}
-    }
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    pub clear_unpublish_at: bool,
 }
 
 #[utoipa::path(
     put,
     path = "/contents/{id}",
     params(
-        ("id" = String, Path, description = "contents ID")
+        ("id" = String, Path, description = "contents ID"),
+        ("If-Match" = String, Header, description = "ETag of the version being edited, or * to update any version"),
     ),
     request_body = UpdateContentJson,
     responses(
-        (status = 200, description = "Update content success", body = Content),
+        (status = 200, description = "Update content success", body = Content,
+            headers(("ETag" = String, description = "Version of the updated content"))),
+        (status = 400, description = "If-Match is neither * nor an ETag, or unpublish_at is given with clear_unpublish_at"),
+        (status = 409, description = "Tag is in the trash", body = TrashError),
+        (status = 412, description = "Content was updated since the ETag was read, carrying its current state", body = VersionConflict),
+        (status = 422, description = "Fields do not match the category schema", body = FieldValidationError),
+        (status = 428, description = "If-Match is missing"),
+        (status = 403, description = "Operation on the category is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "contents",
 )]
 pub async fn update_content(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Path(id): Path<String>,
+    IfMatch(expected_version): IfMatch,
     Json(json): Json<UpdateContentJson>,
-) -> AppResult<()> {
-    let usecase = ContentUsecase::new(registry.content_repository());
+) -> AppResult<Versioned<Content>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
 
     let UpdateContentJson {
+        title,
         fields,
-        status,
+        tag_ids,
         category_id,
+        unpublish_at,
+        clear_unpublish_at,
     } = json;
 
-    let mock_id: String = "id".into();
-    let input = UpdateContentInput::new(id, category_id, fields, status, mock_id);
-    let result = usecase.update(input).await;
-
-    if result.is_ok() {
-        return Ok(());
+    if clear_unpublish_at && unpublish_at.is_some() {
+        return Err(AppError::BadRequest(
+            "unpublish_at cannot be set and cleared at once".into(),
+        ));
     }
 
-    Err(AppError::UpdateRecordError)
+    let mut input = UpdateContentInput::new(
+        id,
+        title,
+        category_id,
+        fields,
+        tag_ids,
+        unpublish_at,
+        current_user.id(),
+        expected_version,
+    );
+    input.clear_unpublish_at = clear_unpublish_at;
+    let result = usecase.update(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
 
 #[utoipa::path(
@@ -171,20 +394,189 @@ pub async fn update_content(
         ("id" = String,  Path, description = "Content ID"),
     ),
     responses(
-        (status = 200, description = "Delete content success")
+        (status = 200, description = "Content is moved to the trash"),
+        (status = 403, description = "Operation on the category is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "contents",
 )]
 pub async fn delete_content(
     State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
     Path(id): Path<String>,
 ) -> AppResult<()> {
-    let usecase = ContentUsecase::new(registry.content_repository());
-    let result = usecase.delete(id).await;
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let result = usecase.delete(&current_user.user, id).await;
 
-    if result.is_ok() {
-        return Ok(());
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
     }
+}
 
-    Err(AppError::DeleteRecordError)
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/restore",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    responses(
+        (status = 200, description = "Restore content from the trash success", body = Content,
+            headers(("ETag" = String, description = "Version of the restored content"))),
+        (status = 403, description = "Operation on the category is not permitted"),
+        (status = 404, description = "Content is not in the trash", body = TrashError),
+        (status = 409, description = "Category of the content is in the trash", body = TrashError),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn restore_content(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Versioned<Content>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+    let result = usecase.restore(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/publish",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    responses(
+        (status = 200, description = "Publish content success", body = Content,
+            headers(("ETag" = String, description = "Version of the content"))),
+        (status = 409, description = "Content cannot be published", body = ContentStatusError),
+        (status = 403, description = "Operation on the category is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn publish_content(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Versioned<Content>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+
+    let result = usecase.publish(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/unpublish",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    responses(
+        (status = 200, description = "Unpublish content success", body = Content,
+            headers(("ETag" = String, description = "Version of the content"))),
+        (status = 409, description = "Content cannot be unpublished", body = ContentStatusError),
+        (status = 403, description = "Operation on the category is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn unpublish_content(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Versioned<Content>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+
+    let result = usecase.unpublish(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct ScheduleContentJson {
+    #[schema(value_type = String)]
+    pub published_at: chrono::DateTime<chrono::Utc>,
+    #[schema(value_type = Option<String>)]
+    pub unpublish_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/schedule",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    request_body = ScheduleContentJson,
+    responses(
+        (status = 200, description = "Schedule content success", body = Content,
+            headers(("ETag" = String, description = "Version of the content"))),
+        (status = 409, description = "Content cannot be scheduled", body = ContentStatusError),
+        (status = 403, description = "Operation on the category is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn schedule_content(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Json(json): Json<ScheduleContentJson>,
+) -> AppResult<Versioned<Content>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+
+    let ScheduleContentJson {
+        published_at,
+        unpublish_at,
+    } = json;
+
+    let result = usecase
+        .schedule(&current_user.user, id, published_at, unpublish_at)
+        .await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/draft",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    responses(
+        (status = 200, description = "Revert content to draft success", body = Content,
+            headers(("ETag" = String, description = "Version of the content"))),
+        (status = 409, description = "Content cannot be reverted to draft", body = ContentStatusError),
+        (status = 403, description = "Operation on the category is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "contents",
+)]
+pub async fn revert_content_to_draft(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Versioned<Content>> {
+    let usecase = ContentUsecase::new(registry.content_repository(), registry.unit_of_work());
+
+    let result = usecase.revert_to_draft(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
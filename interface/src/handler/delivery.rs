@@ -0,0 +1,163 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+    Extension,
+};
+
+use application::usecase::delivery::{DeliveryUsecase, GetDeliveryInput};
+use domain::model::api_key::{ApiKey, ApiKeyScope};
+use domain::model::delivery::DeliveryContent;
+use registry::AppRegistry;
+
+use crate::handler::content::{parse_expand, parse_locale};
+use crate::handler::error::{AppError, AppResult};
+
+fn authorize(api_key: &ApiKey, api_identifier: &str, preview: bool) -> AppResult<()> {
+    let scope = match preview {
+        true => ApiKeyScope::ReadDrafts,
+        false => ApiKeyScope::ReadPublished,
+    };
+
+    if !api_key.has_scope(&scope) {
+        return Err(AppError::MissingScope(scope));
+    }
+
+    if !api_key.allows_category(api_identifier) {
+        return Err(AppError::EntityNotFound("".into()));
+    }
+
+    Ok(())
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetDeliveryRequest {
+    #[param(example = 0)]
+    pub offset: i32,
+    #[param(example = 100)]
+    pub limit: i32,
+    #[serde(default)]
+    pub preview: bool,
+    #[param(example = "author,related.author")]
+    pub expand: Option<String>,
+    #[param(example = "en")]
+    pub locale: Option<String>,
+}
+
+impl From<GetDeliveryRequest> for GetDeliveryInput {
+    fn from(value: GetDeliveryRequest) -> Self {
+        let GetDeliveryRequest {
+            limit,
+            offset,
+            preview,
+            ..
+        } = value;
+
+        Self {
+            limit,
+            offset,
+            include_drafts: preview,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct FindDeliveryRequest {
+    #[serde(default)]
+    pub preview: bool,
+    #[param(example = "author,related.author")]
+    pub expand: Option<String>,
+    #[param(example = "en")]
+    pub locale: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/delivery/v1/{api_identifier}",
+    params(
+        ("api_identifier" = String, Path, description = "Category API identifier"),
+        GetDeliveryRequest,
+    ),
+    responses(
+        (status = 200, description = "Get published contents success. A locale resolves localizable fields through its fallback chain, skipping unpublished localizations unless previewing", body = [DeliveryContent]),
+        (status = 400, description = "Expand paths are invalid or too deep, or the locale is not configured"),
+        (status = 401, description = "Api key is missing or invalid"),
+        (status = 403, description = "Api key lacks the required scope"),
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+    tag = "delivery"
+)]
+pub async fn get_delivery_contents(
+    State(registry): State<AppRegistry>,
+    Extension(api_key): Extension<ApiKey>,
+    Path(api_identifier): Path<String>,
+    Query(query): Query<GetDeliveryRequest>,
+) -> AppResult<Json<Vec<DeliveryContent>>> {
+    authorize(&api_key, &api_identifier, query.preview)?;
+    let expand = parse_expand(query.expand.as_deref())?;
+    let locale = parse_locale(&registry, query.locale.as_deref())?;
+
+    let usecase = DeliveryUsecase::new(
+        registry.delivery_repository(),
+        registry.media_upload_repository(),
+    );
+    let input = GetDeliveryInput::from(query);
+    let result = usecase
+        .get(&api_key, api_identifier, input, &expand, locale.as_ref())
+        .await;
+
+    if let Ok(value) = result {
+        return Ok(Json(value.into_iter().map(DeliveryContent::from).collect()));
+    }
+
+    Err(AppError::EntityNotFound("".into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/delivery/v1/{api_identifier}/{id}",
+    params(
+        ("api_identifier" = String, Path, description = "Category API identifier"),
+        ("id" = String, Path, description = "Content ID"),
+        FindDeliveryRequest,
+    ),
+    responses(
+        (status = 200, description = "Find published content success. A locale resolves localizable fields through its fallback chain, skipping unpublished localizations unless previewing", body = DeliveryContent),
+        (status = 400, description = "Expand paths are invalid or too deep, or the locale is not configured"),
+        (status = 401, description = "Api key is missing or invalid"),
+        (status = 403, description = "Api key lacks the required scope"),
+        (status = 404, description = "Content is not published"),
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+    tag = "delivery"
+)]
+pub async fn find_delivery_content(
+    State(registry): State<AppRegistry>,
+    Extension(api_key): Extension<ApiKey>,
+    Path((api_identifier, id)): Path<(String, String)>,
+    Query(query): Query<FindDeliveryRequest>,
+) -> AppResult<Json<DeliveryContent>> {
+    authorize(&api_key, &api_identifier, query.preview)?;
+    let expand = parse_expand(query.expand.as_deref())?;
+    let locale = parse_locale(&registry, query.locale.as_deref())?;
+
+    let usecase = DeliveryUsecase::new(
+        registry.delivery_repository(),
+        registry.media_upload_repository(),
+    );
+    let result = usecase
+        .find(
+            &api_key,
+            api_identifier,
+            id,
+            query.preview,
+            &expand,
+            locale.as_ref(),
+        )
+        .await;
+
+    if let Ok(value) = result {
+        return Ok(Json(DeliveryContent::from(value)));
+    }
+
+    Err(AppError::EntityNotFound("".into()))
+}
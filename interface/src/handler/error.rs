@@ -1,14 +1,61 @@
 use axum::{
     http::StatusCode,
-    response::{IntoResponse, Response},
+    response::{IntoResponse, Json, Response},
 };
+use domain::model::api_key::ApiKeyScope;
+use domain::model::auth::AuthError;
+use domain::model::content::ContentStatusError;
+use domain::model::field::FieldValidationError;
+use domain::model::locale::LocaleError;
+use domain::model::media::MediaInUseError;
+use domain::model::page::PageError;
+use domain::model::render::RenderError;
+use domain::model::role::{PermissionDenied, RoleError};
+use domain::model::trash::TrashError;
+use domain::model::upload::UploadError;
+use domain::model::version::{PreconditionError, VersionConflict};
+use domain::model::webhook::WebhookError;
 
 #[derive(thiserror::Error, Debug)]
 pub enum AppError {
     #[error("{0}")]
     EntityNotFound(String),
     #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
     ValidationError(#[from] garde::Report),
+    #[error(transparent)]
+    FieldValidationError(#[from] FieldValidationError),
+    #[error(transparent)]
+    ContentStatusError(#[from] ContentStatusError),
+    #[error(transparent)]
+    AuthError(#[from] AuthError),
+    #[error(transparent)]
+    RoleError(#[from] RoleError),
+    #[error(transparent)]
+    MediaInUseError(#[from] MediaInUseError),
+    #[error(transparent)]
+    UploadError(#[from] UploadError),
+    #[error(transparent)]
+    RenderError(#[from] RenderError),
+    #[error(transparent)]
+    PageError(#[from] PageError),
+    #[error(transparent)]
+    LocaleError(#[from] LocaleError),
+    #[error(transparent)]
+    WebhookError(#[from] WebhookError),
+    #[error(transparent)]
+    VersionConflict(#[from] VersionConflict),
+    #[error(transparent)]
+    PreconditionError(#[from] PreconditionError),
+    #[error(transparent)]
+    TrashError(#[from] TrashError),
+    #[error("Unauthorized")]
+    Unauthorized,
+    #[error(transparent)]
+    Forbidden(#[from] PermissionDenied),
+    #[error("Api key lacks the {} scope", .0.as_str())]
+    MissingScope(ApiKeyScope),
     #[error("Failed create record.")]
     CreateRecordError,
     #[error("Failed update record")]
@@ -20,8 +67,77 @@ pub enum AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status_code = match self {
+            Self::FieldValidationError(error) => {
+                return (StatusCode::UNPROCESSABLE_ENTITY, Json(error)).into_response();
+            }
+            Self::ContentStatusError(error) => {
+                return (StatusCode::CONFLICT, Json(error)).into_response();
+            }
+            Self::AuthError(error) => match error {
+                AuthError::InvalidCredentials
+                | AuthError::InvalidToken
+                | AuthError::InvalidLoginState
+                | AuthError::InvalidIdToken => StatusCode::UNAUTHORIZED,
+                AuthError::EmailNotVerified => StatusCode::FORBIDDEN,
+                AuthError::EmailAlreadyRegistered | AuthError::EmailNotLinkable => {
+                    StatusCode::CONFLICT
+                }
+            },
+            Self::RoleError(_) => StatusCode::CONFLICT,
+            Self::MediaInUseError(error) => {
+                return (StatusCode::CONFLICT, Json(error)).into_response();
+            }
+            Self::UploadError(error) => {
+                let status_code = match error {
+                    UploadError::NotFound => StatusCode::NOT_FOUND,
+                    UploadError::Expired => StatusCode::GONE,
+                    UploadError::AlreadyCompleted => StatusCode::CONFLICT,
+                    UploadError::SizeMismatch { .. }
+                    | UploadError::ChecksumMismatch
+                    | UploadError::ObjectMissing => StatusCode::UNPROCESSABLE_ENTITY,
+                };
+
+                return (status_code, Json(error)).into_response();
+            }
+            Self::RenderError(error) => match error {
+                RenderError::NotAnImage => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                RenderError::InvalidSignature | RenderError::SignatureExpired => {
+                    StatusCode::FORBIDDEN
+                }
+                RenderError::SigningDisabled => StatusCode::NOT_IMPLEMENTED,
+            },
+            Self::WebhookError(error) => {
+                return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+            }
+            Self::VersionConflict(error) => {
+                return (StatusCode::PRECONDITION_FAILED, Json(error)).into_response();
+            }
+            Self::PreconditionError(error) => {
+                let status_code = match error {
+                    PreconditionError::Missing => StatusCode::PRECONDITION_REQUIRED,
+                    PreconditionError::Invalid => StatusCode::BAD_REQUEST,
+                };
+
+                return (status_code, Json(error)).into_response();
+            }
+            Self::TrashError(error) => {
+                let status_code = match error {
+                    TrashError::CategoryInUse
+                    | TrashError::CategoryInTrash
+                    | TrashError::CategoryIdentifierInUse
+                    | TrashError::TagInTrash => StatusCode::CONFLICT,
+                    TrashError::NotInTrash => StatusCode::NOT_FOUND,
+                };
+
+                return (status_code, Json(error)).into_response();
+            }
             Self::EntityNotFound(_) => StatusCode::NOT_FOUND,
-            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) | Self::MissingScope(_) => StatusCode::FORBIDDEN,
+            Self::ValidationError(_)
+            | Self::BadRequest(_)
+            | Self::PageError(_)
+            | Self::LocaleError(_) => StatusCode::BAD_REQUEST,
             Self::CreateRecordError | Self::UpdateRecordError | Self::DeleteRecordError => {
                 StatusCode::INTERNAL_SERVER_ERROR
             }
@@ -31,4 +147,73 @@ impl IntoResponse for AppError {
     }
 }
 
+impl AppError {
+    pub fn domain_or(error: anyhow::Error, fallback: AppError) -> AppError {
+        let error = match error.downcast::<FieldValidationError>() {
+            Ok(error) => return Self::FieldValidationError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<ContentStatusError>() {
+            Ok(error) => return Self::ContentStatusError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<AuthError>() {
+            Ok(error) => return Self::AuthError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<RoleError>() {
+            Ok(error) => return Self::RoleError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<MediaInUseError>() {
+            Ok(error) => return Self::MediaInUseError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<UploadError>() {
+            Ok(error) => return Self::UploadError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<RenderError>() {
+            Ok(error) => return Self::RenderError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<PageError>() {
+            Ok(error) => return Self::PageError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<LocaleError>() {
+            Ok(error) => return Self::LocaleError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<WebhookError>() {
+            Ok(error) => return Self::WebhookError(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<VersionConflict>() {
+            Ok(error) => return Self::VersionConflict(error),
+            Err(error) => error,
+        };
+
+        let error = match error.downcast::<TrashError>() {
+            Ok(error) => return Self::TrashError(error),
+            Err(error) => error,
+        };
+
+        match error.downcast::<PermissionDenied>() {
+            Ok(error) => Self::Forbidden(error),
+            Err(_) => fallback,
+        }
+    }
+}
+
 pub type AppResult<T> = Result<T, AppError>;
@@ -0,0 +1,62 @@
+use axum::{extract::State, response::Json};
+
+use application::usecase::category::CategoryUsecase;
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::graphql::executor::{execute, GraphQLRequest, GraphQLResponse};
+use crate::graphql::resolver::Context;
+use crate::graphql::schema::Schema;
+use crate::handler::error::{AppError, AppResult};
+
+async fn build_schema(registry: &AppRegistry, current_user: &CurrentUser) -> AppResult<Schema> {
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+    let result = usecase.content_models(&current_user.user).await;
+
+    match result {
+        Ok(categories) => Ok(Schema::build(&categories)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/graphql",
+    request_body = GraphQLRequest,
+    responses(
+        (status = 200, description = "Query result. Field errors are reported in errors with an HTTP-style code extension. Every content model readable by the user adds a typed query, e.g. blogPost(id) and blogPostList(...) for the blog-post category, whose references resolve to nested contents.", body = GraphQLResponse),
+    ),
+    security(("bearer" = [])),
+    tag = "graphql",
+)]
+pub async fn graphql(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Json(request): Json<GraphQLRequest>,
+) -> AppResult<Json<GraphQLResponse>> {
+    let schema = build_schema(&registry, &current_user).await?;
+    let context = Context::new(registry, current_user.user);
+
+    Ok(Json(execute(&schema, &context, request).await))
+}
+
+#[utoipa::path(
+    get,
+    path = "/graphql/schema",
+    responses(
+        (status = 200, description = "Schema in SDL for the current user", body = String, content_type = "text/plain"),
+    ),
+    security(("bearer" = [])),
+    tag = "graphql",
+)]
+pub async fn graphql_schema(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+) -> AppResult<String> {
+    let schema = build_schema(&registry, &current_user).await?;
+
+    Ok(schema.to_string())
+}
@@ -0,0 +1,196 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use application::usecase::localization::{
+    ContentLocalizationUsecase, UpsertContentLocalizationInput,
+};
+use domain::model::content::ContentStatusError;
+use domain::model::field::FieldValidationError;
+use domain::model::locale::{ContentLocalization, Locales};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+fn localization_usecase(registry: &AppRegistry) -> ContentLocalizationUsecase {
+    ContentLocalizationUsecase::new(
+        registry.content_localization_repository(),
+        registry.content_repository(),
+        registry.locales(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/locales",
+    responses(
+        (status = 200, description = "Configured locales, the default locale and the fallback chains", body = Locales),
+    ),
+    security(("bearer" = [])),
+    tag = "locales"
+)]
+pub async fn get_locales(
+    State(registry): State<AppRegistry>,
+    _current_user: CurrentUser,
+) -> AppResult<Json<Locales>> {
+    Ok(Json(registry.locales()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/contents/{id}/locales",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+    ),
+    responses(
+        (status = 200, description = "Get content localizations success", body = [ContentLocalization]),
+        (status = 403, description = "Reading the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "locales"
+)]
+pub async fn get_content_localizations(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Vec<ContentLocalization>>> {
+    let usecase = localization_usecase(&registry);
+    let result = usecase.get(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct UpsertContentLocalizationJson {
+    pub fields: serde_json::Value,
+}
+
+#[utoipa::path(
+    put,
+    path = "/contents/{id}/locales/{locale}",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        ("locale" = String, Path, description = "Locale code other than the default locale"),
+    ),
+    request_body = UpsertContentLocalizationJson,
+    responses(
+        (status = 200, description = "Save content localization success. New localizations start as Draft", body = ContentLocalization),
+        (status = 400, description = "Locale is not configured or is the default locale"),
+        (status = 403, description = "Updating the content is not permitted"),
+        (status = 422, description = "Fields are not localizable or do not match the category schema", body = FieldValidationError),
+    ),
+    security(("bearer" = [])),
+    tag = "locales"
+)]
+pub async fn upsert_content_localization(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, locale)): Path<(String, String)>,
+    Json(json): Json<UpsertContentLocalizationJson>,
+) -> AppResult<Json<ContentLocalization>> {
+    let usecase = localization_usecase(&registry);
+    let input = UpsertContentLocalizationInput::new(id, locale, json.fields, current_user.id());
+    let result = usecase.upsert(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/contents/{id}/locales/{locale}",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        ("locale" = String, Path, description = "Locale code other than the default locale"),
+    ),
+    responses(
+        (status = 200, description = "Delete content localization success"),
+        (status = 400, description = "Locale is not configured or is the default locale"),
+        (status = 403, description = "Updating the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "locales"
+)]
+pub async fn delete_content_localization(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, locale)): Path<(String, String)>,
+) -> AppResult<()> {
+    let usecase = localization_usecase(&registry);
+    let result = usecase.delete(&current_user.user, id, locale).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/locales/{locale}/publish",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        ("locale" = String, Path, description = "Locale code other than the default locale"),
+    ),
+    responses(
+        (status = 200, description = "Publish content localization success", body = ContentLocalization),
+        (status = 400, description = "Locale is not configured or is the default locale"),
+        (status = 403, description = "Operation on the category is not permitted"),
+        (status = 409, description = "Localization cannot be published", body = ContentStatusError),
+    ),
+    security(("bearer" = [])),
+    tag = "locales"
+)]
+pub async fn publish_content_localization(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, locale)): Path<(String, String)>,
+) -> AppResult<Json<ContentLocalization>> {
+    let usecase = localization_usecase(&registry);
+    let result = usecase.publish(&current_user.user, id, locale).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/locales/{locale}/unpublish",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        ("locale" = String, Path, description = "Locale code other than the default locale"),
+    ),
+    responses(
+        (status = 200, description = "Unpublish content localization success", body = ContentLocalization),
+        (status = 400, description = "Locale is not configured or is the default locale"),
+        (status = 403, description = "Operation on the category is not permitted"),
+        (status = 409, description = "Localization cannot be unpublished", body = ContentStatusError),
+    ),
+    security(("bearer" = [])),
+    tag = "locales"
+)]
+pub async fn unpublish_content_localization(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, locale)): Path<(String, String)>,
+) -> AppResult<Json<ContentLocalization>> {
+    let usecase = localization_usecase(&registry);
+    let result = usecase.unpublish(&current_user.user, id, locale).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
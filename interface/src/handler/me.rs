@@ -0,0 +1,38 @@
+use axum::response::Json;
+
+use domain::model::role::Permission;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::AppResult;
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+pub struct MePermissionsJson {
+    pub user_id: String,
+    pub role_id: String,
+    pub role_name: String,
+    pub is_super_administrator: bool,
+    pub permissions: Vec<Permission>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/me/permissions",
+    responses(
+        (status = 200, description = "Get effective permissions of the current user success", body = MePermissionsJson),
+        (status = 401, description = "Session is invalid"),
+    ),
+    security(("bearer" = [])),
+    tag = "me",
+)]
+pub async fn get_my_permissions(current_user: CurrentUser) -> AppResult<Json<MePermissionsJson>> {
+    let permissions = application::policy::permissions(&current_user.user);
+    let user = current_user.user;
+
+    Ok(Json(MePermissionsJson {
+        user_id: user.id,
+        role_id: user.role.id,
+        role_name: user.role.name,
+        is_super_administrator: user.role.is_super_administrator,
+        permissions,
+    }))
+}
@@ -1,77 +1,365 @@
-use super::error::AppResult;
+use axum::{
+    extract::{Multipart, Path, Query, State},
+    http::header,
+    response::{IntoResponse, Json, Response},
+};
 
-#[derive(utoipa::IntoParams)]
-pub struct GetMediaQuery {}
+use application::usecase::media::{
+    GetMediaInput, MediaUsecase, UpdateMediaInput, UploadMediaInput,
+};
+use domain::model::media::{Media, MediaInUseError, MediaUsage};
+use domain::model::page::{Page, PageError, PageQuery};
+use domain::model::trash::TrashError;
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+pub const MAX_UPLOAD_SIZE: usize = 50 * 1024 * 1024;
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetMediaQuery {
+    #[param(example = 100)]
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
+}
+
+impl TryFrom<GetMediaQuery> for GetMediaInput {
+    type Error = PageError;
+
+    fn try_from(value: GetMediaQuery) -> Result<Self, PageError> {
+        let GetMediaQuery {
+            limit,
+            cursor,
+            total,
+        } = value;
+        let page = PageQuery::try_new(limit, cursor.as_deref(), total.unwrap_or_default())?;
+
+        Ok(Self { page })
+    }
+}
 
 #[utoipa::path(
     get,
     path = "/medias",
     params(GetMediaQuery),
     responses(
-        (status = 200, description = "Get media success", body = ())
+        (status = 200, description = "Get media success. Media are listed newest first.", body = Page<Media>),
+        (status = 400, description = "Limit or cursor is invalid"),
+        (status = 403, description = "Reading media is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "medias",
 )]
-pub fn get_media() -> AppResult<()> {
-    Ok(())
-}
+pub async fn get_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Query(query): Query<GetMediaQuery>,
+) -> AppResult<Json<Page<Media>>> {
+    let input = GetMediaInput::try_from(query)?;
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.get(&current_user.user, input).await;
 
-#[derive(utoipa::IntoParams)]
-pub struct FindMediaQuery {}
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
 
 #[utoipa::path(
     get,
     path = "/medias/{id}",
-    params(FindMediaQuery),
+    params(
+        ("id" = String, Path, description = "Media ID"),
+    ),
+    responses(
+        (status = 200, description = "Find media success", body = Media),
+        (status = 403, description = "Reading media is not permitted"),
+        (status = 404, description = "Media is not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "medias",
+)]
+pub async fn find_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Media>> {
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.find(&current_user.user, id.clone()).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Media {} is not found", id)),
+        )),
+    }
+}
+
+fn content_disposition(filename: &str) -> String {
+    let filename: String = filename
+        .chars()
+        .map(|x| match x {
+            '"' | '\\' => '_',
+            x if x.is_ascii_graphic() || x == ' ' => x,
+            _ => '_',
+        })
+        .collect();
+
+    format!("attachment; filename=\"{}\"", filename)
+}
+
+#[utoipa::path(
+    get,
+    path = "/medias/{id}/download",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+    ),
     responses(
-        (status = 200, description = "Find media success", body = ())
+        (status = 200, description = "Download media success", content_type = "application/octet-stream"),
+        (status = 403, description = "Reading media is not permitted"),
+        (status = 404, description = "Media is not found"),
     ),
+    security(("bearer" = [])),
     tag = "medias",
 )]
-pub fn find_media() -> AppResult<()> {
-    Ok(())
+pub async fn download_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Response> {
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.download(&current_user.user, id.clone()).await;
+
+    match result {
+        Ok(object) => Ok((
+            [
+                (header::CONTENT_TYPE, object.media.mime_type.clone()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    content_disposition(&object.media.filename),
+                ),
+            ],
+            object.body,
+        )
+            .into_response()),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Media {} is not found", id)),
+        )),
+    }
 }
 
+#[allow(unused)]
 #[derive(utoipa::ToSchema)]
-pub struct CreateMediaJson {}
+pub struct UploadMediaForm {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    pub alt_text: Option<String>,
+}
+
+async fn read_upload_form(mut multipart: Multipart) -> AppResult<UploadMediaInput> {
+    let mut file = None;
+    let mut alt_text = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|error| AppError::BadRequest(error.body_text()))?
+    {
+        match field.name() {
+            Some("file") => {
+                let filename = field.file_name().unwrap_or_default().to_string();
+                let mime_type = field
+                    .content_type()
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+                let body = field
+                    .bytes()
+                    .await
+                    .map_err(|error| AppError::BadRequest(error.body_text()))?;
+
+                file = Some((filename, mime_type, body.to_vec()));
+            }
+            Some("alt_text") => {
+                let value = field
+                    .text()
+                    .await
+                    .map_err(|error| AppError::BadRequest(error.body_text()))?;
+
+                alt_text = Some(value).filter(|x| !x.is_empty());
+            }
+            _ => {}
+        }
+    }
+
+    let (filename, mime_type, body) =
+        file.ok_or_else(|| AppError::BadRequest("Field file is required".into()))?;
+
+    Ok(UploadMediaInput::new(filename, mime_type, body, alt_text))
+}
 
 #[utoipa::path(
     post,
     path = "/medias",
-    request_body = CreateMediaJson,
+    request_body(content = UploadMediaForm, content_type = "multipart/form-data"),
     responses(
-        (status = 200, description = "Create media success", body = ())
+        (status = 200, description = "Upload media success", body = Media),
+        (status = 400, description = "Multipart body is malformed or has no file"),
+        (status = 403, description = "Uploading media is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "medias",
 )]
-pub fn create_media() -> AppResult<()> {
-    Ok(())
+pub async fn create_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    multipart: Multipart,
+) -> AppResult<Json<Media>> {
+    let input = read_upload_form(multipart).await?;
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.upload(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
 }
 
-#[derive(utoipa::ToSchema)]
-pub struct UpdateMediaJson {}
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct UpdateMediaJson {
+    pub filename: Option<String>,
+    pub alt_text: Option<String>,
+}
 
 #[utoipa::path(
     put,
     path = "/medias/{id}",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+    ),
     request_body = UpdateMediaJson,
     responses(
-        (status = 200, description = "Update media success", body = ())
+        (status = 200, description = "Update media success. An empty alt text clears it.", body = Media),
+        (status = 403, description = "Updating media is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "medias",
 )]
-pub fn update_media() -> AppResult<()> {
-    Ok(())
+pub async fn update_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Json(json): Json<UpdateMediaJson>,
+) -> AppResult<Json<Media>> {
+    let UpdateMediaJson { filename, alt_text } = json;
+
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let input = UpdateMediaInput::new(id, filename, alt_text);
+    let result = usecase.update(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/medias/{id}/usages",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+    ),
+    responses(
+        (status = 200, description = "Contents referencing the media", body = [MediaUsage]),
+        (status = 403, description = "Reading media is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "medias",
+)]
+pub async fn get_media_usages(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Vec<MediaUsage>>> {
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.usages(&current_user.user, id.clone()).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Media {} is not found", id)),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct DeleteMediaQuery {
+    #[serde(default)]
+    pub force: bool,
 }
 
 #[utoipa::path(
     delete,
     path = "/medias/{id}",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+        DeleteMediaQuery,
+    ),
     responses(
-        (status = 200, description = "Delete media success", body = ())
+        (status = 200, description = "Media is moved to the trash. Forcing removes the references but keeps the ids in content fields."),
+        (status = 403, description = "Deleting media is not permitted"),
+        (status = 409, description = "Media is referenced by contents", body = MediaInUseError),
     ),
+    security(("bearer" = [])),
     tag = "medias",
 )]
-pub fn delete_media() -> AppResult<()> {
-    Ok(())
+pub async fn delete_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Query(query): Query<DeleteMediaQuery>,
+) -> AppResult<()> {
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.delete(&current_user.user, id, query.force).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/medias/{id}/restore",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+    ),
+    responses(
+        (status = 200, description = "Restore media from the trash success", body = Media),
+        (status = 403, description = "Restoring media is not permitted"),
+        (status = 404, description = "Media is not in the trash", body = TrashError),
+    ),
+    security(("bearer" = [])),
+    tag = "medias",
+)]
+pub async fn restore_media(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Media>> {
+    let usecase = MediaUsecase::new(registry.media_repository());
+    let result = usecase.restore(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
@@ -1,9 +1,19 @@
+pub mod api_key;
 pub mod auth;
 pub mod category;
 pub mod content;
+pub mod delivery;
 pub mod error;
+pub mod graphql;
 pub mod health;
+pub mod localization;
+pub mod me;
 pub mod media;
+pub mod render;
+pub mod revision;
 pub mod role;
 pub mod tag;
+pub mod trash;
+pub mod upload;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Json, Response},
+};
+
+use application::usecase::render::MediaRenderUsecase;
+use domain::model::render::{RenderFit, RenderFormat, RenderOptions};
+use domain::model::upload::PresignedUrl;
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+fn build_usecase(registry: &AppRegistry) -> MediaRenderUsecase {
+    MediaRenderUsecase::new(
+        registry.media_repository(),
+        registry.media_render_repository(),
+        registry.render_signer(),
+    )
+}
+
+fn build_options(
+    w: Option<u32>,
+    h: Option<u32>,
+    fit: Option<RenderFit>,
+    format: Option<RenderFormat>,
+    q: Option<u8>,
+) -> AppResult<RenderOptions> {
+    RenderOptions::try_new(w, h, fit, format, q)
+        .map_err(|error| AppError::BadRequest(error.to_string()))
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+pub struct RenderMediaQuery {
+    #[param(example = 320)]
+    pub w: Option<u32>,
+    #[param(example = 240)]
+    pub h: Option<u32>,
+    pub fit: Option<RenderFit>,
+    pub format: Option<RenderFormat>,
+    #[param(example = 80)]
+    pub q: Option<u8>,
+    pub expires: Option<i64>,
+    pub sig: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/medias/{id}/render",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+        RenderMediaQuery,
+    ),
+    responses(
+        (status = 200, description = "Rendered image. Requests carrying expires and sig are served without authentication.", content_type = "image/*"),
+        (status = 400, description = "Render options are invalid"),
+        (status = 401, description = "Neither a bearer token nor a signature is given"),
+        (status = 403, description = "Reading media is not permitted or the signature is invalid or expired"),
+        (status = 404, description = "Media is not found"),
+        (status = 415, description = "Media is not a renderable image"),
+    ),
+    security((), ("bearer" = [])),
+    tag = "medias",
+)]
+pub async fn render_media(
+    State(registry): State<AppRegistry>,
+    current_user: Option<CurrentUser>,
+    Path(id): Path<String>,
+    Query(query): Query<RenderMediaQuery>,
+) -> AppResult<Response> {
+    let RenderMediaQuery {
+        w,
+        h,
+        fit,
+        format,
+        q,
+        expires,
+        sig,
+    } = query;
+
+    let options = build_options(w, h, fit, format, q)?;
+    let usecase = build_usecase(&registry);
+    let (result, cache_control) = match (expires, sig, current_user) {
+        (Some(expires), Some(sig), _) => (
+            usecase
+                .render_signed(id.clone(), options, expires, sig)
+                .await,
+            "public, max-age=86400",
+        ),
+        (_, _, Some(current_user)) => (
+            usecase
+                .render(&current_user.user, id.clone(), options)
+                .await,
+            "private, max-age=86400",
+        ),
+        _ => return Err(AppError::Unauthorized),
+    };
+
+    match result {
+        Ok(image) => Ok((
+            [
+                (header::CONTENT_TYPE, image.content_type),
+                (header::CACHE_CONTROL, cache_control.into()),
+            ],
+            image.body,
+        )
+            .into_response()),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Media {} is not found", id)),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+pub struct RenderUrlQuery {
+    #[param(example = 320)]
+    pub w: Option<u32>,
+    #[param(example = 240)]
+    pub h: Option<u32>,
+    pub fit: Option<RenderFit>,
+    pub format: Option<RenderFormat>,
+    #[param(example = 80)]
+    pub q: Option<u8>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/medias/{id}/render-url",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+        RenderUrlQuery,
+    ),
+    responses(
+        (status = 200, description = "Signed render path that can be requested without authentication until it expires", body = PresignedUrl),
+        (status = 400, description = "Render options are invalid"),
+        (status = 403, description = "Reading media is not permitted"),
+        (status = 404, description = "Media is not found"),
+        (status = 415, description = "Media is not a renderable image"),
+        (status = 501, description = "Render signing key is not configured"),
+    ),
+    security(("bearer" = [])),
+    tag = "medias",
+)]
+pub async fn sign_render_url(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Query(query): Query<RenderUrlQuery>,
+) -> AppResult<Json<PresignedUrl>> {
+    let RenderUrlQuery {
+        w,
+        h,
+        fit,
+        format,
+        q,
+    } = query;
+
+    let options = build_options(w, h, fit, format, q)?;
+    let usecase = build_usecase(&registry);
+    let result = usecase.sign(&current_user.user, id.clone(), options).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Media {} is not found", id)),
+        )),
+    }
+}
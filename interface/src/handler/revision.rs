@@ -0,0 +1,172 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+
+use application::usecase::revision::{ContentRevisionUsecase, GetRevisionInput};
+use domain::model::content::Content;
+use domain::model::field::FieldValidationError;
+use domain::model::revision::{ContentRevision, RevisionDiff};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetRevisionRequest {
+    #[param(example = 0)]
+    pub offset: i32,
+    #[param(example = 100)]
+    pub limit: i32,
+}
+
+impl From<GetRevisionRequest> for GetRevisionInput {
+    fn from(value: GetRevisionRequest) -> Self {
+        let GetRevisionRequest { limit, offset } = value;
+
+        Self { limit, offset }
+    }
+}
+
+fn revision_usecase(registry: &AppRegistry) -> ContentRevisionUsecase {
+    ContentRevisionUsecase::new(
+        registry.content_revision_repository(),
+        registry.content_repository(),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/contents/{id}/revisions",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        GetRevisionRequest,
+    ),
+    responses(
+        (status = 200, description = "Get content revisions success", body = [ContentRevision]),
+        (status = 403, description = "Reading the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "revisions"
+)]
+pub async fn get_content_revisions(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Query(query): Query<GetRevisionRequest>,
+) -> AppResult<Json<Vec<ContentRevision>>> {
+    let usecase = revision_usecase(&registry);
+    let input = GetRevisionInput::from(query);
+    let result = usecase.get(&current_user.user, id, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/contents/{id}/revisions/{revision}",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        ("revision" = i32, Path, description = "Revision number"),
+    ),
+    responses(
+        (status = 200, description = "Find content revision success", body = ContentRevision),
+        (status = 403, description = "Reading the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "revisions"
+)]
+pub async fn find_content_revision(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, revision)): Path<(String, i32)>,
+) -> AppResult<Json<ContentRevision>> {
+    let usecase = revision_usecase(&registry);
+    let result = usecase.find(&current_user.user, id, revision).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct DiffRevisionRequest {
+    #[param(example = 1)]
+    pub from: i32,
+    #[param(example = 2)]
+    pub to: i32,
+}
+
+#[utoipa::path(
+    get,
+    path = "/contents/{id}/revisions/diff",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        DiffRevisionRequest,
+    ),
+    responses(
+        (status = 200, description = "Diff content revisions success", body = RevisionDiff),
+        (status = 403, description = "Reading the content is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "revisions"
+)]
+pub async fn diff_content_revisions(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Query(query): Query<DiffRevisionRequest>,
+) -> AppResult<Json<RevisionDiff>> {
+    let usecase = revision_usecase(&registry);
+    let result = usecase
+        .diff(&current_user.user, id, query.from, query.to)
+        .await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/contents/{id}/revisions/{revision}/restore",
+    params(
+        ("id" = String, Path, description = "Content ID"),
+        ("revision" = i32, Path, description = "Revision number"),
+    ),
+    responses(
+        (status = 200, description = "Restore content revision success", body = Content),
+        (status = 403, description = "Updating the content is not permitted"),
+        (status = 422, description = "Revision does not match the current category schema", body = FieldValidationError),
+    ),
+    security(("bearer" = [])),
+    tag = "revisions"
+)]
+pub async fn restore_content_revision(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, revision)): Path<(String, i32)>,
+) -> AppResult<Json<Content>> {
+    let usecase = revision_usecase(&registry);
+
+    let result = usecase.restore(&current_user.user, id, revision).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
@@ -1,77 +1,209 @@
-use super::error::AppResult;
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
 
-#[derive(utoipa::IntoParams)]
-pub struct GetRoleQuery {}
+use application::usecase::role::{CreateRoleInput, GetRoleInput, RoleUsecase, UpdateRoleInput};
+use domain::model::role::{Permission, Role};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetRoleQuery {
+    #[param(example = 0)]
+    pub offset: i32,
+    #[param(example = 100)]
+    pub limit: i32,
+}
 
 #[utoipa::path(
     get,
     path = "/roles",
     params(GetRoleQuery),
     responses(
-        (status = 200, description = "Get role success", body = ())
+        (status = 200, description = "Get role success", body = [Role]),
+        (status = 403, description = "Reading roles is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "roles",
 )]
-pub fn get_role() -> AppResult<()> {
-    Ok(())
-}
+pub async fn get_roles(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Query(query): Query<GetRoleQuery>,
+) -> AppResult<Json<Vec<Role>>> {
+    let GetRoleQuery { offset, limit } = query;
+    let usecase = RoleUsecase::new(registry.role_repository());
+    let result = usecase
+        .get(&current_user.user, GetRoleInput::new(limit, offset))
+        .await;
 
-#[derive(utoipa::IntoParams)]
-pub struct FindRoleQuery {}
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
 
 #[utoipa::path(
     get,
-    path = "/roles",
-    params(FindRoleQuery),
+    path = "/roles/{id}",
+    params(
+        ("id" = String, Path, description = "Role ID"),
+    ),
     responses(
-        (status = 200, description = "Find role success", body = ())
+        (status = 200, description = "Find role success", body = Role),
+        (status = 403, description = "Reading roles is not permitted"),
+        (status = 404, description = "Role is not found"),
     ),
+    security(("bearer" = [])),
     tag = "roles",
 )]
-pub fn find_role() -> AppResult<()> {
-    Ok(())
+pub async fn find_role(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Role>> {
+    let usecase = RoleUsecase::new(registry.role_repository());
+    let result = usecase.find(&current_user.user, id.clone()).await;
+
+    match result {
+        Ok(Some(role)) => Ok(Json(role)),
+        Ok(None) => Err(AppError::EntityNotFound(format!(
+            "Role {} is not found",
+            id
+        ))),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Role {} is not found", id)),
+        )),
+    }
 }
 
-#[derive(utoipa::ToSchema)]
-pub struct CreateRoleJson {}
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct CreateRoleJson {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
+}
+
+impl From<CreateRoleJson> for CreateRoleInput {
+    fn from(json: CreateRoleJson) -> Self {
+        let CreateRoleJson {
+            name,
+            description,
+            permissions,
+        } = json;
+
+        Self {
+            name,
+            description,
+            permissions,
+        }
+    }
+}
 
 #[utoipa::path(
     post,
     path = "/roles",
     request_body = CreateRoleJson,
     responses(
-        (status = 200, description = "Create role success", body = ())
+        (status = 200, description = "Create role success", body = Role),
+        (status = 403, description = "Managing roles is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "roles",
 )]
-pub fn create_role() -> AppResult<()> {
-    Ok(())
+pub async fn create_role(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Json(json): Json<CreateRoleJson>,
+) -> AppResult<Json<Role>> {
+    let usecase = RoleUsecase::new(registry.role_repository());
+    let result = usecase
+        .create(&current_user.user, CreateRoleInput::from(json))
+        .await;
+
+    match result {
+        Ok(role) => Ok(Json(role)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
 }
 
-#[derive(utoipa::ToSchema)]
-pub struct UpdateRoleJson {}
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct UpdateRoleJson {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub permissions: Option<Vec<Permission>>,
+}
 
 #[utoipa::path(
     put,
-    path = "/roles",
+    path = "/roles/{id}",
+    params(
+        ("id" = String, Path, description = "Role ID"),
+    ),
     request_body = UpdateRoleJson,
     responses(
-        (status = 200, description = "Update role success", body = ())
+        (status = 200, description = "Update role success. Permissions are replaced when given.", body = Role),
+        (status = 403, description = "Managing roles is not permitted"),
+        (status = 409, description = "Super administrator role cannot be modified"),
     ),
+    security(("bearer" = [])),
     tag = "roles",
 )]
-pub fn update_role() -> AppResult<()> {
-    Ok(())
+pub async fn update_role(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Json(json): Json<UpdateRoleJson>,
+) -> AppResult<Json<Role>> {
+    let UpdateRoleJson {
+        name,
+        description,
+        permissions,
+    } = json;
+
+    let usecase = RoleUsecase::new(registry.role_repository());
+    let input = UpdateRoleInput::new(id, name, description, permissions);
+    let result = usecase.update(&current_user.user, input).await;
+
+    match result {
+        Ok(role) => Ok(Json(role)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
 
 #[utoipa::path(
     delete,
-    path = "/roles",
+    path = "/roles/{id}",
+    params(
+        ("id" = String, Path, description = "Role ID"),
+    ),
     responses(
-        (status = 200, description = "Delete role success", body = ())
+        (status = 200, description = "Delete role success"),
+        (status = 403, description = "Managing roles is not permitted"),
+        (status = 409, description = "Role is assigned to users or is a super administrator role"),
     ),
+    security(("bearer" = [])),
     tag = "roles",
 )]
-pub fn delete_role() -> AppResult<()> {
-    Ok(())
+pub async fn delete_role(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<()> {
+    let usecase = RoleUsecase::new(registry.role_repository());
+    let result = usecase.delete(&current_user.user, id).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
 }
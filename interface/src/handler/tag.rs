@@ -1,27 +1,64 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+
+use application::usecase::tag::{CreateTagInput, GetTagInput, TagUsecase, UpdateTagInput};
+use domain::model::page::{Page, PageQuery};
 use domain::model::tag::Tag;
+use domain::model::trash::TrashError;
+use domain::model::version::VersionConflict;
+use registry::AppRegistry;
 
-use crate::handler::error::AppResult;
+use crate::extractor::{CurrentUser, IfMatch, Versioned};
+use crate::handler::error::{AppError, AppResult};
 
-#[derive(utoipa::IntoParams, utoipa::ToSchema)]
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
 pub struct GetTagQuery {
-    #[param(example = 0)]
-    pub offset: usize,
     #[param(example = 100)]
-    pub limit: usize,
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
     pub keyword: Option<String>,
 }
 
 #[utoipa::path(
     get,
     path = "/tags",
-    params(GetTagQuery), 
+    params(GetTagQuery),
     responses(
-        (status = 200, description = "Get tag success", body = [Tag])
+        (status = 200, description = "Get tag success", body = Page<Tag>),
+        (status = 400, description = "Limit or cursor is invalid"),
+        (status = 403, description = "Reading tags is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "tags",
 )]
-pub fn get_tags() -> AppResult<()> {
-    Ok(())
+pub async fn get_tags(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Query(query): Query<GetTagQuery>,
+) -> AppResult<Json<Page<Tag>>> {
+    let GetTagQuery {
+        limit,
+        cursor,
+        total,
+        ..
+    } = query;
+    let page = PageQuery::try_new(limit, cursor.as_deref(), total.unwrap_or_default())?;
+    let usecase = TagUsecase::new(registry.tag_repository(), registry.unit_of_work());
+    let result = usecase
+        .get(&current_user.user, GetTagInput::new(page))
+        .await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
 }
 
 #[utoipa::path(
@@ -36,9 +73,10 @@ pub fn find_tag() -> AppResult<()> {
     Ok(())
 }
 
-#[derive(utoipa::ToSchema)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct CreateTagJson {
     pub name: String,
+    pub description: Option<String>,
 }
 
 #[utoipa::path(
@@ -46,40 +84,124 @@ pub struct CreateTagJson {
     path = "/tags",
     request_body = CreateTagJson,
     responses(
-        (status = 200, description = "Create tag success", body = Tag)
+        (status = 200, description = "Create tag success", body = Tag,
+            headers(("ETag" = String, description = "Version of the tag"))),
+        (status = 403, description = "Creating tags is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "tags",
 )]
-pub fn create_tag() -> AppResult<()> {
-    Ok(())
+pub async fn create_tag(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Json(json): Json<CreateTagJson>,
+) -> AppResult<Versioned<Tag>> {
+    let CreateTagJson { name, description } = json;
+    let usecase = TagUsecase::new(registry.tag_repository(), registry.unit_of_work());
+    let input = CreateTagInput::new(name, description);
+    let result = usecase.create(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
 }
 
-#[derive(utoipa::ToSchema)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct UpdateTagJson {
-    pub name: Option<String>
+    pub name: Option<String>,
+    pub description: Option<String>,
 }
 
 #[utoipa::path(
     put,
     path = "/tags/{id}",
+    params(
+        ("id" = String, Path, description = "Tag ID"),
+        ("If-Match" = String, Header, description = "ETag of the version being edited, or * to update any version"),
+    ),
     request_body = UpdateTagJson,
     responses(
-        (status = 200, description = "Update tag success", body = Tag)
+        (status = 200, description = "Update tag success", body = Tag,
+            headers(("ETag" = String, description = "Version of the updated tag"))),
+        (status = 400, description = "If-Match is neither * nor an ETag"),
+        (status = 412, description = "Tag was updated since the ETag was read, carrying its current state", body = VersionConflict),
+        (status = 428, description = "If-Match is missing"),
+        (status = 403, description = "Updating tags is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "tags",
 )]
-pub fn update_tag() -> AppResult<()> {
-    Ok(())
+pub async fn update_tag(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    IfMatch(expected_version): IfMatch,
+    Json(json): Json<UpdateTagJson>,
+) -> AppResult<Versioned<Tag>> {
+    let UpdateTagJson { name, description } = json;
+    let usecase = TagUsecase::new(registry.tag_repository(), registry.unit_of_work());
+    let input = UpdateTagInput::new(id, name, description, expected_version);
+    let result = usecase.update(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
 
 #[utoipa::path(
     delete,
     path = "/tags/{id}",
+    params(
+        ("id" = String, Path, description = "Tag ID"),
+    ),
     responses(
-        (status = 200, description = "Delete tag success")
+        (status = 200, description = "Tag is moved to the trash"),
+        (status = 403, description = "Deleting tags is not permitted"),
     ),
+    security(("bearer" = [])),
     tag = "tags",
 )]
-pub fn delete_tag() -> AppResult<()> {
-    Ok(())
+pub async fn delete_tag(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<()> {
+    let usecase = TagUsecase::new(registry.tag_repository(), registry.unit_of_work());
+    let result = usecase.delete(&current_user.user, id).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/tags/{id}/restore",
+    params(
+        ("id" = String, Path, description = "Tag ID"),
+    ),
+    responses(
+        (status = 200, description = "Restore tag from the trash success", body = Tag,
+            headers(("ETag" = String, description = "Version of the restored tag"))),
+        (status = 403, description = "Restoring tags is not permitted"),
+        (status = 404, description = "Tag is not in the trash", body = TrashError),
+    ),
+    security(("bearer" = [])),
+    tag = "tags",
+)]
+pub async fn restore_tag(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Versioned<Tag>> {
+    let usecase = TagUsecase::new(registry.tag_repository(), registry.unit_of_work());
+    let result = usecase.restore(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Versioned(value.version, value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
 }
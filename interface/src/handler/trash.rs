@@ -0,0 +1,74 @@
+use std::str::FromStr;
+
+use axum::{
+    extract::{Query, State},
+    response::Json,
+};
+
+use application::usecase::trash::TrashUsecase;
+use domain::model::page::{Page, PageQuery};
+use domain::model::trash::{TrashKind, TrashedItem};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetTrashQuery {
+    #[param(example = 100)]
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
+    #[param(example = "Content,Tag")]
+    pub kind: Option<String>,
+}
+
+fn parse_kinds(kind: Option<&str>) -> anyhow::Result<Option<Vec<TrashKind>>> {
+    kind.map(|kind| {
+        kind.split(',')
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(TrashKind::from_str)
+            .collect()
+    })
+    .transpose()
+}
+
+#[utoipa::path(
+    get,
+    path = "/trash",
+    params(GetTrashQuery),
+    responses(
+        (status = 200, description = "Get trashed records success, most recently deleted first. Contents and categories are limited to the categories the user may delete from.", body = Page<TrashedItem>),
+        (status = 400, description = "Kind, limit or cursor is invalid"),
+        (status = 403, description = "Deleting one of the given kinds is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "trash",
+)]
+pub async fn get_trash(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Query(query): Query<GetTrashQuery>,
+) -> AppResult<Json<Page<TrashedItem>>> {
+    let GetTrashQuery {
+        limit,
+        cursor,
+        total,
+        kind,
+    } = query;
+    let page = PageQuery::try_new(limit, cursor.as_deref(), total.unwrap_or_default())?;
+    let kinds =
+        parse_kinds(kind.as_deref()).map_err(|error| AppError::BadRequest(error.to_string()))?;
+    let usecase = TrashUsecase::new(registry.trash_repository());
+    let result = usecase.get(&current_user.user, page, kinds).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
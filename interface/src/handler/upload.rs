@@ -0,0 +1,172 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use application::usecase::upload::{MediaUploadUsecase, StartUploadInput, StartedUpload};
+use domain::model::media::Media;
+use domain::model::upload::{PresignedPart, PresignedUrl, UploadError, UploadSession};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+fn build_usecase(registry: &AppRegistry) -> MediaUploadUsecase {
+    MediaUploadUsecase::new(
+        registry.media_upload_repository(),
+        registry.media_repository(),
+    )
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct StartUploadJson {
+    pub filename: String,
+    pub mime_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub alt_text: Option<String>,
+}
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+pub struct StartedUploadJson {
+    pub session: UploadSession,
+    pub parts: Vec<PresignedPart>,
+}
+
+impl From<StartedUpload> for StartedUploadJson {
+    fn from(value: StartedUpload) -> Self {
+        let StartedUpload { session, parts } = value;
+
+        Self { session, parts }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/uploads",
+    request_body = StartUploadJson,
+    responses(
+        (status = 200, description = "Start upload success. Each part is uploaded with PUT to its presigned URL.", body = StartedUploadJson),
+        (status = 403, description = "Uploading media is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "uploads",
+)]
+pub async fn start_upload(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Json(json): Json<StartUploadJson>,
+) -> AppResult<Json<StartedUploadJson>> {
+    let StartUploadJson {
+        filename,
+        mime_type,
+        size,
+        checksum,
+        alt_text,
+    } = json;
+
+    let usecase = build_usecase(&registry);
+    let input = StartUploadInput::new(filename, mime_type, size, checksum, alt_text);
+    let result = usecase.start(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(StartedUploadJson::from(value))),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::BadRequest("Failed start upload".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/uploads/{id}/complete",
+    params(
+        ("id" = String, Path, description = "Upload session ID"),
+    ),
+    responses(
+        (status = 200, description = "Complete upload success", body = Media),
+        (status = 403, description = "Uploading media is not permitted"),
+        (status = 404, description = "Upload session is not found", body = UploadError),
+        (status = 409, description = "Upload session is already completed", body = UploadError),
+        (status = 410, description = "Upload session is expired", body = UploadError),
+        (status = 422, description = "Uploaded object is missing or does not match the declared size and checksum", body = UploadError),
+    ),
+    security(("bearer" = [])),
+    tag = "uploads",
+)]
+pub async fn complete_upload(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Media>> {
+    let usecase = build_usecase(&registry);
+    let result = usecase.complete(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/uploads/{id}",
+    params(
+        ("id" = String, Path, description = "Upload session ID"),
+    ),
+    responses(
+        (status = 200, description = "Abort upload success"),
+        (status = 403, description = "Uploading media is not permitted"),
+        (status = 404, description = "Upload session is not found", body = UploadError),
+        (status = 409, description = "Upload session is already completed", body = UploadError),
+    ),
+    security(("bearer" = [])),
+    tag = "uploads",
+)]
+pub async fn abort_upload(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<()> {
+    let usecase = build_usecase(&registry);
+    let result = usecase.abort(&current_user.user, id).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/medias/{id}/url",
+    params(
+        ("id" = String, Path, description = "Media ID"),
+    ),
+    responses(
+        (status = 200, description = "Time-limited download URL", body = PresignedUrl),
+        (status = 403, description = "Reading media is not permitted"),
+        (status = 404, description = "Media is not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "medias",
+)]
+pub async fn find_media_url(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<PresignedUrl>> {
+    let usecase = build_usecase(&registry);
+    let result = usecase
+        .presign_download(&current_user.user, id.clone())
+        .await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound(format!("Media {} is not found", id)),
+        )),
+    }
+}
@@ -1,14 +1,31 @@
-use super::error::AppResult;
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+
+use application::usecase::user::UserUsecase;
+use domain::model::page::Page;
+use domain::model::user::User;
+use registry::AppRegistry;
+
+use super::error::{AppError, AppResult};
+use crate::extractor::CurrentUser;
 
 #[derive(utoipa::IntoParams)]
-pub struct GetUserQuery {}
+pub struct GetUserQuery {
+    #[param(example = 100)]
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
+}
 
 #[utoipa::path(
     get,
     path = "/users",
     params(GetUserQuery), 
     responses(
-        (status = 200, description = "Get user success", body = ())
+        (status = 200, description = "Get user success", body = Page<User>)
     ),
     tag = "users",
 )]
@@ -59,3 +76,39 @@ pub fn update_user() -> AppResult<()> {
 pub fn delete_user() -> AppResult<()> {
     Ok(())
 }
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct AssignRoleJson {
+    pub role_id: String,
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/{id}/role",
+    params(
+        ("id" = String, Path, description = "User ID"),
+    ),
+    request_body = AssignRoleJson,
+    responses(
+        (status = 200, description = "Assign role success", body = User),
+        (status = 403, description = "Assigning roles is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "users",
+)]
+pub async fn assign_role(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Json(json): Json<AssignRoleJson>,
+) -> AppResult<Json<User>> {
+    let usecase = UserUsecase::new(registry.user_repository(), registry.role_repository());
+    let result = usecase
+        .assign_role(&current_user.user, id, json.role_id)
+        .await;
+
+    match result {
+        Ok(user) => Ok(Json(user)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
@@ -0,0 +1,284 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+
+use application::usecase::webhook::{
+    CreateWebhookInput, GetWebhookDeliveryInput, UpdateWebhookInput, WebhookUsecase,
+};
+use domain::model::page::{Page, PageQuery};
+use domain::model::webhook::{Webhook, WebhookDelivery, WebhookEvent};
+use registry::AppRegistry;
+
+use crate::extractor::CurrentUser;
+use crate::handler::error::{AppError, AppResult};
+
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    responses(
+        (status = 200, description = "Get webhooks success", body = [Webhook]),
+        (status = 403, description = "Reading webhooks is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn get_webhooks(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+) -> AppResult<Json<Vec<Webhook>>> {
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let result = usecase.get(&current_user.user).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = String, Path, description = "Webhook ID"),
+    ),
+    responses(
+        (status = 200, description = "Find webhook success", body = Webhook),
+        (status = 403, description = "Reading webhooks is not permitted"),
+        (status = 404, description = "Webhook is not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn find_webhook(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<Json<Webhook>> {
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let result = usecase.find(&current_user.user, id).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct CreateWebhookJson {
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    #[serde(default)]
+    pub category_ids: Vec<String>,
+    #[schema(write_only)]
+    pub secret: String,
+    pub active: Option<bool>,
+}
+
+impl From<CreateWebhookJson> for CreateWebhookInput {
+    fn from(json: CreateWebhookJson) -> Self {
+        let CreateWebhookJson {
+            url,
+            events,
+            category_ids,
+            secret,
+            active,
+        } = json;
+
+        Self {
+            url,
+            events,
+            category_ids,
+            secret,
+            active: active.unwrap_or(true),
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    request_body = CreateWebhookJson,
+    responses(
+        (status = 200, description = "Create webhook success", body = Webhook),
+        (status = 400, description = "Url, events or secret is invalid", body = domain::model::webhook::WebhookError),
+        (status = 403, description = "Creating webhooks is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn create_webhook(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Json(json): Json<CreateWebhookJson>,
+) -> AppResult<Json<Webhook>> {
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let input = CreateWebhookInput::from(json);
+    let result = usecase.create(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::CreateRecordError)),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+pub struct UpdateWebhookJson {
+    pub url: Option<String>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub category_ids: Option<Vec<String>>,
+    pub secret: Option<String>,
+    pub active: Option<bool>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = String, Path, description = "Webhook ID"),
+    ),
+    request_body = UpdateWebhookJson,
+    responses(
+        (status = 200, description = "Update webhook success", body = Webhook),
+        (status = 400, description = "Url, events or secret is invalid", body = domain::model::webhook::WebhookError),
+        (status = 403, description = "Updating webhooks is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn update_webhook(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Json(json): Json<UpdateWebhookJson>,
+) -> AppResult<Json<Webhook>> {
+    let UpdateWebhookJson {
+        url,
+        events,
+        category_ids,
+        secret,
+        active,
+    } = json;
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let input = UpdateWebhookInput::new(id, url, events, category_ids, secret, active);
+    let result = usecase.update(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(error, AppError::UpdateRecordError)),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = String, Path, description = "Webhook ID"),
+    ),
+    responses(
+        (status = 200, description = "Delete webhook success. Its delivery log is deleted too."),
+        (status = 403, description = "Deleting webhooks is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn delete_webhook(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> AppResult<()> {
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let result = usecase.delete(&current_user.user, id).await;
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => Err(AppError::domain_or(error, AppError::DeleteRecordError)),
+    }
+}
+
+#[derive(serde::Deserialize, utoipa::IntoParams, utoipa::ToSchema)]
+pub struct GetWebhookDeliveryQuery {
+    #[param(example = 100)]
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    #[param(example = true)]
+    pub total: Option<bool>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}/deliveries",
+    params(
+        ("id" = String, Path, description = "Webhook ID"),
+        GetWebhookDeliveryQuery,
+    ),
+    responses(
+        (status = 200, description = "Get webhook deliveries success, newest first", body = Page<WebhookDelivery>),
+        (status = 400, description = "Limit or cursor is invalid"),
+        (status = 403, description = "Reading webhooks is not permitted"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn get_webhook_deliveries(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path(id): Path<String>,
+    Query(query): Query<GetWebhookDeliveryQuery>,
+) -> AppResult<Json<Page<WebhookDelivery>>> {
+    let GetWebhookDeliveryQuery {
+        limit,
+        cursor,
+        total,
+    } = query;
+    let page = PageQuery::try_new(limit, cursor.as_deref(), total.unwrap_or_default())?;
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let input = GetWebhookDeliveryInput::new(id, page);
+    let result = usecase.get_deliveries(&current_user.user, input).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/webhooks/{id}/deliveries/{delivery_id}/redeliver",
+    params(
+        ("id" = String, Path, description = "Webhook ID"),
+        ("delivery_id" = String, Path, description = "Webhook delivery ID"),
+    ),
+    responses(
+        (status = 200, description = "Queue a new delivery of the same payload success", body = WebhookDelivery),
+        (status = 403, description = "Updating webhooks is not permitted"),
+        (status = 404, description = "Webhook delivery is not found"),
+    ),
+    security(("bearer" = [])),
+    tag = "webhooks",
+)]
+pub async fn redeliver_webhook(
+    State(registry): State<AppRegistry>,
+    current_user: CurrentUser,
+    Path((id, delivery_id)): Path<(String, String)>,
+) -> AppResult<Json<WebhookDelivery>> {
+    let usecase = WebhookUsecase::new(registry.webhook_repository());
+    let result = usecase.redeliver(&current_user.user, id, delivery_id).await;
+
+    match result {
+        Ok(value) => Ok(Json(value)),
+        Err(error) => Err(AppError::domain_or(
+            error,
+            AppError::EntityNotFound("".into()),
+        )),
+    }
+}
@@ -1,5 +1,12 @@
+pub mod extractor;
+pub mod graphql;
 pub mod handler;
+pub mod middleware;
 pub mod route;
+pub mod scheduler;
+
+#[cfg(test)]
+pub mod tests;
 
 use std::net::{Ipv4Addr, SocketAddr};
 
@@ -10,10 +17,23 @@ use tokio::net::TcpListener;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+use crate::route::api_key::build_api_key_routers;
+use crate::route::auth::build_auth_routers;
 use crate::route::category::build_category_routers;
 use crate::route::content::build_contents_routers;
+use crate::route::delivery::build_delivery_routers;
+use crate::route::graphql::build_graphql_routers;
 use crate::route::health::build_health_check_routers;
+use crate::route::locale::build_locale_routers;
+use crate::route::me::build_me_routers;
+use crate::route::media::build_media_routers;
+use crate::route::role::build_role_routers;
 use crate::route::swagger::ApiDoc;
+use crate::route::tag::build_tag_routers;
+use crate::route::trash::build_trash_routers;
+use crate::route::upload::build_upload_routers;
+use crate::route::user::build_user_routers;
+use crate::route::webhook::build_webhook_routers;
 
 pub struct WebApp;
 
@@ -23,6 +43,19 @@ impl WebApp {
             .merge(build_health_check_routers())
             .merge(build_contents_routers())
             .merge(build_category_routers())
+            .merge(build_locale_routers())
+            .merge(build_delivery_routers(registry.clone()))
+            .merge(build_api_key_routers())
+            .merge(build_auth_routers())
+            .merge(build_role_routers())
+            .merge(build_user_routers())
+            .merge(build_me_routers())
+            .merge(build_media_routers())
+            .merge(build_upload_routers())
+            .merge(build_tag_routers())
+            .merge(build_trash_routers())
+            .merge(build_webhook_routers())
+            .merge(build_graphql_routers())
             .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
             .with_state(registry);
 
@@ -0,0 +1,44 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+
+use application::usecase::api_key::ApiKeyUsecase;
+use registry::AppRegistry;
+
+use crate::handler::error::{AppError, AppResult};
+
+const API_KEY_HEADER: &str = "x-api-key";
+
+fn extract_token(headers: &HeaderMap) -> Option<String> {
+    if let Some(value) = headers.get(API_KEY_HEADER) {
+        return value.to_str().ok().map(String::from);
+    }
+
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(String::from)
+}
+
+pub async fn authenticate_api_key(
+    State(registry): State<AppRegistry>,
+    mut request: Request,
+    next: Next,
+) -> AppResult<Response> {
+    let token = extract_token(request.headers()).ok_or(AppError::Unauthorized)?;
+
+    let usecase = ApiKeyUsecase::new(registry.api_key_repository());
+    let api_key = usecase
+        .authenticate(token)
+        .await
+        .map_err(|_| AppError::Unauthorized)?;
+
+    request.extensions_mut().insert(api_key);
+
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,13 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::api_key::{get_api_keys, issue_api_key, revoke_api_key};
+
+pub fn build_api_key_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::get(get_api_keys).post(issue_api_key))
+        .route("/:id", routing::delete(revoke_api_key));
+
+    Router::new().nest("/api-keys", routers)
+}
@@ -0,0 +1,18 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::auth::{oidc_callback, oidc_login, refresh, signin, signout, signup, verify};
+
+pub fn build_auth_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/signup", routing::post(signup))
+        .route("/verify", routing::post(verify))
+        .route("/signin", routing::post(signin))
+        .route("/refresh", routing::post(refresh))
+        .route("/signout", routing::delete(signout))
+        .route("/oidc/login", routing::get(oidc_login))
+        .route("/oidc/callback", routing::get(oidc_callback));
+
+    Router::new().nest("/auth", routers)
+}
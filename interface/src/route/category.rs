@@ -2,7 +2,9 @@ use axum::{routing, Router};
 
 use registry::AppRegistry;
 
-use crate::handler::category::{create_category, delete_category, get_categories, update_category};
+use crate::handler::category::{
+    create_category, delete_category, get_categories, restore_category, update_category,
+};
 
 pub fn build_category_routers() -> Router<AppRegistry> {
     let routers = Router::new()
@@ -10,7 +12,8 @@ pub fn build_category_routers() -> Router<AppRegistry> {
         .route(
             "/:id",
             routing::put(update_category).delete(delete_category),
-        );
+        )
+        .route("/:id/restore", routing::post(restore_category));
 
     Router::new().nest("/categories", routers)
 }
@@ -1,12 +1,57 @@
 use axum::{routing, Router};
 use registry::AppRegistry;
 
-use crate::handler::content::{create_content, delete_content, get_contents, update_content};
+use crate::handler::content::{
+    create_content, delete_content, find_content, get_content_referrers, get_contents,
+    publish_content, restore_content, revert_content_to_draft, schedule_content, unpublish_content,
+    update_content,
+};
+use crate::handler::localization::{
+    delete_content_localization, get_content_localizations, publish_content_localization,
+    unpublish_content_localization, upsert_content_localization,
+};
+use crate::handler::revision::{
+    diff_content_revisions, find_content_revision, get_content_revisions, restore_content_revision,
+};
 
 pub fn build_contents_routers() -> Router<AppRegistry> {
     let routers = Router::new()
         .route("/", routing::get(get_contents).post(create_content))
-        .route("/:id", routing::put(update_content).delete(delete_content));
+        .route(
+            "/:id",
+            routing::get(find_content)
+                .put(update_content)
+                .delete(delete_content),
+        )
+        .route("/:id/referrers", routing::get(get_content_referrers))
+        .route("/:id/publish", routing::post(publish_content))
+        .route("/:id/unpublish", routing::post(unpublish_content))
+        .route("/:id/schedule", routing::post(schedule_content))
+        .route("/:id/draft", routing::post(revert_content_to_draft))
+        .route("/:id/restore", routing::post(restore_content))
+        .route("/:id/revisions", routing::get(get_content_revisions))
+        .route("/:id/revisions/diff", routing::get(diff_content_revisions))
+        .route(
+            "/:id/revisions/:revision",
+            routing::get(find_content_revision),
+        )
+        .route(
+            "/:id/revisions/:revision/restore",
+            routing::post(restore_content_revision),
+        )
+        .route("/:id/locales", routing::get(get_content_localizations))
+        .route(
+            "/:id/locales/:locale",
+            routing::put(upsert_content_localization).delete(delete_content_localization),
+        )
+        .route(
+            "/:id/locales/:locale/publish",
+            routing::post(publish_content_localization),
+        )
+        .route(
+            "/:id/locales/:locale/unpublish",
+            routing::post(unpublish_content_localization),
+        );
 
     Router::new().nest("/contents", routers)
 }
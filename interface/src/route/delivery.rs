@@ -0,0 +1,18 @@
+use axum::{middleware, routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::delivery::{find_delivery_content, get_delivery_contents};
+use crate::middleware::api_key::authenticate_api_key;
+
+pub fn build_delivery_routers(registry: AppRegistry) -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/:api_identifier", routing::get(get_delivery_contents))
+        .route("/:api_identifier/:id", routing::get(find_delivery_content))
+        .route_layer(middleware::from_fn_with_state(
+            registry,
+            authenticate_api_key,
+        ));
+
+    Router::new().nest("/delivery/v1", routers)
+}
@@ -0,0 +1,12 @@
+use axum::{routing, Router};
+use registry::AppRegistry;
+
+use crate::handler::graphql::{graphql, graphql_schema};
+
+pub fn build_graphql_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::post(graphql))
+        .route("/schema", routing::get(graphql_schema));
+
+    Router::new().nest("/graphql", routers)
+}
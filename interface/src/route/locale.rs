@@ -0,0 +1,9 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::localization::get_locales;
+
+pub fn build_locale_routers() -> Router<AppRegistry> {
+    Router::new().route("/locales", routing::get(get_locales))
+}
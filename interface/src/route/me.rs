@@ -0,0 +1,11 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::me::get_my_permissions;
+
+pub fn build_me_routers() -> Router<AppRegistry> {
+    let routers = Router::new().route("/permissions", routing::get(get_my_permissions));
+
+    Router::new().nest("/me", routers)
+}
@@ -0,0 +1,30 @@
+use axum::{extract::DefaultBodyLimit, routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::media::{
+    create_media, delete_media, download_media, find_media, get_media, get_media_usages,
+    restore_media, update_media, MAX_UPLOAD_SIZE,
+};
+use crate::handler::render::{render_media, sign_render_url};
+use crate::handler::upload::find_media_url;
+
+pub fn build_media_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::get(get_media).post(create_media))
+        .route(
+            "/:id",
+            routing::get(find_media)
+                .put(update_media)
+                .delete(delete_media),
+        )
+        .route("/:id/download", routing::get(download_media))
+        .route("/:id/usages", routing::get(get_media_usages))
+        .route("/:id/restore", routing::post(restore_media))
+        .route("/:id/url", routing::get(find_media_url))
+        .route("/:id/render", routing::get(render_media))
+        .route("/:id/render-url", routing::get(sign_render_url))
+        .layer(DefaultBodyLimit::max(MAX_UPLOAD_SIZE));
+
+    Router::new().nest("/medias", routers)
+}
@@ -1,4 +1,17 @@
+pub mod api_key;
+pub mod auth;
 pub mod category;
 pub mod content;
+pub mod delivery;
+pub mod graphql;
 pub mod health;
+pub mod locale;
+pub mod me;
+pub mod media;
+pub mod role;
 pub mod swagger;
+pub mod tag;
+pub mod trash;
+pub mod upload;
+pub mod user;
+pub mod webhook;
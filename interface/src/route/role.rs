@@ -0,0 +1,16 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::role::{create_role, delete_role, find_role, get_roles, update_role};
+
+pub fn build_role_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::get(get_roles).post(create_role))
+        .route(
+            "/:id",
+            routing::get(find_role).put(update_role).delete(delete_role),
+        );
+
+    Router::new().nest("/roles", routers)
+}
@@ -1,46 +1,245 @@
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 
 use crate::handler;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        handler::auth::signup,
+        handler::auth::verify,
+        handler::auth::signin,
+        handler::auth::refresh,
+        handler::auth::signout,
+        handler::auth::oidc_login,
+        handler::auth::oidc_callback,
+
         handler::category::get_categories,
         handler::category::create_category,
         handler::category::update_category,
         handler::category::delete_category,
+        handler::category::restore_category,
 
         handler::content::get_contents,
+        handler::content::find_content,
+        handler::content::get_content_referrers,
         handler::content::create_content,
         handler::content::update_content,
         handler::content::delete_content,
+        handler::content::restore_content,
+        handler::content::publish_content,
+        handler::content::unpublish_content,
+        handler::content::schedule_content,
+        handler::content::revert_content_to_draft,
+
+        handler::revision::get_content_revisions,
+        handler::revision::find_content_revision,
+        handler::revision::diff_content_revisions,
+        handler::revision::restore_content_revision,
+
+        handler::localization::get_locales,
+        handler::localization::get_content_localizations,
+        handler::localization::upsert_content_localization,
+        handler::localization::delete_content_localization,
+        handler::localization::publish_content_localization,
+        handler::localization::unpublish_content_localization,
+
+        handler::api_key::get_api_keys,
+        handler::api_key::issue_api_key,
+        handler::api_key::revoke_api_key,
+
+        handler::delivery::get_delivery_contents,
+        handler::delivery::find_delivery_content,
 
         handler::tag::get_tags,
         handler::tag::find_tag,
         handler::tag::create_tag,
         handler::tag::update_tag,
         handler::tag::delete_tag,
+        handler::tag::restore_tag,
+        handler::trash::get_trash,
+
+        handler::role::get_roles,
+        handler::role::find_role,
+        handler::role::create_role,
+        handler::role::update_role,
+        handler::role::delete_role,
+
+        handler::user::assign_role,
+
+        handler::me::get_my_permissions,
+
+        handler::media::get_media,
+        handler::media::find_media,
+        handler::media::download_media,
+        handler::media::get_media_usages,
+        handler::media::create_media,
+        handler::media::update_media,
+        handler::media::delete_media,
+        handler::media::restore_media,
+
+        handler::upload::start_upload,
+        handler::upload::complete_upload,
+        handler::upload::abort_upload,
+        handler::upload::find_media_url,
+
+        handler::render::render_media,
+        handler::render::sign_render_url,
+
+        handler::webhook::get_webhooks,
+        handler::webhook::find_webhook,
+        handler::webhook::create_webhook,
+        handler::webhook::update_webhook,
+        handler::webhook::delete_webhook,
+        handler::webhook::get_webhook_deliveries,
+        handler::webhook::redeliver_webhook,
+
+        handler::graphql::graphql,
+        handler::graphql::graphql_schema,
     ),
     components(schemas(
+        handler::auth::SignUpJson,
+        handler::auth::VerifyJson,
+        handler::auth::SignInJson,
+        handler::auth::RefreshJson,
+        handler::auth::SessionJson,
+        handler::auth::OidcCallbackRequest,
+
         domain::model::category::Category,
+        domain::model::field::Field,
+        domain::model::field::FieldType,
+        domain::model::field::FieldError,
+        domain::model::field::FieldValidationError,
         handler::category::GetCategoryQuery,
         handler::category::CreateCategoryJson,
         handler::category::UpdateCategoryJson,
 
         domain::model::content::Content,
-        handler::content::GetContentQuery,
+        handler::content::GetContentRequest,
+        handler::content::FindContentRequest,
+        domain::model::relation::ContentReferrer,
         handler::content::CreateContentJson,
         handler::content::UpdateContentJson,
+        handler::content::ScheduleContentJson,
+        domain::model::content::ContentStatusError,
+
+        domain::model::revision::ContentRevision,
+        domain::model::revision::RevisionDiff,
+        domain::model::revision::RevisionChange,
+        handler::revision::GetRevisionRequest,
+        handler::revision::DiffRevisionRequest,
+
+        domain::model::locale::Locales,
+        domain::model::locale::ContentLocalization,
+        domain::model::locale::LocaleError,
+        handler::localization::UpsertContentLocalizationJson,
+
+        domain::model::api_key::ApiKey,
+        domain::model::api_key::ApiKeyScope,
+        domain::model::api_key::ApiKeyCategory,
+        handler::api_key::GetApiKeyRequest,
+        handler::api_key::IssueApiKeyJson,
+        handler::api_key::IssuedApiKeyJson,
+
+        domain::model::delivery::DeliveryContent,
+        handler::delivery::GetDeliveryRequest,
+        handler::delivery::FindDeliveryRequest,
 
         domain::model::tag::Tag,
         handler::tag::GetTagQuery,
         handler::tag::CreateTagJson,
         handler::tag::UpdateTagJson,
+
+        domain::model::trash::TrashKind,
+        domain::model::trash::TrashedItem,
+        domain::model::trash::TrashError,
+        handler::trash::GetTrashQuery,
+
+        domain::model::role::Role,
+        domain::model::role::Permission,
+        domain::model::role::PermissionResource,
+        domain::model::role::PermissionAction,
+        handler::role::GetRoleQuery,
+        handler::role::CreateRoleJson,
+        handler::role::UpdateRoleJson,
+
+        domain::model::user::User,
+        handler::user::AssignRoleJson,
+
+        handler::me::MePermissionsJson,
+
+        domain::model::media::Media,
+        domain::model::media::MediaUploader,
+        domain::model::media::MediaUsage,
+        domain::model::media::MediaInUseError,
+        domain::model::media::MediaReference,
+        handler::media::GetMediaQuery,
+        handler::media::UploadMediaForm,
+        handler::media::UpdateMediaJson,
+        handler::media::DeleteMediaQuery,
+
+        domain::model::upload::UploadSession,
+        domain::model::upload::UploadPlan,
+        domain::model::upload::PresignedPart,
+        domain::model::upload::PresignedUrl,
+        domain::model::upload::UploadError,
+        handler::upload::StartUploadJson,
+        handler::upload::StartedUploadJson,
+
+        domain::model::render::RenderFit,
+        domain::model::render::RenderFormat,
+
+        domain::model::webhook::Webhook,
+        domain::model::webhook::WebhookEvent,
+        domain::model::webhook::WebhookError,
+        domain::model::webhook::WebhookPayload,
+        domain::model::webhook::WebhookDelivery,
+        domain::model::webhook::WebhookDeliveryStatus,
+        handler::webhook::CreateWebhookJson,
+        handler::webhook::UpdateWebhookJson,
+        handler::webhook::GetWebhookDeliveryQuery,
+
+        domain::model::version::VersionConflict,
+        domain::model::version::PreconditionError,
+
+        crate::graphql::executor::GraphQLRequest,
+        crate::graphql::executor::GraphQLResponse,
+        crate::graphql::executor::GraphQLError,
     )),
     tags(
+        (name = "auth"),
         (name = "categories"),
         (name = "contents"),
+        (name = "revisions"),
+        (name = "locales"),
+        (name = "delivery"),
+        (name = "api-keys"),
         (name = "tags"),
+        (name = "trash"),
+        (name = "roles"),
+        (name = "users"),
+        (name = "me"),
+        (name = "medias"),
+        (name = "uploads"),
+        (name = "webhooks"),
+        (name = "graphql"),
     ),
+    modifiers(&SecurityAddon),
 )]
 pub struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-KEY"))),
+        );
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
@@ -0,0 +1,14 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::tag::{create_tag, delete_tag, get_tags, restore_tag, update_tag};
+
+pub fn build_tag_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::get(get_tags).post(create_tag))
+        .route("/:id", routing::put(update_tag).delete(delete_tag))
+        .route("/:id/restore", routing::post(restore_tag));
+
+    Router::new().nest("/tags", routers)
+}
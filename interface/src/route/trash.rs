@@ -0,0 +1,11 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::trash::get_trash;
+
+pub fn build_trash_routers() -> Router<AppRegistry> {
+    let routers = Router::new().route("/", routing::get(get_trash));
+
+    Router::new().nest("/trash", routers)
+}
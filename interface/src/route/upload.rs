@@ -0,0 +1,14 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::upload::{abort_upload, complete_upload, start_upload};
+
+pub fn build_upload_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::post(start_upload))
+        .route("/:id", routing::delete(abort_upload))
+        .route("/:id/complete", routing::post(complete_upload));
+
+    Router::new().nest("/uploads", routers)
+}
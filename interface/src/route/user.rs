@@ -0,0 +1,11 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::user::assign_role;
+
+pub fn build_user_routers() -> Router<AppRegistry> {
+    let routers = Router::new().route("/:id/role", routing::put(assign_role));
+
+    Router::new().nest("/users", routers)
+}
@@ -0,0 +1,26 @@
+use axum::{routing, Router};
+
+use registry::AppRegistry;
+
+use crate::handler::webhook::{
+    create_webhook, delete_webhook, find_webhook, get_webhook_deliveries, get_webhooks,
+    redeliver_webhook, update_webhook,
+};
+
+pub fn build_webhook_routers() -> Router<AppRegistry> {
+    let routers = Router::new()
+        .route("/", routing::get(get_webhooks).post(create_webhook))
+        .route(
+            "/:id",
+            routing::get(find_webhook)
+                .put(update_webhook)
+                .delete(delete_webhook),
+        )
+        .route("/:id/deliveries", routing::get(get_webhook_deliveries))
+        .route(
+            "/:id/deliveries/:delivery_id/redeliver",
+            routing::post(redeliver_webhook),
+        );
+
+    Router::new().nest("/webhooks", routers)
+}
@@ -0,0 +1,99 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use application::usecase::outbox::{OutboxRelayUsecase, WebhookSink};
+use application::usecase::schedule::ContentScheduleUsecase;
+use application::usecase::trash::TrashPurgeUsecase;
+use application::usecase::webhook::WebhookDispatchUsecase;
+use registry::AppRegistry;
+use tokio::task::JoinHandle;
+
+pub fn spawn_periodic<F, Fut, T>(interval: Duration, mut job: F) -> JoinHandle<()>
+where
+    F: FnMut(chrono::DateTime<chrono::Utc>) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<T>> + Send,
+    T: Debug,
+{
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match job(chrono::Utc::now()).await {
+                Ok(output) => tracing::info!("{:?}", output),
+                Err(error) => tracing::error!("{:?}", error),
+            }
+        }
+    })
+}
+
+pub struct ContentScheduler;
+
+impl ContentScheduler {
+    pub fn spawn(registry: AppRegistry, interval: Duration) -> JoinHandle<()> {
+        let usecase = Arc::new(ContentScheduleUsecase::new(
+            registry.content_schedule_repository(),
+        ));
+
+        spawn_periodic(interval, move |now| {
+            let usecase = usecase.clone();
+            async move { usecase.run(now).await }
+        })
+    }
+}
+
+pub struct OutboxRelay;
+
+impl OutboxRelay {
+    pub fn spawn(registry: AppRegistry, interval: Duration) -> JoinHandle<()> {
+        let usecase = Arc::new(OutboxRelayUsecase::new(
+            registry.outbox_repository(),
+            vec![Arc::new(WebhookSink::new(registry.webhook_repository()))],
+        ));
+
+        spawn_periodic(interval, move |now| {
+            let usecase = usecase.clone();
+            async move { usecase.run(now).await }
+        })
+    }
+}
+
+pub struct WebhookDispatcher;
+
+impl WebhookDispatcher {
+    pub fn spawn(registry: AppRegistry, interval: Duration) -> JoinHandle<()> {
+        let usecase = Arc::new(WebhookDispatchUsecase::new(
+            registry.webhook_repository(),
+            registry.webhook_sender(),
+        ));
+
+        spawn_periodic(interval, move |now| {
+            let usecase = usecase.clone();
+            async move { usecase.run(now).await }
+        })
+    }
+}
+
+pub struct TrashPurger;
+
+impl TrashPurger {
+    pub fn spawn(registry: AppRegistry, interval: Duration, retention_days: i64) -> JoinHandle<()> {
+        let usecase = Arc::new(TrashPurgeUsecase::new(
+            registry.content_repository(),
+            registry.media_repository(),
+            registry.tag_repository(),
+            registry.category_repository(),
+            retention_days,
+        ));
+
+        spawn_periodic(interval, move |now| {
+            let usecase = usecase.clone();
+            async move { usecase.run(now).await }
+        })
+    }
+}
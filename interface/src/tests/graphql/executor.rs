@@ -0,0 +1,511 @@
+use serde_json::{json, Value};
+
+use application::usecase::category::CategoryUsecase;
+use domain::model::role::{Permission, PermissionAction, PermissionResource, Role};
+use domain::model::user::User;
+use infrastructure::database::connection::ConnectionPool;
+use registry::AppRegistry;
+use shared::config::{
+    AppConfig, DatabaseConfig, ImageConfig, LocaleConfig, OutboxConfig, SchedulerConfig,
+    SearchConfig, StorageConfig, TrashConfig, WebhookConfig,
+};
+
+use crate::graphql::executor::{execute, GraphQLRequest};
+use crate::graphql::resolver::Context;
+use crate::graphql::schema::Schema;
+
+fn build_config() -> AppConfig {
+    AppConfig {
+        database: DatabaseConfig::new(
+            "localhost".into(),
+            5432,
+            "app".into(),
+            "passw0rd".into(),
+            "app".into(),
+        ),
+        storage: StorageConfig::new(
+            "access_key".into(),
+            "secret_key".into(),
+            "us-east-1".into(),
+            "http://localhost:9000".into(),
+            "media".into(),
+        ),
+        image: ImageConfig::new(None),
+        scheduler: SchedulerConfig::new(30),
+        webhook: WebhookConfig::new(10, 10),
+        outbox: OutboxConfig::new(5),
+        trash: TrashConfig::new(3600, 30),
+        search: SearchConfig::new("".into()),
+        locale: LocaleConfig::new("en".into(), "".into(), "".into()),
+        oidc: None,
+    }
+}
+
+pub fn build_actor(id: &str, is_super_administrator: bool, permissions: Vec<Permission>) -> User {
+    let role = Role::try_new(
+        "role".into(),
+        "role".into(),
+        "description".into(),
+        is_super_administrator,
+        permissions,
+    )
+    .unwrap();
+
+    User::try_new(
+        id.into(),
+        "user1".into(),
+        "https://example.com/icon.png".into(),
+        role,
+    )
+    .unwrap()
+}
+
+pub async fn super_administrator(pool: &sqlx::PgPool) -> User {
+    let id = sqlx::query_scalar::<_, String>(r#"SELECT id::TEXT FROM users LIMIT 1"#)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+    build_actor(&id, true, vec![])
+}
+
+pub async fn run(pool: &sqlx::PgPool, actor: &User, query: &str, variables: Value) -> Value {
+    let connection_pool = ConnectionPool::new(pool.clone());
+    let registry = AppRegistry::with_pool(connection_pool, build_config()).unwrap();
+    let usecase = CategoryUsecase::new(registry.category_repository(), registry.unit_of_work());
+    let schema = Schema::build(&usecase.content_models(actor).await.unwrap());
+
+    let request = GraphQLRequest {
+        query: query.into(),
+        variables: variables.as_object().cloned(),
+        operation_name: None,
+    };
+    let context = Context::new(registry, actor.clone());
+    let response = execute(&schema, &context, request).await;
+
+    serde_json::to_value(response).unwrap()
+}
+
+pub async fn create_category(pool: &sqlx::PgPool, api_identifier: &str, fields: Value) -> String {
+    let actor = super_administrator(pool).await;
+    let response = run(
+        pool,
+        &actor,
+        "mutation($input: CreateCategoryInput!) { createCategory(input: $input) { id } }",
+        json!({"input": {"name": api_identifier, "apiIdentifier": api_identifier, "fields": fields}}),
+    )
+    .await;
+
+    response["data"]["createCategory"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+pub async fn create_content(
+    pool: &sqlx::PgPool,
+    category_id: &str,
+    title: &str,
+    fields: Value,
+) -> String {
+    let actor = super_administrator(pool).await;
+    let response = run(
+        pool,
+        &actor,
+        "mutation($input: CreateContentInput!) { createContent(input: $input) { id } }",
+        json!({"input": {"title": title, "categoryId": category_id, "fields": fields}}),
+    )
+    .await;
+
+    response["data"]["createContent"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+fn keys(value: &Value) -> Vec<&str> {
+    let mut keys: Vec<&str> = value
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort();
+    keys
+}
+
+fn messages(response: &Value) -> Vec<&str> {
+    response["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["message"].as_str().unwrap())
+        .collect()
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn execute_failure_with_unknown_selection(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+    let cases = [
+        (
+            "{ tags { items { id nope } } }",
+            "Cannot query field nope on type Tag",
+        ),
+        (
+            "{ tags(first: 1) { total } }",
+            "Unknown argument first on field Query.tags",
+        ),
+        (
+            "{ tags { items } }",
+            "Field items of type Tag must have a selection of subfields",
+        ),
+        (
+            "{ tags { total { id } } }",
+            "Field total of type Int has no subfields",
+        ),
+        (
+            "{ tags { ... on Nope { id } } }",
+            "Type Nope is not an object",
+        ),
+    ];
+
+    for (query, message) in cases {
+        let response = run(&pool, &actor, query, json!({})).await;
+
+        assert_eq!(
+            response,
+            json!({"errors": [{"message": message}]}),
+            "{}",
+            query
+        );
+    }
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users", "tags")
+    )
+)]
+fn execute_success_with_fragments(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+    let query = r#"
+        query {
+            tags { ...TagPage }
+        }
+
+        fragment TagPage on TagPage {
+            items { ...TagName ...CategoryName }
+            ... on TagPage { nextCursor }
+        }
+
+        fragment TagName on Tag { name }
+
+        fragment CategoryName on Category { apiIdentifier }
+    "#;
+    let response = run(&pool, &actor, query, json!({})).await;
+
+    assert!(response.get("errors").is_none());
+    let tags = &response["data"]["tags"];
+    assert_eq!(tags["nextCursor"], Value::Null);
+
+    let items = tags["items"].as_array().unwrap();
+    assert_eq!(items.len(), 4);
+    for item in items {
+        assert_eq!(keys(item), vec!["name"]);
+    }
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn execute_failure_with_invalid_fragments(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+    let query = r#"
+        query {
+            tags { ...First ...Missing }
+        }
+
+        fragment First on TagPage { ...Second }
+
+        fragment Second on TagPage { total ...First }
+    "#;
+    let response = run(&pool, &actor, query, json!({})).await;
+
+    assert!(response.get("data").is_none());
+    assert_eq!(
+        messages(&response),
+        vec![
+            "Fragment First spreads itself",
+            "Fragment Missing is not found"
+        ]
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users", "tags")
+    )
+)]
+fn execute_success_with_directives(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+    let query = r#"
+        query($show: Boolean!) {
+            skipped: __typename @skip(if: true)
+            included: __typename @include(if: $show)
+            tags(total: true) {
+                total @skip(if: $show)
+                nextCursor @include(if: false)
+                ... on TagPage @include(if: $show) { prevCursor }
+                ...Items @skip(if: false)
+            }
+        }
+
+        fragment Items on TagPage { items { id } }
+    "#;
+
+    let shown = run(&pool, &actor, query, json!({"show": true})).await;
+    assert_eq!(keys(&shown["data"]), vec!["included", "tags"]);
+    assert_eq!(shown["data"]["included"], json!("Query"));
+    assert_eq!(keys(&shown["data"]["tags"]), vec!["items", "prevCursor"]);
+
+    let hidden = run(&pool, &actor, query, json!({"show": false})).await;
+    assert_eq!(keys(&hidden["data"]), vec!["tags"]);
+    assert_eq!(keys(&hidden["data"]["tags"]), vec!["items", "total"]);
+    assert_eq!(hidden["data"]["tags"]["total"], json!(4));
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users", "tags")
+    )
+)]
+fn execute_success_with_variables(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+    let query = r#"
+        query($limit: Int = 1, $total: Boolean) {
+            tags(limit: $limit, total: $total) { items { id } total }
+        }
+    "#;
+
+    let defaulted = run(&pool, &actor, query, json!({})).await;
+    assert_eq!(
+        defaulted["data"]["tags"]["items"].as_array().unwrap().len(),
+        1
+    );
+    assert_eq!(defaulted["data"]["tags"]["total"], Value::Null);
+
+    let provided = run(&pool, &actor, query, json!({"limit": 3, "total": true})).await;
+    assert_eq!(
+        provided["data"]["tags"]["items"].as_array().unwrap().len(),
+        3
+    );
+    assert_eq!(provided["data"]["tags"]["total"], json!(4));
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn execute_failure_with_invalid_variables(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+
+    let missing = run(
+        &pool,
+        &actor,
+        "query($id: ID!) { content(id: $id) { id } }",
+        json!({"id": null}),
+    )
+    .await;
+    assert!(missing.get("data").is_none());
+    assert_eq!(messages(&missing), vec!["Variable $id is required"]);
+
+    let mismatched = run(
+        &pool,
+        &actor,
+        "query($limit: Int) { tags(limit: $limit) { total } }",
+        json!({"limit": "ten"}),
+    )
+    .await;
+    assert_eq!(mismatched["data"], Value::Null);
+    assert_eq!(mismatched["errors"][0]["path"], json!(["tags"]));
+    assert_eq!(
+        mismatched["errors"][0]["extensions"]["code"],
+        json!("BAD_REQUEST")
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn execute_propagates_root_errors(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+
+    let nullable = run(
+        &pool,
+        &actor,
+        r#"{ content(id: "missing") { id } tags { total } }"#,
+        json!({}),
+    )
+    .await;
+    assert_eq!(
+        nullable["data"],
+        json!({"content": null, "tags": {"total": null}})
+    );
+    assert_eq!(nullable["errors"][0]["path"], json!(["content"]));
+
+    let non_null = run(
+        &pool,
+        &actor,
+        r#"{ tags { total } users(limit: 0) { total } }"#,
+        json!({}),
+    )
+    .await;
+    assert_eq!(non_null["data"], Value::Null);
+    assert_eq!(non_null["errors"][0]["path"], json!(["users"]));
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn execute_drops_unreadable_references(pool: sqlx::PgPool) {
+    let secret_id = create_category(
+        &pool,
+        "secret",
+        json!([{"field_type": "Text", "key": "body"}]),
+    )
+    .await;
+    let article_id = create_category(
+        &pool,
+        "article",
+        json!([{"field_type": "Content", "key": "related", "multiple": true}]),
+    )
+    .await;
+    let secret = create_content(&pool, &secret_id, "secret", json!({"body": "hidden"})).await;
+    create_content(&pool, &article_id, "article", json!({"related": [secret]})).await;
+
+    let actor = build_actor(
+        "reader",
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Read,
+            vec![article_id],
+        )],
+    );
+    let response = run(
+        &pool,
+        &actor,
+        "{ articleList { items { title fields { related { id } } } } }",
+        json!({}),
+    )
+    .await;
+
+    assert_eq!(
+        response,
+        json!({"data": {"articleList": {"items": [{"title": "article", "fields": {"related": []}}]}}})
+    );
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn execute_limits_reference_depth(pool: sqlx::PgPool) {
+    let page_id = create_category(&pool, "page", json!([])).await;
+    let actor = super_administrator(&pool).await;
+    let updated = run(
+        &pool,
+        &actor,
+        "mutation($id: ID!, $input: UpdateCategoryInput!) { updateCategory(id: $id, input: $input) { id } }",
+        json!({
+            "id": page_id,
+            "input": {"fields": [{"field_type": "Content", "key": "parent", "categories": [page_id]}]},
+        }),
+    )
+    .await;
+    assert!(updated.get("errors").is_none());
+
+    let root = create_content(&pool, &page_id, "root", json!({})).await;
+    let child = create_content(&pool, &page_id, "child", json!({"parent": root})).await;
+    let grandchild = create_content(&pool, &page_id, "grandchild", json!({"parent": child})).await;
+    create_content(&pool, &page_id, "leaf", json!({"parent": grandchild})).await;
+
+    let allowed = run(
+        &pool,
+        &actor,
+        r#"{
+            pageList {
+                items { title fields { parent { fields { parent { fields { parent { title } } } } } } }
+            }
+        }"#,
+        json!({}),
+    )
+    .await;
+    assert!(allowed.get("errors").is_none());
+    let leaf = allowed["data"]["pageList"]["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["title"] == json!("leaf"))
+        .unwrap();
+    assert_eq!(
+        leaf["fields"]["parent"]["fields"]["parent"]["fields"]["parent"]["title"],
+        json!("root")
+    );
+
+    let exceeded = run(
+        &pool,
+        &actor,
+        r#"{
+            pageList {
+                items { fields { parent { fields { parent { fields { parent { fields { parent { title } } } } } } } } }
+            }
+        }"#,
+        json!({}),
+    )
+    .await;
+    assert_eq!(exceeded["data"], Value::Null);
+    assert_eq!(
+        exceeded["errors"],
+        json!([{"message": "References exceed maximum depth(3)", "path": ["pageList"]}])
+    );
+}
@@ -0,0 +1,3 @@
+pub mod executor;
+pub mod resolver;
+pub mod schema;
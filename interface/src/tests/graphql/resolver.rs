@@ -0,0 +1,171 @@
+use serde_json::{json, Value};
+
+use domain::model::role::{Permission, PermissionAction, PermissionResource};
+
+use crate::tests::graphql::executor::{
+    build_actor, create_category, create_content, run, super_administrator,
+};
+
+async fn count(pool: &sqlx::PgPool, table: &str) -> i64 {
+    sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM {}", table))
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn create_category_success(pool: sqlx::PgPool) {
+    let actor = super_administrator(&pool).await;
+    let response = run(
+        &pool,
+        &actor,
+        r#"mutation { createCategory(input: {name: "news", apiIdentifier: "news"}) { apiIdentifier version } }"#,
+        json!({}),
+    )
+    .await;
+
+    assert_eq!(
+        response,
+        json!({"data": {"createCategory": {"apiIdentifier": "news", "version": 1}}})
+    );
+    assert_eq!(count(&pool, "category").await, 1);
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn create_category_failure_without_permission(pool: sqlx::PgPool) {
+    let actor = build_actor(
+        "reader",
+        false,
+        vec![Permission::new(
+            PermissionResource::Categories,
+            PermissionAction::Read,
+            vec![],
+        )],
+    );
+    let response = run(
+        &pool,
+        &actor,
+        r#"mutation { createCategory(input: {name: "news", apiIdentifier: "news"}) { id } }"#,
+        json!({}),
+    )
+    .await;
+
+    assert_eq!(response["data"], Value::Null);
+    assert_eq!(response["errors"][0]["path"], json!(["createCategory"]));
+    assert_eq!(
+        response["errors"][0]["extensions"]["code"],
+        json!("FORBIDDEN")
+    );
+    assert_eq!(count(&pool, "category").await, 0);
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn create_content_respects_category_scope(pool: sqlx::PgPool) {
+    let news_id = create_category(&pool, "news", json!([])).await;
+    let blog_id = create_category(&pool, "blog", json!([])).await;
+    let user_id = super_administrator(&pool).await.id;
+    let actor = build_actor(
+        &user_id,
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Create,
+            vec![news_id.clone()],
+        )],
+    );
+    let query = r#"
+        mutation($input: CreateContentInput!) {
+            createContent(input: $input) { title category { id } }
+        }
+    "#;
+
+    let allowed = run(
+        &pool,
+        &actor,
+        query,
+        json!({"input": {"title": "news", "categoryId": news_id, "fields": {}}}),
+    )
+    .await;
+    assert_eq!(
+        allowed,
+        json!({"data": {"createContent": {"title": "news", "category": {"id": news_id}}}})
+    );
+
+    let denied = run(
+        &pool,
+        &actor,
+        query,
+        json!({"input": {"title": "blog", "categoryId": blog_id, "fields": {}}}),
+    )
+    .await;
+    assert_eq!(denied["data"], Value::Null);
+    assert_eq!(
+        denied["errors"][0]["extensions"]["code"],
+        json!("FORBIDDEN")
+    );
+    assert_eq!(count(&pool, "contents").await, 1);
+}
+
+#[tracing::instrument]
+#[sqlx::test(
+    migrations = "../infrastructure/migrations",
+    fixtures(
+        path = "../../../../infrastructure/src/tests/fixtures",
+        scripts("users")
+    )
+)]
+fn delete_content_failure_without_permission(pool: sqlx::PgPool) {
+    let category_id = create_category(&pool, "news", json!([])).await;
+    let content_id = create_content(&pool, &category_id, "news", json!({})).await;
+    let actor = build_actor(
+        "reader",
+        false,
+        vec![Permission::new(
+            PermissionResource::Contents,
+            PermissionAction::Read,
+            vec![],
+        )],
+    );
+    let response = run(
+        &pool,
+        &actor,
+        "mutation($id: ID!) { deleteContent(id: $id) }",
+        json!({"id": content_id}),
+    )
+    .await;
+
+    assert_eq!(response["data"], Value::Null);
+    assert_eq!(response["errors"][0]["path"], json!(["deleteContent"]));
+    assert_eq!(
+        response["errors"][0]["extensions"]["code"],
+        json!("FORBIDDEN")
+    );
+
+    let remaining =
+        sqlx::query_scalar::<_, i64>(r#"SELECT COUNT(*) FROM contents WHERE deleted_at IS NULL"#)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(remaining, 1);
+}
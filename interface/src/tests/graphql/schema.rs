@@ -0,0 +1,122 @@
+use domain::model::category::Category;
+use domain::model::field::{Field, FieldType};
+
+use crate::graphql::schema::{Resolver, RootField, Schema, TypeRef, QUERY};
+
+fn build_category(id: &str, api_identifier: &str, fields: Vec<Field>) -> Category {
+    Category::try_new(
+        id.into(),
+        api_identifier.into(),
+        api_identifier.into(),
+        None,
+        fields,
+    )
+    .unwrap()
+}
+
+fn build_reference(key: &str, categories: Vec<&str>, multiple: bool) -> Field {
+    let mut field = Field::new(FieldType::Content, key.into());
+    field.categories = categories.into_iter().map(String::from).collect();
+    field.multiple = multiple;
+    field
+}
+
+#[rstest::rstest]
+fn build_success() {
+    let categories = vec![build_category(
+        "blog",
+        "blog-post",
+        vec![Field::new(FieldType::Text, "body".into())],
+    )];
+    let schema = Schema::build(&categories);
+
+    assert!(schema.object("BlogPost").is_some());
+    assert!(schema.object("BlogPostPage").is_some());
+    assert_eq!(
+        schema.field("BlogPostFields", "body").unwrap().ty,
+        TypeRef::named("String")
+    );
+    assert_eq!(
+        schema.field(QUERY, "blogPost").unwrap().resolver,
+        Resolver::Root(RootField::CategoryContent("blog".into()))
+    );
+    assert_eq!(
+        schema.field(QUERY, "blogPostList").unwrap().resolver,
+        Resolver::Root(RootField::CategoryContents("blog".into()))
+    );
+    assert!(schema.to_string().contains("type BlogPost {"));
+}
+
+#[rstest::rstest]
+#[case::static_type("tag")]
+#[case::content_type("content")]
+#[case::page_type("category-page")]
+#[case::root_type("query")]
+#[case::root_field("users")]
+#[case::invalid_name("123")]
+fn build_skips_unavailable_name(#[case] api_identifier: &str) {
+    let schema = Schema::build(&[build_category("id", api_identifier, vec![])]);
+
+    let generated = schema.object(QUERY).unwrap().iter().any(|field| {
+        matches!(
+            &field.resolver,
+            Resolver::Root(RootField::CategoryContent(_) | RootField::CategoryContents(_))
+        )
+    });
+
+    assert!(!generated);
+    assert_eq!(
+        schema.field(QUERY, "users").unwrap().resolver,
+        Resolver::Root(RootField::Users)
+    );
+    assert_eq!(
+        schema.field("Tag", "description").unwrap().ty,
+        TypeRef::required("String")
+    );
+}
+
+#[rstest::rstest]
+fn build_keeps_first_of_colliding_categories() {
+    let categories = vec![
+        build_category("first", "blog-post", vec![]),
+        build_category("second", "blog_post", vec![]),
+        build_category("third", "blog-post-fields", vec![]),
+    ];
+    let schema = Schema::build(&categories);
+
+    assert_eq!(
+        schema.field(QUERY, "blogPost").unwrap().resolver,
+        Resolver::Root(RootField::CategoryContent("first".into()))
+    );
+    assert!(schema.field(QUERY, "blogPostFields").is_none());
+    assert!(schema.field(QUERY, "blogPostFieldsList").is_none());
+}
+
+#[rstest::rstest]
+fn build_types_references() {
+    let categories = vec![
+        build_category(
+            "article",
+            "article",
+            vec![
+                build_reference("author", vec!["person"], false),
+                build_reference("related", vec!["article", "person"], true),
+                build_reference("secret", vec!["hidden"], false),
+            ],
+        ),
+        build_category("person", "person", vec![]),
+    ];
+    let schema = Schema::build(&categories);
+
+    let author = schema.field("ArticleFields", "author").unwrap();
+    assert_eq!(author.ty, TypeRef::named("Person"));
+    assert_eq!(author.resolver, Resolver::Reference("author".into()));
+    assert_eq!(
+        schema.field("ArticleFields", "related").unwrap().ty,
+        TypeRef::list(TypeRef::required("Content"))
+    );
+    assert_eq!(
+        schema.field("ArticleFields", "secret").unwrap().ty,
+        TypeRef::named("Content")
+    );
+}
@@ -1,9 +1,38 @@
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 
+use anyhow::Result;
+use domain::model::locale::Locales;
+use domain::model::oidc::RoleMapping;
+use domain::model::render::RenderSigner;
+use domain::model::search::SearchLanguage;
+
+use infrastructure::database::api_key_repository::ApiKeyRepositoryImpl;
+use infrastructure::database::auth_repository::AuthRepositoryImpl;
 use infrastructure::database::category_repository::CategoryRepositoryImpl;
-use infrastructure::database::connection::connect_database_with;
+use infrastructure::database::connection::{connect_database_with, ConnectionPool};
+use infrastructure::database::content_localization_repository::ContentLocalizationRepositoryImpl;
+use infrastructure::database::content_revision_repository::ContentRevisionRepositoryImpl;
+use infrastructure::database::content_schedule_repository::ContentScheduleRepositoryImpl;
 use infrastructure::database::contents_repository::ContentRepositoryImpl;
+use infrastructure::database::delivery_repository::DeliveryRepositoryImpl;
 use infrastructure::database::health_repository::HealthCheckRepositoryImpl;
+use infrastructure::database::oidc_repository::OidcRepositoryImpl;
+use infrastructure::database::outbox_repository::OutboxRepositoryImpl;
+use infrastructure::database::role_repository::RoleRepositoryImpl;
+use infrastructure::database::tag_repository::TagRepositoryImpl;
+use infrastructure::database::trash_repository::TrashRepositoryImpl;
+use infrastructure::database::unit_of_work::UnitOfWorkFactoryImpl;
+use infrastructure::database::user_repository::UserRepositoryImpl;
+use infrastructure::database::webhook_repository::WebhookRepositoryImpl;
+use infrastructure::mailer::log_mailer::LogMailerImpl;
+use infrastructure::oidc::client::OidcClientImpl;
+use infrastructure::storage::client::StorageClient;
+use infrastructure::storage::media::MediaRepositoryImpl;
+use infrastructure::storage::render::MediaRenderRepositoryImpl;
+use infrastructure::storage::upload::MediaUploadRepositoryImpl;
+use infrastructure::webhook::sender::WebhookSenderImpl;
 use shared::config::AppConfig;
 
 #[derive(Clone)]
@@ -11,21 +40,126 @@ pub struct AppRegistry {
     health_check_repository: Arc<HealthCheckRepositoryImpl>,
     content_repository: Arc<ContentRepositoryImpl>,
     category_repository: Arc<CategoryRepositoryImpl>,
+    content_revision_repository: Arc<ContentRevisionRepositoryImpl>,
+    content_schedule_repository: Arc<ContentScheduleRepositoryImpl>,
+    content_localization_repository: Arc<ContentLocalizationRepositoryImpl>,
+    locales: Locales,
+    delivery_repository: Arc<DeliveryRepositoryImpl>,
+    api_key_repository: Arc<ApiKeyRepositoryImpl>,
+    auth_repository: Arc<AuthRepositoryImpl>,
+    mailer: Arc<LogMailerImpl>,
+    oidc_repository: Arc<OidcRepositoryImpl>,
+    oidc_client: Option<Arc<OidcClientImpl>>,
+    oidc_role_mapping: RoleMapping,
+    role_repository: Arc<RoleRepositoryImpl>,
+    tag_repository: Arc<TagRepositoryImpl>,
+    trash_repository: Arc<TrashRepositoryImpl>,
+    user_repository: Arc<UserRepositoryImpl>,
+    outbox_repository: Arc<OutboxRepositoryImpl>,
+    unit_of_work: Arc<UnitOfWorkFactoryImpl>,
+    webhook_repository: Arc<WebhookRepositoryImpl>,
+    webhook_sender: Arc<WebhookSenderImpl>,
+    media_repository: Arc<MediaRepositoryImpl>,
+    media_upload_repository: Arc<MediaUploadRepositoryImpl>,
+    media_render_repository: Arc<MediaRenderRepositoryImpl>,
+    render_signer: Option<RenderSigner>,
 }
 
 impl AppRegistry {
-    pub fn new(config: AppConfig) -> Self {
-        let pool = connect_database_with(config.database);
+    pub fn new(config: AppConfig) -> Result<Self> {
+        let pool = connect_database_with(config.database.clone());
+
+        Self::with_pool(pool, config)
+    }
 
+    pub fn with_pool(pool: ConnectionPool, config: AppConfig) -> Result<Self> {
         let health_check_repository = Arc::new(HealthCheckRepositoryImpl::new(pool.clone()));
-        let content_repository = Arc::new(ContentRepositoryImpl::new(pool.clone()));
+        let search_language = SearchLanguage::from_str(&config.search.language)?;
+        let content_repository = Arc::new(ContentRepositoryImpl::new(
+            pool.clone(),
+            search_language.clone(),
+        ));
         let category_repository = Arc::new(CategoryRepositoryImpl::new(pool.clone()));
+        let content_revision_repository =
+            Arc::new(ContentRevisionRepositoryImpl::new(pool.clone()));
+        let content_schedule_repository =
+            Arc::new(ContentScheduleRepositoryImpl::new(pool.clone()));
+        let content_localization_repository =
+            Arc::new(ContentLocalizationRepositoryImpl::new(pool.clone()));
+        let locales = Locales::try_new(
+            &config.locale.locales,
+            &config.locale.default_locale,
+            &config.locale.fallbacks,
+        )?;
+        let delivery_repository = Arc::new(DeliveryRepositoryImpl::new(pool.clone()));
+        let api_key_repository = Arc::new(ApiKeyRepositoryImpl::new(pool.clone()));
+        let auth_repository = Arc::new(AuthRepositoryImpl::new(pool.clone()));
+        let mailer = Arc::new(LogMailerImpl::new());
+        let oidc_repository = Arc::new(OidcRepositoryImpl::new(pool.clone()));
+        let oidc_role_mapping = match &config.oidc {
+            Some(oidc) => RoleMapping::from_str(&oidc.role_mapping)?
+                .with_default_role(oidc.default_role.clone()),
+            None => RoleMapping::default(),
+        };
+        let oidc_client = config.oidc.map(|oidc| Arc::new(OidcClientImpl::new(oidc)));
+        let role_repository = Arc::new(RoleRepositoryImpl::new(pool.clone()));
+        let tag_repository = Arc::new(TagRepositoryImpl::new(pool.clone()));
+        let trash_repository = Arc::new(TrashRepositoryImpl::new(pool.clone()));
+        let user_repository = Arc::new(UserRepositoryImpl::new(pool.clone()));
+        let outbox_repository = Arc::new(OutboxRepositoryImpl::new(pool.clone()));
+        let unit_of_work = Arc::new(UnitOfWorkFactoryImpl::new(pool.clone(), search_language));
+        let webhook_repository = Arc::new(WebhookRepositoryImpl::new(pool.clone()));
+        let webhook_sender = Arc::new(WebhookSenderImpl::new(
+            Duration::from_secs(config.webhook.timeout_seconds),
+            false,
+        )?);
+        let bucket = config.storage.bucket.clone();
+        let storage_client = StorageClient::new(config.storage);
+        let media_repository = Arc::new(MediaRepositoryImpl::new(
+            pool.clone(),
+            storage_client.clone(),
+            bucket.clone(),
+        ));
+        let media_upload_repository = Arc::new(MediaUploadRepositoryImpl::new(
+            pool.clone(),
+            storage_client.clone(),
+            bucket.clone(),
+        ));
+        let media_render_repository =
+            Arc::new(MediaRenderRepositoryImpl::new(storage_client, bucket));
+        let render_signer = config
+            .image
+            .signing_key
+            .map(|key| RenderSigner::new(key.into_bytes()));
 
-        Self {
+        Ok(Self {
             health_check_repository,
             content_repository,
             category_repository,
-        }
+            content_revision_repository,
+            content_schedule_repository,
+            content_localization_repository,
+            locales,
+            delivery_repository,
+            api_key_repository,
+            auth_repository,
+            mailer,
+            oidc_repository,
+            oidc_client,
+            oidc_role_mapping,
+            role_repository,
+            tag_repository,
+            trash_repository,
+            user_repository,
+            outbox_repository,
+            unit_of_work,
+            webhook_repository,
+            webhook_sender,
+            media_repository,
+            media_upload_repository,
+            media_render_repository,
+            render_signer,
+        })
     }
 
     pub fn health_check_repository(&self) -> Arc<HealthCheckRepositoryImpl> {
@@ -39,4 +173,96 @@ impl AppRegistry {
     pub fn category_repository(&self) -> Arc<CategoryRepositoryImpl> {
         self.category_repository.clone()
     }
+
+    pub fn content_revision_repository(&self) -> Arc<ContentRevisionRepositoryImpl> {
+        self.content_revision_repository.clone()
+    }
+
+    pub fn content_schedule_repository(&self) -> Arc<ContentScheduleRepositoryImpl> {
+        self.content_schedule_repository.clone()
+    }
+
+    pub fn content_localization_repository(&self) -> Arc<ContentLocalizationRepositoryImpl> {
+        self.content_localization_repository.clone()
+    }
+
+    pub fn locales(&self) -> Locales {
+        self.locales.clone()
+    }
+
+    pub fn delivery_repository(&self) -> Arc<DeliveryRepositoryImpl> {
+        self.delivery_repository.clone()
+    }
+
+    pub fn api_key_repository(&self) -> Arc<ApiKeyRepositoryImpl> {
+        self.api_key_repository.clone()
+    }
+
+    pub fn auth_repository(&self) -> Arc<AuthRepositoryImpl> {
+        self.auth_repository.clone()
+    }
+
+    pub fn mailer(&self) -> Arc<LogMailerImpl> {
+        self.mailer.clone()
+    }
+
+    pub fn oidc_repository(&self) -> Arc<OidcRepositoryImpl> {
+        self.oidc_repository.clone()
+    }
+
+    pub fn oidc_client(&self) -> Option<Arc<OidcClientImpl>> {
+        self.oidc_client.clone()
+    }
+
+    pub fn oidc_role_mapping(&self) -> RoleMapping {
+        self.oidc_role_mapping.clone()
+    }
+
+    pub fn role_repository(&self) -> Arc<RoleRepositoryImpl> {
+        self.role_repository.clone()
+    }
+
+    pub fn tag_repository(&self) -> Arc<TagRepositoryImpl> {
+        self.tag_repository.clone()
+    }
+
+    pub fn trash_repository(&self) -> Arc<TrashRepositoryImpl> {
+        self.trash_repository.clone()
+    }
+
+    pub fn user_repository(&self) -> Arc<UserRepositoryImpl> {
+        self.user_repository.clone()
+    }
+
+    pub fn outbox_repository(&self) -> Arc<OutboxRepositoryImpl> {
+        self.outbox_repository.clone()
+    }
+
+    pub fn unit_of_work(&self) -> Arc<UnitOfWorkFactoryImpl> {
+        self.unit_of_work.clone()
+    }
+
+    pub fn webhook_repository(&self) -> Arc<WebhookRepositoryImpl> {
+        self.webhook_repository.clone()
+    }
+
+    pub fn webhook_sender(&self) -> Arc<WebhookSenderImpl> {
+        self.webhook_sender.clone()
+    }
+
+    pub fn media_repository(&self) -> Arc<MediaRepositoryImpl> {
+        self.media_repository.clone()
+    }
+
+    pub fn media_upload_repository(&self) -> Arc<MediaUploadRepositoryImpl> {
+        self.media_upload_repository.clone()
+    }
+
+    pub fn media_render_repository(&self) -> Arc<MediaRenderRepositoryImpl> {
+        self.media_render_repository.clone()
+    }
+
+    pub fn render_signer(&self) -> Option<RenderSigner> {
+        self.render_signer.clone()
+    }
 }
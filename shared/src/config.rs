@@ -1,7 +1,7 @@
 use anyhow::Result;
 use derive_new::new;
 
-#[derive(new, Debug)]
+#[derive(new, Debug, Clone)]
 pub struct DatabaseConfig {
     pub host: String,
     pub port: u16,
@@ -16,11 +16,70 @@ pub struct StorageConfig {
     pub secret_key: String,
     pub region: String,
     pub endpoint: String,
+    pub bucket: String,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct SchedulerConfig {
+    pub interval_seconds: u64,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct WebhookConfig {
+    pub interval_seconds: u64,
+    pub timeout_seconds: u64,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct OutboxConfig {
+    pub interval_seconds: u64,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct TrashConfig {
+    pub interval_seconds: u64,
+    pub retention_days: i64,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct OidcConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub redirect_url: String,
+    pub role_claim: String,
+    pub role_mapping: String,
+    pub default_role: String,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct ImageConfig {
+    pub signing_key: Option<String>,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct SearchConfig {
+    pub language: String,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct LocaleConfig {
+    pub locales: String,
+    pub default_locale: String,
+    pub fallbacks: String,
 }
 
 pub struct AppConfig {
     pub database: DatabaseConfig,
     pub storage: StorageConfig,
+    pub image: ImageConfig,
+    pub scheduler: SchedulerConfig,
+    pub webhook: WebhookConfig,
+    pub outbox: OutboxConfig,
+    pub trash: TrashConfig,
+    pub search: SearchConfig,
+    pub locale: LocaleConfig,
+    pub oidc: Option<OidcConfig>,
 }
 
 impl AppConfig {
@@ -38,8 +97,79 @@ impl AppConfig {
             std::env::var("STORAGE_SECRET_KEY")?,
             std::env::var("STORAGE_REGION")?,
             std::env::var("STORAGE_ENDPOINT")?,
+            std::env::var("STORAGE_BUCKET").unwrap_or_else(|_| "media".into()),
         );
 
-        Ok(Self { database, storage })
+        let image = ImageConfig::new(
+            std::env::var("IMAGE_SIGNING_KEY")
+                .ok()
+                .filter(|value| !value.is_empty()),
+        );
+
+        let scheduler = SchedulerConfig::new(interval_seconds("SCHEDULER_INTERVAL_SECONDS", 30)?);
+
+        let webhook = WebhookConfig::new(
+            interval_seconds("WEBHOOK_INTERVAL_SECONDS", 10)?,
+            std::env::var("WEBHOOK_TIMEOUT_SECONDS")
+                .map(|value| value.parse())
+                .unwrap_or(Ok(10))?,
+        );
+
+        let outbox = OutboxConfig::new(interval_seconds("OUTBOX_INTERVAL_SECONDS", 5)?);
+
+        let trash = TrashConfig::new(
+            interval_seconds("TRASH_INTERVAL_SECONDS", 3600)?,
+            std::env::var("TRASH_RETENTION_DAYS")
+                .map(|value| value.parse())
+                .unwrap_or(Ok(30))?,
+        );
+
+        let search = SearchConfig::new(std::env::var("SEARCH_LANGUAGE").unwrap_or_default());
+
+        let locale = LocaleConfig::new(
+            std::env::var("LOCALES").unwrap_or_else(|_| "en".into()),
+            std::env::var("DEFAULT_LOCALE").unwrap_or_default(),
+            std::env::var("LOCALE_FALLBACKS").unwrap_or_default(),
+        );
+
+        let oidc = match std::env::var("OIDC_ISSUER_URL") {
+            Ok(issuer_url) if !issuer_url.is_empty() => Some(OidcConfig::new(
+                issuer_url,
+                std::env::var("OIDC_CLIENT_ID")?,
+                std::env::var("OIDC_CLIENT_SECRET")
+                    .ok()
+                    .filter(|value| !value.is_empty()),
+                std::env::var("OIDC_REDIRECT_URL")?,
+                std::env::var("OIDC_ROLE_CLAIM").unwrap_or_else(|_| "groups".into()),
+                std::env::var("OIDC_ROLE_MAPPING").unwrap_or_default(),
+                std::env::var("OIDC_DEFAULT_ROLE").unwrap_or_else(|_| "member".into()),
+            )),
+            _ => None,
+        };
+
+        Ok(Self {
+            database,
+            storage,
+            image,
+            scheduler,
+            webhook,
+            outbox,
+            trash,
+            search,
+            locale,
+            oidc,
+        })
+    }
+}
+
+fn interval_seconds(key: &str, default: u64) -> Result<u64> {
+    let seconds = std::env::var(key)
+        .map(|value| value.parse())
+        .unwrap_or(Ok(default))?;
+
+    if seconds < 1 {
+        anyhow::bail!("{} must be at least 1 second", key);
     }
+
+    Ok(seconds)
 }
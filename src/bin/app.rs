@@ -1,4 +1,7 @@
+use std::time::Duration;
+
 use anyhow::Result;
+use interface::scheduler::{ContentScheduler, OutboxRelay, TrashPurger, WebhookDispatcher};
 use interface::WebApp;
 use registry::AppRegistry;
 use shared::config::AppConfig;
@@ -6,6 +9,17 @@ use shared::config::AppConfig;
 #[tokio::main]
 async fn main() -> Result<()> {
     let config = AppConfig::new()?;
-    let registry = AppRegistry::new(config);
+    let interval = Duration::from_secs(config.scheduler.interval_seconds);
+    let webhook_interval = Duration::from_secs(config.webhook.interval_seconds);
+    let outbox_interval = Duration::from_secs(config.outbox.interval_seconds);
+    let trash_interval = Duration::from_secs(config.trash.interval_seconds);
+    let retention_days = config.trash.retention_days;
+    let registry = AppRegistry::new(config)?;
+
+    ContentScheduler::spawn(registry.clone(), interval);
+    OutboxRelay::spawn(registry.clone(), outbox_interval);
+    WebhookDispatcher::spawn(registry.clone(), webhook_interval);
+    TrashPurger::spawn(registry.clone(), trash_interval, retention_days);
+
     WebApp::run(registry).await
 }